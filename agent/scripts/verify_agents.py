@@ -7,6 +7,7 @@ sys.path.insert(0, str(Path(__file__).parent.parent))
 
 from solana_client.client import AgentRegistryClient
 from solders.pubkey import Pubkey
+from solders.system_program import ID as SYS_PROGRAM_ID
 from anchorpy import Context
 
 PROGRAM_ID = "EQ2Zv3cTDBzY1PafPz2WDoup6niUv6X8t9id4PBACL38"
@@ -53,6 +54,12 @@ async def main():
             print(f"  {name} (id={agent_id}) - Error fetching: {e}")
             continue
 
+        summary_pda, _ = client._get_audit_summary_pda(registry_pda)
+        summary = await client.get_audit_summary(registry_pda)
+        audit_index = summary["total_entries"] if summary else 0
+        entry_pda, _ = client._get_audit_entry_pda(registry_pda, audit_index)
+        risk_weights_pda, _ = client._get_risk_weights_pda()
+
         try:
             tx = await client.program.rpc["verify_agent"](
                 ctx=Context(
@@ -60,6 +67,10 @@ async def main():
                         "admin": client.keypair.pubkey(),
                         "registry": registry_pda,
                         "agent": agent_pda,
+                        "risk_weights": risk_weights_pda,
+                        "registry_audit_summary": summary_pda,
+                        "registry_audit_entry": entry_pda,
+                        "system_program": SYS_PROGRAM_ID,
                     },
                     signers=[client.keypair],
                 )
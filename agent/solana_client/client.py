@@ -63,9 +63,9 @@ class AgentRegistryClient:
 
         self.client: Optional[AsyncClient] = None
         self.program: Optional[Program] = None
-        # Local cache of next Merkle batch index per agent PDA
+        # Local cache of (epoch, next sequence) per agent PDA
         # Avoids stale RPC reads between consecutive store_merkle_audit calls
-        self._merkle_batch_cache: dict[str, int] = {}
+        self._merkle_batch_cache: dict[str, tuple[int, int]] = {}
 
     async def connect(self):
         """Connect to Solana and initialize the program"""
@@ -157,17 +157,25 @@ class AgentRegistryClient:
             self.program_id
         )
 
-    def _get_merkle_root_pda(self, agent: Pubkey, batch_index: int) -> tuple[Pubkey, int]:
+    def _get_merkle_root_pda(self, agent: Pubkey, epoch: int, sequence: int) -> tuple[Pubkey, int]:
         """Get a Merkle audit root PDA"""
         return Pubkey.find_program_address(
             [
                 b"merkle_audit",
                 bytes(agent),
-                batch_index.to_bytes(8, "little"),
+                epoch.to_bytes(8, "little"),
+                sequence.to_bytes(4, "little"),
             ],
             self.program_id
         )
 
+    def _get_merkle_root_index_pda(self, agent: Pubkey) -> tuple[Pubkey, int]:
+        """Get the recent Merkle roots index PDA for an agent"""
+        return Pubkey.find_program_address(
+            [b"merkle_root_index", bytes(agent)],
+            self.program_id
+        )
+
     def _get_audit_summary_pda(self, agent: Pubkey) -> tuple[Pubkey, int]:
         """Get the audit summary PDA for an agent"""
         return Pubkey.find_program_address(
@@ -186,6 +194,29 @@ class AgentRegistryClient:
             self.program_id
         )
 
+    def _get_config_pda(self) -> tuple[Pubkey, int]:
+        """Get the registry config PDA"""
+        return Pubkey.find_program_address([b"config"], self.program_id)
+
+    def _get_audit_policy_pda(self, agent: Pubkey) -> tuple[Pubkey, int]:
+        """Get the audit policy PDA for an agent"""
+        return Pubkey.find_program_address(
+            [b"audit_policy", bytes(agent)],
+            self.program_id
+        )
+
+    def _get_action_codes_pda(self) -> tuple[Pubkey, int]:
+        """Get the custom action code registry PDA"""
+        return Pubkey.find_program_address([b"action_codes"], self.program_id)
+
+    def _get_risk_weights_pda(self) -> tuple[Pubkey, int]:
+        """Get the audit base-risk table PDA"""
+        return Pubkey.find_program_address([b"risk_weights"], self.program_id)
+
+    def _get_auditor_registry_pda(self) -> tuple[Pubkey, int]:
+        """Get the registered auditors PDA"""
+        return Pubkey.find_program_address([b"auditors"], self.program_id)
+
     async def get_audit_summary(self, agent_pda: Pubkey) -> Optional[dict]:
         """Get the audit summary for an agent."""
         summary_pda, _ = self._get_audit_summary_pda(agent_pda)
@@ -207,6 +238,8 @@ class AgentRegistryClient:
         action_type: int,
         context_risk: int,
         details_hash: str,
+        custom_code: int = 0,
+        encrypted_details_uri: Optional[str] = None,
     ) -> str:
         """
         Log an audit entry on-chain.
@@ -216,6 +249,8 @@ class AgentRegistryClient:
             action_type: ActionType enum index (4=ChallengePassed, 9=Custom)
             context_risk: Risk score 0-100
             details_hash: SHA256 hash of audit details (64 hex chars)
+            custom_code: Registered action code when action_type is Custom (9)
+            encrypted_details_uri: Where details encrypted for the agent's audit readers live
 
         Returns:
             Transaction signature
@@ -235,18 +270,29 @@ class AgentRegistryClient:
             0: AT.AgentRegistered, 1: AT.AgentUpdated, 2: AT.AgentVerified,
             3: AT.ChallengeCreated, 4: AT.ChallengePassed, 5: AT.ChallengeFailed,
             6: AT.ReputationIncreased, 7: AT.ReputationDecreased,
-            8: AT.SecurityAlert, 9: AT.Custom,
+            8: AT.SecurityAlert, 10: AT.ReputationMigrated,
         }
-        action_type_arg = action_type_map.get(action_type, AT.Custom)()
+        if action_type in action_type_map:
+            action_type_arg = action_type_map[action_type]()
+        else:
+            action_type_arg = AT.Custom((custom_code,))
 
         tx = await self.program.rpc["log_audit"](
             action_type_arg,
             context_risk,
             details_hash,
+            encrypted_details_uri,
             ctx=Context(
                 accounts={
                     "actor": self.keypair.pubkey(),
                     "agent": agent_pda,
+                    "config": self._get_config_pda()[0],
+                    "audit_policy": self._get_audit_policy_pda(agent_pda)[0],
+                    "action_codes": self._get_action_codes_pda()[0],
+                    "risk_weights": self._get_risk_weights_pda()[0],
+                    # No auditor co-signature (program id stands in for None)
+                    "auditor": self.program_id,
+                    "auditor_registry": self._get_auditor_registry_pda()[0],
                     "audit_summary": summary_pda,
                     "audit_entry": entry_pda,
                     "system_program": SYS_PROGRAM_ID,
@@ -715,6 +761,8 @@ class AgentRegistryClient:
                 "total_batches": summary.total_batches,
                 "total_entries": summary.total_entries,
                 "last_batch_at": summary.last_batch_at,
+                "current_epoch": summary.current_epoch,
+                "epoch_sequence": summary.epoch_sequence,
             }
         except Exception as e:
             logger.debug(f"No Merkle summary found: {e}")
@@ -725,30 +773,37 @@ class AgentRegistryClient:
         agent_pda: str,
         merkle_root: list[int],
         entries_count: int,
+        epoch: Optional[int] = None,
     ) -> str:
         """
         Store a Merkle audit root on-chain.
 
-        Uses local batch index cache to avoid stale RPC reads between
-        consecutive stores (Solana RPC can return cached/stale data).
+        Roots are keyed by (agent, epoch, sequence). The epoch defaults to the
+        current Solana epoch. Uses a local sequence cache to avoid stale RPC
+        reads between consecutive stores (Solana RPC can return cached/stale data).
         """
         agent_pubkey = Pubkey.from_string(agent_pda)
 
-        # Use cached batch index if available, otherwise read from chain
-        if agent_pda in self._merkle_batch_cache:
-            batch_index = self._merkle_batch_cache[agent_pda]
-            logger.info(f"store_merkle_audit: using cached batch_index={batch_index}")
+        if epoch is None:
+            epoch = (await self.client.get_epoch_info()).value.epoch
+
+        # Use cached sequence if available, otherwise read from chain
+        cached = self._merkle_batch_cache.get(agent_pda)
+        if cached and cached[0] == epoch:
+            sequence = cached[1]
+            logger.info(f"store_merkle_audit: using cached sequence={sequence}")
         else:
             summary = await self.get_merkle_summary(agent_pubkey)
-            batch_index = summary["total_batches"] if summary else 0
-            logger.info(f"store_merkle_audit: read on-chain batch_index={batch_index}")
+            same_epoch = summary and summary["current_epoch"] == epoch
+            sequence = summary["epoch_sequence"] if same_epoch else 0
+            logger.info(f"store_merkle_audit: read on-chain sequence={sequence}")
 
         summary_pda, _ = self._get_merkle_summary_pda(agent_pubkey)
-        root_pda, _ = self._get_merkle_root_pda(agent_pubkey, batch_index)
+        root_pda, _ = self._get_merkle_root_pda(agent_pubkey, epoch, sequence)
 
         logger.info(
             f"store_merkle_audit: agent_pda={agent_pda}, "
-            f"batch_index={batch_index}, root_pda={root_pda}, "
+            f"epoch={epoch}, sequence={sequence}, root_pda={root_pda}, "
             f"entries_count={entries_count}"
         )
 
@@ -757,35 +812,43 @@ class AgentRegistryClient:
         tx = await self.program.rpc["store_merkle_audit"](
             list(root_bytes),  # [u8; 32]
             entries_count,
+            epoch,
             ctx=Context(
                 accounts={
                     "owner": self.keypair.pubkey(),
                     "agent": agent_pubkey,
                     "audit_summary": summary_pda,
                     "audit_root": root_pda,
+                    "root_index": self._get_merkle_root_index_pda(agent_pubkey)[0],
                     "system_program": SYS_PROGRAM_ID,
                 },
                 signers=[self.keypair],
             )
         )
 
-        # On success, cache the next batch index
-        self._merkle_batch_cache[agent_pda] = batch_index + 1
-        logger.info(f"Merkle audit root stored: batch={batch_index}, entries={entries_count}, tx={tx}")
+        # On success, cache the next sequence
+        self._merkle_batch_cache[agent_pda] = (epoch, sequence + 1)
+        logger.info(
+            f"Merkle audit root stored: epoch={epoch}, sequence={sequence}, "
+            f"entries={entries_count}, tx={tx}"
+        )
         return str(tx)
 
-    async def get_merkle_root(self, agent_pda: Pubkey, batch_index: int) -> Optional[dict]:
+    async def get_merkle_root(
+        self, agent_pda: Pubkey, epoch: int, sequence: int
+    ) -> Optional[dict]:
         """
         Get a specific Merkle audit root.
 
         Args:
             agent_pda: The agent's PDA
-            batch_index: The batch index
+            epoch: The audit epoch the batch was posted in
+            sequence: The batch's position within the epoch
 
         Returns:
             Merkle root dict or None
         """
-        root_pda, _ = self._get_merkle_root_pda(agent_pda, batch_index)
+        root_pda, _ = self._get_merkle_root_pda(agent_pda, epoch, sequence)
 
         try:
             root = await self.program.account["MerkleAuditRoot"].fetch(root_pda)
@@ -795,6 +858,8 @@ class AgentRegistryClient:
                 "entries_count": root.entries_count,
                 "timestamp": root.timestamp,
                 "batch_index": root.batch_index,
+                "epoch": root.epoch,
+                "sequence": root.sequence,
             }
         except Exception as e:
             logger.debug(f"Merkle root not found: {e}")
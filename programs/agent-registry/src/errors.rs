@@ -14,6 +14,9 @@ pub enum RegistryError {
     #[msg("Agent is already verified")]
     AlreadyVerified,
 
+    #[msg("Verification fee is below the registry minimum")]
+    VerificationFeeTooLow,
+
     #[msg("Unauthorized: only owner can update agent")]
     Unauthorized,
 
@@ -41,12 +44,39 @@ pub enum RegistryError {
     #[msg("Response hash must be 64 characters (SHA256 hex)")]
     InvalidResponseHash,
 
+    #[msg("Evidence URI is too long (max 128 characters)")]
+    EvidenceUriTooLong,
+
     #[msg("Challenge has expired")]
     ChallengeExpired,
 
     #[msg("Challenge is not pending")]
     ChallengeNotPending,
 
+    #[msg("Signer's agent reputation is below the required minimum for this action")]
+    ReputationTooLow,
+
+    #[msg("Challenger agent must be a different agent controlled by the challenger")]
+    InvalidChallengerAgent,
+
+    #[msg("Peer result already recorded or challenge has no challenger agent")]
+    PeerResultNotRecordable,
+
+    #[msg("Peer result must be recorded before closing the challenge")]
+    PeerResultUnrecorded,
+
+    #[msg("Challenge has no domain result to record")]
+    DomainResultNotRecordable,
+
+    #[msg("Challenge has no stake to slash")]
+    StakeNotSlashable,
+
+    #[msg("Agent stake must be slashed before closing the challenge")]
+    StakeSlashPending,
+
+    #[msg("Deadline extension must be positive and within the allowed lifetime")]
+    InvalidDeadlineExtension,
+
     #[msg("Challenge does not match the agent")]
     ChallengeMismatch,
 
@@ -63,6 +93,438 @@ pub enum RegistryError {
     #[msg("Audit summary not found for this agent")]
     AuditSummaryNotFound,
 
+    #[msg("Merkle proof does not match the audit root")]
+    InvalidMerkleProof,
+
+    #[msg("Merkle proof is too long")]
+    MerkleProofTooLong,
+
+    #[msg("Audit batch is empty or exceeds the maximum size")]
+    InvalidAuditBatch,
+
+    #[msg("Audit batch account is full")]
+    AuditBatchFull,
+
+    #[msg("Audit summary is already at the current layout")]
+    AuditSummaryAlreadyUpgraded,
+
+    #[msg("Audit policy values are out of range")]
+    InvalidAuditPolicy,
+
+    #[msg("Actor is not allowed by the agent's audit policy")]
+    ActorNotAllowed,
+
+    #[msg("Incident occurrence time must be in the past")]
+    InvalidIncidentReport,
+
+    #[msg("Custom action code is not registered")]
+    UnknownActionCode,
+
+    #[msg("Action code name must be 1-32 characters and base risk 0-100")]
+    InvalidActionCode,
+
+    #[msg("Action code registry is full")]
+    ActionCodeRegistryFull,
+
+    #[msg("Risk weights must be 0-100")]
+    InvalidRiskWeights,
+
+    #[msg("Audit entry is already redacted")]
+    AuditEntryRedacted,
+
+    #[msg("Only the agent's sentinel authority may log high-risk audit entries")]
+    SentinelRequired,
+
+    #[msg("Sentinel authority must differ from the agent owner and operator")]
+    InvalidSentinelAuthority,
+
+    #[msg("No finished audit day is waiting to be finalized")]
+    AuditDayNotFinished,
+
+    #[msg("Digest day does not match the next audit day to finalize")]
+    AuditDayMismatch,
+
+    #[msg("Daily digest Merkle root must not be zero")]
+    InvalidDigestRoot,
+
+    #[msg("CPI audit actor is not the calling program's audit actor PDA")]
+    InvalidCpiActor,
+
+    #[msg("Invalid compressed audit tree account or parameters")]
+    InvalidAuditTree,
+
+    #[msg("Compressed audit tree is full")]
+    AuditTreeFull,
+
+    #[msg("Too many registered auditors")]
+    TooManyAuditors,
+
+    #[msg("Co-signer is not a registered auditor")]
+    AuditorNotRegistered,
+
+    #[msg("Audit entries must be supplied in order from the next uncounted index")]
+    InvalidAuditRange,
+
+    #[msg("Too many audit readers")]
+    TooManyAuditReaders,
+
+    #[msg("Audit reader needs a non-zero encryption key")]
+    InvalidAuditReader,
+
+    #[msg("Encrypted details URI too long")]
+    DetailsUriTooLong,
+
+    #[msg("Sentinel heartbeat is not overdue")]
+    HeartbeatNotMissed,
+
     #[msg("Challenge is still pending (must be resolved before closing)")]
     ChallengeStillPending,
+
+    #[msg("Challenger's reclaim window is still open")]
+    ReclaimWindowOpen,
+
+    #[msg("Challenge is not awaiting a reveal")]
+    ChallengeNotResponded,
+
+    #[msg("Reveal window has closed")]
+    RevealWindowClosed,
+
+    #[msg("Challenge must be resolved by its verifier")]
+    VerifierResolutionRequired,
+
+    #[msg("Signer is not the challenge verifier")]
+    NotChallengeVerifier,
+
+    #[msg("Verifier panel is empty, too large, or has duplicate keys")]
+    InvalidVerifierSet,
+
+    #[msg("Quorum must be between 1 and the number of verifiers")]
+    InvalidQuorum,
+
+    #[msg("Verifier has already attested this challenge")]
+    AlreadyAttested,
+
+    #[msg("Oracle resolution is not enabled in the registry config")]
+    OracleDisabled,
+
+    #[msg("Account is not a valid Switchboard feed for this challenge")]
+    InvalidOracleFeed,
+
+    #[msg("Oracle feed authority is not trusted by the registry")]
+    OracleAuthorityMismatch,
+
+    #[msg("Oracle result predates the agent response")]
+    OracleResultStale,
+
+    #[msg("Challenge must be answered with a zero-knowledge proof")]
+    ZkProofRequired,
+
+    #[msg("Zero-knowledge proof failed verification")]
+    InvalidZkProof,
+
+    #[msg("Batch is empty or has an incomplete account group")]
+    EmptyBatch,
+
+    #[msg("Batch has too many accounts")]
+    BatchTooLarge,
+
+    #[msg("Batch accounts must be in strictly ascending key order")]
+    UnsortedBatch,
+
+    #[msg("Epoch does not match the current epoch")]
+    InvalidEpoch,
+
+    #[msg("Escrow account has insufficient lamports")]
+    InsufficientEscrow,
+
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+
+    #[msg("Difficulty must be between 1 and the configured maximum")]
+    InvalidDifficulty,
+
+    #[msg("Config value is out of the allowed range")]
+    InvalidConfigValue,
+
+    #[msg("Challenge rate limit reached for this agent or challenger")]
+    ChallengeRateLimited,
+
+    #[msg("Reputation update limit reached for this agent in the current window")]
+    ReputationRateLimited,
+
+    #[msg("Challenger is not allowed to challenge this agent")]
+    ChallengerNotAllowed,
+
+    #[msg("Too many ACL entries (max 16)")]
+    TooManyAclEntries,
+
+    #[msg("Score must be between 0 and 100")]
+    InvalidScore,
+
+    #[msg("Verifier program is not allowlisted")]
+    VerifierProgramNotAllowed,
+
+    #[msg("Too many allowlisted verifier programs")]
+    TooManyVerifierPrograms,
+
+    #[msg("Too many authorized reputation updaters")]
+    TooManyUpdaters,
+
+    #[msg("Verifier program returned no valid score")]
+    InvalidVerifierProgramResult,
+
+    #[msg("Payload does not match the committed response hash")]
+    PayloadMismatch,
+
+    #[msg("Invalid rounds: too many, bad commitment, question too long, or non commit-reveal mode")]
+    InvalidRounds,
+
+    #[msg("Metadata URI must be 1-128 chars with a 64 hex char content hash")]
+    InvalidMetadata,
+
+    #[msg("Too many tags (max 4)")]
+    TooManyTags,
+
+    #[msg("Question does not match the template question hash")]
+    TemplateQuestionMismatch,
+
+    // Dispute Errors
+    #[msg("Only passed or failed challenges can be disputed")]
+    ChallengeNotDisputable,
+
+    #[msg("Challenge verdict has already been disputed")]
+    AlreadyDisputed,
+
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+
+    #[msg("Dispute window is still open")]
+    DisputeWindowOpen,
+
+    #[msg("Only the agent owner or challenger can dispute")]
+    NotDisputeParty,
+
+    #[msg("Reason hash must be 64 hex characters (SHA256)")]
+    InvalidReasonHash,
+
+    // Misbehavior Report Errors
+    #[msg("Evidence hash must be 64 hex characters (SHA256)")]
+    InvalidEvidenceHash,
+
+    #[msg("Report penalty or slash exceeds the allowed maximum")]
+    InvalidReportPenalty,
+
+    #[msg("Only the registry admin or a verified verifier can resolve reports")]
+    NotReportResolver,
+
+    // Open Challenge Errors
+    #[msg("Open challenge is not accepting this action in its current status")]
+    OpenChallengeWrongStatus,
+
+    #[msg("Open challenge is still accepting responses")]
+    ResponsePeriodActive,
+
+    #[msg("Claim window has closed")]
+    ClaimWindowClosed,
+
+    #[msg("Claim window is still open")]
+    ClaimWindowOpen,
+
+    #[msg("Response does not match the revealed commitment")]
+    IncorrectResponse,
+
+    #[msg("An earlier correct response has already claimed")]
+    NotEarliestResponse,
+
+    #[msg("Winner or recipient account is missing or does not match")]
+    InvalidPayoutAccount,
+
+    #[msg("Open challenge must be finalized before closing responses")]
+    OpenChallengeNotFinalized,
+
+    // Token Reward Errors
+    #[msg("Reward amount must be greater than 0")]
+    InvalidRewardAmount,
+
+    #[msg("Challenge already has a token reward")]
+    TokenRewardAlreadyFunded,
+
+    #[msg("Challenge has no token reward to settle")]
+    NoTokenReward,
+
+    #[msg("Reward mint does not match the challenge")]
+    RewardMintMismatch,
+
+    #[msg("Token reward must be settled before closing the challenge")]
+    TokenRewardUnsettled,
+
+    // Agent Profile Errors
+    #[msg("Description hash must be 64 hex characters (SHA256)")]
+    InvalidDescriptionHash,
+
+    #[msg("Links string is too long (max 256 characters)")]
+    LinksTooLong,
+
+    #[msg("Avatar URI is too long (max 128 characters)")]
+    AvatarUriTooLong,
+
+    #[msg("Jurisdiction code is too long (max 8 characters)")]
+    JurisdictionTooLong,
+
+    #[msg("Agent account is already at the current layout version")]
+    AgentAlreadyUpgraded,
+
+    #[msg("Agent is retired (reputation migrated to a successor)")]
+    AgentRetired,
+
+    #[msg("Agent is suspended by the audit circuit breaker")]
+    AgentSuspended,
+
+    #[msg("Agent is on probation and cannot take tasks or answer open challenges")]
+    AgentOnProbation,
+
+    #[msg("Agent is not suspended")]
+    AgentNotSuspended,
+
+    #[msg("Reputation can only migrate to a different, freshly registered agent")]
+    InvalidMigration,
+
+    // Leaderboard Errors
+    #[msg("Agent reputation does not beat the lowest leaderboard entry")]
+    BelowLeaderboardMinimum,
+
+    // Stake Errors
+    #[msg("Stake amount must be greater than 0")]
+    InvalidStakeAmount,
+
+    #[msg("Stake position is unbonding")]
+    StakeUnbonding,
+
+    #[msg("Stake is locked until the unbonding period has elapsed")]
+    StakeLocked,
+
+    #[msg("Token accounts do not match the stake vault's denomination")]
+    StakeMintMismatch,
+
+    #[msg("Not a verifier-confirmed challenge failure or Critical audit entry of this agent")]
+    InvalidSlashTrigger,
+
+    #[msg("Stake vault has no stakers to reward")]
+    NoStakers,
+
+    #[msg("Stake vault was depleted while positions remain; deposits are closed")]
+    StakeVaultDepleted,
+
+    #[msg("No staking rewards to claim")]
+    NoRewardsToClaim,
+
+    // Treasury Governance Errors
+    #[msg("Invalid treasury council (members, threshold or timelock)")]
+    InvalidTreasuryCouncil,
+
+    #[msg("Signer is not a treasury council member")]
+    NotCouncilMember,
+
+    #[msg("Replacing the treasury council needs its current threshold of member signatures")]
+    CouncilApprovalRequired,
+
+    #[msg("Spend amount must be greater than 0")]
+    InvalidSpendAmount,
+
+    #[msg("Council member already approved this spend")]
+    SpendAlreadyApproved,
+
+    #[msg("Spend does not have enough council approvals")]
+    SpendNotApproved,
+
+    #[msg("Spend timelock has not elapsed yet")]
+    SpendTimelocked,
+
+    #[msg("Treasury balance is insufficient for this spend")]
+    InsufficientTreasury,
+
+    #[msg("Token accounts do not match the spend's mint or recipient")]
+    SpendMintMismatch,
+
+    // Insurance Errors
+    #[msg("Premium and claim amounts must be greater than 0")]
+    InvalidInsuranceAmount,
+
+    #[msg("Agent was not insured when it was slashed")]
+    InsuranceNotCovered,
+
+    #[msg("Insurance payout exceeds the claimed amount")]
+    InsurancePayoutTooHigh,
+
+    #[msg("Claim must reference a task, stream or challenge between the claimant and the agent")]
+    InsuranceInteractionMismatch,
+
+    // Endorsement Errors
+    #[msg("Only a verified, active agent not on probation can endorse an independent active agent")]
+    InvalidEndorsement,
+
+    #[msg("Endorsement stake is below the minimum")]
+    EndorsementStakeTooLow,
+
+    #[msg("Endorsement is locked until the lock period has elapsed")]
+    EndorsementLocked,
+
+    #[msg("Endorsed agent logged critical-risk entries; the endorsement must be clawed back")]
+    EndorsementClawbackPending,
+
+    #[msg("Endorsed agent logged no critical-risk entries since the endorsement")]
+    EndorsementNotClawable,
+
+    // Reputation Schema Errors
+    #[msg("Reputation curve must be non-decreasing and within the reputation range")]
+    InvalidReputationCurve,
+
+    #[msg("Reputation schema has no room for more curves")]
+    ReputationSchemaFull,
+
+    #[msg("Agent reputation is already on the current schema version")]
+    ReputationAlreadyRecalibrated,
+
+    // Reputation Snapshot Errors
+    #[msg("Reputation was already snapshotted this epoch")]
+    SnapshotAlreadyTaken,
+
+    // Owner Recovery Errors
+    #[msg("NFT account does not match the agent identity NFT")]
+    NftMismatch,
+
+    #[msg("Signer is not the current holder of the identity NFT")]
+    NotNftHolder,
+
+    #[msg("New owner must differ from the current owner")]
+    InvalidNewOwner,
+
+    #[msg("Recovery delay has not elapsed yet")]
+    RecoveryDelayNotElapsed,
+
+    // Task Marketplace Errors
+    #[msg("Task payment must be greater than 0 and its deadline in the future")]
+    InvalidTaskParams,
+
+    #[msg("Task is not in the required state for this action")]
+    InvalidTaskStatus,
+
+    #[msg("Task deadline has not passed")]
+    TaskNotOverdue,
+
+    #[msg("Task deadline has passed")]
+    TaskDeadlinePassed,
+
+    #[msg("Only the client can settle the task before the review window ends")]
+    TaskReviewPending,
+
+    #[msg("Token accounts do not match the task's payment mint")]
+    TaskMintMismatch,
+
+    // Payment Stream Errors
+    #[msg("Stream rate must be greater than 0 and its window in the future and non-empty")]
+    InvalidStreamParams,
+
+    #[msg("Nothing has vested since the last withdrawal")]
+    StreamNothingVested,
 }
@@ -0,0 +1,630 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ActionType, ChallengeKind, IncidentClass, IncidentSeverity, PriceUnit, RedactionReason,
+    RegistryAction, ReportCategory, SlashTrigger, TaskOutcome,
+};
+
+/// Emitted when an NFT holder requests owner recovery for an agent
+#[event]
+pub struct RecoveryRequested {
+    pub agent: Pubkey,
+    pub requester: Pubkey,
+    pub current_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub executable_at: i64,
+}
+
+/// Emitted when the current owner cancels a pending recovery
+#[event]
+pub struct RecoveryCancelled {
+    pub agent: Pubkey,
+    pub owner: Pubkey,
+}
+
+/// Emitted when a recovery is finalized and the owner is reassigned
+#[event]
+pub struct AgentRecovered {
+    pub agent: Pubkey,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a challenge verdict is disputed
+#[event]
+pub struct ChallengeDisputed {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub disputant: Pubkey,
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the arbitrator settles a dispute
+#[event]
+pub struct DisputeArbitrated {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub overturned: bool,
+    pub reputation_score: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a misbehavior report is filed against an agent
+#[event]
+pub struct AgentReported {
+    pub report: Pubkey,
+    pub agent: Pubkey,
+    pub reporter: Pubkey,
+    pub category: ReportCategory,
+    pub evidence_hash: String,
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a misbehavior report is upheld or dismissed
+#[event]
+pub struct ReportResolved {
+    pub report: Pubkey,
+    pub agent: Pubkey,
+    pub resolver: Pubkey,
+    pub upheld: bool,
+    pub penalty: u32,
+    pub slashed: u64,
+    pub reputation_score: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when an agent enters or leaves probation
+#[event]
+pub struct ProbationChanged {
+    pub agent: Pubkey,
+    pub on_probation: bool,
+    pub reputation_score: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when reward lamports are added to an agent's stake vault
+/// (challenge fee share or `fund_stake_rewards`)
+#[event]
+pub struct StakeRewardsFunded {
+    pub agent: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub reward_per_share: u128,
+    pub timestamp: i64,
+}
+
+/// Emitted when a staker claims staking rewards
+#[event]
+pub struct StakeRewardsClaimed {
+    pub agent: Pubkey,
+    pub staker: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the treasury council is set or replaced
+#[event]
+pub struct TreasuryCouncilSet {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timelock_secs: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a council member proposes a treasury spend
+#[event]
+pub struct SpendProposed {
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub memo_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a council member approves a treasury spend
+#[event]
+pub struct SpendApproved {
+    pub index: u64,
+    pub member: Pubkey,
+    pub approvals: u8,
+    /// Set once the threshold is reached (0 = still below it)
+    pub approved_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a treasury spend is paid out
+#[event]
+pub struct SpendExecuted {
+    pub index: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the proposer withdraws a treasury spend
+#[event]
+pub struct SpendCancelled {
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when slash_agent slashes an agent's stake
+#[event]
+pub struct AgentSlashed {
+    pub agent: Pubkey,
+    pub trigger: SlashTrigger,
+    /// The failed challenge or Critical audit entry
+    pub subject: Pubkey,
+    pub amount: u64,
+    pub affected_party: Pubkey,
+    pub affected_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the audit circuit breaker suspends an agent
+#[event]
+pub struct AgentSuspended {
+    pub agent: Pubkey,
+    pub critical_alerts: u32,
+    pub window_start: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin lifts an audit suspension
+#[event]
+pub struct AgentReinstated {
+    pub agent: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a serious incident is reported for an agent
+#[event]
+pub struct IncidentReported {
+    pub incident: Pubkey,
+    pub agent: Pubkey,
+    pub reporter: Pubkey,
+    pub incident_index: u32,
+    pub class: IncidentClass,
+    pub severity: IncidentSeverity,
+    pub affected_parties: u32,
+    pub occurred_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted for every audit entry logged on-chain (AuditEntry PDA, ring or packed batch)
+#[event]
+pub struct AuditLogged {
+    pub agent: Pubkey,
+    pub actor: Pubkey,
+    pub action_type: ActionType,
+    pub risk_score: u8,
+    pub index: u64,
+    pub is_alert: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when an agent anchors a Merkle root of off-chain audit entries
+#[event]
+pub struct MerkleAuditStored {
+    pub agent: Pubkey,
+    pub actor: Pubkey,
+    pub audit_root: Pubkey,
+    pub batch_index: u64,
+    pub epoch: u64,
+    pub sequence: u32,
+    pub entries_count: u32,
+    pub merkle_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when an audit entry is proven to be part of a Merkle audit batch
+#[event]
+pub struct AuditInclusionVerified {
+    pub audit_root: Pubkey,
+    pub agent: Pubkey,
+    pub leaf_hash: [u8; 32],
+    pub batch_index: u64,
+    pub verified_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an audit entry's details are redacted
+#[event]
+pub struct AuditEntryRedacted {
+    pub audit_entry: Pubkey,
+    pub agent: Pubkey,
+    pub audit_index: u64,
+    pub reason: RedactionReason,
+    pub link_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when an audit entry is appended to an agent's compressed audit tree
+/// Carries what indexers need to rebuild the leaf (the entry's chain link hash)
+#[event]
+pub struct AuditTreeAppended {
+    pub agent: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub leaf_index: u64,
+    pub audit_index: u64,
+    pub leaf: [u8; 32],
+    pub details_hash: [u8; 32],
+    pub risk_weights_version: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when recompute_audit_summary corrects drifted summary statistics
+#[event]
+pub struct AuditSummaryDiscrepancy {
+    pub agent: Pubkey,
+    pub total_entries: u64,
+    pub old_avg_risk: u8,
+    pub new_avg_risk: u8,
+    pub old_max_risk: u8,
+    pub new_max_risk: u8,
+    pub action_counts_corrected: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a whole-history audit checkpoint is taken
+#[event]
+pub struct AuditCheckpointed {
+    pub checkpoint: Pubkey,
+    pub agent: Pubkey,
+    pub index: u32,
+    pub entry_count: u64,
+    pub chain_head: [u8; 32],
+    pub merkle_root: [u8; 32],
+    pub created_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an agent's monitoring sentinel is flagged for a missed heartbeat
+#[event]
+pub struct HeartbeatMissed {
+    pub agent: Pubkey,
+    pub last_beat_at: i64,
+    pub interval_secs: u32,
+    pub missed_beats: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when an admin action is written to the registry audit stream
+#[event]
+pub struct RegistryActionLogged {
+    pub registry: Pubkey,
+    pub admin: Pubkey,
+    pub action: RegistryAction,
+    /// Agent, config or collection the action applied to
+    pub subject: Pubkey,
+    pub index: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a finished audit day is rolled up into a DailyDigest
+#[event]
+pub struct AuditDayFinalized {
+    pub digest: Pubkey,
+    pub agent: Pubkey,
+    pub day: u32,
+    pub end_day: u32,
+    pub entry_count: u32,
+    pub first_index: u64,
+    pub max_risk: u8,
+    pub avg_risk: u8,
+    pub merkle_root: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when an audit entry account is closed after being proven into a Merkle root
+#[event]
+pub struct AuditEntryArchived {
+    pub audit_entry: Pubkey,
+    pub agent: Pubkey,
+    pub audit_index: u64,
+    pub audit_root: Pubkey,
+    pub batch_index: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a challenge is created against an agent
+#[event]
+pub struct ChallengeCreated {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub challenger: Pubkey,
+    pub nonce: u64,
+    pub bond: u64,
+    pub reward: u64,
+    pub difficulty: u8,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a challenge receives its final verdict
+#[event]
+pub struct ChallengeResolved {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub challenger: Pubkey,
+    pub nonce: u64,
+    pub passed: bool,
+    pub score: u8,
+    pub reputation_delta: i32,
+    pub reputation_score: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a stale challenge is expired (agent silent) or abandoned (challenger silent)
+#[event]
+pub struct ChallengeExpired {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub challenger: Pubkey,
+    pub nonce: u64,
+    pub abandoned: bool,
+    pub reputation_delta: i32,
+    pub reputation_score: u32,
+    pub bounty: u64,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a challenger closes a finished challenge and reclaims its rent
+#[event]
+pub struct ChallengeClosed {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub challenger: Pubkey,
+    pub nonce: u64,
+    pub score: u8,
+    pub reputation_delta: i32,
+    pub reclaimed: u64,
+    pub timestamp: i64,
+}
+
+/// Why an agent's reputation score changed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReputationChangeReason {
+    /// A challenge verdict (source = challenge)
+    ChallengeResolved,
+    /// One round of a multi-round challenge (source = challenge)
+    ChallengeRound,
+    /// The agent let a challenge expire unanswered (source = challenge)
+    ChallengeExpired,
+    /// A verdict was disputed and its delta withheld (source = challenge)
+    DisputeOpened,
+    /// The arbitrator rejected a dispute and the withheld delta was restored (source = challenge)
+    DisputeRejected,
+    /// The arbitrator overturned a verdict (source = challenge)
+    DisputeOverturned,
+    /// The agent won an open challenge (source = open challenge)
+    OpenChallengeWon,
+    /// Carried over from a retired agent by migrate_reputation (source = old agent)
+    Migration,
+    /// Penalty from an upheld misbehavior report (source = report)
+    ReportUpheld,
+    /// Bonus granted by an endorsement (source = endorser agent)
+    Endorsement,
+    /// Endorsement bonus removed when the endorser revoked it (source = endorser agent)
+    EndorsementRevoked,
+    /// Endorsement bonus clawed back or endorser penalized after a critical-risk entry
+    /// (source = the other agent of the endorsement)
+    EndorsementClawedBack,
+    /// Mapped to a new scale by recalibrate_reputation (source = reputation schema)
+    Recalibration,
+    /// Direct update_reputation call (source = admin or authorized updater)
+    AdminUpdate,
+    /// Paid for a marketplace task (source = task)
+    TaskPaid,
+}
+
+/// Emitted on every reputation score change, so history can be rebuilt from events
+#[event]
+pub struct ReputationChanged {
+    pub agent: Pubkey,
+    pub old: u32,
+    pub new: u32,
+    pub reason: ReputationChangeReason,
+    pub source: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner escrows a fee to request verification
+#[event]
+pub struct VerificationRequested {
+    pub agent: Pubkey,
+    pub requester: Pubkey,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a verification request is serviced or rejected
+#[event]
+pub struct VerificationRequestClosed {
+    pub agent: Pubkey,
+    pub requester: Pubkey,
+    /// Admin or verifier who handled the request
+    pub handled_by: Pubkey,
+    /// true = agent verified and fee claimed, false = rejected and fee refunded
+    pub verified: bool,
+    pub fee: u64,
+    pub reason_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when an agent owner pays an insurance premium
+#[event]
+pub struct InsurancePremiumPaid {
+    pub agent: Pubkey,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub total_premiums: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a harmed client files a claim against an insured agent
+#[event]
+pub struct InsuranceClaimFiled {
+    pub claim: Pubkey,
+    pub agent: Pubkey,
+    pub claimant: Pubkey,
+    pub slash_record: Pubkey,
+    pub interaction: Pubkey,
+    pub amount: u64,
+    pub evidence_hash: [u8; 32],
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an insurance claim is approved or denied
+#[event]
+pub struct InsuranceClaimArbitrated {
+    pub claim: Pubkey,
+    pub agent: Pubkey,
+    pub claimant: Pubkey,
+    pub resolver: Pubkey,
+    pub approved: bool,
+    pub paid_out: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a client posts and funds a task
+#[event]
+pub struct TaskPosted {
+    pub task: Pubkey,
+    pub client: Pubkey,
+    pub payment: u64,
+    pub mint: Pubkey,
+    pub spec_hash: [u8; 32],
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a client assigns a task to an agent
+#[event]
+pub struct TaskAssigned {
+    pub task: Pubkey,
+    pub agent: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an agent submits a task result
+#[event]
+pub struct TaskResultSubmitted {
+    pub task: Pubkey,
+    pub agent: Pubkey,
+    pub result_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a client disputes a task result
+#[event]
+pub struct TaskDisputed {
+    pub task: Pubkey,
+    pub agent: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a task is settled and closed
+#[event]
+pub struct TaskSettled {
+    pub task: Pubkey,
+    pub client: Pubkey,
+    /// Assigned agent (default if cancelled before assignment)
+    pub agent: Pubkey,
+    pub outcome: TaskOutcome,
+    pub payment: u64,
+    pub settled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner publishes or updates a service listing
+#[event]
+pub struct ServiceListingUpdated {
+    pub listing: Pubkey,
+    pub agent: Pubkey,
+    pub listing_id: u16,
+    pub capability: ChallengeKind,
+    pub price_lamports: u64,
+    pub unit: PriceUnit,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner removes a service listing
+#[event]
+pub struct ServiceListingClosed {
+    pub listing: Pubkey,
+    pub agent: Pubkey,
+    pub listing_id: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when a client opens a payment stream to an agent
+#[event]
+pub struct StreamCreated {
+    pub stream: Pubkey,
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub rate_per_sec: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub total: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an agent owner withdraws vested stream funds
+#[event]
+pub struct StreamWithdrawn {
+    pub stream: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+    /// Total withdrawn so far
+    pub withdrawn: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payment stream is cancelled and its balance split
+#[event]
+pub struct StreamCancelled {
+    pub stream: Pubkey,
+    pub client: Pubkey,
+    pub agent: Pubkey,
+    pub paid_to_agent: u64,
+    pub refunded: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a reveal does not open the commitment and the bond is slashed
+#[event]
+pub struct ChallengeRevealRejected {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub challenger: Pubkey,
+    pub nonce: u64,
+    pub slashed: u64,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a challenger withdraws a challenge before the agent responds
+#[event]
+pub struct ChallengeCancelled {
+    pub challenge: Pubkey,
+    pub agent: Pubkey,
+    pub challenger: Pubkey,
+    pub nonce: u64,
+    pub refunded: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AgentAccount, RegistryConfig, Task, TaskOutcome, TaskStatus};
+use crate::errors::RegistryError;
+use crate::events::TaskSettled;
+use crate::utils::{credit_task_to_agent, release_task_payment, TokenMint};
+
+/// Accept a submitted task result, releasing the payment to the agent owner
+///
+/// The client can accept at any time; once `Task::REVIEW_WINDOW_SECS` have
+/// passed without a dispute, anyone can. The agent earns reputation credit and
+/// the task is closed, its rent returned to the client. SPL tasks pay out of
+/// the task token account (transfer-hook accounts in remaining accounts),
+/// which is then closed to the client.
+#[derive(Accounts)]
+pub struct AcceptTaskResult<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        close = client,
+        seeds = [Task::SEED_PREFIX, task.client.as_ref(), task.nonce.to_le_bytes().as_ref()],
+        bump = task.bump,
+        constraint = task.status == TaskStatus::Submitted @ RegistryError::InvalidTaskStatus
+    )]
+    pub task: Account<'info, Task>,
+
+    /// CHECK: Task client receiving the rent (validated against task.client)
+    #[account(mut, address = task.client @ RegistryError::Unauthorized)]
+    pub client: UncheckedAccount<'info>,
+
+    #[account(mut, address = task.agent @ RegistryError::Unauthorized)]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner receiving the payment (validated against agent.owner)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// Registry config (reputation change cap)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Payment mint (SPL tasks only; writable so withheld transfer fees can be harvested)
+    #[account(mut)]
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Token account owned by the task PDA holding the payment (SPL tasks only)
+    #[account(mut)]
+    pub task_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Agent owner's token account receiving the payment (SPL tasks only)
+    #[account(mut, token::authority = agent_owner)]
+    pub agent_owner_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptTaskResult<'info>>) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let task = &ctx.accounts.task;
+    let clock = Clock::get()?;
+    require!(
+        caller == task.client
+            || clock.unix_timestamp >= task.submitted_at + Task::REVIEW_WINDOW_SECS,
+        RegistryError::TaskReviewPending
+    );
+
+    release_task_payment(
+        task,
+        &ctx.accounts.agent_owner.to_account_info(),
+        ctx.accounts.task_token_account.as_ref(),
+        ctx.accounts.agent_owner_token_account.as_ref(),
+        TokenMint::from_accounts(
+            ctx.accounts.payment_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.remaining_accounts,
+        ),
+        &ctx.accounts.client.to_account_info(),
+    )?;
+    credit_task_to_agent(
+        task,
+        &mut ctx.accounts.agent,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    emit!(TaskSettled {
+        task: task.key(),
+        client: task.client,
+        agent: task.agent,
+        outcome: TaskOutcome::Accepted,
+        payment: task.payment,
+        settled_by: caller,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Task {} accepted: {} (mint {}) to agent id={}",
+        task.nonce,
+        task.payment,
+        task.mint,
+        ctx.accounts.agent.agent_id
+    );
+
+    Ok(())
+}
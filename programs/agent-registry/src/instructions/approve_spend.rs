@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{SpendProposal, TreasuryCouncil};
+use crate::errors::RegistryError;
+use crate::events::SpendApproved;
+
+/// Approve a proposed treasury spend (council member only)
+/// The timelock starts when the approvals reach the council threshold, and
+/// restarts if approvals from departed members had dropped it below
+#[derive(Accounts)]
+pub struct ApproveSpend<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [TreasuryCouncil::SEED_PREFIX],
+        bump = council.bump,
+        constraint = council.is_member(&member.key()) @ RegistryError::NotCouncilMember
+    )]
+    pub council: Account<'info, TreasuryCouncil>,
+
+    #[account(
+        mut,
+        seeds = [SpendProposal::SEED_PREFIX, proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, SpendProposal>,
+}
+
+pub fn handler(ctx: Context<ApproveSpend>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let council = &ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.approvals.contains(&member), RegistryError::SpendAlreadyApproved);
+
+    let clock = Clock::get()?;
+    // Drop approvals from keys that have since left the council to stay within max_len
+    proposal.approvals.retain(|key| council.is_member(key));
+    proposal.approvals.push(member);
+    let approvals = proposal.approvals.len();
+    if approvals < council.threshold as usize {
+        proposal.approved_at = 0;
+    } else if proposal.approved_at == 0 {
+        proposal.approved_at = clock.unix_timestamp;
+    }
+
+    emit!(SpendApproved {
+        index: proposal.index,
+        member,
+        approvals: approvals as u8,
+        approved_at: proposal.approved_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Treasury spend #{} approved by {} ({}/{})",
+        proposal.index,
+        member,
+        approvals,
+        council.threshold
+    );
+
+    Ok(())
+}
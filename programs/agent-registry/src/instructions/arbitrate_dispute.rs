@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, Challenge, ChallengeDispute, ChallengeStatus, ChallengerStats, RegistryConfig,
+    RegistryState,
+};
+use crate::errors::RegistryError;
+use crate::events::{DisputeArbitrated, ReputationChangeReason};
+use crate::utils::{emit_reputation_changed, enforce_probation, transfer_from_pda};
+
+/// Settle a disputed challenge (registry admin acts as arbitrator)
+///
+/// If `overturn` is true the verdict is flipped: the original reputation delta
+/// and counters are reverted, the opposite verdict is applied and the bond is
+/// refunded to the disputant. Otherwise the original verdict stands and the
+/// bond goes to the counterparty.
+///
+/// Arbitration only corrects the verdict's reputation, counters and status.
+/// The challenge escrow (bond and reward) was already paid out by
+/// `release_challenge_escrow` at resolution and stays where it went, even when
+/// the verdict is overturned; only the dispute bond follows the outcome.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ArbitrateDispute<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// The challenged agent (reputation corrected on overturn)
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The disputed challenge
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.status == ChallengeStatus::Disputed @ RegistryError::ChallengeNotDisputable
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Dispute record (closed, rent and any remaining bond to the disputant)
+    #[account(
+        mut,
+        close = disputant,
+        seeds = [ChallengeDispute::SEED_PREFIX, challenge.key().as_ref()],
+        bump = dispute.bump
+    )]
+    pub dispute: Account<'info, ChallengeDispute>,
+
+    /// CHECK: Disputant wallet (validated against dispute.disputant)
+    #[account(mut, address = dispute.disputant @ RegistryError::Unauthorized)]
+    pub disputant: UncheckedAccount<'info>,
+
+    /// CHECK: The other party (challenger if the agent owner disputed, agent owner otherwise)
+    #[account(mut)]
+    pub counterparty: UncheckedAccount<'info>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+
+    /// Registry config (probation floor)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<ArbitrateDispute>, _nonce: u64, overturn: bool) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+    let max_change = ctx.accounts.config.max_reputation_change_per_day;
+
+    let expected_counterparty = if dispute.disputant == agent.owner {
+        challenge.challenger
+    } else {
+        agent.owner
+    };
+    require_keys_eq!(
+        ctx.accounts.counterparty.key(),
+        expected_counterparty,
+        RegistryError::Unauthorized
+    );
+
+    let was_passed = dispute.original_status == ChallengeStatus::Passed;
+
+    // The challenger loses if the agent's dispute is upheld or their own is rejected
+    let stats = &mut ctx.accounts.challenger_stats;
+    if overturn == (dispute.disputant == agent.owner) {
+        stats.disputes_lost = stats.disputes_lost.saturating_add(1);
+    }
+
+    let old_reputation = agent.reputation_score;
+    challenge.unfreeze_delta(agent, clock.unix_timestamp);
+    if overturn {
+        // Reputation only: the released challenge escrow is not moved back
+        challenge.revert_verdict(agent, was_passed, clock.unix_timestamp);
+        challenge.apply_verdict(agent, !was_passed, clock.unix_timestamp, max_change);
+        stats.record_overturn(was_passed);
+        emit_reputation_changed(
+            agent.key(),
+            old_reputation,
+            agent.reputation_score,
+            ReputationChangeReason::DisputeOverturned,
+            challenge.key(),
+            clock.unix_timestamp,
+        );
+    } else {
+        challenge.status = dispute.original_status;
+        emit_reputation_changed(
+            agent.key(),
+            old_reputation,
+            agent.reputation_score,
+            ReputationChangeReason::DisputeRejected,
+            challenge.key(),
+            clock.unix_timestamp,
+        );
+
+        // Dispute rejected: bond compensates the counterparty
+        let bond = dispute.bond;
+        dispute.bond = 0;
+        transfer_from_pda(
+            &dispute.to_account_info(),
+            &ctx.accounts.counterparty.to_account_info(),
+            bond,
+        )?;
+    }
+
+    enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
+    emit!(DisputeArbitrated {
+        challenge: challenge.key(),
+        agent: agent.key(),
+        overturned: overturn,
+        reputation_score: agent.reputation_score,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Dispute on challenge {} {}. Agent {} reputation: {}",
+        challenge.key(),
+        if overturn { "UPHELD (verdict overturned)" } else { "REJECTED" },
+        agent.agent_id,
+        agent.reputation_score
+    );
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    InsuranceClaim, InsurancePolicy, InsurancePool, RegistryState, Treasury, VerifiedVerifier,
+};
+use crate::errors::RegistryError;
+use crate::events::InsuranceClaimArbitrated;
+use crate::utils::transfer_from_pda;
+
+/// Settle an insurance claim (registry admin or verified verifier)
+///
+/// If `approved` is true the claimant gets `payout` (at most the claimed
+/// amount, capped by the agent's remaining cover and the pool balance) and the
+/// bond back. Otherwise the bond goes to the treasury. Either way the claim is
+/// closed and its rent returned to the claimant.
+#[derive(Accounts)]
+pub struct ArbitrateInsuranceClaim<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// CHECK: Resolver's verified verifier record (may not exist = not verified)
+    #[account(
+        seeds = [VerifiedVerifier::SEED_PREFIX, resolver.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: UncheckedAccount<'info>,
+
+    /// Claim record (closed, rent and a refunded bond to the claimant)
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [
+            InsuranceClaim::SEED_PREFIX,
+            claim.slash_record.as_ref(),
+            claim.claimant.as_ref()
+        ],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, InsuranceClaim>,
+
+    /// CHECK: Claimant wallet (validated against claim.claimant)
+    #[account(mut, address = claim.claimant @ RegistryError::Unauthorized)]
+    pub claimant: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [InsurancePolicy::SEED_PREFIX, claim.agent.as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, InsurancePolicy>,
+
+    #[account(
+        mut,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    /// Protocol treasury (receives bonds of denied claims)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+pub fn handler(ctx: Context<ArbitrateInsuranceClaim>, approved: bool, payout: u64) -> Result<()> {
+    let resolver = ctx.accounts.resolver.key();
+    require!(
+        resolver == ctx.accounts.registry.admin
+            || VerifiedVerifier::is_active(&ctx.accounts.verifier_record)?,
+        RegistryError::Unauthorized
+    );
+    require!(resolver != ctx.accounts.claim.claimant, RegistryError::Unauthorized);
+
+    let claim = &mut ctx.accounts.claim;
+    let policy = &mut ctx.accounts.policy;
+    let pool = &mut ctx.accounts.insurance_pool;
+    let mut paid_out = 0;
+
+    if approved {
+        require!(payout <= claim.amount, RegistryError::InsurancePayoutTooHigh);
+        let pool_info = pool.to_account_info();
+        let rent = Rent::get()?.minimum_balance(pool_info.data_len());
+        paid_out = payout
+            .min(policy.remaining_coverage())
+            .min(pool_info.lamports().saturating_sub(rent));
+        transfer_from_pda(&pool_info, &ctx.accounts.claimant.to_account_info(), paid_out)?;
+        policy.claims_paid = policy.claims_paid.saturating_add(paid_out);
+        pool.total_paid_out = pool.total_paid_out.saturating_add(paid_out);
+    } else {
+        // Claim denied: bond goes to the treasury
+        let bond = claim.bond;
+        claim.bond = 0;
+        let treasury = &mut ctx.accounts.treasury;
+        transfer_from_pda(&claim.to_account_info(), &treasury.to_account_info(), bond)?;
+        treasury.total_collected = treasury.total_collected.saturating_add(bond);
+    }
+
+    emit!(InsuranceClaimArbitrated {
+        claim: claim.key(),
+        agent: claim.agent,
+        claimant: claim.claimant,
+        resolver,
+        approved,
+        paid_out,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Insurance claim against agent {} {}. Paid out {} lamports",
+        claim.agent,
+        if approved { "APPROVED" } else { "DENIED" },
+        paid_out
+    );
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AgentAccount, RegistryConfig, RegistryState, Task, TaskOutcome, TaskStatus};
+use crate::errors::RegistryError;
+use crate::events::TaskSettled;
+use crate::utils::{credit_task_to_agent, release_task_payment, TokenMint};
+
+/// Settle a disputed task (registry admin acts as arbitrator)
+///
+/// If `agent_wins` is true the payment goes to the agent owner with the usual
+/// reputation credit; otherwise it is refunded to the client. Either way the
+/// task is closed, its rent returned to the client. SPL tasks need the token
+/// account of whichever side wins.
+#[derive(Accounts)]
+pub struct ArbitrateTask<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        close = client,
+        seeds = [Task::SEED_PREFIX, task.client.as_ref(), task.nonce.to_le_bytes().as_ref()],
+        bump = task.bump,
+        constraint = task.status == TaskStatus::Disputed @ RegistryError::InvalidTaskStatus
+    )]
+    pub task: Account<'info, Task>,
+
+    /// CHECK: Task client receiving the rent and any refund (validated against task.client)
+    #[account(mut, address = task.client @ RegistryError::Unauthorized)]
+    pub client: UncheckedAccount<'info>,
+
+    #[account(mut, address = task.agent @ RegistryError::Unauthorized)]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner receiving the payment (validated against agent.owner)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// Registry config (reputation change cap)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Payment mint (SPL tasks only; writable so withheld transfer fees can be harvested)
+    #[account(mut)]
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Token account owned by the task PDA holding the payment (SPL tasks only)
+    #[account(mut)]
+    pub task_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Agent owner's token account receiving the payment (SPL tasks only)
+    #[account(mut, token::authority = agent_owner)]
+    pub agent_owner_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Client's token account receiving the refund (SPL tasks only)
+    #[account(mut, token::authority = client)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ArbitrateTask<'info>>,
+    agent_wins: bool,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    let token = TokenMint::from_accounts(
+        ctx.accounts.payment_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        ctx.remaining_accounts,
+    );
+    let client = ctx.accounts.client.to_account_info();
+    let outcome = if agent_wins {
+        release_task_payment(
+            task,
+            &ctx.accounts.agent_owner.to_account_info(),
+            ctx.accounts.task_token_account.as_ref(),
+            ctx.accounts.agent_owner_token_account.as_ref(),
+            token,
+            &client,
+        )?;
+        credit_task_to_agent(
+            task,
+            &mut ctx.accounts.agent,
+            &ctx.accounts.config,
+            clock.unix_timestamp,
+        );
+        TaskOutcome::AgentWon
+    } else {
+        release_task_payment(
+            task,
+            &client,
+            ctx.accounts.task_token_account.as_ref(),
+            ctx.accounts.client_token_account.as_ref(),
+            token,
+            &client,
+        )?;
+        TaskOutcome::ClientWon
+    };
+
+    emit!(TaskSettled {
+        task: task.key(),
+        client: task.client,
+        agent: task.agent,
+        outcome,
+        payment: task.payment,
+        settled_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Task {} dispute decided for the {}",
+        task.nonce,
+        if agent_wins { "agent" } else { "client" }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Task, TaskStatus};
+use crate::errors::RegistryError;
+use crate::events::TaskAssigned;
+
+/// Assign an open task to a registered agent (client only)
+/// The agent must be active, off probation and not controlled by the client
+#[derive(Accounts)]
+pub struct AssignTask<'info> {
+    pub client: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Task::SEED_PREFIX, task.client.as_ref(), task.nonce.to_le_bytes().as_ref()],
+        bump = task.bump,
+        constraint = task.client == client.key() @ RegistryError::Unauthorized,
+        constraint = task.status == TaskStatus::Open @ RegistryError::InvalidTaskStatus
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_retired() @ RegistryError::AgentRetired,
+        constraint = !agent.suspended @ RegistryError::AgentSuspended,
+        constraint = !agent.on_probation @ RegistryError::AgentOnProbation,
+        constraint = !agent.is_responder(&client.key()) @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+pub fn handler(ctx: Context<AssignTask>) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    task.agent = ctx.accounts.agent.key();
+    task.status = TaskStatus::Assigned;
+
+    emit!(TaskAssigned {
+        task: task.key(),
+        agent: task.agent,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Task {} assigned to agent id={}", task.nonce, ctx.accounts.agent.agent_id);
+
+    Ok(())
+}
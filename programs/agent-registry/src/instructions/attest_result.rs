@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus, ChallengerStats, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::utils::{
+    emit_challenge_resolved, enforce_probation, record_probation_result, release_challenge_escrow,
+    require_min_reputation,
+};
+
+/// Record one panel verifier's verdict on a responded challenge
+///
+/// Each listed verifier attests once. The challenge is finalized as passed when
+/// `quorum` pass attestations are collected, or as failed as soon as enough
+/// members have failed it that the quorum can no longer be reached.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct AttestResult<'info> {
+    /// A member of the challenge's verifier panel
+    pub verifier: Signer<'info>,
+
+    /// The agent that responded
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner wallet (receives the reward on pass)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Original challenger (receives the bond back, and the reward on fail)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// The challenge being attested
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.has_verifier_panel() @ RegistryError::NotChallengeVerifier,
+        constraint = challenge.status == ChallengeStatus::Responded @ RegistryError::ChallengeNotResponded
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+
+    /// Registry config (verifier reputation threshold)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Verifier's agent (required when config.min_reputation_verifier is set)
+    pub verifier_agent: Option<Account<'info, AgentAccount>>,
+}
+
+pub fn handler(ctx: Context<AttestResult>, _nonce: u64, passed: bool) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+    let verifier = ctx.accounts.verifier.key();
+    let clock = Clock::get()?;
+    let max_change = ctx.accounts.config.max_reputation_change_per_day;
+
+    require_min_reputation(
+        ctx.accounts.verifier_agent.as_ref(),
+        &verifier,
+        ctx.accounts.config.min_reputation_verifier,
+    )?;
+
+    require!(
+        !challenge.is_reveal_expired(clock.unix_timestamp),
+        RegistryError::RevealWindowClosed
+    );
+
+    let index = challenge
+        .verifiers
+        .iter()
+        .position(|v| *v == verifier)
+        .ok_or(RegistryError::NotChallengeVerifier)?;
+    let bit = 1u8 << index;
+    require!(challenge.attested_mask & bit == 0, RegistryError::AlreadyAttested);
+
+    challenge.attested_mask |= bit;
+    if passed {
+        challenge.pass_votes += 1;
+    } else {
+        challenge.fail_votes += 1;
+    }
+
+    msg!(
+        "Verifier {} attested {} ({} pass / {} fail, quorum {})",
+        verifier,
+        if passed { "PASS" } else { "FAIL" },
+        challenge.pass_votes,
+        challenge.fail_votes,
+        challenge.quorum
+    );
+
+    if let Some(verdict) = challenge.panel_verdict() {
+        let reward_paid = release_challenge_escrow(
+            challenge,
+            &ctx.accounts.challenger.to_account_info(),
+            &ctx.accounts.agent_owner.to_account_info(),
+            verdict,
+        )?;
+
+        let old_reputation = agent.reputation_score;
+        challenge.apply_verdict(agent, verdict, clock.unix_timestamp, max_change);
+
+        msg!(
+            "Challenge {} by verifier panel. Agent {} reputation: {}, reward: {}",
+            if verdict { "PASSED" } else { "FAILED" },
+            agent.agent_id,
+            agent.reputation_score,
+            reward_paid
+        );
+
+        ctx.accounts.challenger_stats.record_verdict(verdict);
+        emit_challenge_resolved(challenge, agent, old_reputation);
+        record_probation_result(
+            agent,
+            challenge,
+            verdict,
+            &ctx.accounts.config,
+            clock.unix_timestamp,
+        );
+        enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+    }
+
+    Ok(())
+}
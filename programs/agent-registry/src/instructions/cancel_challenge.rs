@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus, ChallengerStats};
+use crate::errors::RegistryError;
+use crate::events::ChallengeCancelled;
+
+/// Withdraw a challenge before the agent responds
+///
+/// Only the original challenger can cancel, and only while the challenge is
+/// still Pending (first round, no response yet). The PDA is closed and every
+/// lamport (rent, bond and reward) goes back to the challenger. No reputation
+/// effect on the agent.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct CancelChallenge<'info> {
+    /// The original challenger (receives rent, bond and reward back)
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// The agent that was challenged (for PDA derivation)
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The challenge to cancel
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.challenger == challenger.key() @ RegistryError::Unauthorized,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending,
+        constraint = challenge.current_round == 0 @ RegistryError::ChallengeNotPending,
+        constraint = challenge.token_reward == 0 @ RegistryError::TokenRewardUnsettled,
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (frees the pending slot)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenger.key().as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+}
+
+pub fn handler(ctx: Context<CancelChallenge>, _nonce: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.challenger_stats.record_released();
+    let challenge = &ctx.accounts.challenge;
+
+    emit!(ChallengeCancelled {
+        challenge: challenge.key(),
+        agent: challenge.agent,
+        challenger: challenge.challenger,
+        nonce: challenge.nonce,
+        refunded: challenge.to_account_info().lamports(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Challenge cancelled by {} for agent {}. Bond {} and reward {} refunded",
+        ctx.accounts.challenger.key(),
+        ctx.accounts.agent.agent_id,
+        challenge.bond,
+        challenge.reward
+    );
+
+    Ok(())
+}
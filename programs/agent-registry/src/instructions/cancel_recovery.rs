@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentRecovery};
+use crate::events::RecoveryCancelled;
+use crate::errors::RegistryError;
+
+/// Cancel a pending recovery (current owner only)
+/// Rent for the request is returned to the original requester
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Original requester receiving the rent refund (validated against recovery.requester)
+    #[account(mut, address = recovery.requester @ RegistryError::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [AgentRecovery::SEED_PREFIX, agent.key().as_ref()],
+        bump = recovery.bump
+    )]
+    pub recovery: Account<'info, AgentRecovery>,
+}
+
+pub fn handler(ctx: Context<CancelRecovery>) -> Result<()> {
+    emit!(RecoveryCancelled {
+        agent: ctx.accounts.agent.key(),
+        owner: ctx.accounts.owner.key(),
+    });
+
+    msg!(
+        "Recovery cancelled: agent={}, requester={}",
+        ctx.accounts.agent.agent_id,
+        ctx.accounts.recovery.requester
+    );
+
+    Ok(())
+}
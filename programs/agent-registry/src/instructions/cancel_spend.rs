@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::SpendProposal;
+use crate::errors::RegistryError;
+use crate::events::SpendCancelled;
+
+/// Withdraw a treasury spend proposal before execution (proposer only, rent refunded)
+#[derive(Accounts)]
+pub struct CancelSpend<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [SpendProposal::SEED_PREFIX, proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.proposer == proposer.key() @ RegistryError::Unauthorized
+    )]
+    pub proposal: Account<'info, SpendProposal>,
+}
+
+pub fn handler(ctx: Context<CancelSpend>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    emit!(SpendCancelled {
+        index: proposal.index,
+        proposer: proposal.proposer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Treasury spend #{} cancelled", proposal.index);
+
+    Ok(())
+}
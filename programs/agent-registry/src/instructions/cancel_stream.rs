@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, PaymentStream};
+use crate::errors::RegistryError;
+use crate::events::StreamCancelled;
+use crate::utils::transfer_from_pda;
+
+/// Stop a payment stream early (client or agent owner)
+///
+/// The vested, unwithdrawn part goes to the agent owner and the unvested
+/// remainder back to the client. The stream is closed, rent to the client.
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        close = client,
+        seeds = [
+            PaymentStream::SEED_PREFIX,
+            stream.client.as_ref(),
+            stream.agent.as_ref(),
+            stream.nonce.to_le_bytes().as_ref()
+        ],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    #[account(address = stream.agent @ RegistryError::Unauthorized)]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Stream client receiving the remainder (validated against stream.client)
+    #[account(mut, address = stream.client @ RegistryError::Unauthorized)]
+    pub client: UncheckedAccount<'info>,
+
+    /// CHECK: Agent owner receiving the vested part (validated against agent.owner)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelStream>) -> Result<()> {
+    let caller = ctx.accounts.caller.key();
+    let stream = &ctx.accounts.stream;
+    require!(
+        caller == stream.client || caller == ctx.accounts.agent.owner,
+        RegistryError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let to_agent = stream.withdrawable(clock.unix_timestamp);
+    let refunded = stream.total() - stream.vested(clock.unix_timestamp);
+    transfer_from_pda(
+        &stream.to_account_info(),
+        &ctx.accounts.agent_owner.to_account_info(),
+        to_agent,
+    )?;
+
+    emit!(StreamCancelled {
+        stream: stream.key(),
+        client: stream.client,
+        agent: stream.agent,
+        paid_to_agent: to_agent,
+        refunded,
+        cancelled_by: caller,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Payment stream cancelled: {} lamports to the agent, {} refunded",
+        to_agent,
+        refunded
+    );
+
+    Ok(())
+}
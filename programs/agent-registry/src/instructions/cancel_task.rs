@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Task, TaskOutcome, TaskStatus};
+use crate::errors::RegistryError;
+use crate::events::TaskSettled;
+use crate::utils::{release_task_payment, TokenMint};
+
+/// Withdraw a task and recover its payment (client only)
+///
+/// Open tasks can be cancelled at any time; assigned ones only after their
+/// deadline passed without a result. The payment and rent go back to the client
+/// (SPL payments to its token account, the task token account being closed).
+#[derive(Accounts)]
+pub struct CancelTask<'info> {
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    #[account(
+        mut,
+        close = client,
+        seeds = [Task::SEED_PREFIX, task.client.as_ref(), task.nonce.to_le_bytes().as_ref()],
+        bump = task.bump,
+        constraint = task.client == client.key() @ RegistryError::Unauthorized
+    )]
+    pub task: Account<'info, Task>,
+
+    /// Payment mint (SPL tasks only; writable so withheld transfer fees can be harvested)
+    #[account(mut)]
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Token account owned by the task PDA holding the payment (SPL tasks only)
+    #[account(mut)]
+    pub task_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Client's token account receiving the refund (SPL tasks only)
+    #[account(mut, token::authority = client)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CancelTask<'info>>) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let clock = Clock::get()?;
+    match task.status {
+        TaskStatus::Open => {}
+        TaskStatus::Assigned => {
+            require!(clock.unix_timestamp > task.deadline, RegistryError::TaskNotOverdue)
+        }
+        _ => return err!(RegistryError::InvalidTaskStatus),
+    }
+
+    // SOL payments ride back with the rent when the task closes
+    if task.is_token() {
+        let client = ctx.accounts.client.to_account_info();
+        release_task_payment(
+            task,
+            &client,
+            ctx.accounts.task_token_account.as_ref(),
+            ctx.accounts.client_token_account.as_ref(),
+            TokenMint::from_accounts(
+                ctx.accounts.payment_mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+                ctx.remaining_accounts,
+            ),
+            &client,
+        )?;
+    }
+
+    emit!(TaskSettled {
+        task: task.key(),
+        client: task.client,
+        agent: task.agent,
+        outcome: TaskOutcome::Cancelled,
+        payment: task.payment,
+        settled_by: task.client,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Task {} cancelled, {} (mint {}) refunded", task.nonce, task.payment, task.mint);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentAuditSummary, AuditCheckpoint, AuditPolicy};
+use crate::errors::RegistryError;
+use crate::events::AuditCheckpointed;
+
+/// Snapshot a commitment to an agent's entire audit history into an AuditCheckpoint PDA
+/// Any key allowed to log audit entries for the agent may checkpoint
+#[derive(Accounts)]
+pub struct CheckpointFullAudit<'info> {
+    #[account(mut)]
+    pub actor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &actor.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_summary.bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    #[account(
+        init,
+        payer = actor,
+        space = 8 + AuditCheckpoint::INIT_SPACE,
+        seeds = [
+            AuditCheckpoint::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_summary.checkpoints.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub checkpoint: Account<'info, AuditCheckpoint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CheckpointFullAudit>, merkle_root: [u8; 32]) -> Result<()> {
+    let summary = &mut ctx.accounts.audit_summary;
+    require!(summary.total_entries > 0, RegistryError::AuditSummaryNotFound);
+
+    let clock = Clock::get()?;
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    checkpoint.agent = ctx.accounts.agent.key();
+    checkpoint.index = summary.checkpoints;
+    checkpoint.entry_count = summary.total_entries;
+    checkpoint.chain_head = summary.chain_head;
+    checkpoint.merkle_root = merkle_root;
+    checkpoint.created_by = ctx.accounts.actor.key();
+    checkpoint.timestamp = clock.unix_timestamp;
+    checkpoint.bump = ctx.bumps.checkpoint;
+
+    summary.checkpoints = summary.checkpoints.saturating_add(1);
+
+    emit!(AuditCheckpointed {
+        checkpoint: checkpoint.key(),
+        agent: checkpoint.agent,
+        index: checkpoint.index,
+        entry_count: checkpoint.entry_count,
+        chain_head: checkpoint.chain_head,
+        merkle_root,
+        created_by: checkpoint.created_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit checkpoint {}: agent={}, entries={}",
+        checkpoint.index,
+        checkpoint.agent,
+        checkpoint.entry_count
+    );
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ReputationCheckpoint};
+use crate::errors::RegistryError;
+
+/// Record a Merkle root over agents' current scores for this epoch (anyone can call)
+///
+/// Agent accounts are passed as read-only `remaining_accounts` in strictly
+/// ascending key order (no duplicates); leaves follow that order. The root is
+/// computed on-chain, so the checkpoint cannot misstate any score. `epoch`
+/// must be the current epoch.
+#[derive(Accounts)]
+#[instruction(epoch: u64, batch_index: u32)]
+pub struct CheckpointReputations<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ReputationCheckpoint::INIT_SPACE,
+        seeds = [
+            ReputationCheckpoint::SEED_PREFIX,
+            epoch.to_le_bytes().as_ref(),
+            batch_index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub checkpoint: Account<'info, ReputationCheckpoint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CheckpointReputations<'info>>,
+    epoch: u64,
+    batch_index: u32,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(epoch == clock.epoch, RegistryError::InvalidEpoch);
+
+    let agents = ctx.remaining_accounts;
+    require!(!agents.is_empty(), RegistryError::EmptyBatch);
+    require!(
+        agents.len() <= ReputationCheckpoint::MAX_AGENTS,
+        RegistryError::BatchTooLarge
+    );
+
+    let mut leaves = Vec::with_capacity(agents.len());
+    let mut previous: Option<Pubkey> = None;
+    for info in agents.iter() {
+        if let Some(previous) = previous {
+            require!(previous < info.key(), RegistryError::UnsortedBatch);
+        }
+        previous = Some(info.key());
+
+        let agent = Account::<AgentAccount>::try_from(info)?;
+        leaves.push(ReputationCheckpoint::leaf(&info.key(), agent.reputation_score));
+    }
+
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    checkpoint.epoch = epoch;
+    checkpoint.batch_index = batch_index;
+    checkpoint.merkle_root = ReputationCheckpoint::root(leaves);
+    checkpoint.agent_count = agents.len() as u32;
+    checkpoint.timestamp = clock.unix_timestamp;
+    checkpoint.bump = ctx.bumps.checkpoint;
+
+    msg!(
+        "Reputation checkpoint: epoch={}, batch={}, agents={}",
+        checkpoint.epoch,
+        batch_index,
+        checkpoint.agent_count
+    );
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{OpenChallenge, OpenResponse};
+use crate::errors::RegistryError;
+
+/// Claim an open challenge with a correct response
+///
+/// Permissionless: the response must bind the revealed answer hash to its own
+/// agent (`OpenResponse::bind`) and becomes the winner if it was submitted in an
+/// earlier slot than the current one.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ClaimOpenChallenge<'info> {
+    /// Anyone can submit a claim on behalf of a correct response
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OpenChallenge::SEED_PREFIX,
+            open_challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = open_challenge.bump
+    )]
+    pub open_challenge: Account<'info, OpenChallenge>,
+
+    #[account(
+        seeds = [
+            OpenResponse::SEED_PREFIX,
+            open_challenge.key().as_ref(),
+            open_response.agent.as_ref(),
+        ],
+        bump = open_response.bump
+    )]
+    pub open_response: Account<'info, OpenResponse>,
+}
+
+pub fn handler(ctx: Context<ClaimOpenChallenge>, _nonce: u64) -> Result<()> {
+    let open_challenge = &mut ctx.accounts.open_challenge;
+    let open_response = &ctx.accounts.open_response;
+    let clock = Clock::get()?;
+
+    require!(
+        open_challenge.is_claim_open(clock.unix_timestamp),
+        RegistryError::ClaimWindowClosed
+    );
+
+    let expected = OpenResponse::bind(&open_challenge.revealed_answer_hash, &open_response.agent);
+    require!(
+        expected.eq_ignore_ascii_case(&open_response.response_hash),
+        RegistryError::IncorrectResponse
+    );
+    require!(
+        !open_challenge.has_winner() || open_response.submitted_slot < open_challenge.winner_slot,
+        RegistryError::NotEarliestResponse
+    );
+
+    open_challenge.winner = open_response.agent;
+    open_challenge.winner_slot = open_response.submitted_slot;
+
+    msg!(
+        "Open challenge {} claimed by agent {} (slot {})",
+        open_challenge.key(),
+        open_response.agent,
+        open_response.submitted_slot
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakePosition, StakeVault};
+use crate::errors::RegistryError;
+use crate::events::StakeRewardsClaimed;
+use crate::utils::transfer_from_pda;
+
+/// Claim a stake position's accrued staking rewards (staker only)
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, stake_vault.agent.as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            StakePosition::SEED_PREFIX,
+            stake_vault.key().as_ref(),
+            staker.key().as_ref(),
+        ],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+}
+
+pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
+    let vault = &ctx.accounts.stake_vault;
+    let position = &mut ctx.accounts.stake_position;
+    position.settle_rewards(vault.reward_per_share);
+    let amount = position.unclaimed_rewards;
+    require!(amount > 0, RegistryError::NoRewardsToClaim);
+    position.unclaimed_rewards = 0;
+
+    transfer_from_pda(
+        &vault.to_account_info(),
+        &ctx.accounts.staker.to_account_info(),
+        amount,
+    )?;
+
+    emit!(StakeRewardsClaimed {
+        agent: vault.agent,
+        staker: position.staker,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Staking rewards claimed by {}: {} lamports", position.staker, amount);
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentAuditSummary, Endorsement, RegistryConfig, Treasury};
+use crate::errors::RegistryError;
+use crate::events::ReputationChangeReason;
+use crate::utils::{emit_reputation_changed, enforce_probation, transfer_from_pda};
+
+/// Claw back an endorsement after the endorsed agent logged a critical-risk audit entry
+///
+/// Permissionless. Only critical-risk entries (RiskLevel::is_critical, the
+/// circuit breaker input) logged since the endorsement count; lesser alerts
+/// do not. Removes the bonus from the endorsed agent, penalizes the
+/// endorser agent by `Endorsement::ENDORSER_PENALTY` and sends the stake to the
+/// treasury. The endorsement rent goes back to the endorser wallet.
+#[derive(Accounts)]
+pub struct ClawbackEndorsement<'info> {
+    /// Anyone can trigger the clawback
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            endorser_agent.pda_owner().as_ref(),
+            endorser_agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = endorser_agent.bump
+    )]
+    pub endorser_agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            endorsed_agent.pda_owner().as_ref(),
+            endorsed_agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = endorsed_agent.bump
+    )]
+    pub endorsed_agent: Account<'info, AgentAccount>,
+
+    /// Endorsed agent's audit summary (source of the critical-risk entry count)
+    #[account(
+        seeds = [AgentAuditSummary::SEED_PREFIX, endorsed_agent.key().as_ref()],
+        bump = audit_summary.bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// CHECK: Endorser wallet (receives the endorsement rent)
+    #[account(mut, address = endorsement.endorser @ RegistryError::Unauthorized)]
+    pub endorser: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = endorser,
+        seeds = [
+            Endorsement::SEED_PREFIX,
+            endorsed_agent.key().as_ref(),
+            endorser_agent.key().as_ref(),
+        ],
+        bump = endorsement.bump,
+        constraint = endorsement.has_new_critical_alerts(audit_summary.critical_alerts)
+            @ RegistryError::EndorsementNotClawable
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+
+    /// Protocol treasury (receives the endorsement stake)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    /// Registry config (probation floor)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<ClawbackEndorsement>) -> Result<()> {
+    let endorsement = &ctx.accounts.endorsement;
+    let now = Clock::get()?.unix_timestamp;
+
+    transfer_from_pda(
+        &endorsement.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        endorsement.stake,
+    )?;
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_collected = treasury.total_collected.saturating_add(endorsement.stake);
+
+    let endorsed_agent = &mut ctx.accounts.endorsed_agent;
+    let old_reputation = endorsed_agent.reputation_score;
+    endorsed_agent.debit_reputation(endorsement.bonus, now);
+    endorsed_agent.endorsement_bonus = endorsed_agent.endorsement_bonus.saturating_sub(endorsement.bonus);
+    emit_reputation_changed(
+        endorsed_agent.key(),
+        old_reputation,
+        endorsed_agent.reputation_score,
+        ReputationChangeReason::EndorsementClawedBack,
+        endorsement.endorser_agent,
+        now,
+    );
+    enforce_probation(endorsed_agent, old_reputation, &ctx.accounts.config, now);
+
+    let endorser_agent = &mut ctx.accounts.endorser_agent;
+    let old_reputation = endorser_agent.reputation_score;
+    endorser_agent.debit_reputation(Endorsement::ENDORSER_PENALTY, now);
+    emit_reputation_changed(
+        endorser_agent.key(),
+        old_reputation,
+        endorser_agent.reputation_score,
+        ReputationChangeReason::EndorsementClawedBack,
+        endorsement.endorsed_agent,
+        now,
+    );
+    enforce_probation(endorser_agent, old_reputation, &ctx.accounts.config, now);
+
+    msg!(
+        "Endorsement CLAWED BACK: agent {} lost its +{} bonus, endorser agent {} penalized -{}, {} lamports to treasury",
+        endorsed_agent.agent_id,
+        endorsement.bonus,
+        endorser_agent.agent_id,
+        Endorsement::ENDORSER_PENALTY,
+        endorsement.stake
+    );
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditEntry, MerkleAuditRoot, MerkleProofNode};
+use crate::errors::RegistryError;
+use crate::events::AuditEntryArchived;
+use crate::utils::decode_hex32;
+
+/// Archive an audit entry that was committed into a Merkle audit root
+///
+/// The entry's details hash must be proven to be a leaf of one of the agent's
+/// stored roots, so the record stays verifiable off-chain through the root.
+/// The entry account is closed and its rent returned to the wallet that paid
+/// for it (the original actor). Callable by the agent owner or that actor.
+#[derive(Accounts)]
+pub struct CloseAuditEntry<'info> {
+    pub closer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The entry to archive
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_entry.audit_index.to_le_bytes().as_ref()
+        ],
+        bump = audit_entry.bump,
+        constraint = closer.key() == agent.owner || closer.key() == audit_entry.actor
+            @ RegistryError::Unauthorized
+    )]
+    pub audit_entry: Account<'info, AuditEntry>,
+
+    /// CHECK: Wallet that paid the entry rent (receives it back)
+    #[account(mut, address = audit_entry.actor @ RegistryError::Unauthorized)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Merkle root the entry was committed into
+    #[account(
+        seeds = [
+            MerkleAuditRoot::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_root.epoch.to_le_bytes().as_ref(),
+            audit_root.sequence.to_le_bytes().as_ref()
+        ],
+        bump = audit_root.bump
+    )]
+    pub audit_root: Account<'info, MerkleAuditRoot>,
+}
+
+pub fn handler(ctx: Context<CloseAuditEntry>, proof: Vec<MerkleProofNode>) -> Result<()> {
+    require!(
+        proof.len() <= MerkleAuditRoot::MAX_PROOF_DEPTH,
+        RegistryError::MerkleProofTooLong
+    );
+
+    let entry = &ctx.accounts.audit_entry;
+    let leaf_hash = decode_hex32(&entry.details_hash).ok_or(RegistryError::InvalidDetailsHash)?;
+    require!(
+        ctx.accounts.audit_root.verify(&leaf_hash, &proof),
+        RegistryError::InvalidMerkleProof
+    );
+
+    emit!(AuditEntryArchived {
+        audit_entry: entry.key(),
+        agent: entry.agent,
+        audit_index: entry.audit_index,
+        audit_root: ctx.accounts.audit_root.key(),
+        batch_index: ctx.accounts.audit_root.batch_index,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Audit entry archived: agent={}, index={}, batch={}, rent returned to {}",
+        entry.agent,
+        entry.audit_index,
+        ctx.accounts.audit_root.batch_index,
+        entry.actor
+    );
+
+    Ok(())
+}
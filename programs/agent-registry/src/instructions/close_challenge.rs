@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use crate::state::{AgentAccount, Challenge};
 use crate::errors::RegistryError;
+use crate::events::ChallengeClosed;
 
 /// Close a resolved challenge account and reclaim rent
 /// Only the original challenger can close, and only after the challenge is resolved
+/// and its dispute window has passed
 /// This is a critical mainnet optimization: reclaims ~0.012 SOL per challenge
 #[derive(Accounts)]
 #[instruction(nonce: u64)]
@@ -16,7 +18,7 @@ pub struct CloseChallenge<'info> {
     #[account(
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump
@@ -35,16 +37,38 @@ pub struct CloseChallenge<'info> {
         ],
         bump = challenge.bump,
         constraint = challenge.challenger == challenger.key() @ RegistryError::Unauthorized,
-        constraint = challenge.status != ChallengeStatus::Pending @ RegistryError::ChallengeStillPending,
+        constraint = challenge.is_resolved() @ RegistryError::ChallengeStillPending,
+        constraint = challenge.token_reward == 0 @ RegistryError::TokenRewardUnsettled,
+        constraint = !challenge.is_peer_result_pending() @ RegistryError::PeerResultUnrecorded,
+        constraint = !challenge.is_stake_slash_pending() @ RegistryError::StakeSlashPending,
     )]
     pub challenge: Account<'info, Challenge>,
 }
 
 pub fn handler(ctx: Context<CloseChallenge>, _nonce: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        !ctx.accounts.challenge.is_dispute_window_open(clock.unix_timestamp),
+        RegistryError::DisputeWindowOpen
+    );
+
     msg!(
         "Challenge closed. Rent reclaimed by {} for agent {}",
         ctx.accounts.challenger.key(),
         ctx.accounts.agent.key()
     );
+
+    let challenge = &ctx.accounts.challenge;
+    emit!(ChallengeClosed {
+        challenge: challenge.key(),
+        agent: challenge.agent,
+        challenger: challenge.challenger,
+        nonce: challenge.nonce,
+        score: challenge.score,
+        reputation_delta: challenge.reputation_delta,
+        reclaimed: challenge.to_account_info().lamports(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::Challenge;
+use crate::errors::RegistryError;
+use crate::events::ChallengeClosed;
+
+/// Close many resolved challenges in one transaction and reclaim their rent
+///
+/// Challenge accounts are passed as writable `remaining_accounts`. Every one must
+/// belong to the signing challenger and satisfy the same rules as `close_challenge`
+/// (resolved, token reward settled, dispute window passed); otherwise the whole
+/// batch fails.
+#[derive(Accounts)]
+pub struct CloseChallengesBatch<'info> {
+    /// The original challenger (receives all rent back)
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseChallengesBatch<'info>>) -> Result<()> {
+    require!(!ctx.remaining_accounts.is_empty(), RegistryError::EmptyBatch);
+
+    let clock = Clock::get()?;
+    let challenger = ctx.accounts.challenger.to_account_info();
+    let mut reclaimed: u64 = 0;
+
+    for info in ctx.remaining_accounts.iter() {
+        let challenge = Account::<Challenge>::try_from(info)?;
+
+        require_keys_eq!(
+            challenge.challenger,
+            challenger.key(),
+            RegistryError::Unauthorized
+        );
+        require!(challenge.is_resolved(), RegistryError::ChallengeStillPending);
+        require!(challenge.token_reward == 0, RegistryError::TokenRewardUnsettled);
+        require!(!challenge.is_peer_result_pending(), RegistryError::PeerResultUnrecorded);
+        require!(!challenge.is_stake_slash_pending(), RegistryError::StakeSlashPending);
+        require!(
+            !challenge.is_dispute_window_open(clock.unix_timestamp),
+            RegistryError::DisputeWindowOpen
+        );
+
+        let lamports = info.lamports();
+        reclaimed = reclaimed.saturating_add(lamports);
+
+        emit!(ChallengeClosed {
+            challenge: info.key(),
+            agent: challenge.agent,
+            challenger: challenge.challenger,
+            nonce: challenge.nonce,
+            score: challenge.score,
+            reputation_delta: challenge.reputation_delta,
+            reclaimed: lamports,
+            timestamp: clock.unix_timestamp,
+        });
+
+        challenge.close(challenger.clone())?;
+    }
+
+    msg!(
+        "Closed {} challenges. {} lamports reclaimed by {}",
+        ctx.remaining_accounts.len(),
+        reclaimed,
+        challenger.key()
+    );
+
+    Ok(())
+}
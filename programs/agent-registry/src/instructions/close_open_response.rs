@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::OpenResponse;
+use crate::errors::RegistryError;
+
+/// Close an open challenge response and reclaim rent
+/// Only allowed once the open challenge has been finalized (and closed)
+#[derive(Accounts)]
+pub struct CloseOpenResponse<'info> {
+    /// The agent owner who submitted the response
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ RegistryError::Unauthorized
+    )]
+    pub open_response: Account<'info, OpenResponse>,
+
+    /// CHECK: The answered open challenge; must already be closed
+    #[account(address = open_response.open_challenge @ RegistryError::ChallengeMismatch)]
+    pub open_challenge: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CloseOpenResponse>) -> Result<()> {
+    require!(
+        ctx.accounts.open_challenge.data_is_empty(),
+        RegistryError::OpenChallengeNotFinalized
+    );
+
+    msg!(
+        "Open response closed. Rent reclaimed by {}",
+        ctx.accounts.owner.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ServiceListing};
+use crate::errors::RegistryError;
+use crate::events::ServiceListingClosed;
+
+/// Remove a service listing and reclaim its rent (owner only)
+#[derive(Accounts)]
+pub struct CloseServiceListing<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing.listing_id.to_le_bytes().as_ref()
+        ],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, ServiceListing>,
+}
+
+pub fn handler(ctx: Context<CloseServiceListing>) -> Result<()> {
+    let listing = &ctx.accounts.listing;
+
+    emit!(ServiceListingClosed {
+        listing: listing.key(),
+        agent: listing.agent,
+        listing_id: listing.listing_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Service listing {} closed", listing.listing_id);
+
+    Ok(())
+}
@@ -1,6 +1,52 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use crate::state::{
+    AgentAccount, Challenge, ChallengeCounter, ChallengeIndex, ChallengerStats, ChallengeKind, ChallengeRound, ChallengeStatus, ChallengerAcl,
+    RegistryConfig, StakeVault, Treasury, VerifiedVerifier, ZkVerifyingKey,
+};
 use crate::errors::RegistryError;
+use crate::events::ChallengeCreated;
+use crate::utils::{collect_challenge_fee, deposit_lamports};
+
+/// Question and answer commitment for one additional round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoundSpec {
+    pub question: String,
+    pub expected_hash: String,
+}
+
+/// Off-chain challenge context and the SHA256 (hex) of its content
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChallengeMetadata {
+    pub uri: String,
+    pub content_hash: String,
+}
+
+/// Optional challenge settings beyond the question and commitment
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChallengeOptions {
+    /// SOL reward escrowed for the agent owner on pass
+    pub reward_lamports: u64,
+    /// Challenge category (selects reputation weights)
+    pub kind: ChallengeKind,
+    /// Difficulty level (1..=config.max_difficulty)
+    pub difficulty: u8,
+    /// Third-party verifier who resolves the challenge (None = commit-reveal)
+    pub verifier: Option<Pubkey>,
+    /// Verifier panel for M-of-N resolution (empty = no panel, exclusive with verifier)
+    pub verifiers: Vec<Pubkey>,
+    /// Pass attestations required from the panel (1..=verifiers.len())
+    pub quorum: u8,
+    /// Switchboard feed that decides the verdict (exclusive with verifier/verifiers)
+    pub oracle_feed: Option<Pubkey>,
+    /// Allowlisted verifier program that scores the response via CPI (exclusive with other modes)
+    pub verifier_program: Option<Pubkey>,
+    /// Further rounds after the first (empty = single-shot; commit-reveal only)
+    pub rounds: Vec<RoundSpec>,
+    /// Rich context document for explorers
+    pub metadata: Option<ChallengeMetadata>,
+    /// Classification codes (up to Challenge::MAX_TAGS)
+    pub tags: Vec<u16>,
+}
 
 #[derive(Accounts)]
 #[instruction(question: String, expected_hash: String, nonce: u64)]
@@ -12,13 +58,22 @@ pub struct CreateChallenge<'info> {
     #[account(
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
-        bump = agent.bump
+        bump = agent.bump,
+        constraint = !agent.is_retired() @ RegistryError::AgentRetired,
+        constraint = !agent.suspended @ RegistryError::AgentSuspended
     )]
     pub agent: Account<'info, AgentAccount>,
 
+    /// Registry config (challenger bond amount, difficulty bounds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     /// The challenge account (PDA derived from agent + challenger + nonce)
     #[account(
         init,
@@ -34,6 +89,73 @@ pub struct CreateChallenge<'info> {
     )]
     pub challenge: Account<'info, Challenge>,
 
+    /// Daily challenge counter for the agent (rate limiting)
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + ChallengeCounter::INIT_SPACE,
+        seeds = [ChallengeCounter::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub agent_counter: Account<'info, ChallengeCounter>,
+
+    /// Per-agent challenge index (latest nonce per challenger)
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + ChallengeIndex::INIT_SPACE,
+        seeds = [ChallengeIndex::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub challenge_index: Account<'info, ChallengeIndex>,
+
+    /// Challenger's aggregate stats
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + ChallengerStats::INIT_SPACE,
+        seeds = [ChallengerStats::SEED_PREFIX, challenger.key().as_ref()],
+        bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+
+    /// CHECK: Agent's challenger ACL PDA (may be uninitialized = anyone allowed)
+    #[account(
+        seeds = [ChallengerAcl::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub acl: UncheckedAccount<'info>,
+
+    /// Groth16 verifying key; when provided the agent must answer with a proof
+    pub zk_verifying_key: Option<Account<'info, ZkVerifyingKey>>,
+
+    /// Registered agent issuing the challenge (signer must be its owner or operator)
+    #[account(mut)]
+    pub challenger_agent: Option<Account<'info, AgentAccount>>,
+
+    /// Protocol treasury (receives the challenge fee)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Challenger's verified-verifier record (may be uninitialized = pays the fee)
+    #[account(
+        seeds = [VerifiedVerifier::SEED_PREFIX, challenger.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: UncheckedAccount<'info>,
+
+    /// CHECK: Agent's stake vault (may be uninitialized = no stake boost or fee share)
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -42,34 +164,248 @@ pub fn handler(
     question: String,
     expected_hash: String,
     nonce: u64,
+    options: ChallengeOptions,
 ) -> Result<()> {
+    let ChallengeOptions {
+        reward_lamports,
+        kind,
+        difficulty,
+        verifier,
+        verifiers,
+        quorum,
+        oracle_feed,
+        verifier_program,
+        rounds,
+        metadata,
+        tags,
+    } = options;
+
     // Validate inputs
     require!(question.len() <= 256, RegistryError::QuestionTooLong);
     require!(
-        expected_hash.len() == 64,
+        expected_hash.len() == 64 && expected_hash.chars().all(|c| c.is_ascii_hexdigit()),
         RegistryError::InvalidExpectedHash
     );
+    require!(
+        difficulty >= 1 && difficulty <= ctx.accounts.config.max_difficulty,
+        RegistryError::InvalidDifficulty
+    );
+    if !verifiers.is_empty() {
+        require!(
+            verifier.is_none() && verifiers.len() <= Challenge::MAX_VERIFIERS,
+            RegistryError::InvalidVerifierSet
+        );
+        require!(
+            verifiers.iter().enumerate().all(|(i, v)| {
+                *v != Pubkey::default() && !verifiers[..i].contains(v)
+            }),
+            RegistryError::InvalidVerifierSet
+        );
+        require!(
+            quorum >= 1 && quorum as usize <= verifiers.len(),
+            RegistryError::InvalidQuorum
+        );
+    }
+    if oracle_feed.is_some() {
+        require!(
+            ctx.accounts.config.oracle_authority != Pubkey::default(),
+            RegistryError::OracleDisabled
+        );
+        require!(
+            verifier.is_none() && verifiers.is_empty(),
+            RegistryError::InvalidVerifierSet
+        );
+    }
+
+    if ctx.accounts.zk_verifying_key.is_some() {
+        require!(
+            verifier.is_none() && verifiers.is_empty() && oracle_feed.is_none(),
+            RegistryError::InvalidVerifierSet
+        );
+    }
+
+    if let Some(metadata) = metadata.as_ref() {
+        require!(
+            !metadata.uri.is_empty()
+                && metadata.uri.len() <= Challenge::MAX_METADATA_URI_LEN
+                && metadata.content_hash.len() == 64
+                && metadata.content_hash.chars().all(|c| c.is_ascii_hexdigit()),
+            RegistryError::InvalidMetadata
+        );
+    }
+    require!(tags.len() <= Challenge::MAX_TAGS, RegistryError::TooManyTags);
+
+    if verifier_program.is_some() {
+        require!(
+            verifier.is_none()
+                && verifiers.is_empty()
+                && oracle_feed.is_none()
+                && ctx.accounts.zk_verifying_key.is_none(),
+            RegistryError::InvalidVerifierSet
+        );
+    }
+
+    if !rounds.is_empty() {
+        require!(
+            rounds.len() < Challenge::MAX_ROUNDS,
+            RegistryError::InvalidRounds
+        );
+        require!(
+            verifier.is_none()
+                && verifiers.is_empty()
+                && oracle_feed.is_none()
+                && verifier_program.is_none()
+                && ctx.accounts.zk_verifying_key.is_none(),
+            RegistryError::InvalidRounds
+        );
+        require!(
+            question.len() <= Challenge::MAX_ROUND_QUESTION_LEN
+                && rounds.iter().all(|round| {
+                    round.question.len() <= Challenge::MAX_ROUND_QUESTION_LEN
+                        && round.expected_hash.len() == 64
+                        && round.expected_hash.chars().all(|c| c.is_ascii_hexdigit())
+                }),
+            RegistryError::InvalidRounds
+        );
+    }
+
+    if let Some(challenger_agent) = ctx.accounts.challenger_agent.as_ref() {
+        require!(
+            challenger_agent.key() != ctx.accounts.agent.key()
+                && challenger_agent.is_responder(&ctx.accounts.challenger.key()),
+            RegistryError::InvalidChallengerAgent
+        );
+    }
+
+    ChallengerAcl::check(&ctx.accounts.acl, &ctx.accounts.challenger.key())?;
 
-    let challenge = &mut ctx.accounts.challenge;
     let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    // Rate limits: per-agent daily cap here, per-challenger pending cap on the stats below
+    let agent_counter = &mut ctx.accounts.agent_counter;
+    agent_counter.bump = ctx.bumps.agent_counter;
+    agent_counter.record(
+        clock.unix_timestamp,
+        ChallengeCounter::DAY,
+        config.max_challenges_per_day,
+    )?;
+
+    let challenge_index = &mut ctx.accounts.challenge_index;
+    challenge_index.agent = ctx.accounts.agent.key();
+    challenge_index.bump = ctx.bumps.challenge_index;
+    challenge_index.record(ctx.accounts.challenger.key(), nonce, clock.unix_timestamp);
+
+    let challenger_stats = &mut ctx.accounts.challenger_stats;
+    challenger_stats.challenger = ctx.accounts.challenger.key();
+    challenger_stats.bump = ctx.bumps.challenger_stats;
+    challenger_stats.record_issued(config.max_pending_per_challenger)?;
+
+    let challenge = &mut ctx.accounts.challenge;
 
     challenge.agent = ctx.accounts.agent.key();
     challenge.challenger = ctx.accounts.challenger.key();
     challenge.question = question.clone();
-    challenge.expected_hash = expected_hash;
+    challenge.expected_hash = expected_hash.clone();
     challenge.status = ChallengeStatus::Pending;
     challenge.created_at = clock.unix_timestamp;
     challenge.expires_at = clock.unix_timestamp + Challenge::DEFAULT_DURATION;
     challenge.responded_at = 0;
     challenge.nonce = nonce;
     challenge.bump = ctx.bumps.challenge;
+    challenge.response_hash = String::new();
+    challenge.bond = ctx.accounts.config.challenge_bond_for(&ctx.accounts.agent);
+    challenge.challenger_weight_bps = ctx.accounts.config.challenger_weight_bps(
+        VerifiedVerifier::is_active(&ctx.accounts.verifier_record)?,
+        &ctx.accounts.challenger_stats,
+    );
+    challenge.stake_boost_bps = StakeVault::boost_bps(&ctx.accounts.stake_vault, &ctx.accounts.config)?;
+    challenge.ema_alpha_bps = ctx.accounts.config.ema_alpha_bps;
+    challenge.base_pass_delta = ctx.accounts.config.pass_delta(kind);
+    challenge.base_fail_delta = ctx.accounts.config.fail_delta(kind);
+    challenge.reward = reward_lamports;
+    challenge.kind = kind;
+    challenge.difficulty = difficulty;
+    challenge.verifier = verifier.unwrap_or_default();
+    challenge.quorum = if verifiers.is_empty() { 0 } else { quorum };
+    challenge.verifiers = verifiers;
+    challenge.oracle_feed = oracle_feed.unwrap_or_default();
+    challenge.verifier_program = verifier_program.unwrap_or_default();
+    if let Some(metadata) = metadata {
+        challenge.metadata_uri = metadata.uri;
+        challenge.metadata_hash = metadata.content_hash;
+    }
+    challenge.tags = tags;
+    if let Some(challenger_agent) = ctx.accounts.challenger_agent.as_mut() {
+        challenge.challenger_agent = challenger_agent.key();
+        challenger_agent.challenges_issued = challenger_agent.challenges_issued.saturating_add(1);
+    }
+    challenge.zk_verifying_key = ctx
+        .accounts
+        .zk_verifying_key
+        .as_ref()
+        .map(|vk| vk.key())
+        .unwrap_or_default();
+    if !rounds.is_empty() {
+        challenge.rounds = std::iter::once(RoundSpec {
+            question: question.clone(),
+            expected_hash,
+        })
+        .chain(rounds)
+        .map(|round| ChallengeRound {
+            question: round.question,
+            expected_hash: round.expected_hash,
+            response_hash: String::new(),
+            passed: false,
+            resolved_at: 0,
+        })
+        .collect();
+    }
+
+    // Lock the challenger bond and reward in the challenge PDA
+    let escrow = challenge
+        .bond
+        .checked_add(challenge.reward)
+        .ok_or(RegistryError::MathOverflow)?;
+    deposit_lamports(
+        &ctx.accounts.challenger.to_account_info(),
+        &challenge.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        escrow,
+    )?;
+    let fee = collect_challenge_fee(
+        &ctx.accounts.config,
+        escrow,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.verifier_record.to_account_info(),
+        &mut ctx.accounts.treasury,
+        Some(&ctx.accounts.stake_vault.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
     msg!(
-        "Challenge created for agent {} by {}: {}",
+        "Challenge created for agent {} by {} (kind={:?}, difficulty={}, bond={}, reward={}, fee={}): {}",
         ctx.accounts.agent.key(),
         ctx.accounts.challenger.key(),
+        challenge.kind,
+        challenge.difficulty,
+        challenge.bond,
+        challenge.reward,
+        fee,
         question
     );
 
+    emit!(ChallengeCreated {
+        challenge: challenge.key(),
+        agent: challenge.agent,
+        challenger: challenge.challenger,
+        nonce: challenge.nonce,
+        bond: challenge.bond,
+        reward: challenge.reward,
+        difficulty: challenge.difficulty,
+        expires_at: challenge.expires_at,
+        timestamp: challenge.created_at,
+    });
+
     Ok(())
 }
@@ -0,0 +1,255 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+use crate::state::{
+    AgentAccount, Challenge, ChallengeCounter, ChallengeIndex, ChallengerStats, ChallengeStatus, ChallengeTemplate, ChallengerAcl,
+    CommitmentScheme, RegistryConfig, StakeVault, Treasury, VerifiedVerifier,
+};
+use crate::errors::RegistryError;
+use crate::events::ChallengeCreated;
+use crate::utils::{collect_challenge_fee, deposit_lamports};
+
+/// Create a challenge from a published template
+///
+/// Kind and difficulty come from the template. The question text must hash to
+/// the template's question_hash. AuthorVerified templates make the template
+/// author the challenge verifier; CommitReveal templates use `expected_hash`
+/// as the usual answer commitment.
+#[derive(Accounts)]
+#[instruction(question: String, expected_hash: String, nonce: u64)]
+pub struct CreateChallengeFromTemplate<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// The agent being challenged
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_retired() @ RegistryError::AgentRetired,
+        constraint = !agent.suspended @ RegistryError::AgentSuspended
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (challenger bond amount)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The template to instantiate
+    #[account(
+        mut,
+        seeds = [
+            ChallengeTemplate::SEED_PREFIX,
+            template.author.as_ref(),
+            template.template_id.to_le_bytes().as_ref(),
+        ],
+        bump = template.bump
+    )]
+    pub template: Account<'info, ChallengeTemplate>,
+
+    /// The challenge account (PDA derived from agent + challenger + nonce)
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + Challenge::INIT_SPACE,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Daily challenge counter for the agent (rate limiting)
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + ChallengeCounter::INIT_SPACE,
+        seeds = [ChallengeCounter::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub agent_counter: Account<'info, ChallengeCounter>,
+
+    /// Per-agent challenge index (latest nonce per challenger)
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + ChallengeIndex::INIT_SPACE,
+        seeds = [ChallengeIndex::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub challenge_index: Account<'info, ChallengeIndex>,
+
+    /// Challenger's aggregate stats
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + ChallengerStats::INIT_SPACE,
+        seeds = [ChallengerStats::SEED_PREFIX, challenger.key().as_ref()],
+        bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+
+    /// CHECK: Agent's challenger ACL PDA (may be uninitialized = anyone allowed)
+    #[account(
+        seeds = [ChallengerAcl::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub acl: UncheckedAccount<'info>,
+
+    /// Protocol treasury (receives the challenge fee)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Challenger's verified-verifier record (may be uninitialized = pays the fee)
+    #[account(
+        seeds = [VerifiedVerifier::SEED_PREFIX, challenger.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: UncheckedAccount<'info>,
+
+    /// CHECK: Agent's stake vault (may be uninitialized = no stake boost or fee share)
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateChallengeFromTemplate>,
+    question: String,
+    expected_hash: String,
+    nonce: u64,
+    reward_lamports: u64,
+) -> Result<()> {
+    let template = &mut ctx.accounts.template;
+
+    require!(question.len() <= 256, RegistryError::QuestionTooLong);
+    let question_hash: String = hash(question.as_bytes())
+        .to_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    require!(
+        question_hash == template.question_hash,
+        RegistryError::TemplateQuestionMismatch
+    );
+    require!(
+        expected_hash.len() == 64 && expected_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidExpectedHash
+    );
+
+    ChallengerAcl::check(&ctx.accounts.acl, &ctx.accounts.challenger.key())?;
+
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config;
+
+    // Rate limits: per-agent daily cap here, per-challenger pending cap on the stats below
+    let agent_counter = &mut ctx.accounts.agent_counter;
+    agent_counter.bump = ctx.bumps.agent_counter;
+    agent_counter.record(
+        clock.unix_timestamp,
+        ChallengeCounter::DAY,
+        config.max_challenges_per_day,
+    )?;
+
+    let challenge_index = &mut ctx.accounts.challenge_index;
+    challenge_index.agent = ctx.accounts.agent.key();
+    challenge_index.bump = ctx.bumps.challenge_index;
+    challenge_index.record(ctx.accounts.challenger.key(), nonce, clock.unix_timestamp);
+
+    let challenger_stats = &mut ctx.accounts.challenger_stats;
+    challenger_stats.challenger = ctx.accounts.challenger.key();
+    challenger_stats.bump = ctx.bumps.challenger_stats;
+    challenger_stats.record_issued(config.max_pending_per_challenger)?;
+
+    let challenge = &mut ctx.accounts.challenge;
+
+    challenge.agent = ctx.accounts.agent.key();
+    challenge.challenger = ctx.accounts.challenger.key();
+    challenge.question = question;
+    challenge.expected_hash = expected_hash;
+    challenge.status = ChallengeStatus::Pending;
+    challenge.created_at = clock.unix_timestamp;
+    challenge.expires_at = clock.unix_timestamp + Challenge::DEFAULT_DURATION;
+    challenge.nonce = nonce;
+    challenge.bump = ctx.bumps.challenge;
+    challenge.bond = ctx.accounts.config.challenge_bond_for(&ctx.accounts.agent);
+    challenge.challenger_weight_bps = ctx.accounts.config.challenger_weight_bps(
+        VerifiedVerifier::is_active(&ctx.accounts.verifier_record)?,
+        &ctx.accounts.challenger_stats,
+    );
+    challenge.stake_boost_bps = StakeVault::boost_bps(&ctx.accounts.stake_vault, &ctx.accounts.config)?;
+    challenge.ema_alpha_bps = ctx.accounts.config.ema_alpha_bps;
+    challenge.base_pass_delta = ctx.accounts.config.pass_delta(template.kind);
+    challenge.base_fail_delta = ctx.accounts.config.fail_delta(template.kind);
+    challenge.reward = reward_lamports;
+    challenge.kind = template.kind;
+    challenge.difficulty = template.difficulty;
+    challenge.verifier = match template.scheme {
+        CommitmentScheme::CommitReveal => Pubkey::default(),
+        CommitmentScheme::AuthorVerified => template.author,
+    };
+    challenge.template = template.key();
+
+    template.uses = template.uses.saturating_add(1);
+
+    // Lock the challenger bond and reward in the challenge PDA
+    let escrow = challenge
+        .bond
+        .checked_add(challenge.reward)
+        .ok_or(RegistryError::MathOverflow)?;
+    deposit_lamports(
+        &ctx.accounts.challenger.to_account_info(),
+        &challenge.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        escrow,
+    )?;
+    let fee = collect_challenge_fee(
+        &ctx.accounts.config,
+        escrow,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.verifier_record.to_account_info(),
+        &mut ctx.accounts.treasury,
+        Some(&ctx.accounts.stake_vault.to_account_info()),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!(
+        "Challenge created from template {} for agent {} by {} (uses={}, fee={})",
+        template.template_id,
+        ctx.accounts.agent.key(),
+        ctx.accounts.challenger.key(),
+        template.uses,
+        fee
+    );
+
+    emit!(ChallengeCreated {
+        challenge: challenge.key(),
+        agent: challenge.agent,
+        challenger: challenge.challenger,
+        nonce: challenge.nonce,
+        bond: challenge.bond,
+        reward: challenge.reward,
+        difficulty: challenge.difficulty,
+        expires_at: challenge.expires_at,
+        timestamp: challenge.created_at,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::RegistryState;
+use crate::state::{AgentAuditSummary, AuditEntry, RegistryAction, RegistryState, RiskWeights};
 use crate::errors::RegistryError;
+use crate::utils::log_registry_action;
 
 /// Set the NFT collection address (admin only)
 /// The collection is created off-chain using Metaplex SDK
@@ -20,6 +21,39 @@ pub struct CreateCollection<'info> {
 
     /// CHECK: The collection account (created off-chain via Metaplex SDK)
     pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// Registry-scoped audit summary (created on the first logged admin action)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, registry.key().as_ref()],
+        bump
+    )]
+    pub registry_audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// Registry audit entry recording the collection address
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            registry.key().as_ref(),
+            registry_audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub registry_audit_entry: Account<'info, AuditEntry>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<CreateCollection>) -> Result<()> {
@@ -34,5 +68,19 @@ pub fn handler(ctx: Context<CreateCollection>) -> Result<()> {
         registry.collection
     );
 
+    let summary = &mut ctx.accounts.registry_audit_summary;
+    summary.agent = ctx.accounts.registry.key();
+    summary.bump = ctx.bumps.registry_audit_summary;
+    ctx.accounts.registry_audit_entry.bump = ctx.bumps.registry_audit_entry;
+    log_registry_action(
+        ctx.accounts.admin.key(),
+        summary,
+        &mut ctx.accounts.registry_audit_entry,
+        &ctx.accounts.risk_weights,
+        RegistryAction::CollectionSet,
+        ctx.accounts.collection.key(),
+        &[],
+    )?;
+
     Ok(())
 }
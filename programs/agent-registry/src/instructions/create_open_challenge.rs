@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, Challenge, ChallengeKind, OpenChallenge, OpenChallengeStatus, RegistryConfig, Treasury,
+    VerifiedVerifier,
+};
+use crate::errors::RegistryError;
+use crate::utils::{collect_challenge_fee, deposit_lamports, require_min_reputation};
+
+/// Create a challenge any registered agent can answer
+#[derive(Accounts)]
+#[instruction(question: String, expected_hash: String, nonce: u64)]
+pub struct CreateOpenChallenge<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// Registry config (challenger bond amount, difficulty bounds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The open challenge account (PDA derived from challenger + nonce)
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + OpenChallenge::INIT_SPACE,
+        seeds = [
+            OpenChallenge::SEED_PREFIX,
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub open_challenge: Account<'info, OpenChallenge>,
+
+    /// Protocol treasury (receives the challenge fee)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Challenger's verified-verifier record (may be uninitialized = pays the fee)
+    #[account(
+        seeds = [VerifiedVerifier::SEED_PREFIX, challenger.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: UncheckedAccount<'info>,
+
+    /// Challenger's agent (required when config.min_reputation_open_challenge is set)
+    pub challenger_agent: Option<Account<'info, AgentAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateOpenChallenge>,
+    question: String,
+    expected_hash: String,
+    nonce: u64,
+    reward_lamports: u64,
+    kind: ChallengeKind,
+    difficulty: u8,
+) -> Result<()> {
+    require_min_reputation(
+        ctx.accounts.challenger_agent.as_ref(),
+        &ctx.accounts.challenger.key(),
+        ctx.accounts.config.min_reputation_open_challenge,
+    )?;
+    require!(question.len() <= 256, RegistryError::QuestionTooLong);
+    require!(
+        expected_hash.len() == 64 && expected_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidExpectedHash
+    );
+    require!(
+        difficulty >= 1 && difficulty <= ctx.accounts.config.max_difficulty,
+        RegistryError::InvalidDifficulty
+    );
+
+    let open_challenge = &mut ctx.accounts.open_challenge;
+    let clock = Clock::get()?;
+
+    open_challenge.challenger = ctx.accounts.challenger.key();
+    open_challenge.question = question.clone();
+    open_challenge.expected_hash = expected_hash;
+    open_challenge.status = OpenChallengeStatus::Open;
+    open_challenge.kind = kind;
+    open_challenge.base_pass_delta = ctx.accounts.config.pass_delta(kind);
+    open_challenge.difficulty = difficulty;
+    open_challenge.created_at = clock.unix_timestamp;
+    open_challenge.expires_at = clock.unix_timestamp + Challenge::DEFAULT_DURATION;
+    open_challenge.revealed_at = 0;
+    open_challenge.revealed_salt = [0u8; 32];
+    open_challenge.revealed_answer_hash = String::new();
+    open_challenge.bond = ctx.accounts.config.challenge_bond_lamports;
+    open_challenge.reward = reward_lamports;
+    open_challenge.response_count = 0;
+    open_challenge.first_responder = Pubkey::default();
+    open_challenge.winner = Pubkey::default();
+    open_challenge.winner_slot = 0;
+    open_challenge.nonce = nonce;
+    open_challenge.bump = ctx.bumps.open_challenge;
+
+    // Lock the challenger bond and reward in the open challenge PDA
+    let escrow = open_challenge
+        .bond
+        .checked_add(open_challenge.reward)
+        .ok_or(RegistryError::MathOverflow)?;
+    deposit_lamports(
+        &ctx.accounts.challenger.to_account_info(),
+        &open_challenge.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        escrow,
+    )?;
+    let fee = collect_challenge_fee(
+        &ctx.accounts.config,
+        escrow,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.verifier_record.to_account_info(),
+        &mut ctx.accounts.treasury,
+        None,
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!(
+        "Open challenge created by {} (kind={:?}, difficulty={}, bond={}, reward={}, fee={}): {}",
+        ctx.accounts.challenger.key(),
+        open_challenge.kind,
+        open_challenge.difficulty,
+        open_challenge.bond,
+        open_challenge.reward,
+        fee,
+        question
+    );
+
+    Ok(())
+}
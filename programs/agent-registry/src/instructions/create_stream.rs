@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, PaymentStream};
+use crate::errors::RegistryError;
+use crate::events::StreamCreated;
+use crate::utils::deposit_lamports;
+
+/// Open a payment stream to an agent, escrowing the full amount (client)
+/// The agent must be active and not controlled by the client
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct CreateStream<'info> {
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = !agent.is_retired() @ RegistryError::AgentRetired,
+        constraint = !agent.suspended @ RegistryError::AgentSuspended,
+        constraint = !agent.is_responder(&client.key()) @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = client,
+        space = 8 + PaymentStream::INIT_SPACE,
+        seeds = [
+            PaymentStream::SEED_PREFIX,
+            client.key().as_ref(),
+            agent.key().as_ref(),
+            nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateStream>,
+    nonce: u64,
+    rate_per_sec: u64,
+    start_ts: i64,
+    end_ts: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        rate_per_sec > 0 && start_ts >= clock.unix_timestamp && end_ts > start_ts,
+        RegistryError::InvalidStreamParams
+    );
+    let total = rate_per_sec
+        .checked_mul((end_ts - start_ts) as u64)
+        .ok_or(RegistryError::MathOverflow)?;
+
+    deposit_lamports(
+        &ctx.accounts.client.to_account_info(),
+        &ctx.accounts.stream.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        total,
+    )?;
+
+    let stream = &mut ctx.accounts.stream;
+    stream.client = ctx.accounts.client.key();
+    stream.agent = ctx.accounts.agent.key();
+    stream.nonce = nonce;
+    stream.rate_per_sec = rate_per_sec;
+    stream.start_ts = start_ts;
+    stream.end_ts = end_ts;
+    stream.withdrawn = 0;
+    stream.bump = ctx.bumps.stream;
+
+    emit!(StreamCreated {
+        stream: stream.key(),
+        client: stream.client,
+        agent: stream.agent,
+        rate_per_sec,
+        start_ts,
+        end_ts,
+        total,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Payment stream opened to agent id={}: {} lamports/s, {} total",
+        ctx.accounts.agent.agent_id,
+        rate_per_sec,
+        total
+    );
+
+    Ok(())
+}
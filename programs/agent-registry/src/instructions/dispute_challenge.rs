@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::{AgentAccount, Challenge, ChallengeDispute, ChallengeStatus, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::events::{ChallengeDisputed, ReputationChangeReason};
+use crate::utils::emit_reputation_changed;
+
+/// Contest a pass/fail verdict within the dispute window
+///
+/// Either the agent owner or the challenger may dispute once per challenge by
+/// locking the configured dispute bond. The challenge moves to `Disputed` until
+/// the registry admin settles it with `arbitrate_dispute`. The verdict's
+/// reputation delta is withheld from the agent until then (`pending_delta`).
+/// Disputes correct reputation only: the challenge escrow has already been
+/// released and is not redistributed if the verdict is overturned.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct DisputeChallenge<'info> {
+    /// Agent owner or challenger (pays rent and the dispute bond)
+    #[account(mut)]
+    pub disputant: Signer<'info>,
+
+    /// The challenged agent (verdict delta withheld during the dispute)
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (dispute bond amount)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The challenge whose verdict is contested
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = !challenge.disputed @ RegistryError::AlreadyDisputed
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Dispute record holding the bond (PDA derived from the challenge)
+    #[account(
+        init,
+        payer = disputant,
+        space = 8 + ChallengeDispute::INIT_SPACE,
+        seeds = [ChallengeDispute::SEED_PREFIX, challenge.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, ChallengeDispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DisputeChallenge>, _nonce: u64, reason_hash: String) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let disputant = ctx.accounts.disputant.key();
+    let clock = Clock::get()?;
+
+    require!(
+        disputant == ctx.accounts.agent.owner || disputant == challenge.challenger,
+        RegistryError::NotDisputeParty
+    );
+    require!(
+        matches!(challenge.status, ChallengeStatus::Passed | ChallengeStatus::Failed),
+        RegistryError::ChallengeNotDisputable
+    );
+    require!(
+        challenge.is_dispute_window_open(clock.unix_timestamp),
+        RegistryError::DisputeWindowClosed
+    );
+    require!(
+        reason_hash.len() == 64 && reason_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidReasonHash
+    );
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.challenge = challenge.key();
+    dispute.disputant = disputant;
+    dispute.original_status = challenge.status;
+    dispute.reason_hash = reason_hash;
+    dispute.bond = ctx.accounts.config.dispute_bond_lamports;
+    dispute.opened_at = clock.unix_timestamp;
+    dispute.bump = ctx.bumps.dispute;
+
+    challenge.status = ChallengeStatus::Disputed;
+    challenge.disputed = true;
+
+    let agent = &mut ctx.accounts.agent;
+    let old_reputation = agent.reputation_score;
+    challenge.freeze_delta(agent, clock.unix_timestamp);
+    emit_reputation_changed(
+        agent.key(),
+        old_reputation,
+        agent.reputation_score,
+        ReputationChangeReason::DisputeOpened,
+        challenge.key(),
+        clock.unix_timestamp,
+    );
+
+    // Lock the dispute bond in the dispute PDA
+    if dispute.bond > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.disputant.to_account_info(),
+                    to: dispute.to_account_info(),
+                },
+            ),
+            dispute.bond,
+        )?;
+    }
+
+    emit!(ChallengeDisputed {
+        challenge: challenge.key(),
+        agent: ctx.accounts.agent.key(),
+        disputant,
+        bond: dispute.bond,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Challenge {} disputed by {} (bond={})",
+        challenge.key(),
+        disputant,
+        dispute.bond
+    );
+
+    Ok(())
+}
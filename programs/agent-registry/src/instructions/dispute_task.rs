@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{Task, TaskStatus};
+use crate::errors::RegistryError;
+use crate::events::TaskDisputed;
+
+/// Dispute a submitted task result within the review window (client only)
+/// The payment stays escrowed until `arbitrate_task`
+#[derive(Accounts)]
+pub struct DisputeTask<'info> {
+    pub client: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Task::SEED_PREFIX, task.client.as_ref(), task.nonce.to_le_bytes().as_ref()],
+        bump = task.bump,
+        constraint = task.client == client.key() @ RegistryError::Unauthorized,
+        constraint = task.status == TaskStatus::Submitted @ RegistryError::InvalidTaskStatus
+    )]
+    pub task: Account<'info, Task>,
+}
+
+pub fn handler(ctx: Context<DisputeTask>, reason_hash: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let task = &mut ctx.accounts.task;
+    require!(
+        clock.unix_timestamp < task.submitted_at + Task::REVIEW_WINDOW_SECS,
+        RegistryError::InvalidTaskStatus
+    );
+    task.status = TaskStatus::Disputed;
+
+    emit!(TaskDisputed {
+        task: task.key(),
+        agent: task.agent,
+        reason_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Task {} disputed by client", task.nonce);
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentAuditSummary, Endorsement, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::events::ReputationChangeReason;
+use crate::utils::{deposit_lamports, emit_reputation_changed};
+
+/// Vouch for another agent with a verified agent (endorser owner or operator)
+///
+/// Locks at least `Endorsement::MIN_STAKE` in the endorsement PDA and grants
+/// the endorsed agent `Endorsement::BONUS` reputation, up to
+/// `Endorsement::MAX_AGENT_BONUS` across all of its endorsements. The endorsed
+/// agent's current critical-risk entry count is recorded so later critical
+/// entries can trigger `clawback_endorsement`. The two agents must not share
+/// an owner or operator, so nobody can endorse their own agents.
+#[derive(Accounts)]
+pub struct EndorseAgent<'info> {
+    #[account(mut)]
+    pub endorser: Signer<'info>,
+
+    /// The vouching agent (must be verified)
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            endorser_agent.pda_owner().as_ref(),
+            endorser_agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = endorser_agent.bump,
+        constraint = endorser_agent.is_responder(&endorser.key()) @ RegistryError::Unauthorized
+    )]
+    pub endorser_agent: Account<'info, AgentAccount>,
+
+    /// The agent being endorsed
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            endorsed_agent.pda_owner().as_ref(),
+            endorsed_agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = endorsed_agent.bump
+    )]
+    pub endorsed_agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Endorsed agent's audit summary (may not exist yet = no alerts)
+    #[account(
+        seeds = [AgentAuditSummary::SEED_PREFIX, endorsed_agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = endorser,
+        space = 8 + Endorsement::INIT_SPACE,
+        seeds = [
+            Endorsement::SEED_PREFIX,
+            endorsed_agent.key().as_ref(),
+            endorser_agent.key().as_ref(),
+        ],
+        bump
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+
+    /// Registry config (reputation change cap)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<EndorseAgent>, amount: u64) -> Result<()> {
+    let endorser_agent = &ctx.accounts.endorser_agent;
+    let endorsed_agent = &mut ctx.accounts.endorsed_agent;
+    require!(
+        endorser_agent.verified
+            && !endorser_agent.is_retired()
+            && !endorser_agent.on_probation
+            && !endorser_agent.suspended
+            && !endorsed_agent.is_retired()
+            && !endorsed_agent.suspended
+            && endorser_agent.key() != endorsed_agent.key()
+            && !endorsed_agent.shares_controller(endorser_agent),
+        RegistryError::InvalidEndorsement
+    );
+    require!(amount >= Endorsement::MIN_STAKE, RegistryError::EndorsementStakeTooLow);
+
+    deposit_lamports(
+        &ctx.accounts.endorser.to_account_info(),
+        &ctx.accounts.endorsement.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let old_reputation = endorsed_agent.reputation_score;
+    let headroom = Endorsement::MAX_AGENT_BONUS.saturating_sub(endorsed_agent.endorsement_bonus);
+    endorsed_agent.credit_reputation(
+        Endorsement::BONUS.min(headroom) as i32,
+        now,
+        ctx.accounts.config.max_reputation_change_per_day,
+    );
+    let bonus = endorsed_agent.reputation_score - old_reputation;
+    endorsed_agent.endorsement_bonus += bonus;
+
+    let endorsement = &mut ctx.accounts.endorsement;
+    endorsement.endorser_agent = endorser_agent.key();
+    endorsement.endorsed_agent = endorsed_agent.key();
+    endorsement.endorser = ctx.accounts.endorser.key();
+    endorsement.stake = amount;
+    endorsement.bonus = bonus;
+    endorsement.critical_alerts_at_endorsement =
+        AgentAuditSummary::critical_alerts_of(&ctx.accounts.audit_summary)?;
+    endorsement.created_at = now;
+    endorsement.bump = ctx.bumps.endorsement;
+
+    emit_reputation_changed(
+        endorsed_agent.key(),
+        old_reputation,
+        endorsed_agent.reputation_score,
+        ReputationChangeReason::Endorsement,
+        endorser_agent.key(),
+        now,
+    );
+
+    msg!(
+        "Agent {} endorsed agent {} with {} lamports. Bonus: +{}, reputation: {}",
+        endorser_agent.agent_id,
+        endorsed_agent.agent_id,
+        amount,
+        bonus,
+        endorsed_agent.reputation_score
+    );
+
+    Ok(())
+}
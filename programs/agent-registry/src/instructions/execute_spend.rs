@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{SpendProposal, Treasury, TreasuryCouncil};
+use crate::errors::RegistryError;
+use crate::events::SpendExecuted;
+use crate::utils::{transfer_from_pda, TokenMint};
+
+/// Pay out an approved treasury spend once its timelock has elapsed (anyone can call)
+///
+/// Approvals are re-counted against the current council, so members removed
+/// since approving no longer count: if that leaves the spend below the
+/// threshold, nothing is paid and its timelock is reset (it restarts once
+/// approve_spend brings it back to the threshold). Token spends pay from a
+/// treasury-owned token account of the proposal's mint (transfer-hook
+/// accounts in remaining accounts). The proposal is closed to its proposer.
+#[derive(Accounts)]
+pub struct ExecuteSpend<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [TreasuryCouncil::SEED_PREFIX],
+        bump = council.bump
+    )]
+    pub council: Account<'info, TreasuryCouncil>,
+
+    #[account(
+        mut,
+        seeds = [SpendProposal::SEED_PREFIX, proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, SpendProposal>,
+
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Spend recipient (validated against proposal.recipient)
+    #[account(mut, address = proposal.recipient @ RegistryError::Unauthorized)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Proposer receiving the proposal rent (validated against proposal.proposer)
+    #[account(mut, address = proposal.proposer @ RegistryError::Unauthorized)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Mint of the spend (token spends only, validated against proposal.mint)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Treasury-owned token account paying the spend (token spends only)
+    #[account(mut, token::authority = treasury)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Recipient's token account (token spends only)
+    #[account(mut, token::authority = recipient)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteSpend<'info>>) -> Result<()> {
+    let council = &ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    if council.count_approvals(&proposal.approvals) < council.threshold as usize {
+        require!(proposal.approved_at > 0, RegistryError::SpendNotApproved);
+        proposal.approved_at = 0;
+        msg!(
+            "Treasury spend #{} fell below the council threshold; timelock reset",
+            proposal.index
+        );
+        return Ok(());
+    }
+    require!(proposal.approved_at > 0, RegistryError::SpendNotApproved);
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= proposal.approved_at + council.timelock_secs as i64,
+        RegistryError::SpendTimelocked
+    );
+
+    if proposal.is_token() {
+        let token = TokenMint::from_accounts(
+            ctx.accounts.mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.remaining_accounts,
+        )
+        .ok_or(RegistryError::SpendMintMismatch)?;
+        let (Some(from), Some(to)) = (
+            &ctx.accounts.treasury_token_account,
+            &ctx.accounts.recipient_token_account,
+        ) else {
+            return err!(RegistryError::SpendMintMismatch);
+        };
+        let mint = proposal.mint;
+        require!(
+            token.mint.key() == mint && from.mint == mint && to.mint == mint,
+            RegistryError::SpendMintMismatch
+        );
+        require!(from.amount >= proposal.amount, RegistryError::InsufficientTreasury);
+        token.transfer(
+            &from.to_account_info(),
+            &to.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            &[&[Treasury::SEED_PREFIX, &[ctx.accounts.treasury.bump]]],
+            proposal.amount,
+        )?;
+    } else {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let rent = Rent::get()?.minimum_balance(treasury_info.data_len());
+        require!(
+            treasury_info.lamports().saturating_sub(rent) >= proposal.amount,
+            RegistryError::InsufficientTreasury
+        );
+        let recipient = ctx.accounts.recipient.to_account_info();
+        transfer_from_pda(&treasury_info, &recipient, proposal.amount)?;
+    }
+
+    emit!(SpendExecuted {
+        index: proposal.index,
+        recipient: proposal.recipient,
+        amount: proposal.amount,
+        mint: proposal.mint,
+        executed_by: ctx.accounts.caller.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Treasury spend #{} executed: {} (mint {}) to {}",
+        proposal.index,
+        proposal.amount,
+        proposal.mint,
+        proposal.recipient
+    );
+
+    proposal.close(ctx.accounts.proposer.to_account_info())
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengerStats, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::events::ChallengeClosed;
+use crate::instructions::expire_challenge::{expire, ExpirePayees};
+use crate::utils::transfer_from_pda;
+
+/// Expire (if still open) and close a challenge the challenger never cleaned up
+///
+/// Once the challenger's reclaim window (`Challenge::RECLAIM_WINDOW`) has lapsed,
+/// anyone may close the account. A stale challenge is first expired exactly as
+/// `expire_challenge` would (including the keeper bounty). The caller then keeps
+/// `Challenge::CLEANUP_RENT_BPS` of the rent; the remaining lamports (rest of
+/// the rent and any unreleased bond) go back to the challenger.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ExpireAndClose<'info> {
+    /// Anyone can call this (receives the bounty and a share of the rent)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Registry config (expiration bounty share)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The agent that was challenged
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner wallet (receives a slashed bond on abandonment)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Original challenger (receives the remaining lamports)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// The challenge to expire and close
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.token_reward == 0 @ RegistryError::TokenRewardUnsettled
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+}
+
+pub fn handler(ctx: Context<ExpireAndClose>, _nonce: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let caller = ctx.accounts.caller.to_account_info();
+    let challenger = ctx.accounts.challenger.to_account_info();
+
+    require!(
+        !ctx.accounts.challenge.is_reclaim_window_open(clock.unix_timestamp),
+        RegistryError::ReclaimWindowOpen
+    );
+
+    if ctx.accounts.challenge.is_awaiting_verdict() {
+        let agent_owner = ctx.accounts.agent_owner.to_account_info();
+        expire(
+            &mut ctx.accounts.challenge,
+            &mut ctx.accounts.agent,
+            &mut ctx.accounts.challenger_stats,
+            ExpirePayees {
+                agent_owner: &agent_owner,
+                challenger: &challenger,
+                caller: &caller,
+            },
+            &ctx.accounts.config,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    let challenge = &mut ctx.accounts.challenge;
+    require!(challenge.is_resolved(), RegistryError::ChallengeStillPending);
+    require!(!challenge.is_peer_result_pending(), RegistryError::PeerResultUnrecorded);
+    require!(!challenge.is_stake_slash_pending(), RegistryError::StakeSlashPending);
+    require!(
+        !challenge.is_dispute_window_open(clock.unix_timestamp),
+        RegistryError::DisputeWindowOpen
+    );
+
+    // Cleanup incentive: a share of the rent, never the bond
+    let challenge_info = challenge.to_account_info();
+    let rent = Rent::get()?.minimum_balance(challenge_info.data_len());
+    let incentive = rent * Challenge::CLEANUP_RENT_BPS / RegistryConfig::BPS_DENOMINATOR as u64;
+    transfer_from_pda(&challenge_info, &caller, incentive)?;
+
+    let reclaimed = challenge_info.lamports();
+    emit!(ChallengeClosed {
+        challenge: challenge.key(),
+        agent: challenge.agent,
+        challenger: challenge.challenger,
+        nonce: challenge.nonce,
+        score: challenge.score,
+        reputation_delta: challenge.reputation_delta,
+        reclaimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    challenge.close(challenger.clone())?;
+
+    msg!(
+        "Challenge closed by {}. Cleanup reward {}, {} lamports returned to challenger {}",
+        caller.key(),
+        incentive,
+        reclaimed,
+        challenger.key()
+    );
+
+    Ok(())
+}
@@ -1,21 +1,43 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, Challenge, ChallengeStatus, RegistryState};
+use crate::state::{
+    AgentAccount, Challenge, ChallengeStatus, ChallengerStats, RegistryConfig, RegistryState,
+};
 use crate::errors::RegistryError;
+use crate::events::{ChallengeExpired, ReputationChangeReason};
+use crate::utils::{
+    emit_reputation_changed, enforce_probation, pay_expire_bounty, slash_challenge_bond,
+    transfer_from_pda,
+};
 
 /// Expire a challenge that has passed its deadline
 ///
+/// Pending challenges past `expires_at` are marked Expired and the agent is penalized.
+/// Responded challenges whose reveal window lapsed are marked Abandoned (no penalty)
+/// and the challenger bond is slashed to the agent owner.
+/// Either way the escrowed reward (and, on expiry, the rest of the bond) is
+/// refunded to the challenger immediately.
+///
 /// This instruction can be called by ANYONE to expire a stale challenge.
 /// This is important for:
 /// 1. Network hygiene - cleaning up stale state
 /// 2. Accountability - agents that don't respond get penalized
 /// 3. Permissionless - anyone can trigger this, incentivizing cleanup
+///
+/// The caller earns `config.expire_bounty_bps` of the challenger bond as a keeper bounty.
 #[derive(Accounts)]
 #[instruction(nonce: u64)]
 pub struct ExpireChallenge<'info> {
-    /// Anyone can call this to expire a challenge
+    /// Anyone can call this to expire a challenge (receives the bounty)
     #[account(mut)]
     pub caller: Signer<'info>,
 
+    /// Registry config (expiration bounty share)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
     /// The registry (for validation)
     #[account(
         seeds = [RegistryState::SEED_PREFIX],
@@ -28,13 +50,21 @@ pub struct ExpireChallenge<'info> {
         mut,
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump
     )]
     pub agent: Account<'info, AgentAccount>,
 
+    /// CHECK: Agent owner wallet (receives a slashed bond on abandonment)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Original challenger (receives the escrow refund)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
     /// The challenge to expire
     #[account(
         mut,
@@ -46,36 +76,158 @@ pub struct ExpireChallenge<'info> {
         ],
         bump = challenge.bump,
         constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
-        constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending
+        constraint = challenge.is_awaiting_verdict() @ RegistryError::ChallengeNotPending
     )]
     pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
 }
 
 pub fn handler(ctx: Context<ExpireChallenge>, _nonce: u64) -> Result<()> {
-    let challenge = &mut ctx.accounts.challenge;
-    let agent = &mut ctx.accounts.agent;
     let clock = Clock::get()?;
+    let caller = ctx.accounts.caller.to_account_info();
+    let agent_owner = ctx.accounts.agent_owner.to_account_info();
+    let challenger = ctx.accounts.challenger.to_account_info();
+
+    expire(
+        &mut ctx.accounts.challenge,
+        &mut ctx.accounts.agent,
+        &mut ctx.accounts.challenger_stats,
+        ExpirePayees {
+            agent_owner: &agent_owner,
+            challenger: &challenger,
+            caller: &caller,
+        },
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    )
+}
+
+/// Wallets that receive lamports when a challenge is expired
+pub(crate) struct ExpirePayees<'a, 'info> {
+    /// Receives the slashed bond on abandonment
+    pub agent_owner: &'a AccountInfo<'info>,
+    /// Receives the escrow refund
+    pub challenger: &'a AccountInfo<'info>,
+    /// Receives the keeper bounty
+    pub caller: &'a AccountInfo<'info>,
+}
+
+/// Expire or abandon one stale challenge (shared with `expire_challenges_batch`)
+/// The caller must have checked that the challenge belongs to the agent and
+/// is still awaiting a verdict.
+pub(crate) fn expire<'info>(
+    challenge: &mut Account<'info, Challenge>,
+    agent: &mut Account<'info, AgentAccount>,
+    challenger_stats: &mut ChallengerStats,
+    payees: ExpirePayees<'_, 'info>,
+    config: &RegistryConfig,
+    now: i64,
+) -> Result<()> {
+    if challenge.status == ChallengeStatus::Responded {
+        // Agent answered but the challenger never revealed the salt
+        require!(
+            challenge.is_reveal_expired(now),
+            RegistryError::ChallengeNotExpired
+        );
+
+        // No reputation effect: the agent did its part
+        challenge.status = ChallengeStatus::Abandoned;
+        challenger_stats.record_abandoned();
+
+        // Keeper bounty first, then slash the rest of the bond to the agent owner
+        // (the unpaid reward goes back to the challenger)
+        let bounty = pay_expire_bounty(challenge, payees.caller, config.expire_bounty_bps)?;
+        let (bond, refunded) =
+            slash_challenge_bond(challenge, payees.challenger, payees.agent_owner)?;
+
+        msg!(
+            "Challenge ABANDONED by challenger {}. Bond {} slashed to agent {}, reward {} refunded, bounty {}",
+            challenge.challenger,
+            bond,
+            agent.agent_id,
+            refunded,
+            bounty
+        );
+
+        emit!(ChallengeExpired {
+            challenge: challenge.key(),
+            agent: challenge.agent,
+            challenger: challenge.challenger,
+            nonce: challenge.nonce,
+            abandoned: true,
+            reputation_delta: 0,
+            reputation_score: agent.reputation_score,
+            bounty,
+            refunded,
+            timestamp: now,
+        });
+
+        return Ok(());
+    }
 
     // Verify challenge is actually expired
-    require!(
-        challenge.is_expired(clock.unix_timestamp),
-        RegistryError::ChallengeNotExpired
-    );
+    require!(challenge.is_expired(now), RegistryError::ChallengeNotExpired);
 
     // Mark as expired
     challenge.status = ChallengeStatus::Expired;
-    challenge.responded_at = clock.unix_timestamp;
+    challenge.responded_at = now;
 
     // Apply penalty for not responding (same as failing)
+    let delta = challenge.verdict_delta(agent.reputation_score, 0);
+    let old_reputation = agent.reputation_score;
     agent.challenges_failed = agent.challenges_failed.saturating_add(1);
-    agent.adjust_reputation(Challenge::FAIL_REPUTATION_DELTA);
-    agent.updated_at = clock.unix_timestamp;
+    let delta = agent.credit_reputation(delta, now, config.max_reputation_change_per_day);
+    emit_reputation_changed(
+        agent.key(),
+        old_reputation,
+        agent.reputation_score,
+        ReputationChangeReason::ChallengeExpired,
+        challenge.key(),
+        now,
+    );
+    enforce_probation(agent, old_reputation, config, now);
+    agent.updated_at = now;
+    // Rounds already scored on a multi-round challenge stay in the running total
+    challenge.reputation_delta += delta;
+    challenger_stats.record_verdict(false);
+
+    // Keeper bounty from the bond; the rest of the bond and the reward go back to the challenger
+    let bounty = pay_expire_bounty(challenge, payees.caller, config.expire_bounty_bps)?;
+    let refunded = challenge
+        .bond
+        .checked_add(challenge.reward)
+        .ok_or(RegistryError::MathOverflow)?;
+    challenge.bond = 0;
+    challenge.reward = 0;
+    transfer_from_pda(&challenge.to_account_info(), payees.challenger, refunded)?;
 
     msg!(
-        "Challenge EXPIRED! Agent {} did not respond. Reputation: {}",
+        "Challenge EXPIRED! Agent {} did not respond. Reputation: {}, bounty {}, refunded {}",
         agent.agent_id,
-        agent.reputation_score
+        agent.reputation_score,
+        bounty,
+        refunded
     );
 
+    emit!(ChallengeExpired {
+        challenge: challenge.key(),
+        agent: challenge.agent,
+        challenger: challenge.challenger,
+        nonce: challenge.nonce,
+        abandoned: false,
+        reputation_delta: delta,
+        reputation_score: agent.reputation_score,
+        bounty,
+        refunded,
+        timestamp: now,
+    });
+
     Ok(())
 }
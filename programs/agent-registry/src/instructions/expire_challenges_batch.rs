@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengerStats, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::instructions::expire_challenge::{expire, ExpirePayees};
+
+/// Expire many stale challenges in one transaction (keeper crank)
+///
+/// `remaining_accounts` holds writable groups of (agent, challenge, agent owner
+/// wallet, challenger wallet, challenger stats). Each challenge is processed exactly like `expire_challenge`: Pending
+/// past its deadline is Expired with a penalty, Responded past its reveal window
+/// is Abandoned with the bond slashed to the owner. The caller earns the bounty
+/// for each one. Any invalid triple fails the whole batch.
+#[derive(Accounts)]
+pub struct ExpireChallengesBatch<'info> {
+    /// Anyone can crank expirations (receives the bounties)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Registry config (expiration bounty share)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExpireChallengesBatch<'info>>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    let groups = remaining.chunks_exact(5);
+    require!(
+        !remaining.is_empty() && groups.remainder().is_empty(),
+        RegistryError::EmptyBatch
+    );
+
+    let clock = Clock::get()?;
+    let caller = ctx.accounts.caller.to_account_info();
+
+    for group in groups {
+        let (agent_info, challenge_info, owner_info, challenger_info, stats_info) =
+            (&group[0], &group[1], &group[2], &group[3], &group[4]);
+
+        let mut agent = Account::<AgentAccount>::try_from(agent_info)?;
+        let mut challenge = Account::<Challenge>::try_from(challenge_info)?;
+        let mut stats = Account::<ChallengerStats>::try_from(stats_info)?;
+
+        require_keys_eq!(challenge.agent, agent.key(), RegistryError::ChallengeMismatch);
+        require_keys_eq!(owner_info.key(), agent.owner, RegistryError::Unauthorized);
+        require!(challenge.is_awaiting_verdict(), RegistryError::ChallengeNotPending);
+        require_keys_eq!(challenger_info.key(), challenge.challenger, RegistryError::Unauthorized);
+        require_keys_eq!(stats.challenger, challenge.challenger, RegistryError::ChallengeMismatch);
+
+        expire(
+            &mut challenge,
+            &mut agent,
+            &mut stats,
+            ExpirePayees {
+                agent_owner: owner_info,
+                challenger: challenger_info,
+                caller: &caller,
+            },
+            &ctx.accounts.config,
+            clock.unix_timestamp,
+        )?;
+
+        // Persist now so a later group for the same agent or challenger sees the update
+        agent.exit(&crate::ID)?;
+        challenge.exit(&crate::ID)?;
+        stats.exit(&crate::ID)?;
+    }
+
+    msg!("Expired {} challenges", remaining.len() / 5);
+
+    Ok(())
+}
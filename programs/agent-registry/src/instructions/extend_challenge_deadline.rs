@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use crate::errors::RegistryError;
+
+/// Push back the response deadline of a pending challenge
+///
+/// The challenger alone may extend up to `Challenge::MAX_SOLO_LIFETIME` after
+/// creation; with the agent owner co-signing, up to `Challenge::MAX_AGREED_LIFETIME`.
+/// Each call extends by at most `Challenge::MAX_DEADLINE_EXTENSION`.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ExtendChallengeDeadline<'info> {
+    /// The original challenger
+    pub challenger: Signer<'info>,
+
+    /// Agent owner co-signature (optional, lifts the lifetime cap)
+    pub agent_owner: Option<Signer<'info>>,
+
+    /// The agent that was challenged (for PDA derivation)
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The challenge to extend
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.challenger == challenger.key() @ RegistryError::Unauthorized,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending
+    )]
+    pub challenge: Account<'info, Challenge>,
+}
+
+pub fn handler(ctx: Context<ExtendChallengeDeadline>, _nonce: u64, extension: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let challenge = &mut ctx.accounts.challenge;
+
+    require!(
+        !challenge.is_expired(clock.unix_timestamp),
+        RegistryError::ChallengeExpired
+    );
+
+    let co_signed = match &ctx.accounts.agent_owner {
+        Some(owner) => {
+            require_keys_eq!(owner.key(), ctx.accounts.agent.owner, RegistryError::Unauthorized);
+            true
+        }
+        None => false,
+    };
+    let max_lifetime = if co_signed {
+        Challenge::MAX_AGREED_LIFETIME
+    } else {
+        Challenge::MAX_SOLO_LIFETIME
+    };
+
+    let new_expires_at = challenge.expires_at + extension;
+    require!(
+        extension > 0
+            && extension <= Challenge::MAX_DEADLINE_EXTENSION
+            && new_expires_at <= challenge.created_at + max_lifetime,
+        RegistryError::InvalidDeadlineExtension
+    );
+
+    challenge.expires_at = new_expires_at;
+
+    msg!(
+        "Challenge deadline extended by {}s to {} (agent {}, co-signed: {})",
+        extension,
+        new_expires_at,
+        ctx.accounts.agent.agent_id,
+        co_signed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsuranceClaim, InsurancePolicy, RegistryConfig, SlashRecord};
+use crate::errors::RegistryError;
+use crate::events::InsuranceClaimFiled;
+use crate::utils::deposit_lamports;
+
+/// File a bonded insurance claim against a slashed, insured agent
+///
+/// The claim must point at a slash of the agent made while its policy was
+/// open, and at the interaction the claimant was harmed in: a task or payment
+/// stream the claimant funded, or a challenge the claimant opened, against the
+/// same agent. The claimant locks config.insurance_claim_bond_lamports, refunded if
+/// the claim is approved by `arbitrate_insurance_claim`.
+#[derive(Accounts)]
+pub struct FileInsuranceClaim<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// Slash proving the agent misbehaved
+    #[account(
+        seeds = [SlashRecord::SEED_PREFIX, slash_record.subject.as_ref()],
+        bump = slash_record.bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+
+    #[account(
+        seeds = [InsurancePolicy::SEED_PREFIX, slash_record.agent.as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, InsurancePolicy>,
+
+    /// CHECK: Harmed interaction (Task, PaymentStream or Challenge), validated in the handler
+    pub interaction: UncheckedAccount<'info>,
+
+    /// Registry config (claim bond amount)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + InsuranceClaim::INIT_SPACE,
+        seeds = [
+            InsuranceClaim::SEED_PREFIX,
+            slash_record.key().as_ref(),
+            claimant.key().as_ref()
+        ],
+        bump
+    )]
+    pub claim: Account<'info, InsuranceClaim>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<FileInsuranceClaim>,
+    amount: u64,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidInsuranceAmount);
+    require!(
+        ctx.accounts.policy.created_at <= ctx.accounts.slash_record.timestamp,
+        RegistryError::InsuranceNotCovered
+    );
+    let (client, agent) = InsuranceClaim::interaction_parties(&ctx.accounts.interaction)?;
+    require!(
+        client == ctx.accounts.claimant.key() && agent == ctx.accounts.slash_record.agent,
+        RegistryError::InsuranceInteractionMismatch
+    );
+
+    let bond = ctx.accounts.config.insurance_claim_bond_lamports;
+    deposit_lamports(
+        &ctx.accounts.claimant.to_account_info(),
+        &ctx.accounts.claim.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        bond,
+    )?;
+
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim;
+    claim.slash_record = ctx.accounts.slash_record.key();
+    claim.agent = ctx.accounts.slash_record.agent;
+    claim.interaction = ctx.accounts.interaction.key();
+    claim.claimant = ctx.accounts.claimant.key();
+    claim.amount = amount;
+    claim.evidence_hash = evidence_hash;
+    claim.bond = bond;
+    claim.filed_at = clock.unix_timestamp;
+    claim.bump = ctx.bumps.claim;
+
+    emit!(InsuranceClaimFiled {
+        claim: claim.key(),
+        agent: claim.agent,
+        claimant: claim.claimant,
+        slash_record: claim.slash_record,
+        interaction: claim.interaction,
+        amount,
+        evidence_hash,
+        bond,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Insurance claim filed against agent {}: {} lamports, bond {}",
+        claim.agent,
+        amount,
+        bond
+    );
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentAuditSummary, AuditPolicy, DailyDigest, DayRollup};
+use crate::errors::RegistryError;
+use crate::events::AuditDayFinalized;
+
+/// Roll a finished audit day up into a DailyDigest PDA
+///
+/// Any key allowed to log audit entries for the agent may finalize. `day` must
+/// be the start day of the next pending rollup; if finalization fell behind,
+/// the digest covers every unfinalized day up to the last one closed.
+#[derive(Accounts)]
+#[instruction(day: u32)]
+pub struct FinalizeAuditDay<'info> {
+    #[account(mut)]
+    pub actor: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &actor.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_summary.bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    #[account(
+        init,
+        payer = actor,
+        space = 8 + DailyDigest::INIT_SPACE,
+        seeds = [DailyDigest::SEED_PREFIX, agent.key().as_ref(), day.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub digest: Account<'info, DailyDigest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FinalizeAuditDay>, day: u32, merkle_root: [u8; 32]) -> Result<()> {
+    require!(merkle_root != [0u8; 32], RegistryError::InvalidDigestRoot);
+
+    let clock = Clock::get()?;
+    let today = DayRollup::day_of(clock.unix_timestamp);
+    let rollup = ctx
+        .accounts
+        .audit_summary
+        .take_finished_days(today)
+        .ok_or(RegistryError::AuditDayNotFinished)?;
+    require!(rollup.start_day == day, RegistryError::AuditDayMismatch);
+
+    let digest = &mut ctx.accounts.digest;
+    digest.agent = ctx.accounts.agent.key();
+    digest.day = rollup.start_day;
+    digest.end_day = rollup.end_day;
+    digest.entry_count = rollup.entries;
+    digest.first_index = rollup.first_index;
+    digest.max_risk = rollup.max_risk;
+    digest.avg_risk = rollup.avg_risk();
+    digest.merkle_root = merkle_root;
+    digest.finalized_by = ctx.accounts.actor.key();
+    digest.finalized_at = clock.unix_timestamp;
+    digest.bump = ctx.bumps.digest;
+
+    emit!(AuditDayFinalized {
+        digest: digest.key(),
+        agent: digest.agent,
+        day,
+        end_day: digest.end_day,
+        entry_count: digest.entry_count,
+        first_index: digest.first_index,
+        max_risk: digest.max_risk,
+        avg_risk: digest.avg_risk,
+        merkle_root,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit day finalized: agent={}, day={}, entries={}, max_risk={}, avg_risk={}",
+        digest.agent,
+        day,
+        digest.entry_count,
+        digest.max_risk,
+        digest.avg_risk
+    );
+
+    Ok(())
+}
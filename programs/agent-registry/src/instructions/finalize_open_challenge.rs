@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, OpenChallenge, OpenChallengeStatus, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::events::ReputationChangeReason;
+use crate::utils::{emit_reputation_changed, transfer_from_pda};
+
+/// Finalize an open challenge and close it (permissionless)
+///
+/// - Revealed, claim window over: the winner's owner receives the reward and the
+///   winning agent the pass reputation delta (no winner = nothing paid)
+/// - Never revealed within the reveal window: the bond is slashed to the first
+///   responder's owner
+/// - Abandoned by a bad reveal: the bond was already slashed, nothing is paid
+///
+/// Remaining lamports (unpaid reward, rent) return to the challenger.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct FinalizeOpenChallenge<'info> {
+    /// Anyone can finalize
+    pub caller: Signer<'info>,
+
+    /// CHECK: Original challenger receiving the remaining lamports
+    #[account(mut, address = open_challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [
+            OpenChallenge::SEED_PREFIX,
+            open_challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = open_challenge.bump
+    )]
+    pub open_challenge: Account<'info, OpenChallenge>,
+
+    /// The winning agent (required when there is a winner)
+    #[account(mut)]
+    pub winner_agent: Option<Account<'info, AgentAccount>>,
+
+    /// CHECK: Winner's owner (on win) or first responder (on abandonment), validated in the handler
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    /// Registry config (reputation change cap)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<FinalizeOpenChallenge>, _nonce: u64) -> Result<()> {
+    let open_challenge = &mut ctx.accounts.open_challenge;
+    let clock = Clock::get()?;
+
+    match open_challenge.status {
+        OpenChallengeStatus::Revealed => {
+            require!(
+                !open_challenge.is_claim_open(clock.unix_timestamp),
+                RegistryError::ClaimWindowOpen
+            );
+            if !open_challenge.has_winner() {
+                msg!("Open challenge {} finalized without a winner", open_challenge.key());
+                return Ok(());
+            }
+
+            let winner_agent = ctx
+                .accounts
+                .winner_agent
+                .as_mut()
+                .ok_or(RegistryError::InvalidPayoutAccount)?;
+            let recipient = ctx
+                .accounts
+                .recipient
+                .as_ref()
+                .ok_or(RegistryError::InvalidPayoutAccount)?;
+            require_keys_eq!(
+                winner_agent.key(),
+                open_challenge.winner,
+                RegistryError::InvalidPayoutAccount
+            );
+            require_keys_eq!(
+                recipient.key(),
+                winner_agent.owner,
+                RegistryError::InvalidPayoutAccount
+            );
+
+            let reward = open_challenge.reward;
+            open_challenge.reward = 0;
+            transfer_from_pda(
+                &open_challenge.to_account_info(),
+                &recipient.to_account_info(),
+                reward,
+            )?;
+
+            let delta = open_challenge.pass_delta();
+            let old_reputation = winner_agent.reputation_score;
+            winner_agent.challenges_passed = winner_agent.challenges_passed.saturating_add(1);
+            winner_agent.credit_reputation(
+                delta,
+                clock.unix_timestamp,
+                ctx.accounts.config.max_reputation_change_per_day,
+            );
+            emit_reputation_changed(
+                winner_agent.key(),
+                old_reputation,
+                winner_agent.reputation_score,
+                ReputationChangeReason::OpenChallengeWon,
+                open_challenge.key(),
+                clock.unix_timestamp,
+            );
+            winner_agent.updated_at = clock.unix_timestamp;
+
+            msg!(
+                "Open challenge {} WON by agent {}. Reputation: {}, reward: {}",
+                open_challenge.key(),
+                winner_agent.agent_id,
+                winner_agent.reputation_score,
+                reward
+            );
+        }
+        OpenChallengeStatus::Abandoned => {
+            msg!("Open challenge {} finalized after a rejected reveal", open_challenge.key());
+        }
+        OpenChallengeStatus::Open => {
+            require!(
+                open_challenge.is_reveal_expired(clock.unix_timestamp),
+                RegistryError::ChallengeNotExpired
+            );
+
+            // Challenger never revealed: slash the bond to the first responder
+            let mut slashed = 0;
+            if open_challenge.response_count > 0 {
+                let recipient = ctx
+                    .accounts
+                    .recipient
+                    .as_ref()
+                    .ok_or(RegistryError::InvalidPayoutAccount)?;
+                require_keys_eq!(
+                    recipient.key(),
+                    open_challenge.first_responder,
+                    RegistryError::InvalidPayoutAccount
+                );
+
+                slashed = open_challenge.bond;
+                open_challenge.bond = 0;
+                transfer_from_pda(
+                    &open_challenge.to_account_info(),
+                    &recipient.to_account_info(),
+                    slashed,
+                )?;
+            }
+
+            msg!(
+                "Open challenge {} ABANDONED by challenger. Bond {} slashed",
+                open_challenge.key(),
+                slashed
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentRecovery};
+use crate::events::AgentRecovered;
+use crate::errors::RegistryError;
+
+/// Finalize a pending recovery once the delay has elapsed
+/// The NFT holder must still hold the identity NFT at finalization time
+#[derive(Accounts)]
+pub struct FinalizeRecovery<'info> {
+    /// Current holder of the identity NFT (receives the request rent back)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Identity NFT asset; holder is read from the Metaplex Core layout in the handler
+    #[account(address = agent.nft_mint @ RegistryError::NftMismatch)]
+    pub nft_asset: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = holder,
+        seeds = [AgentRecovery::SEED_PREFIX, agent.key().as_ref()],
+        bump = recovery.bump,
+        constraint = recovery.requester == holder.key() @ RegistryError::Unauthorized
+    )]
+    pub recovery: Account<'info, AgentRecovery>,
+}
+
+pub fn handler(ctx: Context<FinalizeRecovery>) -> Result<()> {
+    let holder = AgentRecovery::nft_holder(&ctx.accounts.nft_asset.to_account_info())
+        .ok_or(RegistryError::NotNftHolder)?;
+    require_keys_eq!(holder, ctx.accounts.holder.key(), RegistryError::NotNftHolder);
+
+    let clock = Clock::get()?;
+    let recovery = &ctx.accounts.recovery;
+    require!(
+        clock.unix_timestamp >= recovery.executable_at,
+        RegistryError::RecoveryDelayNotElapsed
+    );
+
+    let agent = &mut ctx.accounts.agent;
+    let old_owner = agent.owner;
+
+    // Pin the PDA seed owner before reassigning (legacy accounts)
+    if agent.original_owner == Pubkey::default() {
+        agent.original_owner = old_owner;
+    }
+    agent.owner = recovery.new_owner;
+    // The previous owner's delegated key must not survive the handover
+    agent.operator = Pubkey::default();
+    agent.updated_at = clock.unix_timestamp;
+
+    emit!(AgentRecovered {
+        agent: agent.key(),
+        old_owner,
+        new_owner: agent.owner,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Agent recovered: id={}, old_owner={}, new_owner={}",
+        agent.agent_id,
+        old_owner,
+        agent.owner
+    );
+
+    Ok(())
+}
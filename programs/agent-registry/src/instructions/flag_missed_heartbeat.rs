@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditEntry,
+    HeartbeatMonitor, RegistryConfig, RiskLevel, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::{AuditLogged, HeartbeatMissed};
+use crate::utils::enforce_circuit_breaker;
+
+/// Log a SecurityAlert for an agent whose sentinel missed its heartbeat (anyone can crank)
+///
+/// Allowed once the gap since the later of the last heartbeat and the last
+/// missed-heartbeat alert exceeds the configured interval. The entry's details
+/// hash is sha256(heartbeat account || last heartbeat time || now).
+#[derive(Accounts)]
+pub struct FlagMissedHeartbeat<'info> {
+    /// Pays for the audit entry
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (audit circuit breaker thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [HeartbeatMonitor::SEED_PREFIX, agent.key().as_ref()],
+        bump = heartbeat.bump
+    )]
+    pub heartbeat: Account<'info, HeartbeatMonitor>,
+
+    /// The audit summary for this agent (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// Audit entry recording the missed heartbeat
+    #[account(
+        init,
+        payer = caller,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub audit_entry: Account<'info, AuditEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FlagMissedHeartbeat>) -> Result<()> {
+    let clock = Clock::get()?;
+    let heartbeat = &mut ctx.accounts.heartbeat;
+    require!(heartbeat.is_missed(clock.unix_timestamp), RegistryError::HeartbeatNotMissed);
+
+    let details = hashv(&[
+        heartbeat.key().as_ref(),
+        &heartbeat.last_beat_at.to_le_bytes(),
+        &clock.unix_timestamp.to_le_bytes(),
+    ])
+    .to_bytes();
+    heartbeat.last_flagged_at = clock.unix_timestamp;
+    heartbeat.missed_beats = heartbeat.missed_beats.saturating_add(1);
+
+    let agent_key = ctx.accounts.agent.key();
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = agent_key;
+        summary.bump = ctx.bumps.audit_summary;
+    }
+    summary.decay_risk(clock.unix_timestamp, &ctx.accounts.config);
+
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+    let risk_score =
+        weights.risk_score(&ActionType::SecurityAlert, 0, &ActionCodeRegistry::default())?;
+    let entry = &mut ctx.accounts.audit_entry;
+    entry.agent = agent_key;
+    entry.actor = ctx.accounts.caller.key();
+    entry.action_type = ActionType::SecurityAlert;
+    entry.risk_score = risk_score;
+    entry.risk_level = RiskLevel::from_score(risk_score);
+    entry.timestamp = clock.unix_timestamp;
+    entry.details_hash = details.iter().map(|b| format!("{:02x}", b)).collect();
+    entry.audit_index = summary.total_entries;
+    entry.bump = ctx.bumps.audit_entry;
+    entry.risk_weights_version = weights.version;
+    entry.auditor = Pubkey::default();
+    entry.encrypted_details_uri = None;
+    entry.is_alert = true;
+    entry.prev_entry_hash = summary.advance_chain(
+        entry.audit_index,
+        ActionType::SecurityAlert,
+        risk_score,
+        clock.unix_timestamp,
+        &details,
+    );
+    summary.record_entry(ActionType::SecurityAlert, risk_score, true, clock.unix_timestamp);
+
+    let critical = RiskLevel::is_critical(risk_score) as u32;
+    enforce_circuit_breaker(
+        &mut ctx.accounts.agent,
+        summary,
+        critical,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    emit!(HeartbeatMissed {
+        agent: agent_key,
+        last_beat_at: heartbeat.last_beat_at,
+        interval_secs: heartbeat.interval_secs,
+        missed_beats: heartbeat.missed_beats,
+        timestamp: clock.unix_timestamp,
+    });
+    emit!(AuditLogged {
+        agent: agent_key,
+        actor: entry.actor,
+        action_type: ActionType::SecurityAlert,
+        risk_score,
+        index: entry.audit_index,
+        is_alert: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Missed sentinel heartbeat: agent={}, last_beat_at={}, missed={}",
+        agent_key,
+        heartbeat.last_beat_at,
+        heartbeat.missed_beats
+    );
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, StakeVault};
+use crate::errors::RegistryError;
+use crate::events::StakeRewardsFunded;
+use crate::utils::deposit_lamports;
+
+/// Add reward lamports to an agent's stake vault, shared pro-rata by its stakers
+///
+/// Permissionless: sponsors, the agent owner, or an emissions program can fund
+/// rewards. Challenge fees feed the same pool via config.staker_fee_share_bps.
+#[derive(Accounts)]
+pub struct FundStakeRewards<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundStakeRewards>, amount: u64) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidRewardAmount);
+
+    deposit_lamports(
+        &ctx.accounts.funder.to_account_info(),
+        &ctx.accounts.stake_vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+    let vault = &mut ctx.accounts.stake_vault;
+    vault.accrue_rewards(amount)?;
+
+    emit!(StakeRewardsFunded {
+        agent: vault.agent,
+        funder: ctx.accounts.funder.key(),
+        amount,
+        reward_per_share: vault.reward_per_share,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Staking rewards funded for agent {}: {} lamports. Total rewards: {}",
+        ctx.accounts.agent.agent_id,
+        amount,
+        vault.total_rewards
+    );
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use crate::errors::RegistryError;
+use crate::utils::{tokens_received, TokenMint};
+
+/// Attach an SPL-token reward to a pending challenge
+///
+/// Tokens are held in an associated token account owned by the challenge PDA
+/// and released by `settle_token_reward` after the challenge is resolved:
+/// paid to the agent owner on pass, refunded to the challenger otherwise.
+/// Works with SPL and Token-2022 mints: the reward is the amount that reached
+/// the vault after any transfer fee, and transfer-hook accounts go in
+/// remaining accounts.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct FundTokenReward<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.challenger == challenger.key() @ RegistryError::Unauthorized,
+        constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending,
+        constraint = challenge.token_reward == 0 @ RegistryError::TokenRewardAlreadyFunded
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    /// Challenger's token account funding the reward
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = challenger,
+        token::token_program = token_program
+    )]
+    pub challenger_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Reward vault owned by the challenge PDA
+    #[account(
+        init,
+        payer = challenger,
+        associated_token::mint = reward_mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FundTokenReward<'info>>,
+    _nonce: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidRewardAmount);
+
+    let token = TokenMint {
+        mint: &ctx.accounts.reward_mint,
+        token_program: &ctx.accounts.token_program,
+        hook_accounts: ctx.remaining_accounts,
+    };
+    let before = ctx.accounts.vault.amount;
+    token.transfer(
+        &ctx.accounts.challenger_token_account.to_account_info(),
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.challenger.to_account_info(),
+        &[],
+        amount,
+    )?;
+    let received = tokens_received(&mut ctx.accounts.vault, before)?;
+    require!(received > 0, RegistryError::InvalidRewardAmount);
+
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.reward_mint = ctx.accounts.reward_mint.key();
+    challenge.token_reward = received;
+
+    msg!(
+        "Token reward funded: challenge={}, mint={}, amount={}",
+        challenge.key(),
+        challenge.reward_mint,
+        received
+    );
+
+    Ok(())
+}
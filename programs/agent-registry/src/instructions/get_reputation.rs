@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::AgentAccount;
+
+/// Accounts for querying an agent's reputation estimate (read-only helper)
+#[derive(Accounts)]
+pub struct GetReputation<'info> {
+    /// The agent to query
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+/// Returns the additive score alongside the pass-rate model
+pub fn handler(ctx: Context<GetReputation>) -> Result<ReputationEstimate> {
+    let agent = &ctx.accounts.agent;
+
+    Ok(ReputationEstimate {
+        reputation_score: agent.reputation_score,
+        successes: agent.challenges_passed,
+        failures: agent.challenges_failed,
+        mean_bps: agent.reputation_mean_bps(),
+        lower_bound_bps: agent.reputation_lower_bound_bps(),
+        last_update: agent.reputation_updated_at,
+        on_probation: agent.on_probation,
+        probation_streak: agent.probation_streak,
+        suspended: agent.suspended,
+        staked_amount: agent.staked_amount,
+    })
+}
+
+/// Response struct for reputation estimate queries
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReputationEstimate {
+    /// Additive reputation score (0-10000)
+    pub reputation_score: u32,
+    pub successes: u32,
+    pub failures: u32,
+    /// Beta(1, 1) posterior mean pass rate (basis points)
+    pub mean_bps: u16,
+    /// 95% Wilson lower confidence bound of the pass rate (basis points)
+    pub lower_bound_bps: u16,
+    pub last_update: i64,
+    /// Whether the agent is on probation, and its streak of verifier-resolved passes
+    pub on_probation: bool,
+    pub probation_streak: u8,
+    /// Whether the audit circuit breaker suspended the agent
+    pub suspended: bool,
+    /// Amount staked behind the agent (lamports, or base units of the vault's mint)
+    pub staked_amount: u64,
+}
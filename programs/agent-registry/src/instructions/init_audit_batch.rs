@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditBatch};
+use crate::errors::RegistryError;
+
+/// Initialize a pre-allocated AuditBatch account for an agent (agent owner)
+#[derive(Accounts)]
+pub struct InitAuditBatch<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Allocated by the client (create_account with AuditBatch::SPACE bytes, owned by this program)
+    #[account(zero)]
+    pub audit_batch: AccountLoader<'info, AuditBatch>,
+}
+
+pub fn handler(ctx: Context<InitAuditBatch>) -> Result<()> {
+    let mut batch = ctx.accounts.audit_batch.load_init()?;
+    batch.agent = ctx.accounts.agent.key();
+    batch.created_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Audit batch {} created for agent {} ({} entries)",
+        ctx.accounts.audit_batch.key(),
+        ctx.accounts.agent.agent_id,
+        AuditBatch::CAPACITY
+    );
+
+    Ok(())
+}
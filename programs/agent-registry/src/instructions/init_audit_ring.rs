@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditRing};
+use crate::errors::RegistryError;
+
+/// Create the fixed-size audit ring for an agent (agent owner, one-time)
+#[derive(Accounts)]
+pub struct InitAuditRing<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = AuditRing::SPACE,
+        seeds = [AuditRing::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_ring: AccountLoader<'info, AuditRing>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAuditRing>) -> Result<()> {
+    let mut ring = ctx.accounts.audit_ring.load_init()?;
+    ring.agent = ctx.accounts.agent.key();
+    ring.bump = ctx.bumps.audit_ring;
+
+    msg!(
+        "Audit ring created for agent {} ({} entries)",
+        ctx.accounts.agent.agent_id,
+        AuditRing::CAPACITY
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::{AgentAccount, AuditTree};
+use crate::errors::RegistryError;
+
+/// Create a compressed audit tree for an agent (owner only)
+///
+/// The client allocates `merkle_tree` beforehand (owned by the account-compression
+/// program and sized for max_depth/max_buffer_size); this instruction records it
+/// and initializes it with the AuditTree PDA as tree authority.
+#[derive(Accounts)]
+pub struct InitAuditTree<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AuditTree::INIT_SPACE,
+        seeds = [AuditTree::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_tree: Account<'info, AuditTree>,
+
+    /// CHECK: Uninitialized concurrent Merkle tree account, validated by the compression program
+    #[account(mut, owner = AuditTree::COMPRESSION_PROGRAM_ID @ RegistryError::InvalidAuditTree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL account-compression program
+    #[account(address = AuditTree::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL noop program
+    #[account(address = AuditTree::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitAuditTree>, max_depth: u32, max_buffer_size: u32) -> Result<()> {
+    require!(
+        (1..=AuditTree::MAX_DEPTH).contains(&max_depth) && max_buffer_size > 0,
+        RegistryError::InvalidAuditTree
+    );
+
+    let agent_key = ctx.accounts.agent.key();
+    let tree = &mut ctx.accounts.audit_tree;
+    tree.agent = agent_key;
+    tree.merkle_tree = ctx.accounts.merkle_tree.key();
+    tree.max_depth = max_depth;
+    tree.max_buffer_size = max_buffer_size;
+    tree.leaves_appended = 0;
+    tree.bump = ctx.bumps.audit_tree;
+
+    invoke_signed(
+        &AuditTree::init_ix(tree.merkle_tree, tree.key(), max_depth, max_buffer_size),
+        &[
+            ctx.accounts.merkle_tree.to_account_info(),
+            tree.to_account_info(),
+            ctx.accounts.noop_program.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+        ],
+        &[&[AuditTree::SEED_PREFIX, agent_key.as_ref(), &[tree.bump]]],
+    )?;
+
+    msg!(
+        "Audit tree created: agent={}, tree={}, depth={}, buffer={}",
+        agent_key,
+        tree.merkle_tree,
+        max_depth,
+        max_buffer_size
+    );
+
+    Ok(())
+}
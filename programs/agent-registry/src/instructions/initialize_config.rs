@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::state::{ChallengeKind, RegistryConfig, RegistryState};
+use crate::errors::RegistryError;
+
+/// Create the registry config with default parameters (admin only, one-time)
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RegistryConfig::INIT_SPACE,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.challenge_bond_lamports = RegistryConfig::DEFAULT_CHALLENGE_BOND;
+    config.max_difficulty = RegistryConfig::DEFAULT_MAX_DIFFICULTY;
+    config.dispute_bond_lamports = RegistryConfig::DEFAULT_DISPUTE_BOND;
+    config.oracle_authority = Pubkey::default();
+    config.expire_bounty_bps = RegistryConfig::DEFAULT_EXPIRE_BOUNTY_BPS;
+    config.max_challenges_per_day = RegistryConfig::DEFAULT_MAX_CHALLENGES_PER_DAY;
+    config.max_pending_per_challenger = RegistryConfig::DEFAULT_MAX_PENDING_PER_CHALLENGER;
+    config.verified_stake_per_tier = RegistryConfig::DEFAULT_VERIFIED_STAKE_PER_TIER;
+    config.challenge_fee_bps = RegistryConfig::DEFAULT_CHALLENGE_FEE_BPS;
+    config.verified_challenger_weight_bps = RegistryConfig::DEFAULT_VERIFIED_CHALLENGER_WEIGHT_BPS;
+    config.anonymous_challenger_weight_bps = RegistryConfig::DEFAULT_ANONYMOUS_CHALLENGER_WEIGHT_BPS;
+    config.fast_response_secs = RegistryConfig::DEFAULT_FAST_RESPONSE_SECS;
+    config.quick_response_secs = RegistryConfig::DEFAULT_QUICK_RESPONSE_SECS;
+    config.max_reputation_change_per_day = RegistryConfig::DEFAULT_MAX_REPUTATION_CHANGE_PER_DAY;
+    config.stake_boost_unit_lamports = RegistryConfig::DEFAULT_STAKE_BOOST_UNIT_LAMPORTS;
+    config.stake_boost_bps_per_unit = RegistryConfig::DEFAULT_STAKE_BOOST_BPS_PER_UNIT;
+    config.max_stake_boost_bps = RegistryConfig::DEFAULT_MAX_STAKE_BOOST_BPS;
+    config.stake_slash_bps = RegistryConfig::DEFAULT_STAKE_SLASH_BPS;
+    config.min_reputation_open_challenge = 0;
+    config.min_reputation_verifier = 0;
+    config.reputation_migration_bps = RegistryConfig::DEFAULT_REPUTATION_MIGRATION_BPS;
+    config.ema_alpha_bps = 0;
+    config.report_bond_lamports = RegistryConfig::DEFAULT_REPORT_BOND;
+    config.probation_floor = 0;
+    config.probation_exit_streak = RegistryConfig::DEFAULT_PROBATION_EXIT_STREAK;
+    config.pass_reputation_deltas = ChallengeKind::ALL.map(|kind| kind.default_pass_delta());
+    config.fail_reputation_deltas = ChallengeKind::ALL.map(|kind| kind.default_fail_delta());
+    config.suspend_critical_alerts = RegistryConfig::DEFAULT_SUSPEND_CRITICAL_ALERTS;
+    config.suspend_window_secs = RegistryConfig::DEFAULT_SUSPEND_WINDOW_SECS;
+    config.sentinel_risk_threshold = RegistryConfig::DEFAULT_SENTINEL_RISK_THRESHOLD;
+    config.risk_decay_half_life_secs = RegistryConfig::DEFAULT_RISK_DECAY_HALF_LIFE_SECS;
+    config.max_risk_reset_secs = RegistryConfig::DEFAULT_MAX_RISK_RESET_SECS;
+    config.stake_mint = Pubkey::default();
+    config.critical_alert_slash_bps = RegistryConfig::DEFAULT_CRITICAL_ALERT_SLASH_BPS;
+    config.slash_affected_share_bps = RegistryConfig::DEFAULT_SLASH_AFFECTED_SHARE_BPS;
+    config.staker_fee_share_bps = RegistryConfig::DEFAULT_STAKER_FEE_SHARE_BPS;
+    config.min_verification_fee = RegistryConfig::DEFAULT_MIN_VERIFICATION_FEE;
+    config.insurance_claim_bond_lamports = RegistryConfig::DEFAULT_INSURANCE_CLAIM_BOND;
+    config.bump = ctx.bumps.config;
+
+    msg!(
+        "Registry config initialized: challenge_bond={}, max_difficulty={}, dispute_bond={}, expire_bounty_bps={}, max_challenges_per_day={}, max_pending_per_challenger={}, verified_stake_per_tier={}, challenge_fee_bps={}, verified_challenger_weight_bps={}, anonymous_challenger_weight_bps={}, fast_response_secs={}, quick_response_secs={}, max_reputation_change_per_day={}, stake_boost_unit_lamports={}, stake_boost_bps_per_unit={}, max_stake_boost_bps={}, stake_slash_bps={}, min_reputation_open_challenge={}, min_reputation_verifier={}, reputation_migration_bps={}, ema_alpha_bps={}, report_bond={}, probation_floor={}, probation_exit_streak={}, pass_reputation_deltas={:?}, fail_reputation_deltas={:?}, suspend_critical_alerts={}, suspend_window_secs={}, sentinel_risk_threshold={}, risk_decay_half_life_secs={}, max_risk_reset_secs={}, stake_mint={}, critical_alert_slash_bps={}, slash_affected_share_bps={}, staker_fee_share_bps={}, min_verification_fee={}, insurance_claim_bond={}",
+        config.challenge_bond_lamports,
+        config.max_difficulty,
+        config.dispute_bond_lamports,
+        config.expire_bounty_bps,
+        config.max_challenges_per_day,
+        config.max_pending_per_challenger,
+        config.verified_stake_per_tier,
+        config.challenge_fee_bps,
+        config.verified_challenger_weight_bps,
+        config.anonymous_challenger_weight_bps,
+        config.fast_response_secs,
+        config.quick_response_secs,
+        config.max_reputation_change_per_day,
+        config.stake_boost_unit_lamports,
+        config.stake_boost_bps_per_unit,
+        config.max_stake_boost_bps,
+        config.stake_slash_bps,
+        config.min_reputation_open_challenge,
+        config.min_reputation_verifier,
+        config.reputation_migration_bps,
+        config.ema_alpha_bps,
+        config.report_bond_lamports,
+        config.probation_floor,
+        config.probation_exit_streak,
+        config.pass_reputation_deltas,
+        config.fail_reputation_deltas,
+        config.suspend_critical_alerts,
+        config.suspend_window_secs,
+        config.sentinel_risk_threshold,
+        config.risk_decay_half_life_secs,
+        config.max_risk_reset_secs,
+        config.stake_mint,
+        config.critical_alert_slash_bps,
+        config.slash_affected_share_bps,
+        config.staker_fee_share_bps,
+        config.min_verification_fee,
+        config.insurance_claim_bond_lamports
+    );
+
+    Ok(())
+}
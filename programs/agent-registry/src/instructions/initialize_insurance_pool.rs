@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{InsurancePool, RegistryState};
+use crate::errors::RegistryError;
+
+/// Create the shared insurance pool PDA (admin only, one-time)
+#[derive(Accounts)]
+pub struct InitializeInsurancePool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + InsurancePool::INIT_SPACE,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeInsurancePool>) -> Result<()> {
+    let pool = &mut ctx.accounts.insurance_pool;
+    pool.total_premiums = 0;
+    pool.total_paid_out = 0;
+    pool.bump = ctx.bumps.insurance_pool;
+
+    msg!("Insurance pool initialized: {}", pool.key());
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, Treasury};
+use crate::errors::RegistryError;
+
+/// Create the protocol treasury PDA (admin only, one-time)
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [Treasury::SEED_PREFIX],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTreasury>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_collected = 0;
+    treasury.bump = ctx.bumps.treasury;
+
+    msg!("Treasury initialized: {}", treasury.key());
+
+    Ok(())
+}
@@ -1,27 +1,73 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, AuditEntry, AgentAuditSummary, ActionType, RiskLevel};
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditEntry, AuditPolicy,
+    AuditorRegistry, DayRollup, RegistryConfig, RiskLevel, RiskWeights,
+};
 use crate::errors::RegistryError;
+use crate::events::AuditLogged;
+use crate::utils::{decode_hex32, enforce_circuit_breaker};
 
 /// Accounts for logging an audit entry
 /// Follows Solana best practices: minimal accounts, proper PDA derivation
 #[derive(Accounts)]
 #[instruction(action_type: ActionType, context_risk: u8, details_hash: String)]
 pub struct LogAudit<'info> {
-    /// The actor triggering this audit (must be agent owner or admin)
+    /// The actor triggering this audit (owner, operator or a designated sentinel)
     #[account(mut)]
     pub actor: Signer<'info>,
 
     /// The agent being audited
     #[account(
+        mut,
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump
     )]
     pub agent: Account<'info, AgentAccount>,
 
+    /// Registry config (audit circuit breaker thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &actor.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Custom action code registry (may not exist yet = no custom codes)
+    #[account(
+        seeds = [ActionCodeRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub action_codes: UncheckedAccount<'info>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// Optional external auditor co-signing the entry (must be in the AuditorRegistry)
+    pub auditor: Option<Signer<'info>>,
+
+    /// CHECK: Registered auditors (may not exist yet = none registered)
+    #[account(
+        seeds = [AuditorRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub auditor_registry: UncheckedAccount<'info>,
+
     /// The audit summary for this agent (created if first audit)
     #[account(
         init_if_needed,
@@ -54,23 +100,40 @@ pub fn handler(
     action_type: ActionType,
     context_risk: u8,
     details_hash: String,
+    encrypted_details_uri: Option<String>,
 ) -> Result<()> {
     // Validate details hash (should be SHA256 hex)
-    require!(
-        details_hash.len() == 64 && details_hash.chars().all(|c| c.is_ascii_hexdigit()),
-        RegistryError::InvalidDetailsHash
-    );
+    let details_digest = decode_hex32(&details_hash).ok_or(RegistryError::InvalidDetailsHash)?;
+    if let Some(uri) = &encrypted_details_uri {
+        require!(uri.len() <= AuditEntry::MAX_URI_LEN, RegistryError::DetailsUriTooLong);
+    }
 
     // Validate context risk
     require!(context_risk <= 100, RegistryError::InvalidRiskScore);
 
     let clock = Clock::get()?;
     let agent_key = ctx.accounts.agent.key();
+    let policy = AuditPolicy::load_or_default(&ctx.accounts.audit_policy, agent_key)?;
+    let codes = ActionCodeRegistry::load_or_empty(&ctx.accounts.action_codes)?;
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+    let auditor = match &ctx.accounts.auditor {
+        Some(auditor) => {
+            let auditors = AuditorRegistry::load_or_empty(&ctx.accounts.auditor_registry)?;
+            require!(auditors.is_registered(&auditor.key()), RegistryError::AuditorNotRegistered);
+            auditor.key()
+        }
+        None => Pubkey::default(),
+    };
 
     // Calculate risk score based on action type and context
-    let risk_score = AuditEntry::calculate_risk_score(&action_type, context_risk);
+    let risk_score = weights.risk_score(&action_type, context_risk, &codes)?;
+    require!(
+        !ctx.accounts.config.requires_sentinel(&action_type, risk_score)
+            || ctx.accounts.agent.may_log_high_risk(&ctx.accounts.actor.key()),
+        RegistryError::SentinelRequired
+    );
     let risk_level = RiskLevel::from_score(risk_score);
-    let is_alert = matches!(action_type, ActionType::SecurityAlert) || risk_score >= 75;
+    let is_alert = policy.is_alert(action_type, risk_score, &ctx.accounts.config);
 
     // Initialize audit summary if first entry
     let summary = &mut ctx.accounts.audit_summary;
@@ -78,6 +141,7 @@ pub fn handler(
         summary.agent = agent_key;
         summary.bump = ctx.bumps.audit_summary;
     }
+    summary.decay_risk(clock.unix_timestamp, &ctx.accounts.config);
 
     // Create audit entry
     let entry = &mut ctx.accounts.audit_entry;
@@ -90,9 +154,39 @@ pub fn handler(
     entry.details_hash = details_hash;
     entry.audit_index = summary.total_entries;
     entry.bump = ctx.bumps.audit_entry;
+    entry.risk_weights_version = weights.version;
+    entry.is_alert = is_alert;
+    entry.auditor = auditor;
+    entry.encrypted_details_uri = encrypted_details_uri;
+    entry.prev_entry_hash = summary.advance_chain(
+        entry.audit_index,
+        action_type,
+        risk_score,
+        clock.unix_timestamp,
+        &details_digest,
+    );
 
     // Update summary
-    summary.record_entry(risk_score, is_alert, clock.unix_timestamp);
+    summary.record_entry(action_type, risk_score, is_alert, clock.unix_timestamp);
+
+    let critical = RiskLevel::is_critical(risk_score) as u32;
+    enforce_circuit_breaker(
+        &mut ctx.accounts.agent,
+        summary,
+        critical,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    emit!(AuditLogged {
+        agent: agent_key,
+        actor: entry.actor,
+        action_type,
+        risk_score,
+        index: entry.audit_index,
+        is_alert,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!(
         "Audit logged: agent={}, action={:?}, risk={}, index={}",
@@ -112,7 +206,7 @@ pub struct GetAuditStatus<'info> {
     #[account(
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump
@@ -130,6 +224,7 @@ pub struct GetAuditStatus<'info> {
 /// Returns audit status for an agent
 pub fn get_audit_status(ctx: Context<GetAuditStatus>) -> Result<AuditStatusResponse> {
     let summary = &ctx.accounts.audit_summary;
+    let today = DayRollup::day_of(Clock::get()?.unix_timestamp);
 
     Ok(AuditStatusResponse {
         total_entries: summary.total_entries,
@@ -139,6 +234,13 @@ pub fn get_audit_status(ctx: Context<GetAuditStatus>) -> Result<AuditStatusRespo
         safe_streak: summary.safe_streak,
         is_trusted: summary.is_trusted(),
         last_audit_at: summary.last_audit_at,
+        action_counts: summary.action_counts,
+        incidents_reported: summary.incidents_reported,
+        min_risk_score: summary.min_risk_score,
+        median_risk_score: summary.risk_percentile(50),
+        p95_risk_score: summary.risk_percentile(95),
+        risk_histogram: summary.risk_histogram,
+        alerts_last_30_days: summary.recent_alerts(today),
     })
 }
 
@@ -152,4 +254,12 @@ pub struct AuditStatusResponse {
     pub safe_streak: u32,
     pub is_trusted: bool,
     pub last_audit_at: i64,
+    pub action_counts: [u32; ActionType::COUNT],
+    pub incidents_reported: u32,
+    pub min_risk_score: u8,
+    /// Median and 95th percentile are bucket upper bounds from risk_histogram
+    pub median_risk_score: u8,
+    pub p95_risk_score: u8,
+    pub risk_histogram: [u32; AgentAuditSummary::RISK_BUCKETS],
+    pub alerts_last_30_days: u32,
 }
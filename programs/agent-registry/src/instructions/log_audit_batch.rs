@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditPolicy, AuditRing,
+    RegistryConfig, RiskLevel, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::AuditLogged;
+use crate::utils::enforce_circuit_breaker;
+
+/// One audit record in a log_audit_batch call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditBatchItem {
+    pub action_type: ActionType,
+    pub context_risk: u8,
+    pub details_hash: [u8; 32],
+}
+
+/// Accounts for logging a burst of audit entries into the agent's audit ring
+#[derive(Accounts)]
+pub struct LogAuditBatch<'info> {
+    /// Agent owner, operator or a designated sentinel
+    #[account(mut)]
+    pub actor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (audit circuit breaker thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &actor.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Custom action code registry (may not exist yet = no custom codes)
+    #[account(
+        seeds = [ActionCodeRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub action_codes: UncheckedAccount<'info>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// The audit summary for this agent (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = actor,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    #[account(
+        mut,
+        seeds = [AuditRing::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_ring: AccountLoader<'info, AuditRing>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<LogAuditBatch>, entries: Vec<AuditBatchItem>) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= AuditRing::MAX_BATCH,
+        RegistryError::InvalidAuditBatch
+    );
+    require!(
+        entries.iter().all(|item| item.context_risk <= 100),
+        RegistryError::InvalidRiskScore
+    );
+
+    let clock = Clock::get()?;
+    let agent_key = ctx.accounts.agent.key();
+    let policy = AuditPolicy::load_or_default(&ctx.accounts.audit_policy, agent_key)?;
+    let codes = ActionCodeRegistry::load_or_empty(&ctx.accounts.action_codes)?;
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+    let actor = ctx.accounts.actor.key();
+
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = agent_key;
+        summary.bump = ctx.bumps.audit_summary;
+    }
+    summary.decay_risk(clock.unix_timestamp, &ctx.accounts.config);
+    let first_index = summary.total_entries;
+
+    let mut ring = ctx.accounts.audit_ring.load_mut()?;
+    let mut results = Vec::with_capacity(entries.len());
+    for (offset, item) in entries.into_iter().enumerate() {
+        let risk_score = weights.risk_score(&item.action_type, item.context_risk, &codes)?;
+        require!(
+            !ctx.accounts.config.requires_sentinel(&item.action_type, risk_score)
+                || ctx.accounts.agent.may_log_high_risk(&actor),
+            RegistryError::SentinelRequired
+        );
+        let is_alert = policy.is_alert(item.action_type, risk_score, &ctx.accounts.config);
+        emit!(AuditLogged {
+            agent: agent_key,
+            actor,
+            action_type: item.action_type,
+            risk_score,
+            index: first_index + offset as u64,
+            is_alert,
+            timestamp: clock.unix_timestamp,
+        });
+        summary.advance_chain(
+            first_index + offset as u64,
+            item.action_type,
+            risk_score,
+            clock.unix_timestamp,
+            &item.details_hash,
+        );
+        ring.record(
+            item.action_type,
+            risk_score,
+            item.details_hash,
+            first_index + offset as u64,
+            clock.unix_timestamp,
+            weights.version,
+        );
+        results.push((item.action_type, risk_score, is_alert));
+    }
+
+    summary.record_batch(&results, clock.unix_timestamp);
+
+    let critical = results
+        .iter()
+        .filter(|(_, risk_score, _)| RiskLevel::is_critical(*risk_score))
+        .count() as u32;
+    enforce_circuit_breaker(
+        &mut ctx.accounts.agent,
+        summary,
+        critical,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    msg!(
+        "Audit batch logged (ring): agent={}, entries={}, first_index={}, alerts={}",
+        agent_key,
+        results.len(),
+        first_index,
+        results.iter().filter(|(_, _, is_alert)| *is_alert).count()
+    );
+
+    Ok(())
+}
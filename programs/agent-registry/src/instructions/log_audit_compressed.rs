@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditPolicy, AuditTree,
+    RegistryConfig, RiskLevel, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::{AuditLogged, AuditTreeAppended};
+use crate::utils::{decode_hex32, enforce_circuit_breaker};
+
+/// Log an audit entry as a leaf of the agent's compressed audit tree
+/// Same checks and summary updates as log_audit, without an AuditEntry PDA
+#[derive(Accounts)]
+pub struct LogAuditCompressed<'info> {
+    /// The actor triggering this audit (owner, operator or a designated sentinel)
+    #[account(mut)]
+    pub actor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (audit circuit breaker thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &actor.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Custom action code registry (may not exist yet = no custom codes)
+    #[account(
+        seeds = [ActionCodeRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub action_codes: UncheckedAccount<'info>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// The audit summary for this agent (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = actor,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    #[account(
+        mut,
+        seeds = [AuditTree::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_tree.bump
+    )]
+    pub audit_tree: Account<'info, AuditTree>,
+
+    /// CHECK: The agent's concurrent Merkle tree, validated by the compression program
+    #[account(mut, address = audit_tree.merkle_tree @ RegistryError::InvalidAuditTree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL account-compression program
+    #[account(address = AuditTree::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL noop program
+    #[account(address = AuditTree::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<LogAuditCompressed>,
+    action_type: ActionType,
+    context_risk: u8,
+    details_hash: String,
+) -> Result<()> {
+    let details_digest = decode_hex32(&details_hash).ok_or(RegistryError::InvalidDetailsHash)?;
+    require!(context_risk <= 100, RegistryError::InvalidRiskScore);
+    require!(ctx.accounts.audit_tree.has_capacity(), RegistryError::AuditTreeFull);
+
+    let clock = Clock::get()?;
+    let agent_key = ctx.accounts.agent.key();
+    let actor = ctx.accounts.actor.key();
+    let policy = AuditPolicy::load_or_default(&ctx.accounts.audit_policy, agent_key)?;
+    let codes = ActionCodeRegistry::load_or_empty(&ctx.accounts.action_codes)?;
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+
+    let risk_score = weights.risk_score(&action_type, context_risk, &codes)?;
+    require!(
+        !ctx.accounts.config.requires_sentinel(&action_type, risk_score)
+            || ctx.accounts.agent.may_log_high_risk(&actor),
+        RegistryError::SentinelRequired
+    );
+    let is_alert = policy.is_alert(action_type, risk_score, &ctx.accounts.config);
+
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = agent_key;
+        summary.bump = ctx.bumps.audit_summary;
+    }
+    summary.decay_risk(clock.unix_timestamp, &ctx.accounts.config);
+
+    // The leaf is the entry's chain link, so the tree and the hash chain commit to the same data
+    let audit_index = summary.total_entries;
+    summary.advance_chain(
+        audit_index,
+        action_type,
+        risk_score,
+        clock.unix_timestamp,
+        &details_digest,
+    );
+    let leaf = summary.chain_head;
+
+    let tree = &mut ctx.accounts.audit_tree;
+    invoke_signed(
+        &AuditTree::append_ix(tree.merkle_tree, tree.key(), leaf),
+        &[
+            ctx.accounts.merkle_tree.to_account_info(),
+            tree.to_account_info(),
+            ctx.accounts.noop_program.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+        ],
+        &[&[AuditTree::SEED_PREFIX, agent_key.as_ref(), &[tree.bump]]],
+    )?;
+    let leaf_index = tree.leaves_appended;
+    tree.leaves_appended += 1;
+
+    summary.record_entry(action_type, risk_score, is_alert, clock.unix_timestamp);
+
+    let critical = RiskLevel::is_critical(risk_score) as u32;
+    enforce_circuit_breaker(
+        &mut ctx.accounts.agent,
+        summary,
+        critical,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    emit!(AuditTreeAppended {
+        agent: agent_key,
+        merkle_tree: tree.merkle_tree,
+        leaf_index,
+        audit_index,
+        leaf,
+        details_hash: details_digest,
+        risk_weights_version: weights.version,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(AuditLogged {
+        agent: agent_key,
+        actor,
+        action_type,
+        risk_score,
+        index: audit_index,
+        is_alert,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit logged to tree: agent={}, action={:?}, risk={}, index={}, leaf={}",
+        agent_key,
+        action_type,
+        risk_score,
+        audit_index,
+        leaf_index
+    );
+
+    Ok(())
+}
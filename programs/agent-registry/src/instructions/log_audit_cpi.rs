@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditEntry, AuditPolicy,
+    RegistryConfig, RiskLevel, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::AuditLogged;
+use crate::utils::{decode_hex32, enforce_circuit_breaker};
+
+/// Accounts for logging an audit entry from another on-chain program
+///
+/// The actor is the calling program's PDA `[AuditEntry::CPI_ACTOR_SEED]`,
+/// signed with `invoke_signed`, so entries are attributed to the program
+/// rather than to whoever sent the transaction. That address must be listed
+/// as a sentinel in the agent's AuditPolicy (or be its sentinel authority)
+/// just like any other audit actor. Build the call with the `cpi` feature:
+/// `agent_registry::cpi::log_audit_cpi(ctx.with_signer(..), ..)`.
+#[derive(Accounts)]
+#[instruction(action_type: ActionType, context_risk: u8, details_hash: String)]
+pub struct LogAuditCpi<'info> {
+    /// Pays for the entry (and the summary on first audit)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The calling program's audit actor PDA
+    #[account(
+        constraint = actor.key() == AuditEntry::cpi_actor(&caller_program.key()).0
+            @ RegistryError::InvalidCpiActor
+    )]
+    pub actor: Signer<'info>,
+
+    /// CHECK: The calling program; only used to derive the expected actor
+    #[account(executable)]
+    pub caller_program: UncheckedAccount<'info>,
+
+    /// The agent being audited
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (audit circuit breaker thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &actor.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Custom action code registry (may not exist yet = no custom codes)
+    #[account(
+        seeds = [ActionCodeRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub action_codes: UncheckedAccount<'info>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// The audit summary for this agent (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// The new audit entry
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub audit_entry: Account<'info, AuditEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<LogAuditCpi>,
+    action_type: ActionType,
+    context_risk: u8,
+    details_hash: String,
+) -> Result<()> {
+    let details_digest = decode_hex32(&details_hash).ok_or(RegistryError::InvalidDetailsHash)?;
+    require!(context_risk <= 100, RegistryError::InvalidRiskScore);
+
+    let clock = Clock::get()?;
+    let agent_key = ctx.accounts.agent.key();
+    let actor = ctx.accounts.actor.key();
+    let policy = AuditPolicy::load_or_default(&ctx.accounts.audit_policy, agent_key)?;
+    let codes = ActionCodeRegistry::load_or_empty(&ctx.accounts.action_codes)?;
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+
+    let risk_score = weights.risk_score(&action_type, context_risk, &codes)?;
+    require!(
+        !ctx.accounts.config.requires_sentinel(&action_type, risk_score)
+            || ctx.accounts.agent.may_log_high_risk(&actor),
+        RegistryError::SentinelRequired
+    );
+    let is_alert = policy.is_alert(action_type, risk_score, &ctx.accounts.config);
+
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = agent_key;
+        summary.bump = ctx.bumps.audit_summary;
+    }
+    summary.decay_risk(clock.unix_timestamp, &ctx.accounts.config);
+
+    let entry = &mut ctx.accounts.audit_entry;
+    entry.agent = agent_key;
+    entry.actor = actor;
+    entry.action_type = action_type;
+    entry.risk_score = risk_score;
+    entry.risk_level = RiskLevel::from_score(risk_score);
+    entry.timestamp = clock.unix_timestamp;
+    entry.details_hash = details_hash;
+    entry.audit_index = summary.total_entries;
+    entry.bump = ctx.bumps.audit_entry;
+    entry.risk_weights_version = weights.version;
+    entry.auditor = Pubkey::default();
+    entry.encrypted_details_uri = None;
+    entry.is_alert = is_alert;
+    entry.prev_entry_hash = summary.advance_chain(
+        entry.audit_index,
+        action_type,
+        risk_score,
+        clock.unix_timestamp,
+        &details_digest,
+    );
+
+    summary.record_entry(action_type, risk_score, is_alert, clock.unix_timestamp);
+
+    let critical = RiskLevel::is_critical(risk_score) as u32;
+    enforce_circuit_breaker(
+        &mut ctx.accounts.agent,
+        summary,
+        critical,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    emit!(AuditLogged {
+        agent: agent_key,
+        actor,
+        action_type,
+        risk_score,
+        index: entry.audit_index,
+        is_alert,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit logged via CPI: agent={}, program={}, action={:?}, risk={}, index={}",
+        agent_key,
+        ctx.accounts.caller_program.key(),
+        action_type,
+        risk_score,
+        entry.audit_index
+    );
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditPolicy, AuditRing,
+    RegistryConfig, RiskLevel, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::AuditLogged;
+use crate::utils::enforce_circuit_breaker;
+
+/// Accounts for logging an audit entry into the agent's audit ring
+/// No per-entry rent: the entry overwrites the oldest one once the ring is full
+#[derive(Accounts)]
+pub struct LogAuditRing<'info> {
+    /// Agent owner, operator or a designated sentinel
+    #[account(mut)]
+    pub actor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (audit circuit breaker thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &actor.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    /// CHECK: Custom action code registry (may not exist yet = no custom codes)
+    #[account(
+        seeds = [ActionCodeRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub action_codes: UncheckedAccount<'info>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// The audit summary for this agent (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = actor,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    #[account(
+        mut,
+        seeds = [AuditRing::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_ring: AccountLoader<'info, AuditRing>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<LogAuditRing>,
+    action_type: ActionType,
+    context_risk: u8,
+    details_hash: [u8; 32],
+) -> Result<()> {
+    require!(context_risk <= 100, RegistryError::InvalidRiskScore);
+
+    let clock = Clock::get()?;
+    let agent_key = ctx.accounts.agent.key();
+    let policy = AuditPolicy::load_or_default(&ctx.accounts.audit_policy, agent_key)?;
+    let codes = ActionCodeRegistry::load_or_empty(&ctx.accounts.action_codes)?;
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+    let risk_score = weights.risk_score(&action_type, context_risk, &codes)?;
+    require!(
+        !ctx.accounts.config.requires_sentinel(&action_type, risk_score)
+            || ctx.accounts.agent.may_log_high_risk(&ctx.accounts.actor.key()),
+        RegistryError::SentinelRequired
+    );
+    let is_alert = policy.is_alert(action_type, risk_score, &ctx.accounts.config);
+
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = agent_key;
+        summary.bump = ctx.bumps.audit_summary;
+    }
+    summary.decay_risk(clock.unix_timestamp, &ctx.accounts.config);
+    let audit_index = summary.total_entries;
+    summary.advance_chain(audit_index, action_type, risk_score, clock.unix_timestamp, &details_hash);
+
+    let mut ring = ctx.accounts.audit_ring.load_mut()?;
+    let slot = ring.record(
+        action_type,
+        risk_score,
+        details_hash,
+        audit_index,
+        clock.unix_timestamp,
+        weights.version,
+    );
+
+    summary.record_entry(action_type, risk_score, is_alert, clock.unix_timestamp);
+
+    let critical = RiskLevel::is_critical(risk_score) as u32;
+    enforce_circuit_breaker(
+        &mut ctx.accounts.agent,
+        summary,
+        critical,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    emit!(AuditLogged {
+        agent: agent_key,
+        actor: ctx.accounts.actor.key(),
+        action_type,
+        risk_score,
+        index: audit_index,
+        is_alert,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit logged (ring): agent={}, action={:?}, risk={}, index={}, slot={}",
+        agent_key,
+        action_type,
+        risk_score,
+        audit_index,
+        slot
+    );
+
+    Ok(())
+}
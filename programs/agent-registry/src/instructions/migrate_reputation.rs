@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditEntry, RegistryConfig,
+    RiskLevel, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::ReputationChangeReason;
+use crate::utils::{emit_reputation_changed, enforce_probation};
+
+/// Carry a discounted share of an old agent's reputation over to a new one (owner only)
+///
+/// For re-registrations such as a model upgrade. The new agent starts from
+/// the initial reputation plus `config.reputation_migration_bps` of the old
+/// agent's distance from it, so poor reputation carries over as well. The old
+/// agent is retired: it accepts no new challenges and cannot migrate again,
+/// while challenges already open against it still settle. An audit entry on
+/// the new agent records the lineage.
+#[derive(Accounts)]
+pub struct MigrateReputation<'info> {
+    /// Owner of both agents (pays for the audit entry)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Agent being retired
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            old_agent.pda_owner().as_ref(),
+            old_agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = old_agent.bump,
+        constraint = old_agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !old_agent.is_retired() @ RegistryError::AgentRetired,
+        constraint = !old_agent.suspended @ RegistryError::AgentSuspended
+    )]
+    pub old_agent: Account<'info, AgentAccount>,
+
+    /// Freshly registered successor (no challenge history yet)
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            new_agent.pda_owner().as_ref(),
+            new_agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = new_agent.bump,
+        constraint = new_agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = new_agent.key() != old_agent.key() @ RegistryError::InvalidMigration,
+        constraint = !new_agent.is_retired() @ RegistryError::AgentRetired
+    )]
+    pub new_agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// The new agent's audit summary (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, new_agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// Audit entry recording the lineage
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            new_agent.key().as_ref(),
+            audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub audit_entry: Account<'info, AuditEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateReputation>) -> Result<()> {
+    let old_agent = &mut ctx.accounts.old_agent;
+    let new_agent = &mut ctx.accounts.new_agent;
+    let clock = Clock::get()?;
+
+    require!(
+        new_agent.challenges_passed == 0
+            && new_agent.challenges_failed == 0
+            && new_agent.reputation_score == AgentAccount::INITIAL_REPUTATION,
+        RegistryError::InvalidMigration
+    );
+
+    // Discount the old agent's distance from the initial reputation
+    let distance = old_agent.reputation_score as i64 - AgentAccount::INITIAL_REPUTATION as i64;
+    let carried = (distance * ctx.accounts.config.reputation_migration_bps as i64
+        / RegistryConfig::BPS_DENOMINATOR as i64) as i32;
+
+    let old_reputation = new_agent.reputation_score;
+    new_agent.credit_reputation(
+        carried,
+        clock.unix_timestamp,
+        ctx.accounts.config.max_reputation_change_per_day,
+    );
+    new_agent.updated_at = clock.unix_timestamp;
+    old_agent.successor = new_agent.key();
+    old_agent.updated_at = clock.unix_timestamp;
+
+    emit_reputation_changed(
+        new_agent.key(),
+        old_reputation,
+        new_agent.reputation_score,
+        ReputationChangeReason::Migration,
+        old_agent.key(),
+        clock.unix_timestamp,
+    );
+    enforce_probation(new_agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = new_agent.key();
+        summary.bump = ctx.bumps.audit_summary;
+    }
+
+    // Lineage: the details field carries the predecessor address (hex)
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+    let risk_score = weights.risk_score(
+        &ActionType::ReputationMigrated,
+        0,
+        &ActionCodeRegistry::default(),
+    )?;
+    let entry = &mut ctx.accounts.audit_entry;
+    entry.agent = new_agent.key();
+    entry.actor = ctx.accounts.owner.key();
+    entry.action_type = ActionType::ReputationMigrated;
+    entry.risk_score = risk_score;
+    entry.risk_level = RiskLevel::from_score(risk_score);
+    entry.timestamp = clock.unix_timestamp;
+    entry.details_hash = old_agent
+        .key()
+        .to_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    entry.audit_index = summary.total_entries;
+    entry.bump = ctx.bumps.audit_entry;
+    entry.risk_weights_version = weights.version;
+    entry.auditor = Pubkey::default();
+    entry.encrypted_details_uri = None;
+    entry.is_alert = false;
+    entry.prev_entry_hash = summary.advance_chain(
+        entry.audit_index,
+        ActionType::ReputationMigrated,
+        risk_score,
+        clock.unix_timestamp,
+        &old_agent.key().to_bytes(),
+    );
+    summary.record_entry(ActionType::ReputationMigrated, risk_score, false, clock.unix_timestamp);
+
+    msg!(
+        "Reputation migrated: agent {} -> agent {}, carried {}, new reputation {}",
+        old_agent.agent_id,
+        new_agent.agent_id,
+        carried,
+        new_agent.reputation_score
+    );
+
+    Ok(())
+}
@@ -1,25 +1,243 @@
 pub mod initialize;
+pub mod initialize_config;
+pub mod update_config;
+pub mod initialize_treasury;
+pub mod set_treasury_council;
+pub mod propose_spend;
+pub mod approve_spend;
+pub mod execute_spend;
+pub mod cancel_spend;
+pub mod set_verified_verifier;
+pub mod set_verifier_program;
+pub mod register_zk_verifying_key;
 pub mod create_collection;
 pub mod register_agent;
 pub mod update_agent;
+pub mod update_agent_profile;
+pub mod set_service_listing;
+pub mod refresh_service_listing;
+pub mod close_service_listing;
+pub mod upgrade_agent_account;
+pub mod migrate_reputation;
+pub mod set_agent_operator;
+pub mod set_challenger_acl;
 pub mod verify_agent;
+pub mod request_verification;
+pub mod reject_verification;
+pub mod reinstate_agent;
+pub mod set_sentinel_authority;
 pub mod update_reputation;
+pub mod set_authorized_updater;
+pub mod snapshot_reputation;
+pub mod get_reputation;
+pub mod update_leaderboard;
+pub mod checkpoint_reputations;
+pub mod set_reputation_schema;
+pub mod recalibrate_reputation;
+pub mod stake_agent;
+pub mod request_unstake;
+pub mod withdraw_stake;
+pub mod fund_stake_rewards;
+pub mod claim_rewards;
+pub mod endorse_agent;
+pub mod revoke_endorsement;
+pub mod clawback_endorsement;
 pub mod create_challenge;
+pub mod publish_challenge_template;
+pub mod create_challenge_from_template;
 pub mod submit_response;
+pub mod submit_zk_response;
+pub mod reveal_challenge;
+pub mod resolve_challenge;
+pub mod attest_result;
+pub mod resolve_challenge_with_oracle;
+pub mod resolve_with_verifier_program;
+pub mod dispute_challenge;
+pub mod arbitrate_dispute;
+pub mod report_agent;
+pub mod resolve_report;
+pub mod initialize_insurance_pool;
+pub mod pay_insurance_premium;
+pub mod file_insurance_claim;
+pub mod arbitrate_insurance_claim;
+pub mod post_task;
+pub mod assign_task;
+pub mod submit_task_result;
+pub mod accept_task_result;
+pub mod dispute_task;
+pub mod arbitrate_task;
+pub mod cancel_task;
+pub mod create_stream;
+pub mod withdraw_stream;
+pub mod cancel_stream;
 pub mod expire_challenge;
+pub mod expire_challenges_batch;
+pub mod record_peer_result;
+pub mod record_domain_result;
+pub mod slash_stake;
+pub mod slash_agent;
+pub mod cancel_challenge;
+pub mod extend_challenge_deadline;
 pub mod close_challenge;
+pub mod close_challenges_batch;
+pub mod expire_and_close;
+pub mod create_open_challenge;
+pub mod submit_open_response;
+pub mod reveal_open_challenge;
+pub mod claim_open_challenge;
+pub mod finalize_open_challenge;
+pub mod close_open_response;
+pub mod fund_token_reward;
+pub mod settle_token_reward;
 pub mod log_audit;
+pub mod log_audit_cpi;
+pub mod init_audit_tree;
+pub mod log_audit_compressed;
+pub mod init_audit_ring;
+pub mod log_audit_ring;
+pub mod log_audit_batch;
+pub mod init_audit_batch;
+pub mod append_audit_batch;
+pub mod upgrade_audit_summary;
+pub mod set_audit_policy;
+pub mod report_incident;
+pub mod set_action_code;
+pub mod set_risk_weights;
+pub mod redact_audit_entry;
+pub mod finalize_audit_day;
+pub mod set_policy_hash;
+pub mod set_auditor;
+pub mod recompute_audit_summary;
+pub mod set_audit_reader;
+pub mod checkpoint_full_audit;
+pub mod set_heartbeat_interval;
+pub mod sentinel_heartbeat;
+pub mod flag_missed_heartbeat;
 pub mod store_merkle_audit;
+pub mod verify_audit_inclusion;
+pub mod close_audit_entry;
+pub mod recover_agent;
+pub mod finalize_recovery;
+pub mod cancel_recovery;
 
 pub use initialize::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use initialize_treasury::*;
+pub use set_treasury_council::*;
+pub use propose_spend::*;
+pub use approve_spend::*;
+pub use execute_spend::*;
+pub use cancel_spend::*;
+pub use set_verified_verifier::*;
+pub use set_verifier_program::*;
+pub use register_zk_verifying_key::*;
 pub use create_collection::*;
 pub use register_agent::*;
 pub use update_agent::*;
+pub use update_agent_profile::*;
+pub use set_service_listing::*;
+pub use refresh_service_listing::*;
+pub use close_service_listing::*;
+pub use upgrade_agent_account::*;
+pub use migrate_reputation::*;
+pub use set_agent_operator::*;
+pub use set_challenger_acl::*;
 pub use verify_agent::*;
+pub use request_verification::*;
+pub use reject_verification::*;
+pub use reinstate_agent::*;
+pub use set_sentinel_authority::*;
 pub use update_reputation::*;
+pub use set_authorized_updater::*;
+pub use snapshot_reputation::*;
+pub use get_reputation::*;
+pub use update_leaderboard::*;
+pub use checkpoint_reputations::*;
+pub use set_reputation_schema::*;
+pub use recalibrate_reputation::*;
+pub use stake_agent::*;
+pub use request_unstake::*;
+pub use withdraw_stake::*;
+pub use fund_stake_rewards::*;
+pub use claim_rewards::*;
+pub use endorse_agent::*;
+pub use revoke_endorsement::*;
+pub use clawback_endorsement::*;
 pub use create_challenge::*;
+pub use publish_challenge_template::*;
+pub use create_challenge_from_template::*;
 pub use submit_response::*;
+pub use submit_zk_response::*;
+pub use reveal_challenge::*;
+pub use resolve_challenge::*;
+pub use attest_result::*;
+pub use resolve_challenge_with_oracle::*;
+pub use resolve_with_verifier_program::*;
+pub use dispute_challenge::*;
+pub use arbitrate_dispute::*;
+pub use report_agent::*;
+pub use resolve_report::*;
+pub use initialize_insurance_pool::*;
+pub use pay_insurance_premium::*;
+pub use file_insurance_claim::*;
+pub use arbitrate_insurance_claim::*;
+pub use post_task::*;
+pub use assign_task::*;
+pub use submit_task_result::*;
+pub use accept_task_result::*;
+pub use dispute_task::*;
+pub use arbitrate_task::*;
+pub use cancel_task::*;
+pub use create_stream::*;
+pub use withdraw_stream::*;
+pub use cancel_stream::*;
 pub use expire_challenge::*;
+pub use expire_challenges_batch::*;
+pub use record_peer_result::*;
+pub use record_domain_result::*;
+pub use slash_stake::*;
+pub use slash_agent::*;
+pub use cancel_challenge::*;
+pub use extend_challenge_deadline::*;
 pub use close_challenge::*;
+pub use close_challenges_batch::*;
+pub use expire_and_close::*;
+pub use create_open_challenge::*;
+pub use submit_open_response::*;
+pub use reveal_open_challenge::*;
+pub use claim_open_challenge::*;
+pub use finalize_open_challenge::*;
+pub use close_open_response::*;
+pub use fund_token_reward::*;
+pub use settle_token_reward::*;
 pub use log_audit::*;
+pub use log_audit_cpi::*;
+pub use init_audit_tree::*;
+pub use log_audit_compressed::*;
+pub use init_audit_ring::*;
+pub use log_audit_ring::*;
+pub use log_audit_batch::*;
+pub use init_audit_batch::*;
+pub use append_audit_batch::*;
+pub use upgrade_audit_summary::*;
+pub use set_audit_policy::*;
+pub use report_incident::*;
+pub use set_action_code::*;
+pub use set_risk_weights::*;
+pub use redact_audit_entry::*;
+pub use finalize_audit_day::*;
+pub use set_policy_hash::*;
+pub use set_auditor::*;
+pub use recompute_audit_summary::*;
+pub use set_audit_reader::*;
+pub use checkpoint_full_audit::*;
+pub use set_heartbeat_interval::*;
+pub use sentinel_heartbeat::*;
+pub use flag_missed_heartbeat::*;
 pub use store_merkle_audit::*;
+pub use verify_audit_inclusion::*;
+pub use close_audit_entry::*;
+pub use recover_agent::*;
+pub use finalize_recovery::*;
+pub use cancel_recovery::*;
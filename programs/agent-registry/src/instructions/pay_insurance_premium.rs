@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, InsurancePolicy, InsurancePool};
+use crate::errors::RegistryError;
+use crate::events::InsurancePremiumPaid;
+use crate::utils::deposit_lamports;
+
+/// Pay a premium into the insurance pool for an agent (owner only)
+///
+/// The first premium opens the agent's policy. Cover grows with premiums paid
+/// (`InsurancePolicy::COVERAGE_MULTIPLIER` per lamport) and only applies to
+/// slashes after the policy was opened.
+#[derive(Accounts)]
+pub struct PayInsurancePremium<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + InsurancePolicy::INIT_SPACE,
+        seeds = [InsurancePolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub policy: Account<'info, InsurancePolicy>,
+
+    #[account(
+        mut,
+        seeds = [InsurancePool::SEED_PREFIX],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PayInsurancePremium>, amount: u64) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidInsuranceAmount);
+
+    deposit_lamports(
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.insurance_pool.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    let clock = Clock::get()?;
+    let policy = &mut ctx.accounts.policy;
+    if policy.agent == Pubkey::default() {
+        policy.agent = ctx.accounts.agent.key();
+        policy.created_at = clock.unix_timestamp;
+        policy.bump = ctx.bumps.policy;
+    }
+    policy.total_premiums = policy
+        .total_premiums
+        .checked_add(amount)
+        .ok_or(RegistryError::MathOverflow)?;
+    policy.last_paid_at = clock.unix_timestamp;
+
+    let pool = &mut ctx.accounts.insurance_pool;
+    pool.total_premiums = pool.total_premiums.saturating_add(amount);
+
+    emit!(InsurancePremiumPaid {
+        agent: policy.agent,
+        payer: ctx.accounts.owner.key(),
+        amount,
+        total_premiums: policy.total_premiums,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Insurance premium paid for agent {}: {} lamports (cover left {})",
+        ctx.accounts.agent.agent_id,
+        amount,
+        policy.remaining_coverage()
+    );
+
+    Ok(())
+}
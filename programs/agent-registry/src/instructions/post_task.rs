@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{Task, TaskStatus};
+use crate::errors::RegistryError;
+use crate::events::TaskPosted;
+use crate::utils::{deposit_lamports, tokens_received, TokenMint};
+
+/// Post a task and escrow its payment (client)
+///
+/// The task is assigned to an agent with `assign_task`. If no result arrives
+/// by `deadline`, the client can get the payment back with `cancel_task`.
+/// Passing `payment_mint` and the token accounts posts an SPL task instead of
+/// a SOL one: the task token account is any token account of the mint owned by
+/// the task PDA (e.g. its associated token account). Token-2022 mints are
+/// supported: the payment is the amount received after any transfer fee, and
+/// transfer-hook accounts go in remaining accounts.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct PostTask<'info> {
+    #[account(mut)]
+    pub client: Signer<'info>,
+
+    #[account(
+        init,
+        payer = client,
+        space = 8 + Task::INIT_SPACE,
+        seeds = [Task::SEED_PREFIX, client.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub task: Account<'info, Task>,
+
+    /// Payment mint (SPL tasks only)
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Client's token account funding the payment (SPL tasks only)
+    #[account(mut, token::authority = client)]
+    pub client_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token account owned by the task PDA holding the payment (SPL tasks only)
+    #[account(mut, token::authority = task)]
+    pub task_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PostTask<'info>>,
+    nonce: u64,
+    payment: u64,
+    spec_hash: [u8; 32],
+    deadline: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(deadline > clock.unix_timestamp, RegistryError::InvalidTaskParams);
+
+    let token = TokenMint::from_accounts(
+        ctx.accounts.payment_mint.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        ctx.remaining_accounts,
+    );
+    let (mint, received) = if let Some(token) = token {
+        let (Some(from), Some(to)) = (
+            &ctx.accounts.client_token_account,
+            ctx.accounts.task_token_account.as_mut(),
+        ) else {
+            return err!(RegistryError::TaskMintMismatch);
+        };
+        let mint = token.mint.key();
+        require!(
+            from.mint == mint && to.mint == mint,
+            RegistryError::TaskMintMismatch
+        );
+        let before = to.amount;
+        token.transfer(
+            &from.to_account_info(),
+            &to.to_account_info(),
+            &ctx.accounts.client.to_account_info(),
+            &[],
+            payment,
+        )?;
+        (mint, tokens_received(to, before)?)
+    } else {
+        require!(payment >= Task::MIN_PAYMENT, RegistryError::InvalidTaskParams);
+        deposit_lamports(
+            &ctx.accounts.client.to_account_info(),
+            &ctx.accounts.task.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            payment,
+        )?;
+        (Pubkey::default(), payment)
+    };
+    require!(received > 0, RegistryError::InvalidTaskParams);
+
+    let task = &mut ctx.accounts.task;
+    task.client = ctx.accounts.client.key();
+    task.nonce = nonce;
+    task.agent = Pubkey::default();
+    task.payment = received;
+    task.mint = mint;
+    task.spec_hash = spec_hash;
+    task.result_hash = [0u8; 32];
+    task.status = TaskStatus::Open;
+    task.deadline = deadline;
+    task.submitted_at = 0;
+    task.created_at = clock.unix_timestamp;
+    task.bump = ctx.bumps.task;
+
+    emit!(TaskPosted {
+        task: task.key(),
+        client: task.client,
+        payment: received,
+        mint,
+        spec_hash,
+        deadline,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Task posted: nonce={}, payment={} (mint {}), deadline={}",
+        nonce,
+        received,
+        mint,
+        deadline
+    );
+
+    Ok(())
+}
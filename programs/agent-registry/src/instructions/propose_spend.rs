@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{SpendProposal, TreasuryCouncil};
+use crate::errors::RegistryError;
+use crate::events::{SpendApproved, SpendProposed};
+
+/// Propose paying lamports out of the treasury (council member only)
+///
+/// Passing `mint` proposes a token spend instead, paid from a treasury-owned
+/// token account of that mint (e.g. slashed SPL stake). The proposer's
+/// approval is recorded immediately; with a threshold of one the timelock
+/// starts right away.
+#[derive(Accounts)]
+pub struct ProposeSpend<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TreasuryCouncil::SEED_PREFIX],
+        bump = council.bump,
+        constraint = council.is_member(&proposer.key()) @ RegistryError::NotCouncilMember
+    )]
+    pub council: Account<'info, TreasuryCouncil>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + SpendProposal::INIT_SPACE,
+        seeds = [SpendProposal::SEED_PREFIX, council.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, SpendProposal>,
+
+    /// Mint of the tokens to pay (token spends only)
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeSpend>,
+    recipient: Pubkey,
+    amount: u64,
+    memo_hash: [u8; 32],
+) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidSpendAmount);
+
+    let clock = Clock::get()?;
+    let council = &mut ctx.accounts.council;
+    let proposer = ctx.accounts.proposer.key();
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.index = council.proposal_count;
+    proposal.proposer = proposer;
+    proposal.recipient = recipient;
+    proposal.amount = amount;
+    proposal.mint = ctx.accounts.mint.as_ref().map_or(Pubkey::default(), |mint| mint.key());
+    proposal.memo_hash = memo_hash;
+    proposal.approvals = vec![proposer];
+    proposal.approved_at = if council.threshold <= 1 { clock.unix_timestamp } else { 0 };
+    proposal.created_at = clock.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+    council.proposal_count += 1;
+
+    emit!(SpendProposed {
+        index: proposal.index,
+        proposer,
+        recipient,
+        amount,
+        mint: proposal.mint,
+        memo_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    emit!(SpendApproved {
+        index: proposal.index,
+        member: proposer,
+        approvals: 1,
+        approved_at: proposal.approved_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Treasury spend #{} proposed by {}: {} (mint {}) to {}",
+        proposal.index,
+        proposer,
+        amount,
+        proposal.mint,
+        recipient
+    );
+
+    Ok(())
+}
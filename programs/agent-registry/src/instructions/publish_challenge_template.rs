@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{ChallengeKind, ChallengeTemplate, CommitmentScheme, RegistryConfig};
+use crate::errors::RegistryError;
+
+/// Publish a reusable challenge template (any verifier)
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct PublishChallengeTemplate<'info> {
+    /// Template author (pays rent; grades AuthorVerified challenges)
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    /// Registry config (difficulty bounds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + ChallengeTemplate::INIT_SPACE,
+        seeds = [
+            ChallengeTemplate::SEED_PREFIX,
+            author.key().as_ref(),
+            template_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub template: Account<'info, ChallengeTemplate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PublishChallengeTemplate>,
+    template_id: u64,
+    question_hash: String,
+    scheme: CommitmentScheme,
+    kind: ChallengeKind,
+    difficulty: u8,
+) -> Result<()> {
+    require!(
+        question_hash.len() == 64 && question_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidExpectedHash
+    );
+    require!(
+        difficulty >= 1 && difficulty <= ctx.accounts.config.max_difficulty,
+        RegistryError::InvalidDifficulty
+    );
+
+    let template = &mut ctx.accounts.template;
+    template.author = ctx.accounts.author.key();
+    template.template_id = template_id;
+    template.question_hash = question_hash.to_ascii_lowercase();
+    template.scheme = scheme;
+    template.kind = kind;
+    template.difficulty = difficulty;
+    template.uses = 0;
+    template.created_at = Clock::get()?.unix_timestamp;
+    template.bump = ctx.bumps.template;
+
+    msg!(
+        "Challenge template {} published by {} (scheme={:?}, kind={:?}, difficulty={})",
+        template_id,
+        template.author,
+        scheme,
+        kind,
+        difficulty
+    );
+
+    Ok(())
+}
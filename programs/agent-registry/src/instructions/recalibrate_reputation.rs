@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ReputationSchema};
+use crate::errors::RegistryError;
+use crate::events::ReputationChangeReason;
+use crate::utils::emit_reputation_changed;
+
+/// Bring an agent's reputation to the current schema version (permissionless)
+/// Applies every curve published since the agent's recorded version, once
+#[derive(Accounts)]
+pub struct RecalibrateReputation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        seeds = [ReputationSchema::SEED_PREFIX],
+        bump = reputation_schema.bump
+    )]
+    pub reputation_schema: Account<'info, ReputationSchema>,
+}
+
+pub fn handler(ctx: Context<RecalibrateReputation>) -> Result<()> {
+    let schema = &ctx.accounts.reputation_schema;
+    let agent = &mut ctx.accounts.agent;
+    require!(
+        agent.reputation_schema_version < schema.version(),
+        RegistryError::ReputationAlreadyRecalibrated
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let old_reputation = agent.reputation_score;
+    let old_version = agent.reputation_schema_version;
+    agent.reputation_score = schema.recalibrate(old_reputation, old_version);
+    agent.reputation_schema_version = schema.version();
+    agent.reputation_updated_at = now;
+
+    emit_reputation_changed(
+        agent.key(),
+        old_reputation,
+        agent.reputation_score,
+        ReputationChangeReason::Recalibration,
+        schema.key(),
+        now,
+    );
+
+    msg!(
+        "Agent {} recalibrated: schema v{} -> v{}, reputation {} -> {}",
+        agent.agent_id,
+        old_version,
+        agent.reputation_schema_version,
+        old_reputation,
+        agent.reputation_score
+    );
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentAuditSummary, AuditEntry, AuditRecompute};
+use crate::errors::RegistryError;
+use crate::events::AuditSummaryDiscrepancy;
+
+/// Re-derive an agent's audit summary from its AuditEntry accounts (anyone can crank)
+///
+/// Entry accounts are passed as read-only `remaining_accounts` in audit_index
+/// order, continuing from where the previous call stopped; progress is kept in
+/// an AuditRecompute PDA. Once every entry has been counted, the summary's
+/// average, maximum and per-action counts are compared with the recomputed
+/// values (average and maximum only while no risk decay has applied),
+/// corrected if they drifted (emitting AuditSummaryDiscrepancy), and the
+/// progress account is closed to the caller. Agents whose entries were logged
+/// to a ring, batch or tree, or archived, cannot be recomputed this way.
+#[derive(Accounts)]
+pub struct RecomputeAuditSummary<'info> {
+    /// Pays for (and is refunded) the progress account
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_summary.bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = 8 + AuditRecompute::INIT_SPACE,
+        seeds = [AuditRecompute::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub recompute: Account<'info, AuditRecompute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecomputeAuditSummary<'info>>,
+) -> Result<()> {
+    let agent_key = ctx.accounts.agent.key();
+    let recompute = &mut ctx.accounts.recompute;
+    if recompute.next_index == 0 {
+        recompute.agent = agent_key;
+        recompute.bump = ctx.bumps.recompute;
+    }
+
+    for info in ctx.remaining_accounts.iter() {
+        let entry = Account::<AuditEntry>::try_from(info)?;
+        require!(
+            entry.agent == agent_key && entry.audit_index == recompute.next_index,
+            RegistryError::InvalidAuditRange
+        );
+        recompute.record(&entry);
+    }
+
+    let summary = &mut ctx.accounts.audit_summary;
+    require!(
+        recompute.next_index <= summary.total_entries,
+        RegistryError::InvalidAuditRange
+    );
+    if recompute.next_index < summary.total_entries {
+        msg!(
+            "Audit recompute progress: agent={}, counted {}/{}",
+            agent_key,
+            recompute.next_index,
+            summary.total_entries
+        );
+        return Ok(());
+    }
+
+    // Decayed or reset risk figures are intentionally not lifetime stats; keep them
+    let (avg_risk, max_risk) = if summary.risk_decayed {
+        (summary.avg_risk_score, summary.max_risk_score)
+    } else {
+        (recompute.avg_risk(), recompute.max_risk)
+    };
+    let drifted = summary.avg_risk_score != avg_risk
+        || summary.max_risk_score != max_risk
+        || summary.action_counts != recompute.action_counts;
+    if drifted {
+        emit!(AuditSummaryDiscrepancy {
+            agent: agent_key,
+            total_entries: summary.total_entries,
+            old_avg_risk: summary.avg_risk_score,
+            new_avg_risk: avg_risk,
+            old_max_risk: summary.max_risk_score,
+            new_max_risk: max_risk,
+            action_counts_corrected: summary.action_counts != recompute.action_counts,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        summary.avg_risk_score = avg_risk;
+        summary.max_risk_score = max_risk;
+        summary.action_counts = recompute.action_counts;
+    }
+
+    msg!(
+        "Audit summary recomputed: agent={}, entries={}, drifted={}",
+        agent_key,
+        summary.total_entries,
+        drifted
+    );
+
+    ctx.accounts
+        .recompute
+        .close(ctx.accounts.caller.to_account_info())
+}
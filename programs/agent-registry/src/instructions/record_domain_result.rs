@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus, DomainReputation};
+use crate::errors::RegistryError;
+
+/// Apply a final challenge verdict to the agent's per-domain reputation
+///
+/// Permissionless, once per challenge, after the verdict is final (dispute
+/// window closed). The challenge's reputation delta is applied to the domain
+/// of its kind. Abandoned challenges have no reputation effect and are skipped.
+/// Best effort: closing the challenge does not wait for it, so it must run
+/// before the challenge is closed or the result is not recorded. The caller
+/// pays for the DomainReputation account on first use.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RecordDomainResult<'info> {
+    /// Anyone can record the result
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// The challenged agent
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The agent's per-domain reputation
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = 8 + DomainReputation::INIT_SPACE,
+        seeds = [DomainReputation::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub domain_reputation: Account<'info, DomainReputation>,
+
+    /// The resolved challenge
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.is_domain_result_pending() @ RegistryError::DomainResultNotRecordable
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordDomainResult>, _nonce: u64) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let domain_reputation = &mut ctx.accounts.domain_reputation;
+    let clock = Clock::get()?;
+
+    require!(
+        !challenge.is_dispute_window_open(clock.unix_timestamp),
+        RegistryError::DisputeWindowOpen
+    );
+
+    if domain_reputation.agent == Pubkey::default() {
+        domain_reputation.init(ctx.accounts.agent.key(), ctx.bumps.domain_reputation);
+    }
+
+    let passed = challenge.status == ChallengeStatus::Passed;
+    domain_reputation.record(
+        challenge.kind,
+        passed,
+        challenge.reputation_delta,
+        clock.unix_timestamp,
+    );
+    challenge.domain_recorded = true;
+
+    msg!(
+        "Domain result: agent {} {:?} {}. Domain reputation: {}",
+        ctx.accounts.agent.agent_id,
+        challenge.kind,
+        if passed { "PASSED" } else { "FAILED" },
+        domain_reputation.domain(challenge.kind).score
+    );
+
+    Ok(())
+}
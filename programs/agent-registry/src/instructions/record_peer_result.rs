@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use crate::errors::RegistryError;
+
+/// Record the outcome of an agent-to-agent challenge on the challenger agent
+///
+/// Permissionless, once per challenge, after the verdict is final (dispute
+/// window closed). The challenger agent scores a peer win when the challenged
+/// agent failed or let the challenge expire, and a peer loss when it passed or
+/// the challenger abandoned the reveal. Must run before the challenge is closed.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RecordPeerResult<'info> {
+    /// Anyone can record the result
+    pub caller: Signer<'info>,
+
+    /// The challenged agent (for PDA derivation)
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The agent that issued the challenge
+    #[account(
+        mut,
+        address = challenge.challenger_agent @ RegistryError::PeerResultNotRecordable
+    )]
+    pub challenger_agent: Account<'info, AgentAccount>,
+
+    /// The resolved peer challenge
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.is_peer_result_pending() @ RegistryError::PeerResultNotRecordable,
+        constraint = challenge.is_resolved() @ RegistryError::ChallengeStillPending
+    )]
+    pub challenge: Account<'info, Challenge>,
+}
+
+pub fn handler(ctx: Context<RecordPeerResult>, _nonce: u64) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let challenger_agent = &mut ctx.accounts.challenger_agent;
+    let clock = Clock::get()?;
+
+    require!(
+        !challenge.is_dispute_window_open(clock.unix_timestamp),
+        RegistryError::DisputeWindowOpen
+    );
+
+    let won = matches!(challenge.status, ChallengeStatus::Failed | ChallengeStatus::Expired);
+    if won {
+        challenger_agent.peer_wins = challenger_agent.peer_wins.saturating_add(1);
+    } else {
+        challenger_agent.peer_losses = challenger_agent.peer_losses.saturating_add(1);
+    }
+    challenger_agent.updated_at = clock.unix_timestamp;
+    challenge.peer_recorded = true;
+
+    msg!(
+        "Peer challenge result: agent {} {} against agent {}",
+        challenger_agent.agent_id,
+        if won { "WON" } else { "LOST" },
+        ctx.accounts.agent.agent_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentRecovery};
+use crate::events::RecoveryRequested;
+use crate::errors::RegistryError;
+
+/// Request owner recovery for an agent by proving custody of its identity NFT
+///
+/// Covers the case where the owner key is lost but the NFT is still safe
+/// (e.g., held by a multisig). The new owner is installed by `finalize_recovery`
+/// after `AgentAccount::RECOVERY_DELAY`; the current owner can cancel meanwhile.
+#[derive(Accounts)]
+pub struct RecoverAgent<'info> {
+    /// Current holder of the identity NFT (pays rent for the request)
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Identity NFT asset; holder is read from the Metaplex Core layout in the handler
+    #[account(address = agent.nft_mint @ RegistryError::NftMismatch)]
+    pub nft_asset: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + AgentRecovery::INIT_SPACE,
+        seeds = [AgentRecovery::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub recovery: Account<'info, AgentRecovery>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecoverAgent>, new_owner: Pubkey) -> Result<()> {
+    let holder = AgentRecovery::nft_holder(&ctx.accounts.nft_asset.to_account_info())
+        .ok_or(RegistryError::NotNftHolder)?;
+    require_keys_eq!(holder, ctx.accounts.holder.key(), RegistryError::NotNftHolder);
+    require!(
+        new_owner != Pubkey::default() && new_owner != ctx.accounts.agent.owner,
+        RegistryError::InvalidNewOwner
+    );
+
+    let clock = Clock::get()?;
+    let recovery = &mut ctx.accounts.recovery;
+    recovery.agent = ctx.accounts.agent.key();
+    recovery.requester = ctx.accounts.holder.key();
+    recovery.new_owner = new_owner;
+    recovery.requested_at = clock.unix_timestamp;
+    recovery.executable_at = clock.unix_timestamp + AgentAccount::RECOVERY_DELAY;
+    recovery.bump = ctx.bumps.recovery;
+
+    emit!(RecoveryRequested {
+        agent: recovery.agent,
+        requester: recovery.requester,
+        current_owner: ctx.accounts.agent.owner,
+        new_owner,
+        executable_at: recovery.executable_at,
+    });
+
+    msg!(
+        "Recovery requested: agent={}, new_owner={}, executable_at={}",
+        ctx.accounts.agent.agent_id,
+        new_owner,
+        recovery.executable_at
+    );
+
+    Ok(())
+}
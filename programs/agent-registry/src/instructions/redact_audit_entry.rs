@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditEntry, RedactionReason, RegistryState};
+use crate::errors::RegistryError;
+use crate::events::AuditEntryRedacted;
+use crate::utils::decode_hex32;
+
+/// Redact the details of an audit entry (agent owner and registry admin co-sign)
+///
+/// The details hash is zeroed and replaced by a tombstone: the reason, the
+/// redaction time and the entry's chain link, so the hash chain and the
+/// audit index sequence remain verifiable without the redacted details.
+#[derive(Accounts)]
+pub struct RedactAuditEntry<'info> {
+    pub owner: Signer<'info>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_entry.audit_index.to_le_bytes().as_ref()
+        ],
+        bump = audit_entry.bump
+    )]
+    pub audit_entry: Account<'info, AuditEntry>,
+}
+
+pub fn handler(ctx: Context<RedactAuditEntry>, reason: RedactionReason) -> Result<()> {
+    let entry = &mut ctx.accounts.audit_entry;
+    require!(entry.redaction.is_none(), RegistryError::AuditEntryRedacted);
+
+    let details = decode_hex32(&entry.details_hash).ok_or(RegistryError::InvalidDetailsHash)?;
+    let clock = Clock::get()?;
+
+    entry.redacted_link_hash = AuditEntry::link_hash(
+        &entry.prev_entry_hash,
+        &entry.agent,
+        entry.audit_index,
+        entry.action_type,
+        entry.risk_score,
+        entry.timestamp,
+        &details,
+    );
+    entry.details_hash = "0".repeat(64);
+    entry.redaction = Some(reason);
+    entry.redacted_at = clock.unix_timestamp;
+
+    emit!(AuditEntryRedacted {
+        audit_entry: entry.key(),
+        agent: entry.agent,
+        audit_index: entry.audit_index,
+        reason,
+        link_hash: entry.redacted_link_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit entry redacted: agent={}, index={}, reason={:?}",
+        entry.agent,
+        entry.audit_index,
+        reason
+    );
+
+    Ok(())
+}
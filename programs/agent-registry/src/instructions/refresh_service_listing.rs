@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ServiceListing};
+use crate::errors::RegistryError;
+
+/// Re-sync a listing's reputation snapshot with its agent (anyone can call)
+/// Listings of retired agents are deactivated
+#[derive(Accounts)]
+pub struct RefreshServiceListing<'info> {
+    #[account(
+        mut,
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            listing.agent.as_ref(),
+            listing.listing_id.to_le_bytes().as_ref()
+        ],
+        bump = listing.bump
+    )]
+    pub listing: Account<'info, ServiceListing>,
+
+    #[account(address = listing.agent @ RegistryError::Unauthorized)]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+pub fn handler(ctx: Context<RefreshServiceListing>) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let listing = &mut ctx.accounts.listing;
+    listing.sync_agent(agent.reputation_score, agent.verified, Clock::get()?.unix_timestamp);
+    if agent.is_retired() {
+        listing.active = false;
+    }
+
+    msg!(
+        "Service listing {} refreshed: reputation={}, verified={}",
+        listing.listing_id,
+        listing.agent_reputation,
+        listing.agent_verified
+    );
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, RegistryState};
+use crate::state::{AgentAccount, RegistryState, ReputationSchema};
 use crate::errors::RegistryError;
 
 #[derive(Accounts)]
@@ -42,6 +42,13 @@ pub struct RegisterAgent<'info> {
     /// Without these checks, any arbitrary pubkey can be passed as the NFT mint.
     pub nft_mint: UncheckedAccount<'info>,
 
+    /// CHECK: Reputation schema (may not exist yet = version 0)
+    #[account(
+        seeds = [ReputationSchema::SEED_PREFIX],
+        bump
+    )]
+    pub reputation_schema: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -66,17 +73,39 @@ pub fn handler(
     // Set agent fields
     agent.agent_id = registry.total_agents;
     agent.owner = ctx.accounts.owner.key();
+    agent.original_owner = ctx.accounts.owner.key();
+    agent.operator = Pubkey::default();
     agent.name = name.clone();
     agent.model_hash = model_hash;
     agent.capabilities = capabilities;
     agent.reputation_score = AgentAccount::INITIAL_REPUTATION;
     agent.challenges_passed = 0;
     agent.challenges_failed = 0;
+    agent.challenges_issued = 0;
+    agent.peer_wins = 0;
+    agent.peer_losses = 0;
+    agent.reputation_window_start = 0;
+    agent.reputation_window_change = 0;
+    agent.reputation_updated_at = clock.unix_timestamp;
+    agent.successor = Pubkey::default();
+    agent.endorsement_bonus = 0;
+    agent.on_probation = false;
+    agent.suspended = false;
+    agent.sentinel_authority = Pubkey::default();
+    agent.policy_hash = [0u8; 32];
+    agent.policy_version = 0;
+    agent.policy_updated_at = 0;
+    agent.staked_amount = 0;
+    agent.task_credit_window_start = 0;
+    agent.task_credit_window_change = 0;
+    agent.probation_streak = 0;
+    agent.reputation_schema_version = ReputationSchema::current_version(&ctx.accounts.reputation_schema)?;
     agent.verified = false;
     agent.created_at = clock.unix_timestamp;
     agent.updated_at = clock.unix_timestamp;
     agent.nft_mint = ctx.accounts.nft_mint.key();
     agent.bump = ctx.bumps.agent;
+    agent.version = AgentAccount::CURRENT_VERSION;
 
     // Increment total agents
     registry.total_agents = registry.total_agents.checked_add(1)
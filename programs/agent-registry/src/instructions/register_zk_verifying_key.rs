@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, ZkVerifyingKey};
+use crate::errors::RegistryError;
+
+/// Groth16 verifying key components (EIP-197 big-endian encoding)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerifyingKeyParams {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+    pub ic: [[u8; 64]; 4],
+}
+
+/// Register a proof-of-inference circuit's verifying key (admin only)
+#[derive(Accounts)]
+#[instruction(circuit_id: u64)]
+pub struct RegisterZkVerifyingKey<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + ZkVerifyingKey::INIT_SPACE,
+        seeds = [ZkVerifyingKey::SEED_PREFIX, circuit_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub verifying_key: Account<'info, ZkVerifyingKey>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterZkVerifyingKey>,
+    circuit_id: u64,
+    params: VerifyingKeyParams,
+) -> Result<()> {
+    let vk = &mut ctx.accounts.verifying_key;
+
+    vk.circuit_id = circuit_id;
+    vk.authority = ctx.accounts.admin.key();
+    vk.alpha_g1 = params.alpha_g1;
+    vk.beta_g2 = params.beta_g2;
+    vk.gamma_g2 = params.gamma_g2;
+    vk.delta_g2 = params.delta_g2;
+    vk.ic = params.ic;
+    vk.bump = ctx.bumps.verifying_key;
+
+    msg!("ZK verifying key registered: circuit {}", circuit_id);
+
+    Ok(())
+}
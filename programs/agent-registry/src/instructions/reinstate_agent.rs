@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentAuditSummary, AuditEntry, RegistryAction, RegistryState, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::AgentReinstated;
+use crate::utils::log_registry_action;
+
+/// Lift an audit circuit breaker suspension (admin only)
+/// Also resets the breaker window so the next alert does not re-trip it immediately
+#[derive(Accounts)]
+pub struct ReinstateAgent<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump = audit_summary.bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// Registry-scoped audit summary (created on the first logged admin action)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, registry.key().as_ref()],
+        bump
+    )]
+    pub registry_audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// Registry audit entry recording the reinstatement
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            registry.key().as_ref(),
+            registry_audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub registry_audit_entry: Account<'info, AuditEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReinstateAgent>) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+    require!(agent.suspended, RegistryError::AgentNotSuspended);
+
+    let clock = Clock::get()?;
+    agent.suspended = false;
+    agent.updated_at = clock.unix_timestamp;
+
+    let summary = &mut ctx.accounts.audit_summary;
+    summary.critical_window_start = clock.unix_timestamp;
+    summary.critical_window_alerts = 0;
+
+    let summary = &mut ctx.accounts.registry_audit_summary;
+    summary.agent = ctx.accounts.registry.key();
+    summary.bump = ctx.bumps.registry_audit_summary;
+    ctx.accounts.registry_audit_entry.bump = ctx.bumps.registry_audit_entry;
+    log_registry_action(
+        ctx.accounts.admin.key(),
+        summary,
+        &mut ctx.accounts.registry_audit_entry,
+        &ctx.accounts.risk_weights,
+        RegistryAction::AgentReinstated,
+        agent.key(),
+        &[],
+    )?;
+
+    emit!(AgentReinstated {
+        agent: agent.key(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Agent reinstated: id={}, name={}", agent.agent_id, agent.name);
+
+    Ok(())
+}
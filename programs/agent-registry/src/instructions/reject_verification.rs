@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, VerificationRequest, VerifiedVerifier};
+use crate::errors::RegistryError;
+use crate::events::VerificationRequestClosed;
+
+/// Turn down a verification request and refund its fee (admin or verified verifier)
+///
+/// The requester may also withdraw their own open request this way. The
+/// request is closed, so the escrowed fee and the rent go back to the requester.
+#[derive(Accounts)]
+pub struct RejectVerification<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// CHECK: Resolver's verified verifier record (may not exist = not verified)
+    #[account(
+        seeds = [VerifiedVerifier::SEED_PREFIX, resolver.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [VerificationRequest::SEED_PREFIX, verification_request.agent.as_ref()],
+        bump = verification_request.bump
+    )]
+    pub verification_request: Account<'info, VerificationRequest>,
+
+    /// CHECK: Requester wallet (validated against verification_request.requester)
+    #[account(mut, address = verification_request.requester @ RegistryError::Unauthorized)]
+    pub requester: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RejectVerification>, reason_hash: [u8; 32]) -> Result<()> {
+    let resolver = ctx.accounts.resolver.key();
+    let request = &ctx.accounts.verification_request;
+    require!(
+        resolver == ctx.accounts.registry.admin
+            || resolver == request.requester
+            || VerifiedVerifier::is_active(&ctx.accounts.verifier_record)?,
+        RegistryError::Unauthorized
+    );
+
+    emit!(VerificationRequestClosed {
+        agent: request.agent,
+        requester: request.requester,
+        handled_by: resolver,
+        verified: false,
+        fee: request.fee,
+        reason_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Verification request for agent {} rejected, {} lamports refunded",
+        request.agent,
+        request.fee
+    );
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, RegistryConfig, Report, ReportCategory};
+use crate::errors::RegistryError;
+use crate::events::AgentReported;
+use crate::utils::deposit_lamports;
+
+/// Report agent misbehavior with an evidence commitment
+///
+/// Anyone may file a report by locking config.report_bond_lamports. The
+/// evidence itself stays off-chain; only its SHA256 hash is recorded. The
+/// registry admin or a verified verifier settles it with `resolve_report`.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ReportAgent<'info> {
+    /// Reporter (pays rent and the report bond)
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// The reported agent
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (report bond amount)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + Report::INIT_SPACE,
+        seeds = [
+            Report::SEED_PREFIX,
+            agent.key().as_ref(),
+            reporter.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub report: Account<'info, Report>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ReportAgent>,
+    nonce: u64,
+    category: ReportCategory,
+    evidence_hash: String,
+) -> Result<()> {
+    require!(
+        evidence_hash.len() == 64 && evidence_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidEvidenceHash
+    );
+
+    let clock = Clock::get()?;
+    let bond = ctx.accounts.config.report_bond_lamports;
+    deposit_lamports(
+        &ctx.accounts.reporter.to_account_info(),
+        &ctx.accounts.report.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        bond,
+    )?;
+
+    let report = &mut ctx.accounts.report;
+    report.agent = ctx.accounts.agent.key();
+    report.reporter = ctx.accounts.reporter.key();
+    report.nonce = nonce;
+    report.category = category;
+    report.evidence_hash = evidence_hash;
+    report.bond = bond;
+    report.created_at = clock.unix_timestamp;
+    report.bump = ctx.bumps.report;
+
+    emit!(AgentReported {
+        report: report.key(),
+        agent: report.agent,
+        reporter: report.reporter,
+        category,
+        evidence_hash: report.evidence_hash.clone(),
+        bond,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Agent {} reported by {} ({:?}, bond={})",
+        ctx.accounts.agent.agent_id,
+        report.reporter,
+        category,
+        bond
+    );
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentAuditSummary, AuditPolicy, IncidentClass, IncidentReport, IncidentSeverity,
+    RegistryConfig,
+};
+use crate::errors::RegistryError;
+use crate::events::IncidentReported;
+use crate::utils::enforce_circuit_breaker;
+
+/// File a structured serious-incident report for an agent
+///
+/// Reporters are the agent's audit actors (owner, operator or a designated
+/// sentinel). The full report stays off-chain; its SHA256 hash, class,
+/// severity and reach are recorded and counted in the audit summary.
+#[derive(Accounts)]
+pub struct ReportIncident<'info> {
+    /// Agent owner, operator or a designated sentinel
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (audit circuit breaker thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_audit_actor(&audit_policy, &agent, &reporter.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    /// The audit summary for this agent (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + IncidentReport::INIT_SPACE,
+        seeds = [
+            IncidentReport::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_summary.incidents_reported.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub incident: Account<'info, IncidentReport>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ReportIncident>,
+    class: IncidentClass,
+    severity: IncidentSeverity,
+    affected_parties: u32,
+    report_hash: String,
+    occurred_at: i64,
+) -> Result<()> {
+    require!(
+        report_hash.len() == 64 && report_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidEvidenceHash
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        occurred_at > 0 && occurred_at <= clock.unix_timestamp,
+        RegistryError::InvalidIncidentReport
+    );
+
+    let agent_key = ctx.accounts.agent.key();
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = agent_key;
+        summary.bump = ctx.bumps.audit_summary;
+    }
+
+    let incident = &mut ctx.accounts.incident;
+    incident.agent = agent_key;
+    incident.reporter = ctx.accounts.reporter.key();
+    incident.incident_index = summary.incidents_reported;
+    incident.class = class;
+    incident.severity = severity;
+    incident.affected_parties = affected_parties;
+    incident.report_hash = report_hash;
+    incident.occurred_at = occurred_at;
+    incident.reported_at = clock.unix_timestamp;
+    incident.bump = ctx.bumps.incident;
+
+    summary.record_incident(clock.unix_timestamp);
+
+    let critical = (severity == IncidentSeverity::Critical) as u32;
+    enforce_circuit_breaker(
+        &mut ctx.accounts.agent,
+        summary,
+        critical,
+        &ctx.accounts.config,
+        clock.unix_timestamp,
+    );
+
+    emit!(IncidentReported {
+        incident: incident.key(),
+        agent: agent_key,
+        reporter: incident.reporter,
+        incident_index: incident.incident_index,
+        class,
+        severity,
+        affected_parties,
+        occurred_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Incident reported: agent={}, index={}, class={:?}, severity={:?}, affected={}",
+        agent_key,
+        incident.incident_index,
+        class,
+        severity,
+        affected_parties
+    );
+
+    Ok(())
+}
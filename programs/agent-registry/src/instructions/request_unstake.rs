@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakePosition, StakeVault};
+use crate::errors::RegistryError;
+
+/// Start unbonding a stake position (staker only)
+///
+/// The stake keeps boosting and remains slashable until withdrawn after
+/// `StakeVault::UNBONDING_PERIOD`.
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    pub staker: Signer<'info>,
+
+    #[account(
+        seeds = [StakeVault::SEED_PREFIX, stake_vault.agent.as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            StakePosition::SEED_PREFIX,
+            stake_vault.key().as_ref(),
+            staker.key().as_ref(),
+        ],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+}
+
+pub fn handler(ctx: Context<RequestUnstake>) -> Result<()> {
+    let position = &mut ctx.accounts.stake_position;
+    require!(!position.is_unbonding(), RegistryError::StakeUnbonding);
+
+    position.unbonding_at = Clock::get()?.unix_timestamp + StakeVault::UNBONDING_PERIOD;
+
+    msg!(
+        "Unstake requested by {}: {} shares withdrawable at {}",
+        position.staker,
+        position.shares,
+        position.unbonding_at
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, RegistryConfig, VerificationRequest};
+use crate::errors::RegistryError;
+use crate::events::VerificationRequested;
+use crate::utils::deposit_lamports;
+
+/// Queue an agent for verification by escrowing a fee (owner only)
+///
+/// The fee must be at least config.min_verification_fee; a higher fee buys a
+/// better place in the off-chain queue. The admin or a verified verifier claims
+/// it through `verify_agent`, or refunds it with `reject_verification`.
+#[derive(Accounts)]
+pub struct RequestVerification<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (minimum verification fee)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Request escrow (one open request per agent)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + VerificationRequest::INIT_SPACE,
+        seeds = [VerificationRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub verification_request: Account<'info, VerificationRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RequestVerification>, fee: u64) -> Result<()> {
+    require!(!ctx.accounts.agent.verified, RegistryError::AlreadyVerified);
+    require!(
+        fee >= ctx.accounts.config.min_verification_fee,
+        RegistryError::VerificationFeeTooLow
+    );
+
+    deposit_lamports(
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.verification_request.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        fee,
+    )?;
+
+    let clock = Clock::get()?;
+    let request = &mut ctx.accounts.verification_request;
+    request.agent = ctx.accounts.agent.key();
+    request.requester = ctx.accounts.owner.key();
+    request.fee = fee;
+    request.created_at = clock.unix_timestamp;
+    request.bump = ctx.bumps.verification_request;
+
+    emit!(VerificationRequested {
+        agent: request.agent,
+        requester: request.requester,
+        fee,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Verification requested: agent id={}, fee={} lamports",
+        ctx.accounts.agent.agent_id,
+        fee
+    );
+
+    Ok(())
+}
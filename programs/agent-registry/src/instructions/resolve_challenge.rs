@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus, ChallengerStats, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::utils::{
+    emit_challenge_resolved, enforce_probation, record_probation_result, release_challenge_escrow,
+    require_min_reputation,
+};
+
+/// Resolve a responded challenge as its designated third-party verifier
+///
+/// Used for open-ended questions where a hash comparison cannot decide the
+/// outcome: the verifier (human grader or oracle key) scores the response
+/// off-chain and records a 0-100 score here, within the reveal window. The
+/// reputation delta is proportional to the score; PASS_SCORE and above passes.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ResolveChallenge<'info> {
+    /// The verifier designated at challenge creation
+    pub verifier: Signer<'info>,
+
+    /// The agent that responded
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner wallet (receives the reward on pass)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Original challenger (receives the bond back, and the reward on fail)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// The challenge being resolved
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.has_verifier() @ RegistryError::NotChallengeVerifier,
+        constraint = challenge.verifier == verifier.key() @ RegistryError::NotChallengeVerifier,
+        constraint = challenge.status == ChallengeStatus::Responded @ RegistryError::ChallengeNotResponded
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+
+    /// Registry config (verifier reputation threshold, probation)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Verifier's agent (required when config.min_reputation_verifier is set)
+    pub verifier_agent: Option<Account<'info, AgentAccount>>,
+}
+
+pub fn handler(ctx: Context<ResolveChallenge>, _nonce: u64, score: u8) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+    let clock = Clock::get()?;
+    let max_change = ctx.accounts.config.max_reputation_change_per_day;
+
+    require_min_reputation(
+        ctx.accounts.verifier_agent.as_ref(),
+        &ctx.accounts.verifier.key(),
+        ctx.accounts.config.min_reputation_verifier,
+    )?;
+    require!(score <= Challenge::MAX_SCORE, RegistryError::InvalidScore);
+    let passed = score >= Challenge::PASS_SCORE;
+
+    require!(
+        !challenge.is_reveal_expired(clock.unix_timestamp),
+        RegistryError::RevealWindowClosed
+    );
+
+    let reward_paid = release_challenge_escrow(
+        challenge,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.agent_owner.to_account_info(),
+        passed,
+    )?;
+
+    let old_reputation = agent.reputation_score;
+    challenge.apply_score(agent, score, clock.unix_timestamp, max_change);
+
+    msg!(
+        "Challenge {} (score {}) by verifier {}. Agent {} reputation: {}, reward: {}",
+        if passed { "PASSED" } else { "FAILED" },
+        score,
+        ctx.accounts.verifier.key(),
+        agent.agent_id,
+        agent.reputation_score,
+        reward_paid
+    );
+
+    ctx.accounts.challenger_stats.record_verdict(passed);
+    emit_challenge_resolved(challenge, agent, old_reputation);
+    record_probation_result(agent, challenge, passed, &ctx.accounts.config, clock.unix_timestamp);
+    enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, Challenge, ChallengeStatus, ChallengerStats, RegistryConfig, SwitchboardResult,
+};
+use crate::errors::RegistryError;
+use crate::utils::{emit_challenge_resolved, enforce_probation, release_challenge_escrow};
+
+/// Resolve a responded challenge from its Switchboard feed
+///
+/// Permissionless: the verdict comes from the feed chosen at creation, which must
+/// be controlled by the config's trusted oracle authority and updated after the
+/// agent responded. Lets off-chain evaluation pipelines grade trustlessly.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ResolveChallengeWithOracle<'info> {
+    /// Anyone can crank an oracle resolution
+    pub caller: Signer<'info>,
+
+    /// The agent that responded
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner wallet (receives the reward on pass)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Original challenger (receives the bond back, and the reward on fail)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// Registry config (trusted oracle authority, probation floor)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Switchboard pull feed (address and layout validated in the handler)
+    #[account(address = challenge.oracle_feed @ RegistryError::InvalidOracleFeed)]
+    pub oracle_feed: UncheckedAccount<'info>,
+
+    /// The challenge being resolved
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.has_oracle() @ RegistryError::InvalidOracleFeed,
+        constraint = challenge.status == ChallengeStatus::Responded @ RegistryError::ChallengeNotResponded
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+}
+
+pub fn handler(ctx: Context<ResolveChallengeWithOracle>, _nonce: u64) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+    let clock = Clock::get()?;
+    let max_change = ctx.accounts.config.max_reputation_change_per_day;
+
+    require!(
+        !challenge.is_reveal_expired(clock.unix_timestamp),
+        RegistryError::RevealWindowClosed
+    );
+
+    let result = SwitchboardResult::read(&ctx.accounts.oracle_feed.to_account_info())
+        .ok_or(RegistryError::InvalidOracleFeed)?;
+    require!(
+        ctx.accounts.config.oracle_authority != Pubkey::default()
+            && result.authority == ctx.accounts.config.oracle_authority,
+        RegistryError::OracleAuthorityMismatch
+    );
+    require!(
+        result.last_update_timestamp >= challenge.responded_at,
+        RegistryError::OracleResultStale
+    );
+
+    let passed = result.passed();
+    let reward_paid = release_challenge_escrow(
+        challenge,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.agent_owner.to_account_info(),
+        passed,
+    )?;
+
+    let old_reputation = agent.reputation_score;
+    challenge.apply_verdict(agent, passed, clock.unix_timestamp, max_change);
+
+    msg!(
+        "Challenge {} by oracle {} (value={}). Agent {} reputation: {}, reward: {}",
+        if passed { "PASSED" } else { "FAILED" },
+        ctx.accounts.oracle_feed.key(),
+        result.value,
+        agent.agent_id,
+        agent.reputation_score,
+        reward_paid
+    );
+
+    ctx.accounts.challenger_stats.record_verdict(passed);
+    emit_challenge_resolved(challenge, agent, old_reputation);
+    enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
+    Ok(())
+}
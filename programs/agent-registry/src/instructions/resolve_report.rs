@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    AgentAccount, RegistryConfig, RegistryState, Report, StakeVault, Treasury, VerifiedVerifier,
+};
+use crate::errors::RegistryError;
+use crate::events::{ReportResolved, ReputationChangeReason};
+use crate::utils::{
+    emit_reputation_changed, enforce_probation, pay_out_stake, transfer_from_pda, TokenMint,
+};
+
+/// Settle a misbehavior report (registry admin or verified verifier)
+///
+/// If `upheld` is true the agent loses `penalty` reputation (at most
+/// `Report::MAX_PENALTY`), `slash_bps` of its stake vault goes to the treasury
+/// and the bond is refunded to the reporter. Otherwise the bond goes to the
+/// treasury. Either way the report is closed and its rent returned. Slashing an
+/// SPL stake vault needs the token accounts; those tokens are not counted in
+/// the treasury's total_collected.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ResolveReport<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    /// CHECK: Resolver's verified verifier record (may not exist = not verified)
+    #[account(
+        seeds = [VerifiedVerifier::SEED_PREFIX, resolver.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: UncheckedAccount<'info>,
+
+    /// The reported agent
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Report record (closed, rent and a refunded bond to the reporter)
+    #[account(
+        mut,
+        close = reporter,
+        seeds = [
+            Report::SEED_PREFIX,
+            agent.key().as_ref(),
+            report.reporter.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = report.bump
+    )]
+    pub report: Account<'info, Report>,
+
+    /// CHECK: Reporter wallet (validated against report.reporter)
+    #[account(mut, address = report.reporter @ RegistryError::Unauthorized)]
+    pub reporter: UncheckedAccount<'info>,
+
+    /// Agent's stake vault (required to slash)
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Option<Account<'info, StakeVault>>,
+
+    /// Protocol treasury (receives slashed stake and dismissed bonds)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    /// Registry config (probation floor)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Token account owned by the vault PDA (SPL vaults only)
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury-owned token account receiving slashed tokens (SPL vaults only)
+    #[account(mut, token::authority = treasury)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake mint (SPL vaults only, needed for transfer_checked)
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveReport<'info>>,
+    _nonce: u64,
+    upheld: bool,
+    penalty: u32,
+    slash_bps: u16,
+) -> Result<()> {
+    let resolver = ctx.accounts.resolver.key();
+    require!(
+        resolver == ctx.accounts.registry.admin
+            || VerifiedVerifier::is_active(&ctx.accounts.verifier_record)?,
+        RegistryError::NotReportResolver
+    );
+    require!(
+        resolver != ctx.accounts.report.reporter && !ctx.accounts.agent.is_responder(&resolver),
+        RegistryError::NotReportResolver
+    );
+    require!(
+        penalty <= Report::MAX_PENALTY && slash_bps <= RegistryConfig::MAX_SLASH_BPS,
+        RegistryError::InvalidReportPenalty
+    );
+    require!(
+        upheld || (penalty == 0 && slash_bps == 0),
+        RegistryError::InvalidReportPenalty
+    );
+
+    let clock = Clock::get()?;
+    let report = &mut ctx.accounts.report;
+    let treasury = &mut ctx.accounts.treasury;
+    let agent = &mut ctx.accounts.agent;
+    let mut slashed = 0;
+    let mut slashed_lamports = 0;
+
+    if upheld {
+        let old_reputation = agent.reputation_score;
+        agent.debit_reputation(penalty, clock.unix_timestamp);
+        emit_reputation_changed(
+            agent.key(),
+            old_reputation,
+            agent.reputation_score,
+            ReputationChangeReason::ReportUpheld,
+            report.key(),
+            clock.unix_timestamp,
+        );
+        enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
+        if slash_bps > 0 {
+            let vault = ctx
+                .accounts
+                .stake_vault
+                .as_mut()
+                .ok_or(RegistryError::InvalidReportPenalty)?;
+            slashed = (vault.total_staked as u128 * slash_bps as u128
+                / RegistryConfig::BPS_DENOMINATOR as u128) as u64;
+            vault.total_staked -= slashed;
+            vault.total_slashed = vault.total_slashed.saturating_add(slashed);
+            agent.staked_amount = vault.total_staked;
+            pay_out_stake(
+                vault,
+                &treasury.to_account_info(),
+                ctx.accounts.vault_token_account.as_ref(),
+                ctx.accounts.treasury_token_account.as_ref(),
+                TokenMint::from_accounts(
+                    ctx.accounts.stake_mint.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                    ctx.remaining_accounts,
+                ),
+                slashed,
+            )?;
+            if !vault.is_token() {
+                slashed_lamports = slashed;
+            }
+        }
+    } else {
+        // Report dismissed: bond is burned to the treasury
+        let bond = report.bond;
+        report.bond = 0;
+        transfer_from_pda(&report.to_account_info(), &treasury.to_account_info(), bond)?;
+        treasury.total_collected = treasury.total_collected.saturating_add(bond);
+    }
+    treasury.total_collected = treasury.total_collected.saturating_add(slashed_lamports);
+
+    emit!(ReportResolved {
+        report: report.key(),
+        agent: agent.key(),
+        resolver,
+        upheld,
+        penalty,
+        slashed,
+        reputation_score: agent.reputation_score,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Report against agent {} {}. Penalty -{}, slashed {} stake, reputation: {}",
+        agent.agent_id,
+        if upheld { "UPHELD" } else { "DISMISSED" },
+        penalty,
+        slashed,
+        agent.reputation_score
+    );
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+use solana_sha256_hasher::hash;
+use crate::state::{
+    AgentAccount, Challenge, ChallengeStatus, ChallengerStats, RegistryConfig, VerifierProgramRegistry,
+    VerifyResponseArgs,
+};
+use crate::errors::RegistryError;
+use crate::utils::{
+    emit_challenge_resolved, enforce_probation, record_probation_result, release_challenge_escrow,
+};
+
+/// Resolve a responded challenge by CPI into its external verifier program
+///
+/// Permissionless: the caller supplies the full response (`payload`), which must
+/// hash to the agent's committed response_hash. The verifier program chosen at
+/// creation must still be allowlisted in the VerifierProgramRegistry; it scores
+/// the response (0-100) via return data. Extra accounts the verifier needs are
+/// passed as remaining_accounts. As an allowlisted grader, the verdict counts
+/// towards the agent leaving probation like a designated verifier's.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ResolveWithVerifierProgram<'info> {
+    /// Anyone can crank a verifier program resolution
+    pub caller: Signer<'info>,
+
+    /// The agent that responded
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner wallet (receives the reward on pass)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// CHECK: Original challenger (receives the bond back, and the reward on fail)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// Allowlist of verifier programs
+    #[account(
+        seeds = [VerifierProgramRegistry::SEED_PREFIX],
+        bump = verifier_programs.bump,
+        constraint = verifier_programs.is_allowed(&challenge.verifier_program)
+            @ RegistryError::VerifierProgramNotAllowed
+    )]
+    pub verifier_programs: Account<'info, VerifierProgramRegistry>,
+
+    /// CHECK: The verifier program chosen at creation (allowlisted above)
+    #[account(
+        executable,
+        address = challenge.verifier_program @ RegistryError::VerifierProgramNotAllowed
+    )]
+    pub verifier_program: UncheckedAccount<'info>,
+
+    /// The challenge being resolved
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.has_verifier_program() @ RegistryError::VerifierProgramNotAllowed,
+        constraint = challenge.status == ChallengeStatus::Responded @ RegistryError::ChallengeNotResponded
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+
+    /// Registry config (probation floor)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveWithVerifierProgram<'info>>,
+    _nonce: u64,
+    payload: Vec<u8>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let max_change = ctx.accounts.config.max_reputation_change_per_day;
+
+    require!(
+        !ctx.accounts.challenge.is_reveal_expired(clock.unix_timestamp),
+        RegistryError::RevealWindowClosed
+    );
+
+    // The payload must be the response the agent committed to
+    let payload_hash: String = hash(&payload)
+        .to_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    require!(
+        payload_hash.eq_ignore_ascii_case(&ctx.accounts.challenge.response_hash),
+        RegistryError::PayloadMismatch
+    );
+
+    // CPI into the verifier: challenge (read-only) + caller-supplied accounts
+    let challenge_info = ctx.accounts.challenge.to_account_info();
+    let program_id = ctx.accounts.verifier_program.key();
+    let args = VerifyResponseArgs {
+        challenge: challenge_info.key(),
+        question: ctx.accounts.challenge.question.clone(),
+        response_hash: ctx.accounts.challenge.response_hash.clone(),
+        payload,
+    };
+    let mut data = VerifierProgramRegistry::VERIFY_DISCRIMINATOR.to_vec();
+    args.serialize(&mut data)?;
+
+    let mut metas = vec![AccountMeta::new_readonly(challenge_info.key(), false)];
+    let mut infos = vec![challenge_info];
+    for info in ctx.remaining_accounts.iter() {
+        metas.push(if info.is_writable {
+            AccountMeta::new(info.key(), info.is_signer)
+        } else {
+            AccountMeta::new_readonly(info.key(), info.is_signer)
+        });
+        infos.push(info.clone());
+    }
+    invoke(
+        &Instruction {
+            program_id,
+            accounts: metas,
+            data,
+        },
+        &infos,
+    )?;
+
+    let score = match get_return_data() {
+        Some((returned_by, data)) if returned_by == program_id && !data.is_empty() => data[0],
+        _ => return err!(RegistryError::InvalidVerifierProgramResult),
+    };
+    require!(
+        score <= Challenge::MAX_SCORE,
+        RegistryError::InvalidVerifierProgramResult
+    );
+    let passed = score >= Challenge::PASS_SCORE;
+
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+
+    let reward_paid = release_challenge_escrow(
+        challenge,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.agent_owner.to_account_info(),
+        passed,
+    )?;
+
+    let old_reputation = agent.reputation_score;
+    challenge.apply_score(agent, score, clock.unix_timestamp, max_change);
+
+    msg!(
+        "Challenge {} (score {}) by verifier program {}. Agent {} reputation: {}, reward: {}",
+        if passed { "PASSED" } else { "FAILED" },
+        score,
+        program_id,
+        agent.agent_id,
+        agent.reputation_score,
+        reward_paid
+    );
+
+    ctx.accounts.challenger_stats.record_verdict(passed);
+    emit_challenge_resolved(challenge, agent, old_reputation);
+    record_probation_result(agent, challenge, passed, &ctx.accounts.config, clock.unix_timestamp);
+    enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Challenge, ChallengeStatus, ChallengerStats, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::events::{ChallengeRevealRejected, ReputationChangeReason};
+use crate::utils::{
+    emit_challenge_resolved, emit_reputation_changed, enforce_probation, release_challenge_escrow,
+    slash_challenge_bond,
+};
+
+/// Reveal the committed answer and resolve a responded challenge
+///
+/// Commit-reveal flow:
+/// 1. `create_challenge` stores commitment = SHA256(answer_hash || salt)
+/// 2. `submit_response` stores the agent's answer_hash
+/// 3. `reveal_challenge` opens the commitment with the challenger's
+///    (answer_hash, salt); only the committed opening is accepted, then the
+///    revealed answer is compared with the agent's response
+///
+/// An opening that does not match the commitment abandons the challenge: the
+/// bond is slashed to the agent owner and the reward refunded, exactly as when
+/// the reveal window is missed (see `expire_challenge`).
+///
+/// Multi-round challenges repeat steps 2-3 per round (one salt per round); the
+/// escrow is released after the last round.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RevealChallenge<'info> {
+    /// The original challenger (receives the bond back on an honest reveal,
+    /// and the reward on fail)
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// The agent that responded
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent owner wallet (receives the reward on pass, the bond on a bad opening)
+    #[account(mut, address = agent.owner @ RegistryError::Unauthorized)]
+    pub agent_owner: UncheckedAccount<'info>,
+
+    /// The challenge being resolved
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.challenger == challenger.key() @ RegistryError::Unauthorized,
+        constraint = challenge.status == ChallengeStatus::Responded @ RegistryError::ChallengeNotResponded,
+        constraint = !challenge.has_verifier() @ RegistryError::VerifierResolutionRequired
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+
+    /// Registry config (probation floor)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(
+    ctx: Context<RevealChallenge>,
+    _nonce: u64,
+    answer_hash: String,
+    salt: [u8; 32],
+) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+    let clock = Clock::get()?;
+    let max_change = ctx.accounts.config.max_reputation_change_per_day;
+
+    require!(
+        !challenge.is_reveal_expired(clock.unix_timestamp),
+        RegistryError::RevealWindowClosed
+    );
+
+    // The opening must match the commitment, so a wrong salt cannot force a fail;
+    // a dishonest opening abandons the challenge and slashes the bond
+    let commitment = Challenge::compute_commitment(&answer_hash, &salt);
+    if !commitment.eq_ignore_ascii_case(&challenge.expected_hash) {
+        challenge.status = ChallengeStatus::Abandoned;
+        ctx.accounts.challenger_stats.record_abandoned();
+
+        let (slashed, refunded) = slash_challenge_bond(
+            challenge,
+            &ctx.accounts.challenger.to_account_info(),
+            &ctx.accounts.agent_owner.to_account_info(),
+        )?;
+
+        msg!(
+            "Reveal does not match the commitment. Bond {} slashed to agent {}, reward {} refunded",
+            slashed,
+            agent.agent_id,
+            refunded
+        );
+
+        emit!(ChallengeRevealRejected {
+            challenge: challenge.key(),
+            agent: challenge.agent,
+            challenger: challenge.challenger,
+            nonce: challenge.nonce,
+            slashed,
+            refunded,
+            timestamp: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+    let passed = answer_hash.eq_ignore_ascii_case(&challenge.response_hash);
+    let old_reputation = agent.reputation_score;
+
+    // Multi-round: score this round, then stop unless it was the last one
+    let passed = if challenge.is_multi_round() {
+        let round = challenge.current_round;
+        if !challenge.record_round(agent, passed, clock.unix_timestamp, max_change) {
+            emit_reputation_changed(
+                challenge.agent,
+                old_reputation,
+                agent.reputation_score,
+                ReputationChangeReason::ChallengeRound,
+                challenge.key(),
+                clock.unix_timestamp,
+            );
+            enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+            msg!(
+                "Round {} {}. Agent {} reputation: {}. Next round due by {}",
+                round + 1,
+                if passed { "PASSED" } else { "FAILED" },
+                agent.agent_id,
+                agent.reputation_score,
+                challenge.expires_at
+            );
+            return Ok(());
+        }
+        challenge.finish_rounds(agent, clock.unix_timestamp)
+    } else {
+        passed
+    };
+
+    // Honest resolution: refund the bond, pay or refund the reward
+    let reward_paid = release_challenge_escrow(
+        challenge,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.agent_owner.to_account_info(),
+        passed,
+    )?;
+
+    if !challenge.is_multi_round() {
+        challenge.apply_verdict(agent, passed, clock.unix_timestamp, max_change);
+    }
+
+    msg!(
+        "Challenge {} ({:?}). Agent {} reputation: {}, reward: {}",
+        if passed { "PASSED" } else { "FAILED" },
+        challenge.kind.audit_action(passed),
+        agent.agent_id,
+        agent.reputation_score,
+        reward_paid
+    );
+
+    ctx.accounts.challenger_stats.record_verdict(passed);
+    emit_challenge_resolved(challenge, agent, old_reputation);
+    enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::{Challenge, OpenChallenge, OpenChallengeStatus};
+use crate::errors::RegistryError;
+use crate::utils::transfer_from_pda;
+
+/// Reveal the answer hash and salt of an open challenge once responses have closed
+///
+/// An opening that matches the commitment refunds the challenger bond and opens
+/// the claim window. Any other opening abandons the challenge: the bond is
+/// slashed to the first responder's owner and no claims are possible.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RevealOpenChallenge<'info> {
+    /// The original challenger (receives the bond back on an honest reveal)
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            OpenChallenge::SEED_PREFIX,
+            challenger.key().as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = open_challenge.bump,
+        constraint = open_challenge.challenger == challenger.key() @ RegistryError::Unauthorized,
+        constraint = open_challenge.status == OpenChallengeStatus::Open @ RegistryError::OpenChallengeWrongStatus
+    )]
+    pub open_challenge: Account<'info, OpenChallenge>,
+
+    /// CHECK: First responder's owner (receives the bond on a bad opening),
+    /// validated in the handler
+    #[account(mut)]
+    pub first_responder: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(
+    ctx: Context<RevealOpenChallenge>,
+    _nonce: u64,
+    answer_hash: String,
+    salt: [u8; 32],
+) -> Result<()> {
+    let open_challenge = &mut ctx.accounts.open_challenge;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp > open_challenge.expires_at,
+        RegistryError::ResponsePeriodActive
+    );
+    require!(
+        !open_challenge.is_reveal_expired(clock.unix_timestamp),
+        RegistryError::RevealWindowClosed
+    );
+
+    // Only the committed opening is accepted; anything else slashes the bond
+    let commitment = Challenge::compute_commitment(&answer_hash, &salt);
+    if !commitment.eq_ignore_ascii_case(&open_challenge.expected_hash) {
+        open_challenge.status = OpenChallengeStatus::Abandoned;
+
+        // Without responses nobody was harmed: the bond returns on finalize
+        let mut slashed = 0;
+        if open_challenge.response_count > 0 {
+            let first_responder = ctx
+                .accounts
+                .first_responder
+                .as_ref()
+                .ok_or(RegistryError::InvalidPayoutAccount)?;
+            require_keys_eq!(
+                first_responder.key(),
+                open_challenge.first_responder,
+                RegistryError::InvalidPayoutAccount
+            );
+
+            slashed = open_challenge.bond;
+            open_challenge.bond = 0;
+            transfer_from_pda(
+                &open_challenge.to_account_info(),
+                &first_responder.to_account_info(),
+                slashed,
+            )?;
+        }
+
+        msg!(
+            "Open challenge {} reveal does not match the commitment. Bond {} slashed",
+            open_challenge.key(),
+            slashed
+        );
+        return Ok(());
+    }
+
+    open_challenge.status = OpenChallengeStatus::Revealed;
+    open_challenge.revealed_at = clock.unix_timestamp;
+    open_challenge.revealed_salt = salt;
+    open_challenge.revealed_answer_hash = answer_hash;
+
+    // Honest reveal: refund the bond
+    let bond = open_challenge.bond;
+    open_challenge.bond = 0;
+    transfer_from_pda(
+        &open_challenge.to_account_info(),
+        &ctx.accounts.challenger.to_account_info(),
+        bond,
+    )?;
+
+    msg!(
+        "Open challenge {} revealed with {} responses. Claims open for {}s",
+        open_challenge.key(),
+        open_challenge.response_count,
+        OpenChallenge::CLAIM_WINDOW
+    );
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentAuditSummary, Endorsement, RegistryConfig};
+use crate::errors::RegistryError;
+use crate::events::ReputationChangeReason;
+use crate::utils::{emit_reputation_changed, enforce_probation};
+
+/// Withdraw an endorsement after its lock period (original endorser wallet only)
+///
+/// Removes the bonus from the endorsed agent and returns the stake and rent.
+/// Not possible once the endorsed agent logged a critical-risk audit entry;
+/// such endorsements can only be clawed back.
+#[derive(Accounts)]
+pub struct RevokeEndorsement<'info> {
+    #[account(mut)]
+    pub endorser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            endorsed_agent.pda_owner().as_ref(),
+            endorsed_agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = endorsed_agent.bump
+    )]
+    pub endorsed_agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Endorsed agent's audit summary (may not exist = no alerts)
+    #[account(
+        seeds = [AgentAuditSummary::SEED_PREFIX, endorsed_agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = endorser,
+        seeds = [
+            Endorsement::SEED_PREFIX,
+            endorsed_agent.key().as_ref(),
+            endorsement.endorser_agent.as_ref(),
+        ],
+        bump = endorsement.bump,
+        constraint = endorsement.endorser == endorser.key() @ RegistryError::Unauthorized
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+    /// Registry config (probation floor)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<RevokeEndorsement>) -> Result<()> {
+    let endorsement = &ctx.accounts.endorsement;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= endorsement.created_at + Endorsement::LOCK_PERIOD,
+        RegistryError::EndorsementLocked
+    );
+    require!(
+        !endorsement.has_new_critical_alerts(AgentAuditSummary::critical_alerts_of(
+            &ctx.accounts.audit_summary
+        )?),
+        RegistryError::EndorsementClawbackPending
+    );
+
+    let endorsed_agent = &mut ctx.accounts.endorsed_agent;
+    let old_reputation = endorsed_agent.reputation_score;
+    endorsed_agent.debit_reputation(endorsement.bonus, now);
+    endorsed_agent.endorsement_bonus = endorsed_agent.endorsement_bonus.saturating_sub(endorsement.bonus);
+
+    emit_reputation_changed(
+        endorsed_agent.key(),
+        old_reputation,
+        endorsed_agent.reputation_score,
+        ReputationChangeReason::EndorsementRevoked,
+        endorsement.endorser_agent,
+        now,
+    );
+    enforce_probation(endorsed_agent, old_reputation, &ctx.accounts.config, now);
+
+    msg!(
+        "Endorsement of agent {} revoked. Bonus -{}, {} lamports returned to {}",
+        endorsed_agent.agent_id,
+        endorsement.bonus,
+        endorsement.stake,
+        endorsement.endorser
+    );
+
+    Ok(())
+}
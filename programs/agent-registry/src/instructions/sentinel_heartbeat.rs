@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditPolicy, HeartbeatMonitor};
+use crate::errors::RegistryError;
+
+/// Check in as the agent's monitoring sentinel
+#[derive(Accounts)]
+pub struct SentinelHeartbeat<'info> {
+    pub sentinel: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump,
+        constraint = AuditPolicy::is_sentinel(&audit_policy, &agent, &sentinel.key())
+            @ RegistryError::ActorNotAllowed
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [HeartbeatMonitor::SEED_PREFIX, agent.key().as_ref()],
+        bump = heartbeat.bump
+    )]
+    pub heartbeat: Account<'info, HeartbeatMonitor>,
+}
+
+pub fn handler(ctx: Context<SentinelHeartbeat>) -> Result<()> {
+    let heartbeat = &mut ctx.accounts.heartbeat;
+    heartbeat.last_sentinel = ctx.accounts.sentinel.key();
+    heartbeat.last_beat_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Sentinel heartbeat: agent={}, sentinel={}",
+        heartbeat.agent,
+        heartbeat.last_sentinel
+    );
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{ActionCode, ActionCodeRegistry, RegistryState};
+use crate::errors::RegistryError;
+
+/// Register or update a custom audit action code (admin only)
+#[derive(Accounts)]
+pub struct SetActionCode<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ActionCodeRegistry::INIT_SPACE,
+        seeds = [ActionCodeRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub action_codes: Account<'info, ActionCodeRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetActionCode>, code: u16, name: String, base_risk: u8) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= ActionCodeRegistry::MAX_NAME_LEN && base_risk <= 100,
+        RegistryError::InvalidActionCode
+    );
+
+    let registry = &mut ctx.accounts.action_codes;
+    registry.bump = ctx.bumps.action_codes;
+
+    if let Some(entry) = registry.codes.iter_mut().find(|entry| entry.code == code) {
+        entry.name = name.clone();
+        entry.base_risk = base_risk;
+    } else {
+        require!(
+            registry.codes.len() < ActionCodeRegistry::MAX_CODES,
+            RegistryError::ActionCodeRegistryFull
+        );
+        registry.codes.push(ActionCode {
+            code,
+            name: name.clone(),
+            base_risk,
+        });
+    }
+
+    msg!("Action code {} set: name={}, base_risk={}", code, name, base_risk);
+
+    Ok(())
+}
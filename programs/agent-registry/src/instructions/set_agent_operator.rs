@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::AgentAccount;
+use crate::errors::RegistryError;
+
+/// Set or clear the agent's delegated operator key (owner only)
+///
+/// The operator can submit challenge responses on the agent's behalf so the
+/// owner's cold key does not need to be online. Pass the default pubkey to
+/// revoke. Cleared automatically when ownership is recovered.
+#[derive(Accounts)]
+pub struct SetAgentOperator<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+pub fn handler(ctx: Context<SetAgentOperator>, operator: Pubkey) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+
+    agent.operator = operator;
+    agent.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Agent operator set: id={}, operator={}", agent.agent_id, operator);
+
+    Ok(())
+}
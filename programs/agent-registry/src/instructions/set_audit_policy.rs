@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{ActionType, AgentAccount, AuditPolicy};
+use crate::errors::RegistryError;
+
+/// New audit policy for an agent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditPolicyParams {
+    pub alert_threshold: u8,
+    pub sentinels: Vec<Pubkey>,
+    pub max_risk: [u8; ActionType::COUNT],
+    /// New compliance officer (None = keep, default pubkey = remove; owner only)
+    pub compliance_officer: Option<Pubkey>,
+}
+
+/// Create or replace an agent's audit policy (owner or compliance officer)
+#[derive(Accounts)]
+pub struct SetAuditPolicy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AuditPolicy::INIT_SPACE,
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_policy: Account<'info, AuditPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetAuditPolicy>, params: AuditPolicyParams) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let authority = ctx.accounts.authority.key();
+    let policy = &mut ctx.accounts.audit_policy;
+
+    require!(policy.is_manager(agent, &authority), RegistryError::Unauthorized);
+    require!(
+        (1..=100).contains(&params.alert_threshold)
+            && params.max_risk.iter().all(|max| *max <= 100)
+            && params.sentinels.len() <= AuditPolicy::MAX_SENTINELS,
+        RegistryError::InvalidAuditPolicy
+    );
+
+    if let Some(compliance_officer) = params.compliance_officer {
+        require_keys_eq!(authority, agent.owner, RegistryError::Unauthorized);
+        policy.compliance_officer = compliance_officer;
+    }
+
+    policy.agent = agent.key();
+    policy.alert_threshold = params.alert_threshold;
+    policy.sentinels = params.sentinels;
+    policy.max_risk = params.max_risk;
+    policy.updated_at = Clock::get()?.unix_timestamp;
+    policy.bump = ctx.bumps.audit_policy;
+
+    msg!(
+        "Audit policy set for agent {} by {}: alert_threshold={}, sentinels={}, max_risk={:?}, compliance_officer={}",
+        agent.agent_id,
+        authority,
+        policy.alert_threshold,
+        policy.sentinels.len(),
+        policy.max_risk,
+        policy.compliance_officer
+    );
+
+    Ok(())
+}
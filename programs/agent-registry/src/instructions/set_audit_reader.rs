@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditPolicy, AuditReader, AuditReaders};
+use crate::errors::RegistryError;
+
+/// Add, re-key or remove a reader of the agent's encrypted audit details
+/// (owner or compliance officer)
+#[derive(Accounts)]
+pub struct SetAuditReader<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AuditReaders::INIT_SPACE,
+        seeds = [AuditReaders::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_readers: Account<'info, AuditReaders>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetAuditReader>,
+    reader: Pubkey,
+    encryption_key: [u8; 32],
+    registered: bool,
+) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let authority = ctx.accounts.authority.key();
+    let policy = AuditPolicy::load_or_default(&ctx.accounts.audit_policy, agent.key())?;
+    require!(policy.is_manager(agent, &authority), RegistryError::Unauthorized);
+
+    let clock = Clock::get()?;
+    let readers = &mut ctx.accounts.audit_readers;
+    readers.agent = agent.key();
+    readers.bump = ctx.bumps.audit_readers;
+    readers.readers.retain(|r| r.reader != reader);
+
+    if registered {
+        require!(encryption_key != [0u8; 32], RegistryError::InvalidAuditReader);
+        require!(
+            readers.readers.len() < AuditReaders::MAX_READERS,
+            RegistryError::TooManyAuditReaders
+        );
+        readers.readers.push(AuditReader {
+            reader,
+            encryption_key,
+            added_at: clock.unix_timestamp,
+        });
+    }
+    readers.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Audit reader {} for agent {} registered: {} (by {})",
+        reader,
+        agent.agent_id,
+        registered,
+        authority
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{AuditorRegistry, RegistryState};
+use crate::errors::RegistryError;
+
+/// Register or remove an external auditor key (admin only)
+#[derive(Accounts)]
+pub struct SetAuditor<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + AuditorRegistry::INIT_SPACE,
+        seeds = [AuditorRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub auditors: Account<'info, AuditorRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetAuditor>, auditor: Pubkey, registered: bool) -> Result<()> {
+    let auditors = &mut ctx.accounts.auditors;
+    auditors.bump = ctx.bumps.auditors;
+
+    if registered {
+        if !auditors.is_registered(&auditor) {
+            require!(
+                auditors.auditors.len() < AuditorRegistry::MAX_AUDITORS,
+                RegistryError::TooManyAuditors
+            );
+            auditors.auditors.push(auditor);
+        }
+    } else {
+        auditors.auditors.retain(|a| *a != auditor);
+    }
+
+    msg!("Auditor {} registered: {}", auditor, registered);
+
+    Ok(())
+}
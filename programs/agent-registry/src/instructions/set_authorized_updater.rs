@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{AuthorizedUpdaters, RegistryState};
+use crate::errors::RegistryError;
+
+/// Add or remove a reputation updater (admin only)
+#[derive(Accounts)]
+pub struct SetAuthorizedUpdater<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + AuthorizedUpdaters::INIT_SPACE,
+        seeds = [AuthorizedUpdaters::SEED_PREFIX],
+        bump
+    )]
+    pub authorized_updaters: Account<'info, AuthorizedUpdaters>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetAuthorizedUpdater>, updater: Pubkey, allowed: bool) -> Result<()> {
+    let authorized_updaters = &mut ctx.accounts.authorized_updaters;
+    authorized_updaters.bump = ctx.bumps.authorized_updaters;
+
+    if allowed {
+        if !authorized_updaters.is_authorized(&updater) {
+            require!(
+                authorized_updaters.updaters.len() < AuthorizedUpdaters::MAX_UPDATERS,
+                RegistryError::TooManyUpdaters
+            );
+            authorized_updaters.updaters.push(updater);
+        }
+    } else {
+        authorized_updaters.updaters.retain(|u| *u != updater);
+    }
+
+    msg!("Reputation updater {} allowed: {}", updater, allowed);
+
+    Ok(())
+}
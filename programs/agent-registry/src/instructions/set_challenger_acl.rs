@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{AclMode, AgentAccount, ChallengerAcl};
+use crate::errors::RegistryError;
+
+/// Set the challenger allowlist/blocklist for an agent (owner only)
+/// The ACL PDA is created on first use (owner pays rent)
+#[derive(Accounts)]
+pub struct SetChallengerAcl<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ChallengerAcl::INIT_SPACE,
+        seeds = [ChallengerAcl::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub acl: Account<'info, ChallengerAcl>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetChallengerAcl>, mode: AclMode, entries: Vec<Pubkey>) -> Result<()> {
+    require!(
+        entries.len() <= ChallengerAcl::MAX_ENTRIES,
+        RegistryError::TooManyAclEntries
+    );
+
+    let acl = &mut ctx.accounts.acl;
+    acl.agent = ctx.accounts.agent.key();
+    acl.mode = mode;
+    acl.entries = entries;
+    acl.bump = ctx.bumps.acl;
+
+    msg!(
+        "Challenger ACL set for agent {}: mode={:?}, entries={}",
+        ctx.accounts.agent.agent_id,
+        mode,
+        acl.entries.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AuditPolicy, HeartbeatMonitor};
+use crate::errors::RegistryError;
+
+/// Set how often the agent's sentinel must check in (owner or compliance officer)
+/// Setting it (re)starts the clock; 0 disables missed-heartbeat alerts
+#[derive(Accounts)]
+pub struct SetHeartbeatInterval<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Agent's audit policy (may not exist yet = default policy)
+    #[account(
+        seeds = [AuditPolicy::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_policy: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HeartbeatMonitor::INIT_SPACE,
+        seeds = [HeartbeatMonitor::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub heartbeat: Account<'info, HeartbeatMonitor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetHeartbeatInterval>, interval_secs: u32) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let authority = ctx.accounts.authority.key();
+    let policy = AuditPolicy::load_or_default(&ctx.accounts.audit_policy, agent.key())?;
+    require!(policy.is_manager(agent, &authority), RegistryError::Unauthorized);
+
+    let heartbeat = &mut ctx.accounts.heartbeat;
+    heartbeat.agent = agent.key();
+    heartbeat.interval_secs = interval_secs;
+    heartbeat.last_beat_at = Clock::get()?.unix_timestamp;
+    heartbeat.bump = ctx.bumps.heartbeat;
+
+    msg!(
+        "Heartbeat interval for agent {} set to {}s by {}",
+        agent.agent_id,
+        interval_secs,
+        authority
+    );
+
+    Ok(())
+}
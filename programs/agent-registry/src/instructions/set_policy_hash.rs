@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ActionCodeRegistry, ActionType, AgentAccount, AgentAuditSummary, AuditEntry, RiskLevel,
+    RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::events::AuditLogged;
+use crate::utils::decode_hex32;
+
+/// Declare a new version of the agent's safety/audit policy document (owner only)
+///
+/// Stores the document hash on the agent and writes an AgentUpdated audit entry
+/// whose details are the same hash, so the audit chain shows which policy was in
+/// force at any timestamp.
+#[derive(Accounts)]
+pub struct SetPolicyHash<'info> {
+    /// Agent owner (pays for the audit entry)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// The agent's audit summary (created if first audit)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// Audit entry recording the policy change
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            agent.key().as_ref(),
+            audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub audit_entry: Account<'info, AuditEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetPolicyHash>, policy_hash: String) -> Result<()> {
+    let digest = decode_hex32(&policy_hash).ok_or(RegistryError::InvalidDetailsHash)?;
+    require!(digest != [0u8; 32], RegistryError::InvalidDetailsHash);
+
+    let clock = Clock::get()?;
+    let agent = &mut ctx.accounts.agent;
+    let agent_key = agent.key();
+    agent.policy_hash = digest;
+    agent.policy_version = agent.policy_version.saturating_add(1);
+    agent.policy_updated_at = clock.unix_timestamp;
+    agent.updated_at = clock.unix_timestamp;
+
+    let summary = &mut ctx.accounts.audit_summary;
+    if summary.total_entries == 0 {
+        summary.agent = agent_key;
+        summary.bump = ctx.bumps.audit_summary;
+    }
+
+    let weights = RiskWeights::load_or_default(&ctx.accounts.risk_weights)?;
+    let risk_score =
+        weights.risk_score(&ActionType::AgentUpdated, 0, &ActionCodeRegistry::default())?;
+    let entry = &mut ctx.accounts.audit_entry;
+    entry.agent = agent_key;
+    entry.actor = ctx.accounts.owner.key();
+    entry.action_type = ActionType::AgentUpdated;
+    entry.risk_score = risk_score;
+    entry.risk_level = RiskLevel::from_score(risk_score);
+    entry.timestamp = clock.unix_timestamp;
+    entry.details_hash = policy_hash;
+    entry.audit_index = summary.total_entries;
+    entry.bump = ctx.bumps.audit_entry;
+    entry.risk_weights_version = weights.version;
+    entry.auditor = Pubkey::default();
+    entry.encrypted_details_uri = None;
+    entry.is_alert = false;
+    entry.prev_entry_hash = summary.advance_chain(
+        entry.audit_index,
+        ActionType::AgentUpdated,
+        risk_score,
+        clock.unix_timestamp,
+        &digest,
+    );
+    summary.record_entry(ActionType::AgentUpdated, risk_score, false, clock.unix_timestamp);
+
+    emit!(AuditLogged {
+        agent: agent_key,
+        actor: entry.actor,
+        action_type: ActionType::AgentUpdated,
+        risk_score,
+        index: entry.audit_index,
+        is_alert: false,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Policy declared: agent={}, version={}, audit_index={}",
+        agent.agent_id,
+        agent.policy_version,
+        entry.audit_index
+    );
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, ReputationSchema};
+use crate::errors::RegistryError;
+
+/// Publish a new reputation recalibration curve (admin only)
+/// Bumps the schema version; agents are migrated lazily by `recalibrate_reputation`
+#[derive(Accounts)]
+pub struct SetReputationSchema<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ReputationSchema::INIT_SPACE,
+        seeds = [ReputationSchema::SEED_PREFIX],
+        bump
+    )]
+    pub reputation_schema: Account<'info, ReputationSchema>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetReputationSchema>, curve: [u16; 11]) -> Result<()> {
+    require!(
+        ReputationSchema::is_valid_curve(&curve),
+        RegistryError::InvalidReputationCurve
+    );
+
+    let schema = &mut ctx.accounts.reputation_schema;
+    require!(
+        schema.curves.len() < ReputationSchema::MAX_CURVES,
+        RegistryError::ReputationSchemaFull
+    );
+    schema.curves.push(curve);
+    schema.updated_at = Clock::get()?.unix_timestamp;
+    schema.bump = ctx.bumps.reputation_schema;
+
+    msg!("Reputation schema v{} published: {:?}", schema.version(), curve);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{ActionType, RegistryState, RiskWeights};
+use crate::errors::RegistryError;
+
+/// Publish a new base-risk table for audit scoring (admin only)
+/// Bumps the weights version recorded on subsequent audit entries
+#[derive(Accounts)]
+pub struct SetRiskWeights<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + RiskWeights::INIT_SPACE,
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: Account<'info, RiskWeights>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetRiskWeights>, base_risk: [u8; ActionType::COUNT]) -> Result<()> {
+    require!(
+        base_risk.iter().all(|risk| *risk <= 100),
+        RegistryError::InvalidRiskWeights
+    );
+
+    let weights = &mut ctx.accounts.risk_weights;
+    weights.version = weights.version.saturating_add(1);
+    weights.base_risk = base_risk;
+    weights.updated_at = Clock::get()?.unix_timestamp;
+    weights.bump = ctx.bumps.risk_weights;
+
+    msg!("Risk weights v{} published: {:?}", weights.version, base_risk);
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, RegistryState};
+use crate::errors::RegistryError;
+
+/// Assign an agent's sentinel guardian key (admin only)
+/// Pass the default pubkey to remove it
+#[derive(Accounts)]
+pub struct SetSentinelAuthority<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+pub fn handler(ctx: Context<SetSentinelAuthority>, sentinel_authority: Pubkey) -> Result<()> {
+    let agent = &mut ctx.accounts.agent;
+    require!(
+        sentinel_authority == Pubkey::default()
+            || (sentinel_authority != agent.owner && sentinel_authority != agent.operator),
+        RegistryError::InvalidSentinelAuthority
+    );
+
+    agent.sentinel_authority = sentinel_authority;
+    agent.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!(
+        "Sentinel authority set: agent={}, sentinel={}",
+        agent.agent_id,
+        sentinel_authority
+    );
+
+    Ok(())
+}
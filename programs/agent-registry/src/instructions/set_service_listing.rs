@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ChallengeKind, PriceUnit, ServiceListing};
+use crate::errors::RegistryError;
+use crate::events::ServiceListingUpdated;
+
+/// Publish or update a priced service listing for an agent (owner only)
+/// The listing PDA is created on first use (owner pays rent)
+#[derive(Accounts)]
+#[instruction(listing_id: u16)]
+pub struct SetServiceListing<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + ServiceListing::INIT_SPACE,
+        seeds = [
+            ServiceListing::SEED_PREFIX,
+            agent.key().as_ref(),
+            listing_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub listing: Account<'info, ServiceListing>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetServiceListing>,
+    listing_id: u16,
+    capability: ChallengeKind,
+    price_lamports: u64,
+    unit: PriceUnit,
+    sla_hash: [u8; 32],
+    active: bool,
+) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let clock = Clock::get()?;
+    let listing = &mut ctx.accounts.listing;
+
+    if listing.agent == Pubkey::default() {
+        listing.agent = agent.key();
+        listing.listing_id = listing_id;
+        listing.created_at = clock.unix_timestamp;
+        listing.bump = ctx.bumps.listing;
+    }
+    listing.capability = capability;
+    listing.active = active && !agent.is_retired();
+    listing.price_lamports = price_lamports;
+    listing.unit = unit;
+    listing.sla_hash = sla_hash;
+    listing.sync_agent(agent.reputation_score, agent.verified, clock.unix_timestamp);
+
+    emit!(ServiceListingUpdated {
+        listing: listing.key(),
+        agent: listing.agent,
+        listing_id,
+        capability,
+        price_lamports,
+        unit,
+        active: listing.active,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Service listing {} for agent id={}: {:?} at {} lamports {:?}, active={}",
+        listing_id,
+        agent.agent_id,
+        capability,
+        price_lamports,
+        unit,
+        listing.active
+    );
+
+    Ok(())
+}
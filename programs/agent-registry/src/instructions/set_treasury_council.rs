@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, TreasuryCouncil};
+use crate::errors::RegistryError;
+use crate::events::TreasuryCouncilSet;
+
+/// Set or replace the multisig council governing treasury spends (admin only)
+///
+/// Replacing an existing council also needs `threshold` of its current members
+/// to co-sign, passed as signer `remaining_accounts`, so the admin alone cannot
+/// take over the treasury.
+#[derive(Accounts)]
+pub struct SetTreasuryCouncil<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + TreasuryCouncil::INIT_SPACE,
+        seeds = [TreasuryCouncil::SEED_PREFIX],
+        bump
+    )]
+    pub council: Account<'info, TreasuryCouncil>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetTreasuryCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+    timelock_secs: u32,
+) -> Result<()> {
+    let distinct = members
+        .iter()
+        .enumerate()
+        .all(|(i, member)| !members[..i].contains(member));
+    require!(
+        !members.is_empty()
+            && members.len() <= TreasuryCouncil::MAX_MEMBERS
+            && distinct
+            && threshold > 0
+            && threshold as usize <= members.len()
+            && timelock_secs >= TreasuryCouncil::MIN_TIMELOCK_SECS,
+        RegistryError::InvalidTreasuryCouncil
+    );
+
+    let council = &mut ctx.accounts.council;
+    if !council.members.is_empty() {
+        let mut cosigners: Vec<Pubkey> = Vec::new();
+        for info in ctx.remaining_accounts.iter() {
+            if info.is_signer && council.is_member(info.key) && !cosigners.contains(info.key) {
+                cosigners.push(*info.key);
+            }
+        }
+        require!(
+            cosigners.len() >= council.threshold as usize,
+            RegistryError::CouncilApprovalRequired
+        );
+    }
+
+    council.members = members;
+    council.threshold = threshold;
+    council.timelock_secs = timelock_secs;
+    council.bump = ctx.bumps.council;
+
+    emit!(TreasuryCouncilSet {
+        members: council.members.clone(),
+        threshold,
+        timelock_secs,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Treasury council set: {} members, threshold={}, timelock={}s",
+        council.members.len(),
+        threshold,
+        timelock_secs
+    );
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, VerifiedVerifier};
+use crate::errors::RegistryError;
+
+/// Mark a verifier as verified or revoke it (admin only)
+/// Verified verifiers create challenges without the protocol fee
+#[derive(Accounts)]
+#[instruction(verifier: Pubkey)]
+pub struct SetVerifiedVerifier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + VerifiedVerifier::INIT_SPACE,
+        seeds = [VerifiedVerifier::SEED_PREFIX, verifier.as_ref()],
+        bump
+    )]
+    pub verifier_record: Account<'info, VerifiedVerifier>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetVerifiedVerifier>, verifier: Pubkey, active: bool) -> Result<()> {
+    let record = &mut ctx.accounts.verifier_record;
+    record.verifier = verifier;
+    record.active = active;
+    record.updated_at = Clock::get()?.unix_timestamp;
+    record.bump = ctx.bumps.verifier_record;
+
+    msg!("Verifier {} verified: {}", verifier, active);
+
+    Ok(())
+}
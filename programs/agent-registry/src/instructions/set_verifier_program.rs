@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{RegistryState, VerifierProgramRegistry};
+use crate::errors::RegistryError;
+
+/// Add or remove an external verifier program from the allowlist (admin only)
+#[derive(Accounts)]
+pub struct SetVerifierProgram<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + VerifierProgramRegistry::INIT_SPACE,
+        seeds = [VerifierProgramRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub verifier_programs: Account<'info, VerifierProgramRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetVerifierProgram>, program_id: Pubkey, allowed: bool) -> Result<()> {
+    let verifier_programs = &mut ctx.accounts.verifier_programs;
+    verifier_programs.bump = ctx.bumps.verifier_programs;
+
+    if allowed {
+        if !verifier_programs.is_allowed(&program_id) {
+            require!(
+                verifier_programs.programs.len() < VerifierProgramRegistry::MAX_PROGRAMS,
+                RegistryError::TooManyVerifierPrograms
+            );
+            verifier_programs.programs.push(program_id);
+        }
+    } else {
+        verifier_programs.programs.retain(|p| *p != program_id);
+    }
+
+    msg!("Verifier program {} allowed: {}", program_id, allowed);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AgentAccount, Challenge, ChallengeStatus};
+use crate::errors::RegistryError;
+use crate::utils::TokenMint;
+
+/// Release the token reward of a resolved challenge
+///
+/// Permissionless: paid to the agent owner if the challenge passed, refunded
+/// to the challenger on fail/expire/abandon, once the dispute window has passed. The vault is closed afterwards
+/// and its rent returned to the challenger. For Token-2022 transfer-fee mints
+/// the fees withheld in the vault are harvested to the mint first so it can close.
+#[derive(Accounts)]
+pub struct SettleTokenReward<'info> {
+    /// Anyone can settle a resolved challenge
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            challenge.nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.is_resolved() @ RegistryError::ChallengeStillPending,
+        constraint = challenge.token_reward > 0 @ RegistryError::NoTokenReward
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Reward mint (writable: transfer fees withheld in the vault are harvested to it)
+    #[account(mut, address = challenge.reward_mint @ RegistryError::RewardMintMismatch)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+
+    /// Reward vault owned by the challenge PDA
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = challenge,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account receiving the reward (agent owner on pass, challenger otherwise)
+    #[account(mut, token::mint = reward_mint)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Original challenger receiving the vault rent (validated against challenge.challenger)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, SettleTokenReward<'info>>) -> Result<()> {
+    let challenge = &ctx.accounts.challenge;
+    let clock = Clock::get()?;
+    require!(
+        !challenge.is_dispute_window_open(clock.unix_timestamp),
+        RegistryError::DisputeWindowOpen
+    );
+
+    let passed = challenge.status == ChallengeStatus::Passed;
+
+    let expected_recipient = if passed {
+        ctx.accounts.agent.owner
+    } else {
+        challenge.challenger
+    };
+    require_keys_eq!(
+        ctx.accounts.recipient_token_account.owner,
+        expected_recipient,
+        RegistryError::Unauthorized
+    );
+
+    let nonce_bytes = challenge.nonce.to_le_bytes();
+    let bump = [challenge.bump];
+    let seeds = challenge.signer_seeds(&nonce_bytes, &bump);
+    let signer_seeds: &[&[&[u8]]] = &[&seeds];
+
+    let amount = ctx.accounts.vault.amount;
+    let token = TokenMint {
+        mint: &ctx.accounts.reward_mint,
+        token_program: &ctx.accounts.token_program,
+        hook_accounts: ctx.remaining_accounts,
+    };
+    token.transfer(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.recipient_token_account.to_account_info(),
+        &ctx.accounts.challenge.to_account_info(),
+        signer_seeds,
+        amount,
+    )?;
+
+    token.close(
+        &ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.challenge.to_account_info(),
+        signer_seeds,
+    )?;
+
+    ctx.accounts.challenge.token_reward = 0;
+
+    msg!(
+        "Token reward settled: challenge={}, amount={}, recipient={}",
+        ctx.accounts.challenge.key(),
+        amount,
+        expected_recipient
+    );
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{
+    AgentAccount, AuditEntry, Challenge, ChallengeStatus, RegistryConfig, RiskLevel, SlashRecord,
+    SlashTrigger, StakeVault, Treasury, VerifiedVerifier,
+};
+use crate::errors::RegistryError;
+use crate::events::AgentSlashed;
+use crate::utils::{pay_out_stake, TokenMint};
+
+/// Slash an agent's stake for a verifier-confirmed failure or a Critical audit entry
+///
+/// Permissionless, once per triggering challenge or audit entry (enforced by
+/// the SlashRecord PDA). A failed challenge resolved by a third party slashes
+/// config.stake_slash_bps once its dispute window has closed; it also marks
+/// the challenge slashed so `slash_stake` cannot slash it again. The challenger
+/// receives config.slash_affected_share_bps of the slash only when the verdict
+/// came from a single verified (VerifiedVerifier) verifier that is neither the
+/// challenger nor the agent's owner or operator; otherwise all of it goes to
+/// the treasury, so a challenger cannot confirm their own failure for pay. A
+/// Critical audit entry slashes config.critical_alert_slash_bps, all to the
+/// treasury. SPL vaults need the token accounts.
+#[derive(Accounts)]
+pub struct SlashAgent<'info> {
+    /// Anyone can trigger the slash (pays for the slash record)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The slashed agent (staked_amount is synced)
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    /// CHECK: The failed Challenge or Critical AuditEntry, deserialized and
+    /// validated in the handler according to the trigger
+    #[account(mut, owner = crate::ID)]
+    pub subject: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = caller,
+        space = 8 + SlashRecord::INIT_SPACE,
+        seeds = [SlashRecord::SEED_PREFIX, subject.key().as_ref()],
+        bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+
+    /// Protocol treasury (receives the slash minus the affected party's share)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Challenger receiving the affected-party share (ChallengeFailed only,
+    /// validated against the challenge)
+    #[account(mut)]
+    pub affected_party: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: VerifiedVerifier record PDA of the challenge verifier (required for
+    /// ChallengeFailed with an independent verifier, validated in the handler)
+    pub verifier_record: Option<UncheckedAccount<'info>>,
+
+    /// Token account owned by the vault PDA (SPL vaults only)
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury-owned token account receiving slashed tokens (SPL vaults only)
+    #[account(mut, token::authority = treasury)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Affected party's token account (SPL vaults with an affected-party share only)
+    #[account(mut)]
+    pub affected_party_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake mint (SPL vaults only, needed for transfer_checked)
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SlashAgent<'info>>,
+    trigger: SlashTrigger,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let agent_key = ctx.accounts.agent.key();
+    let config = &ctx.accounts.config;
+    let subject = ctx.accounts.subject.key();
+
+    let (slash_bps, affected_party) = match trigger {
+        SlashTrigger::ChallengeFailed => {
+            let mut data = ctx.accounts.subject.try_borrow_mut_data()?;
+            let mut challenge = Challenge::try_deserialize(&mut &data[..])?;
+            require!(
+                challenge.agent == agent_key
+                    && challenge.status == ChallengeStatus::Failed
+                    && challenge.has_verifier()
+                    && !challenge.stake_slashed,
+                RegistryError::InvalidSlashTrigger
+            );
+            require!(
+                !challenge.is_dispute_window_open(clock.unix_timestamp),
+                RegistryError::DisputeWindowOpen
+            );
+            challenge.stake_slashed = true;
+            challenge.try_serialize(&mut &mut data[..])?;
+
+            // Only a single independent verifier can earn the challenger a share;
+            // its record is then required so a caller cannot withhold the share
+            let independent = challenge.verifier != Pubkey::default()
+                && !challenge.has_verifier_panel()
+                && challenge.has_independent_verifiers(&ctx.accounts.agent);
+            let verified = if independent {
+                let record = ctx
+                    .accounts
+                    .verifier_record
+                    .as_ref()
+                    .ok_or(RegistryError::Unauthorized)?;
+                VerifiedVerifier::is_active_for(record, &challenge.verifier)?
+            } else {
+                false
+            };
+            let affected_party = if verified { challenge.challenger } else { Pubkey::default() };
+            (config.stake_slash_bps, affected_party)
+        }
+        SlashTrigger::CriticalAlert => {
+            let data = ctx.accounts.subject.try_borrow_data()?;
+            let entry = AuditEntry::try_deserialize(&mut &data[..])?;
+            require!(
+                entry.agent == agent_key && RiskLevel::is_critical(entry.risk_score),
+                RegistryError::InvalidSlashTrigger
+            );
+            (config.critical_alert_slash_bps, Pubkey::default())
+        }
+    };
+
+    let vault = &mut ctx.accounts.stake_vault;
+    let slashed = (vault.total_staked as u128 * slash_bps as u128
+        / RegistryConfig::BPS_DENOMINATOR as u128) as u64;
+    require!(slashed > 0, RegistryError::StakeNotSlashable);
+    let affected_amount = if affected_party == Pubkey::default() {
+        0
+    } else {
+        (slashed as u128 * config.slash_affected_share_bps as u128
+            / RegistryConfig::BPS_DENOMINATOR as u128) as u64
+    };
+
+    vault.total_staked -= slashed;
+    vault.total_slashed = vault.total_slashed.saturating_add(slashed);
+    ctx.accounts.agent.staked_amount = vault.total_staked;
+
+    if affected_amount > 0 {
+        let recipient = ctx
+            .accounts
+            .affected_party
+            .as_ref()
+            .ok_or(RegistryError::Unauthorized)?;
+        require_keys_eq!(recipient.key(), affected_party, RegistryError::Unauthorized);
+        if let Some(tokens) = &ctx.accounts.affected_party_token_account {
+            require_keys_eq!(tokens.owner, affected_party, RegistryError::Unauthorized);
+        }
+        pay_out_stake(
+            vault,
+            &recipient.to_account_info(),
+            ctx.accounts.vault_token_account.as_ref(),
+            ctx.accounts.affected_party_token_account.as_ref(),
+            TokenMint::from_accounts(
+                ctx.accounts.stake_mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+                ctx.remaining_accounts,
+            ),
+            affected_amount,
+        )?;
+    }
+
+    let treasury_amount = slashed - affected_amount;
+    pay_out_stake(
+        vault,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.treasury_token_account.as_ref(),
+        TokenMint::from_accounts(
+            ctx.accounts.stake_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.remaining_accounts,
+        ),
+        treasury_amount,
+    )?;
+    if !vault.is_token() {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_collected = treasury.total_collected.saturating_add(treasury_amount);
+    }
+
+    let record = &mut ctx.accounts.slash_record;
+    record.agent = agent_key;
+    record.trigger = trigger;
+    record.subject = subject;
+    record.amount = slashed;
+    record.affected_party = affected_party;
+    record.affected_amount = affected_amount;
+    record.slashed_by = ctx.accounts.caller.key();
+    record.timestamp = clock.unix_timestamp;
+    record.bump = ctx.bumps.slash_record;
+
+    emit!(AgentSlashed {
+        agent: agent_key,
+        trigger,
+        subject,
+        amount: slashed,
+        affected_party,
+        affected_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Agent {} SLASHED {} ({:?} {}): {} to affected party. Total staked: {}",
+        ctx.accounts.agent.agent_id,
+        slashed,
+        trigger,
+        subject,
+        affected_amount,
+        vault.total_staked
+    );
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AgentAccount, Challenge, ChallengeKind, RegistryConfig, StakeVault, Treasury};
+use crate::errors::RegistryError;
+use crate::utils::{pay_out_stake, TokenMint};
+
+/// Slash an agent's stake for a failed or expired boosted challenge
+///
+/// Permissionless, once per challenge, after the verdict is final (dispute
+/// window closed). Slashes config.stake_slash_bps of the vault, doubled for
+/// failed safety probes (security alerts) up to RegistryConfig::MAX_SLASH_BPS,
+/// to the treasury. Only challenges
+/// created while the agent had stake (stake_boost_bps > 0) are slashable, and
+/// those must be slashed before the challenge is closed. Slashed SPL stake goes
+/// to a treasury-owned token account and is not counted in total_collected.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct SlashStake<'info> {
+    /// Anyone can trigger the slash
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The challenged agent (staked_amount is synced)
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    /// The failed or expired challenge
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.is_stake_slash_pending() @ RegistryError::StakeNotSlashable
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Protocol treasury (receives the slashed stake)
+    #[account(
+        mut,
+        seeds = [Treasury::SEED_PREFIX],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Token account owned by the vault PDA (SPL vaults only)
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury-owned token account receiving slashed tokens (SPL vaults only)
+    #[account(mut, token::authority = treasury)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake mint (SPL vaults only, needed for transfer_checked)
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SlashStake<'info>>,
+    _nonce: u64,
+) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let clock = Clock::get()?;
+
+    require!(
+        !challenge.is_dispute_window_open(clock.unix_timestamp),
+        RegistryError::DisputeWindowOpen
+    );
+
+    let mut slash_bps = ctx.accounts.config.stake_slash_bps as u64;
+    if challenge.kind == ChallengeKind::Safety {
+        slash_bps = (slash_bps * 2).min(RegistryConfig::MAX_SLASH_BPS as u64);
+    }
+    let vault = &mut ctx.accounts.stake_vault;
+    let slashed = (vault.total_staked as u128 * slash_bps as u128
+        / RegistryConfig::BPS_DENOMINATOR as u128) as u64;
+
+    vault.total_staked -= slashed;
+    vault.total_slashed = vault.total_slashed.saturating_add(slashed);
+    ctx.accounts.agent.staked_amount = vault.total_staked;
+    pay_out_stake(
+        vault,
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.treasury_token_account.as_ref(),
+        TokenMint::from_accounts(
+            ctx.accounts.stake_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.remaining_accounts,
+        ),
+        slashed,
+    )?;
+    if !vault.is_token() {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_collected = treasury.total_collected.saturating_add(slashed);
+    }
+    challenge.stake_slashed = true;
+
+    msg!(
+        "Stake SLASHED: agent {} lost {} lamports for challenge {}. Total staked: {}",
+        ctx.accounts.agent.agent_id,
+        slashed,
+        challenge.key(),
+        vault.total_staked
+    );
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ReputationSnapshot, ReputationSnapshots};
+use crate::errors::RegistryError;
+
+/// Record the agent's current reputation for this epoch (anyone can call)
+///
+/// The caller pays for the snapshot PDA on first use. At most one snapshot is
+/// taken per agent and epoch.
+#[derive(Accounts)]
+pub struct SnapshotReputation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ReputationSnapshots::INIT_SPACE,
+        seeds = [ReputationSnapshots::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub snapshots: Account<'info, ReputationSnapshots>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SnapshotReputation>) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let snapshots = &mut ctx.accounts.snapshots;
+    let clock = Clock::get()?;
+
+    if let Some(latest) = snapshots.latest() {
+        require!(latest.epoch < clock.epoch, RegistryError::SnapshotAlreadyTaken);
+    }
+
+    snapshots.agent = agent.key();
+    snapshots.bump = ctx.bumps.snapshots;
+    snapshots.push(ReputationSnapshot {
+        epoch: clock.epoch,
+        reputation_score: agent.reputation_score,
+        challenges_passed: agent.challenges_passed,
+        challenges_failed: agent.challenges_failed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Reputation snapshot: agent={}, epoch={}, score={}, passed={}, failed={}",
+        agent.agent_id,
+        clock.epoch,
+        agent.reputation_score,
+        agent.challenges_passed,
+        agent.challenges_failed
+    );
+
+    Ok(())
+}
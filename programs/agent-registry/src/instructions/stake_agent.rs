@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AgentAccount, RegistryConfig, StakePosition, StakeVault};
+use crate::errors::RegistryError;
+use crate::utils::{deposit_lamports, tokens_received, TokenMint};
+
+/// Lock SOL or SPL tokens behind an agent to boost its reputation gains (owner or any backer)
+///
+/// A new vault takes config.stake_mint as its denomination. SPL vaults need the
+/// token accounts and the mint; the vault token account is any token account of
+/// the mint owned by the vault PDA (e.g. its associated token account).
+/// Token-2022 mints are supported: shares are minted for the amount received
+/// after any transfer fee, and transfer-hook accounts go in remaining accounts.
+#[derive(Accounts)]
+pub struct StakeAgent<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + StakeVault::INIT_SPACE,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + StakePosition::INIT_SPACE,
+        seeds = [
+            StakePosition::SEED_PREFIX,
+            stake_vault.key().as_ref(),
+            staker.key().as_ref(),
+        ],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    /// Registry config (boost formula, for logging)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Staker's token account (SPL vaults only)
+    #[account(mut, token::authority = staker)]
+    pub staker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token account owned by the vault PDA (SPL vaults only)
+    #[account(mut, token::authority = stake_vault)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake mint (SPL vaults only, needed for transfer_checked)
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, StakeAgent<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, RegistryError::InvalidStakeAmount);
+    require!(
+        !ctx.accounts.stake_position.is_unbonding(),
+        RegistryError::StakeUnbonding
+    );
+
+    let vault = &mut ctx.accounts.stake_vault;
+    if vault.agent == Pubkey::default() {
+        vault.agent = ctx.accounts.agent.key();
+        vault.mint = ctx.accounts.config.stake_mint;
+        vault.bump = ctx.bumps.stake_vault;
+    }
+
+    let received = if vault.is_token() {
+        let token = TokenMint::from_accounts(
+            ctx.accounts.stake_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.remaining_accounts,
+        );
+        let (Some(from), Some(to), Some(token)) = (
+            &ctx.accounts.staker_token_account,
+            ctx.accounts.vault_token_account.as_mut(),
+            token,
+        ) else {
+            return err!(RegistryError::StakeMintMismatch);
+        };
+        require!(
+            token.mint.key() == vault.mint && from.mint == vault.mint && to.mint == vault.mint,
+            RegistryError::StakeMintMismatch
+        );
+        let before = to.amount;
+        token.transfer(
+            &from.to_account_info(),
+            &to.to_account_info(),
+            &ctx.accounts.staker.to_account_info(),
+            &[],
+            amount,
+        )?;
+        tokens_received(to, before)?
+    } else {
+        deposit_lamports(
+            &ctx.accounts.staker.to_account_info(),
+            &vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            amount,
+        )?;
+        amount
+    };
+    require!(received > 0, RegistryError::InvalidStakeAmount);
+
+    let shares = vault.deposit(received)?;
+    ctx.accounts.agent.staked_amount = vault.total_staked;
+
+    let position = &mut ctx.accounts.stake_position;
+    position.vault = vault.key();
+    position.staker = ctx.accounts.staker.key();
+    position.bump = ctx.bumps.stake_position;
+    position.settle_rewards(vault.reward_per_share);
+    position.shares = position
+        .shares
+        .checked_add(shares)
+        .ok_or(RegistryError::MathOverflow)?;
+    position.reset_reward_debt(vault.reward_per_share);
+
+    let config = &ctx.accounts.config;
+    let boost_bps = if vault.mint == config.stake_mint {
+        config.stake_boost_bps(vault.total_staked)
+    } else {
+        0
+    };
+    msg!(
+        "Staked {} on agent {} ({} shares, mint {}). Total staked: {}, boost: {} bps",
+        received,
+        ctx.accounts.agent.agent_id,
+        shares,
+        vault.mint,
+        vault.total_staked,
+        boost_bps
+    );
+
+    Ok(())
+}
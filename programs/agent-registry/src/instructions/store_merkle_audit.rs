@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, MerkleAuditRoot, MerkleAuditSummary};
+use crate::state::{
+    AgentAccount, MerkleAuditRoot, MerkleAuditSummary, MerkleRootIndex, MerkleRootRecord,
+};
+use crate::events::MerkleAuditStored;
 
 /// Accounts for storing a Merkle audit root
+/// Roots are keyed by (agent, epoch, sequence) so an agent can post an ongoing
+/// stream of batches; the epoch is chosen by the poster and may only move forward
 #[derive(Accounts)]
-#[instruction(merkle_root: [u8; 32], entries_count: u32)]
+#[instruction(merkle_root: [u8; 32], entries_count: u32, epoch: u64)]
 pub struct StoreMerkleAudit<'info> {
     /// The agent owner (must own the agent being audited)
     #[account(mut)]
@@ -13,7 +18,7 @@ pub struct StoreMerkleAudit<'info> {
     #[account(
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump,
@@ -39,12 +44,23 @@ pub struct StoreMerkleAudit<'info> {
         seeds = [
             MerkleAuditRoot::SEED_PREFIX,
             agent.key().as_ref(),
-            audit_summary.total_batches.to_le_bytes().as_ref()
+            epoch.to_le_bytes().as_ref(),
+            audit_summary.sequence_for(epoch).to_le_bytes().as_ref()
         ],
         bump
     )]
     pub audit_root: Account<'info, MerkleAuditRoot>,
 
+    /// Recent roots for this agent (created if first batch)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + MerkleRootIndex::INIT_SPACE,
+        seeds = [MerkleRootIndex::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub root_index: Account<'info, MerkleRootIndex>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -54,12 +70,15 @@ pub enum StoreMerkleAuditError {
     NotAgentOwner,
     #[msg("Entries count must be greater than 0")]
     EmptyBatch,
+    #[msg("Audit epoch must not be older than the latest posted epoch")]
+    EpochRegressed,
 }
 
 pub fn handler(
     ctx: Context<StoreMerkleAudit>,
     merkle_root: [u8; 32],
     entries_count: u32,
+    epoch: u64,
 ) -> Result<()> {
     require!(entries_count > 0, StoreMerkleAuditError::EmptyBatch);
 
@@ -72,6 +91,8 @@ pub fn handler(
         summary.agent = agent_key;
         summary.bump = ctx.bumps.audit_summary;
     }
+    require!(epoch >= summary.current_epoch, StoreMerkleAuditError::EpochRegressed);
+    let sequence = summary.sequence_for(epoch);
 
     // Create the Merkle root entry
     let root = &mut ctx.accounts.audit_root;
@@ -81,16 +102,48 @@ pub fn handler(
     root.timestamp = clock.unix_timestamp;
     root.batch_index = summary.total_batches;
     root.bump = ctx.bumps.audit_root;
+    root.epoch = epoch;
+    root.sequence = sequence;
+
+    let index = &mut ctx.accounts.root_index;
+    if index.roots.is_empty() {
+        index.agent = agent_key;
+        index.bump = ctx.bumps.root_index;
+    }
+    index.push(MerkleRootRecord {
+        epoch,
+        sequence,
+        merkle_root,
+        entries_count,
+        first_entry: summary.total_entries,
+        timestamp: clock.unix_timestamp,
+    });
 
     // Update summary
     summary.total_batches = summary.total_batches.saturating_add(1);
     summary.total_entries = summary.total_entries.saturating_add(entries_count as u64);
     summary.last_batch_at = clock.unix_timestamp;
+    summary.current_epoch = epoch;
+    summary.epoch_sequence = sequence.saturating_add(1);
+
+    emit!(MerkleAuditStored {
+        agent: agent_key,
+        actor: ctx.accounts.owner.key(),
+        audit_root: root.key(),
+        batch_index: root.batch_index,
+        epoch,
+        sequence,
+        entries_count,
+        merkle_root,
+        timestamp: clock.unix_timestamp,
+    });
 
     msg!(
-        "Merkle audit root stored: agent={}, batch={}, entries={}, root={:?}",
+        "Merkle audit root stored: agent={}, batch={}, epoch={}, seq={}, entries={}, root={:?}",
         agent_key,
         root.batch_index,
+        epoch,
+        sequence,
         entries_count,
         &merkle_root[..8] // Log first 8 bytes for brevity
     );
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, OpenChallenge, OpenChallengeStatus, OpenResponse};
+use crate::errors::RegistryError;
+
+/// Submit a registered agent's answer to an open challenge (one per agent)
+///
+/// `response_hash` is the answer hash bound to the responding agent,
+/// `OpenResponse::bind(answer_hash, agent)`, so copying it wins nothing for
+/// another agent.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct SubmitOpenResponse<'info> {
+    /// Agent owner submitting the response (pays rent for the response record)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The responding agent (must be owned by signer and off probation)
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized,
+        constraint = !agent.on_probation @ RegistryError::AgentOnProbation
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// The open challenge being answered
+    #[account(
+        mut,
+        seeds = [
+            OpenChallenge::SEED_PREFIX,
+            open_challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = open_challenge.bump,
+        constraint = open_challenge.status == OpenChallengeStatus::Open @ RegistryError::OpenChallengeWrongStatus
+    )]
+    pub open_challenge: Account<'info, OpenChallenge>,
+
+    /// The response record (PDA derived from open challenge + agent)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + OpenResponse::INIT_SPACE,
+        seeds = [
+            OpenResponse::SEED_PREFIX,
+            open_challenge.key().as_ref(),
+            agent.key().as_ref(),
+        ],
+        bump
+    )]
+    pub open_response: Account<'info, OpenResponse>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitOpenResponse>, _nonce: u64, response_hash: String) -> Result<()> {
+    let open_challenge = &mut ctx.accounts.open_challenge;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp <= open_challenge.expires_at,
+        RegistryError::ChallengeExpired
+    );
+    require!(
+        response_hash.len() == 64 && response_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidResponseHash
+    );
+
+    let open_response = &mut ctx.accounts.open_response;
+    open_response.open_challenge = open_challenge.key();
+    open_response.agent = ctx.accounts.agent.key();
+    open_response.owner = ctx.accounts.owner.key();
+    open_response.response_hash = response_hash;
+    open_response.submitted_slot = clock.slot;
+    open_response.submitted_at = clock.unix_timestamp;
+    open_response.bump = ctx.bumps.open_response;
+
+    if open_challenge.response_count == 0 {
+        open_challenge.first_responder = ctx.accounts.owner.key();
+    }
+    open_challenge.response_count = open_challenge.response_count.saturating_add(1);
+
+    msg!(
+        "Open challenge {} answered by agent {} (response #{})",
+        open_challenge.key(),
+        ctx.accounts.agent.agent_id,
+        open_challenge.response_count
+    );
+
+    Ok(())
+}
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, Challenge, ChallengeStatus, RegistryState};
+use crate::state::{AgentAccount, Challenge, ChallengeStatus, RegistryConfig, RegistryState};
 use crate::errors::RegistryError;
 
 #[derive(Accounts)]
 #[instruction(response_hash: String, nonce: u64)]
 pub struct SubmitResponse<'info> {
-    /// Agent owner submitting the response
+    /// Agent owner or its delegated operator key submitting the response
     #[account(mut)]
     pub owner: Signer<'info>,
 
@@ -16,15 +16,22 @@ pub struct SubmitResponse<'info> {
     )]
     pub registry: Account<'info, RegistryState>,
 
-    /// The agent account (must be owned by signer)
+    /// Registry config (fast-response thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// The agent account (signer must be its owner or operator)
     #[account(
-        mut,
         seeds = [
             AgentAccount::SEED_PREFIX,
-            owner.key().as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
-        bump = agent.bump
+        bump = agent.bump,
+        constraint = agent.is_responder(&owner.key()) @ RegistryError::Unauthorized
     )]
     pub agent: Account<'info, AgentAccount>,
 
@@ -39,7 +46,8 @@ pub struct SubmitResponse<'info> {
         ],
         bump = challenge.bump,
         constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
-        constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending
+        constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending,
+        constraint = !challenge.requires_zk_proof() @ RegistryError::ZkProofRequired
     )]
     pub challenge: Account<'info, Challenge>,
 }
@@ -48,9 +56,9 @@ pub fn handler(
     ctx: Context<SubmitResponse>,
     response_hash: String,
     _nonce: u64,
+    evidence_uri: Option<String>,
 ) -> Result<()> {
     let challenge = &mut ctx.accounts.challenge;
-    let agent = &mut ctx.accounts.agent;
     let clock = Clock::get()?;
 
     // Check if challenge has expired
@@ -65,35 +73,27 @@ pub fn handler(
         RegistryError::InvalidResponseHash
     );
 
-    // Record response time
-    challenge.responded_at = clock.unix_timestamp;
-
-    // Verify the response
-    if response_hash == challenge.expected_hash {
-        // Challenge passed
-        challenge.status = ChallengeStatus::Passed;
-        agent.challenges_passed = agent.challenges_passed.saturating_add(1);
-        agent.adjust_reputation(Challenge::PASS_REPUTATION_DELTA);
-        agent.updated_at = clock.unix_timestamp;
+    let evidence_uri = evidence_uri.unwrap_or_default();
+    require!(
+        evidence_uri.len() <= Challenge::MAX_EVIDENCE_URI_LEN,
+        RegistryError::EvidenceUriTooLong
+    );
 
-        msg!(
-            "Challenge PASSED! Agent {} reputation: {}",
-            agent.agent_id,
-            agent.reputation_score
-        );
-    } else {
-        // Challenge failed
-        challenge.status = ChallengeStatus::Failed;
-        agent.challenges_failed = agent.challenges_failed.saturating_add(1);
-        agent.adjust_reputation(Challenge::FAIL_REPUTATION_DELTA);
-        agent.updated_at = clock.unix_timestamp;
+    // Record the response; verification happens when the challenger reveals the salt
+    challenge.response_hash = response_hash;
+    challenge.responded_at = clock.unix_timestamp;
+    challenge.response_bonus = ctx
+        .accounts
+        .config
+        .response_bonus(clock.unix_timestamp - challenge.created_at);
+    challenge.evidence_uri = evidence_uri;
+    challenge.status = ChallengeStatus::Responded;
 
-        msg!(
-            "Challenge FAILED. Agent {} reputation: {}",
-            agent.agent_id,
-            agent.reputation_score
-        );
-    }
+    msg!(
+        "Response submitted for agent {}. Awaiting reveal until {}",
+        ctx.accounts.agent.agent_id,
+        challenge.responded_at + Challenge::REVEAL_WINDOW
+    );
 
     Ok(())
 }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Task, TaskStatus};
+use crate::errors::RegistryError;
+use crate::events::TaskResultSubmitted;
+
+/// Submit the result hash for an assigned task (agent owner or operator)
+/// Results are only accepted until the task deadline, after which the client may cancel
+#[derive(Accounts)]
+pub struct SubmitTaskResult<'info> {
+    pub responder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Task::SEED_PREFIX, task.client.as_ref(), task.nonce.to_le_bytes().as_ref()],
+        bump = task.bump,
+        constraint = task.status == TaskStatus::Assigned @ RegistryError::InvalidTaskStatus
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        address = task.agent @ RegistryError::Unauthorized,
+        constraint = agent.is_responder(&responder.key()) @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+}
+
+pub fn handler(ctx: Context<SubmitTaskResult>, result_hash: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let task = &mut ctx.accounts.task;
+    require!(clock.unix_timestamp <= task.deadline, RegistryError::TaskDeadlinePassed);
+    task.result_hash = result_hash;
+    task.submitted_at = clock.unix_timestamp;
+    task.status = TaskStatus::Submitted;
+
+    emit!(TaskResultSubmitted {
+        task: task.key(),
+        agent: task.agent,
+        result_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Task {} result submitted by agent id={}", task.nonce, ctx.accounts.agent.agent_id);
+
+    Ok(())
+}
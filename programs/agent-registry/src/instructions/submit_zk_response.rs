@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, Challenge, ChallengeStatus, ChallengerStats, Groth16Proof, RegistryConfig, ZkVerifyingKey,
+};
+use crate::errors::RegistryError;
+use crate::utils::{emit_challenge_resolved, release_challenge_escrow};
+use crate::zk::{public_input, verify_groth16};
+
+/// Answer a proof-of-inference challenge with a Groth16 proof
+///
+/// The proof attests that the agent's committed model produced an output with
+/// `response_hash` for the challenge question. Public inputs, in order:
+/// public_input(agent.model_hash), public_input(question), public_input(response_hash).
+/// A valid proof passes the challenge immediately; an invalid one is rejected
+/// and the agent may retry until the challenge expires.
+#[derive(Accounts)]
+#[instruction(response_hash: String, nonce: u64)]
+pub struct SubmitZkResponse<'info> {
+    /// Agent owner submitting the proof (receives the reward)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The agent account (must be owned by signer)
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    /// Registry config (fast-response thresholds)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Original challenger (receives the bond back)
+    #[account(mut, address = challenge.challenger @ RegistryError::Unauthorized)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// The challenge to answer
+    #[account(
+        mut,
+        seeds = [
+            Challenge::SEED_PREFIX,
+            agent.key().as_ref(),
+            challenge.challenger.as_ref(),
+            nonce.to_le_bytes().as_ref(),
+        ],
+        bump = challenge.bump,
+        constraint = challenge.agent == agent.key() @ RegistryError::ChallengeMismatch,
+        constraint = challenge.status == ChallengeStatus::Pending @ RegistryError::ChallengeNotPending
+    )]
+    pub challenge: Account<'info, Challenge>,
+
+    /// Verifying key chosen at challenge creation
+    #[account(address = challenge.zk_verifying_key @ RegistryError::ZkProofRequired)]
+    pub verifying_key: Account<'info, ZkVerifyingKey>,
+
+    /// Challenger's aggregate stats (updated with the verdict)
+    #[account(
+        mut,
+        seeds = [ChallengerStats::SEED_PREFIX, challenge.challenger.as_ref()],
+        bump = challenger_stats.bump
+    )]
+    pub challenger_stats: Account<'info, ChallengerStats>,
+}
+
+pub fn handler(
+    ctx: Context<SubmitZkResponse>,
+    response_hash: String,
+    _nonce: u64,
+    proof: Groth16Proof,
+) -> Result<()> {
+    let challenge = &mut ctx.accounts.challenge;
+    let agent = &mut ctx.accounts.agent;
+    let clock = Clock::get()?;
+    let max_change = ctx.accounts.config.max_reputation_change_per_day;
+
+    require!(
+        !challenge.is_expired(clock.unix_timestamp),
+        RegistryError::ChallengeExpired
+    );
+    require!(
+        response_hash.len() == 64 && response_hash.chars().all(|c| c.is_ascii_hexdigit()),
+        RegistryError::InvalidResponseHash
+    );
+
+    let inputs = [
+        public_input(agent.model_hash.as_bytes()),
+        public_input(challenge.question.as_bytes()),
+        public_input(response_hash.as_bytes()),
+    ];
+    require!(
+        verify_groth16(&ctx.accounts.verifying_key, &proof, &inputs)?,
+        RegistryError::InvalidZkProof
+    );
+
+    challenge.response_hash = response_hash;
+    challenge.responded_at = clock.unix_timestamp;
+    challenge.response_bonus = ctx
+        .accounts
+        .config
+        .response_bonus(clock.unix_timestamp - challenge.created_at);
+
+    let reward_paid = release_challenge_escrow(
+        challenge,
+        &ctx.accounts.challenger.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        true,
+    )?;
+
+    let old_reputation = agent.reputation_score;
+    challenge.apply_verdict(agent, true, clock.unix_timestamp, max_change);
+
+    msg!(
+        "Challenge PASSED with ZK proof. Agent {} reputation: {}, reward: {}",
+        agent.agent_id,
+        agent.reputation_score,
+        reward_paid
+    );
+
+    ctx.accounts.challenger_stats.record_verdict(true);
+    emit_challenge_resolved(challenge, agent, old_reputation);
+
+    Ok(())
+}
@@ -11,7 +11,7 @@ pub struct UpdateAgent<'info> {
         mut,
         seeds = [
             AgentAccount::SEED_PREFIX,
-            owner.key().as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump,
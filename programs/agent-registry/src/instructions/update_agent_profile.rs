@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, AgentProfile};
+use crate::errors::RegistryError;
+
+/// Create or update the optional profile extension for an agent
+/// The profile PDA is created on first use (owner pays rent)
+#[derive(Accounts)]
+pub struct UpdateAgentProfile<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + AgentProfile::INIT_SPACE,
+        seeds = [AgentProfile::SEED_PREFIX, agent.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, AgentProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateAgentProfile>,
+    description_hash: Option<String>,
+    links: Option<String>,
+    avatar_uri: Option<String>,
+    jurisdiction: Option<String>,
+) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let clock = Clock::get()?;
+
+    // Initialize profile on first use
+    if profile.agent == Pubkey::default() {
+        profile.agent = ctx.accounts.agent.key();
+        profile.bump = ctx.bumps.profile;
+    }
+
+    if let Some(new_description_hash) = description_hash {
+        require!(
+            new_description_hash.len() == 64
+                && new_description_hash.chars().all(|c| c.is_ascii_hexdigit()),
+            RegistryError::InvalidDescriptionHash
+        );
+        profile.description_hash = new_description_hash;
+    }
+
+    if let Some(new_links) = links {
+        require!(
+            new_links.len() <= AgentProfile::MAX_LINKS_LEN,
+            RegistryError::LinksTooLong
+        );
+        profile.links = new_links;
+    }
+
+    if let Some(new_avatar_uri) = avatar_uri {
+        require!(
+            new_avatar_uri.len() <= AgentProfile::MAX_AVATAR_URI_LEN,
+            RegistryError::AvatarUriTooLong
+        );
+        profile.avatar_uri = new_avatar_uri;
+    }
+
+    if let Some(new_jurisdiction) = jurisdiction {
+        require!(
+            new_jurisdiction.len() <= AgentProfile::MAX_JURISDICTION_LEN,
+            RegistryError::JurisdictionTooLong
+        );
+        profile.jurisdiction = new_jurisdiction;
+    }
+
+    profile.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Agent profile updated: agent={}",
+        ctx.accounts.agent.agent_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,408 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AgentAccount, AgentAuditSummary, AuditEntry, Challenge, ChallengeKind, RegistryAction,
+    RegistryConfig, RegistryState, RiskWeights,
+};
+use crate::errors::RegistryError;
+use crate::utils::log_registry_action;
+
+/// Config fields to update (None = keep current value)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigParams {
+    pub challenge_bond_lamports: Option<u64>,
+    pub max_difficulty: Option<u8>,
+    pub dispute_bond_lamports: Option<u64>,
+    pub oracle_authority: Option<Pubkey>,
+    pub expire_bounty_bps: Option<u16>,
+    pub max_challenges_per_day: Option<u32>,
+    pub max_pending_per_challenger: Option<u32>,
+    pub verified_stake_per_tier: Option<u64>,
+    pub challenge_fee_bps: Option<u16>,
+    pub verified_challenger_weight_bps: Option<u16>,
+    pub anonymous_challenger_weight_bps: Option<u16>,
+    pub fast_response_secs: Option<u32>,
+    pub quick_response_secs: Option<u32>,
+    pub max_reputation_change_per_day: Option<u32>,
+    pub stake_boost_unit_lamports: Option<u64>,
+    pub stake_boost_bps_per_unit: Option<u16>,
+    pub max_stake_boost_bps: Option<u16>,
+    pub stake_slash_bps: Option<u16>,
+    pub min_reputation_open_challenge: Option<u32>,
+    pub min_reputation_verifier: Option<u32>,
+    pub reputation_migration_bps: Option<u16>,
+    pub ema_alpha_bps: Option<u16>,
+    pub report_bond_lamports: Option<u64>,
+    pub probation_floor: Option<u32>,
+    pub probation_exit_streak: Option<u8>,
+    pub pass_reputation_deltas: Option<[i32; ChallengeKind::COUNT]>,
+    pub fail_reputation_deltas: Option<[i32; ChallengeKind::COUNT]>,
+    pub suspend_critical_alerts: Option<u32>,
+    pub suspend_window_secs: Option<u32>,
+    pub sentinel_risk_threshold: Option<u8>,
+    pub risk_decay_half_life_secs: Option<u32>,
+    pub max_risk_reset_secs: Option<u32>,
+    pub stake_mint: Option<Pubkey>,
+    pub critical_alert_slash_bps: Option<u16>,
+    pub slash_affected_share_bps: Option<u16>,
+    pub staker_fee_share_bps: Option<u16>,
+    pub min_verification_fee: Option<u64>,
+    pub insurance_claim_bond_lamports: Option<u64>,
+}
+
+/// Update registry config parameters (admin only)
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [RegistryState::SEED_PREFIX],
+        bump = registry.bump,
+        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+    )]
+    pub registry: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// Registry-scoped audit summary (created on the first logged admin action)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, registry.key().as_ref()],
+        bump
+    )]
+    pub registry_audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// Registry audit entry recording the config change (details commit to the serialized params)
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            registry.key().as_ref(),
+            registry_audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub registry_audit_entry: Account<'info, AuditEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let mut payload = Vec::new();
+    params.serialize(&mut payload)?;
+    let config = &mut ctx.accounts.config;
+
+    if let Some(challenge_bond_lamports) = params.challenge_bond_lamports {
+        config.challenge_bond_lamports = challenge_bond_lamports;
+    }
+
+    if let Some(max_difficulty) = params.max_difficulty {
+        require!(
+            (Challenge::BASELINE_DIFFICULTY..=RegistryConfig::MAX_DIFFICULTY_CAP)
+                .contains(&max_difficulty),
+            RegistryError::InvalidConfigValue
+        );
+        config.max_difficulty = max_difficulty;
+    }
+
+    if let Some(dispute_bond_lamports) = params.dispute_bond_lamports {
+        config.dispute_bond_lamports = dispute_bond_lamports;
+    }
+
+    if let Some(oracle_authority) = params.oracle_authority {
+        config.oracle_authority = oracle_authority;
+    }
+
+    if let Some(expire_bounty_bps) = params.expire_bounty_bps {
+        require!(
+            expire_bounty_bps <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidConfigValue
+        );
+        config.expire_bounty_bps = expire_bounty_bps;
+    }
+
+    if let Some(max_challenges_per_day) = params.max_challenges_per_day {
+        require!(max_challenges_per_day > 0, RegistryError::InvalidConfigValue);
+        config.max_challenges_per_day = max_challenges_per_day;
+    }
+
+    if let Some(max_pending_per_challenger) = params.max_pending_per_challenger {
+        require!(max_pending_per_challenger > 0, RegistryError::InvalidConfigValue);
+        config.max_pending_per_challenger = max_pending_per_challenger;
+    }
+
+    if let Some(verified_stake_per_tier) = params.verified_stake_per_tier {
+        config.verified_stake_per_tier = verified_stake_per_tier;
+    }
+
+    if let Some(challenge_fee_bps) = params.challenge_fee_bps {
+        require!(
+            challenge_fee_bps <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidConfigValue
+        );
+        config.challenge_fee_bps = challenge_fee_bps;
+    }
+
+    if let Some(weight_bps) = params.verified_challenger_weight_bps {
+        require!(
+            weight_bps > 0 && weight_bps <= RegistryConfig::MAX_CHALLENGER_WEIGHT_BPS,
+            RegistryError::InvalidConfigValue
+        );
+        config.verified_challenger_weight_bps = weight_bps;
+    }
+
+    if let Some(weight_bps) = params.anonymous_challenger_weight_bps {
+        require!(
+            weight_bps > 0 && weight_bps <= RegistryConfig::MAX_CHALLENGER_WEIGHT_BPS,
+            RegistryError::InvalidConfigValue
+        );
+        config.anonymous_challenger_weight_bps = weight_bps;
+    }
+
+    if let Some(fast_response_secs) = params.fast_response_secs {
+        config.fast_response_secs = fast_response_secs;
+    }
+
+    if let Some(quick_response_secs) = params.quick_response_secs {
+        config.quick_response_secs = quick_response_secs;
+    }
+
+    if let Some(max_reputation_change_per_day) = params.max_reputation_change_per_day {
+        require!(max_reputation_change_per_day > 0, RegistryError::InvalidConfigValue);
+        config.max_reputation_change_per_day = max_reputation_change_per_day;
+    }
+
+    if let Some(stake_boost_unit_lamports) = params.stake_boost_unit_lamports {
+        require!(stake_boost_unit_lamports > 0, RegistryError::InvalidConfigValue);
+        config.stake_boost_unit_lamports = stake_boost_unit_lamports;
+    }
+
+    if let Some(stake_boost_bps_per_unit) = params.stake_boost_bps_per_unit {
+        config.stake_boost_bps_per_unit = stake_boost_bps_per_unit;
+    }
+
+    if let Some(max_stake_boost_bps) = params.max_stake_boost_bps {
+        require!(
+            max_stake_boost_bps <= RegistryConfig::MAX_STAKE_BOOST_CAP_BPS,
+            RegistryError::InvalidConfigValue
+        );
+        config.max_stake_boost_bps = max_stake_boost_bps;
+    }
+
+    if let Some(stake_slash_bps) = params.stake_slash_bps {
+        require!(
+            stake_slash_bps <= RegistryConfig::MAX_SLASH_BPS,
+            RegistryError::InvalidConfigValue
+        );
+        config.stake_slash_bps = stake_slash_bps;
+    }
+
+    if let Some(min_reputation) = params.min_reputation_open_challenge {
+        require!(
+            min_reputation <= AgentAccount::MAX_REPUTATION,
+            RegistryError::InvalidConfigValue
+        );
+        config.min_reputation_open_challenge = min_reputation;
+    }
+
+    if let Some(min_reputation) = params.min_reputation_verifier {
+        require!(
+            min_reputation <= AgentAccount::MAX_REPUTATION,
+            RegistryError::InvalidConfigValue
+        );
+        config.min_reputation_verifier = min_reputation;
+    }
+
+    if let Some(reputation_migration_bps) = params.reputation_migration_bps {
+        require!(
+            reputation_migration_bps <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidConfigValue
+        );
+        config.reputation_migration_bps = reputation_migration_bps;
+    }
+
+    if let Some(ema_alpha_bps) = params.ema_alpha_bps {
+        require!(
+            ema_alpha_bps <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidConfigValue
+        );
+        config.ema_alpha_bps = ema_alpha_bps;
+    }
+
+    if let Some(report_bond_lamports) = params.report_bond_lamports {
+        config.report_bond_lamports = report_bond_lamports;
+    }
+
+    if let Some(probation_floor) = params.probation_floor {
+        require!(
+            probation_floor <= AgentAccount::MAX_REPUTATION,
+            RegistryError::InvalidConfigValue
+        );
+        config.probation_floor = probation_floor;
+    }
+
+    if let Some(probation_exit_streak) = params.probation_exit_streak {
+        require!(probation_exit_streak > 0, RegistryError::InvalidConfigValue);
+        config.probation_exit_streak = probation_exit_streak;
+    }
+
+    if let Some(pass_reputation_deltas) = params.pass_reputation_deltas {
+        require!(
+            pass_reputation_deltas
+                .iter()
+                .all(|delta| (0..=RegistryConfig::MAX_KIND_DELTA).contains(delta)),
+            RegistryError::InvalidConfigValue
+        );
+        config.pass_reputation_deltas = pass_reputation_deltas;
+    }
+
+    if let Some(fail_reputation_deltas) = params.fail_reputation_deltas {
+        require!(
+            fail_reputation_deltas
+                .iter()
+                .all(|delta| (-RegistryConfig::MAX_KIND_DELTA..=0).contains(delta)),
+            RegistryError::InvalidConfigValue
+        );
+        config.fail_reputation_deltas = fail_reputation_deltas;
+    }
+
+    if let Some(suspend_critical_alerts) = params.suspend_critical_alerts {
+        config.suspend_critical_alerts = suspend_critical_alerts;
+    }
+
+    if let Some(suspend_window_secs) = params.suspend_window_secs {
+        require!(suspend_window_secs > 0, RegistryError::InvalidConfigValue);
+        config.suspend_window_secs = suspend_window_secs;
+    }
+
+    if let Some(sentinel_risk_threshold) = params.sentinel_risk_threshold {
+        require!(
+            (1..=100).contains(&sentinel_risk_threshold),
+            RegistryError::InvalidConfigValue
+        );
+        config.sentinel_risk_threshold = sentinel_risk_threshold;
+    }
+
+    if let Some(risk_decay_half_life_secs) = params.risk_decay_half_life_secs {
+        config.risk_decay_half_life_secs = risk_decay_half_life_secs;
+    }
+
+    if let Some(max_risk_reset_secs) = params.max_risk_reset_secs {
+        config.max_risk_reset_secs = max_risk_reset_secs;
+    }
+
+    if let Some(stake_mint) = params.stake_mint {
+        config.stake_mint = stake_mint;
+    }
+
+    if let Some(critical_alert_slash_bps) = params.critical_alert_slash_bps {
+        require!(
+            critical_alert_slash_bps <= RegistryConfig::MAX_SLASH_BPS,
+            RegistryError::InvalidConfigValue
+        );
+        config.critical_alert_slash_bps = critical_alert_slash_bps;
+    }
+
+    if let Some(slash_affected_share_bps) = params.slash_affected_share_bps {
+        require!(
+            slash_affected_share_bps <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidConfigValue
+        );
+        config.slash_affected_share_bps = slash_affected_share_bps;
+    }
+
+    if let Some(staker_fee_share_bps) = params.staker_fee_share_bps {
+        require!(
+            staker_fee_share_bps <= RegistryConfig::BPS_DENOMINATOR,
+            RegistryError::InvalidConfigValue
+        );
+        config.staker_fee_share_bps = staker_fee_share_bps;
+    }
+
+    if let Some(min_verification_fee) = params.min_verification_fee {
+        config.min_verification_fee = min_verification_fee;
+    }
+
+    if let Some(insurance_claim_bond_lamports) = params.insurance_claim_bond_lamports {
+        config.insurance_claim_bond_lamports = insurance_claim_bond_lamports;
+    }
+
+    require!(
+        config.fast_response_secs <= config.quick_response_secs,
+        RegistryError::InvalidConfigValue
+    );
+
+    msg!(
+        "Registry config updated: challenge_bond={}, max_difficulty={}, dispute_bond={}, oracle_authority={}, expire_bounty_bps={}, max_challenges_per_day={}, max_pending_per_challenger={}, verified_stake_per_tier={}, challenge_fee_bps={}, verified_challenger_weight_bps={}, anonymous_challenger_weight_bps={}, fast_response_secs={}, quick_response_secs={}, max_reputation_change_per_day={}, stake_boost_unit_lamports={}, stake_boost_bps_per_unit={}, max_stake_boost_bps={}, stake_slash_bps={}, min_reputation_open_challenge={}, min_reputation_verifier={}, reputation_migration_bps={}, ema_alpha_bps={}, report_bond={}, probation_floor={}, probation_exit_streak={}, pass_reputation_deltas={:?}, fail_reputation_deltas={:?}, suspend_critical_alerts={}, suspend_window_secs={}, sentinel_risk_threshold={}, risk_decay_half_life_secs={}, max_risk_reset_secs={}, stake_mint={}, critical_alert_slash_bps={}, slash_affected_share_bps={}, staker_fee_share_bps={}, min_verification_fee={}, insurance_claim_bond={}",
+        config.challenge_bond_lamports,
+        config.max_difficulty,
+        config.dispute_bond_lamports,
+        config.oracle_authority,
+        config.expire_bounty_bps,
+        config.max_challenges_per_day,
+        config.max_pending_per_challenger,
+        config.verified_stake_per_tier,
+        config.challenge_fee_bps,
+        config.verified_challenger_weight_bps,
+        config.anonymous_challenger_weight_bps,
+        config.fast_response_secs,
+        config.quick_response_secs,
+        config.max_reputation_change_per_day,
+        config.stake_boost_unit_lamports,
+        config.stake_boost_bps_per_unit,
+        config.max_stake_boost_bps,
+        config.stake_slash_bps,
+        config.min_reputation_open_challenge,
+        config.min_reputation_verifier,
+        config.reputation_migration_bps,
+        config.ema_alpha_bps,
+        config.report_bond_lamports,
+        config.probation_floor,
+        config.probation_exit_streak,
+        config.pass_reputation_deltas,
+        config.fail_reputation_deltas,
+        config.suspend_critical_alerts,
+        config.suspend_window_secs,
+        config.sentinel_risk_threshold,
+        config.risk_decay_half_life_secs,
+        config.max_risk_reset_secs,
+        config.stake_mint,
+        config.critical_alert_slash_bps,
+        config.slash_affected_share_bps,
+        config.staker_fee_share_bps,
+        config.min_verification_fee,
+        config.insurance_claim_bond_lamports
+    );
+
+    let summary = &mut ctx.accounts.registry_audit_summary;
+    summary.agent = ctx.accounts.registry.key();
+    summary.bump = ctx.bumps.registry_audit_summary;
+    ctx.accounts.registry_audit_entry.bump = ctx.bumps.registry_audit_entry;
+    log_registry_action(
+        ctx.accounts.admin.key(),
+        summary,
+        &mut ctx.accounts.registry_audit_entry,
+        &ctx.accounts.risk_weights,
+        RegistryAction::ConfigUpdated,
+        ctx.accounts.config.key(),
+        &payload,
+    )?;
+
+    Ok(())
+}
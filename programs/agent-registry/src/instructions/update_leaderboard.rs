@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, Leaderboard, LeaderboardEntry};
+
+/// Submit an agent to the leaderboard (anyone can call)
+///
+/// Refreshes the agent's listed score, or inserts it when the board has room
+/// or the agent beats the current minimum. The caller pays for the
+/// leaderboard account on first use.
+#[derive(Accounts)]
+pub struct UpdateLeaderboard<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The submitted agent (its account proves the score)
+    #[account(
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Leaderboard::INIT_SPACE,
+        seeds = [Leaderboard::SEED_PREFIX],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateLeaderboard>) -> Result<()> {
+    let agent = &ctx.accounts.agent;
+    let leaderboard = &mut ctx.accounts.leaderboard;
+
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.submit(LeaderboardEntry {
+        agent: agent.key(),
+        agent_id: agent.agent_id,
+        reputation_score: agent.reputation_score,
+    })?;
+    leaderboard.updated_at = Clock::get()?.unix_timestamp;
+
+    let rank = leaderboard
+        .entries
+        .iter()
+        .position(|e| e.agent == agent.key())
+        .map_or(0, |i| i + 1);
+    msg!(
+        "Leaderboard updated: agent {} (reputation {}) ranked #{}",
+        agent.agent_id,
+        agent.reputation_score,
+        rank
+    );
+
+    Ok(())
+}
@@ -1,34 +1,47 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, RegistryState};
+use crate::state::{AgentAccount, AuthorizedUpdaters, RegistryConfig, RegistryState};
 use crate::errors::RegistryError;
+use crate::events::ReputationChangeReason;
+use crate::utils::{emit_reputation_changed, enforce_probation};
 
 #[derive(Accounts)]
 pub struct UpdateReputation<'info> {
-    /// Authority for reputation updates - SECURITY NOTICE
+    /// Authority for reputation updates: the registry admin or an authorized updater
     ///
-    /// HACKATHON LIMITATION: Currently only admin can update reputation.
+    /// Integrating programs sign with a PDA (invoke_signed) listed in AuthorizedUpdaters.
+    ///
+    /// Total absolute change per agent is capped per day (config.max_reputation_change_per_day).
     ///
     /// PRODUCTION REQUIREMENTS:
-    /// 1. Add a separate "authorized_verifiers" PDA to store allowed callers
-    /// 2. Allow challenge program PDAs to update reputation via CPI
-    /// 3. Consider time-locked updates or multi-sig for large reputation changes
-    /// 4. Implement rate limiting per agent to prevent reputation farming
+    /// 1. Consider time-locked updates or multi-sig for large reputation changes
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         seeds = [RegistryState::SEED_PREFIX],
-        bump = registry.bump,
-        // Currently admin-only. For production, expand to include verified challenge programs
-        constraint = registry.admin == authority.key() @ RegistryError::Unauthorized
+        bump = registry.bump
     )]
     pub registry: Account<'info, RegistryState>,
 
+    /// Registry config (daily reputation change cap)
+    #[account(
+        seeds = [RegistryConfig::SEED_PREFIX],
+        bump = config.bump
+    )]
+    pub config: Account<'info, RegistryConfig>,
+
+    /// Updater allowlist (required unless the authority is the admin)
+    #[account(
+        seeds = [AuthorizedUpdaters::SEED_PREFIX],
+        bump = authorized_updaters.bump
+    )]
+    pub authorized_updaters: Option<Account<'info, AuthorizedUpdaters>>,
+
     #[account(
         mut,
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump
@@ -37,6 +50,17 @@ pub struct UpdateReputation<'info> {
 }
 
 pub fn handler(ctx: Context<UpdateReputation>, delta: i32) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        ctx.accounts.registry.admin == authority
+            || ctx
+                .accounts
+                .authorized_updaters
+                .as_ref()
+                .is_some_and(|updaters| updaters.is_authorized(&authority)),
+        RegistryError::Unauthorized
+    );
+
     // Limit reputation changes to prevent abuse
     require!(
         delta.abs() <= 1000,
@@ -45,6 +69,14 @@ pub fn handler(ctx: Context<UpdateReputation>, delta: i32) -> Result<()> {
 
     let agent = &mut ctx.accounts.agent;
     let old_reputation = agent.reputation_score;
+    let clock = Clock::get()?;
+
+    // Cap the total change per agent per window to limit reputation farming
+    agent.record_reputation_change(
+        delta,
+        clock.unix_timestamp,
+        ctx.accounts.config.max_reputation_change_per_day,
+    )?;
 
     // Update challenge counters based on delta
     if delta > 0 {
@@ -54,11 +86,19 @@ pub fn handler(ctx: Context<UpdateReputation>, delta: i32) -> Result<()> {
     }
 
     // Apply reputation change
-    agent.adjust_reputation(delta);
-
-    let clock = Clock::get()?;
+    agent.adjust_reputation(delta, clock.unix_timestamp);
     agent.updated_at = clock.unix_timestamp;
 
+    emit_reputation_changed(
+        agent.key(),
+        old_reputation,
+        agent.reputation_score,
+        ReputationChangeReason::AdminUpdate,
+        authority,
+        clock.unix_timestamp,
+    );
+    enforce_probation(agent, old_reputation, &ctx.accounts.config, clock.unix_timestamp);
+
     msg!(
         "Reputation updated: agent={}, old={}, new={}, delta={}",
         agent.agent_id,
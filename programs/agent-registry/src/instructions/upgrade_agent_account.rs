@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::AgentAccount;
+use crate::errors::RegistryError;
+
+/// Upgrade an existing agent account to the current layout version
+///
+/// Agent accounts created by older program versions are smaller than the
+/// current `AgentAccount` layout. This instruction reallocates the account
+/// (owner pays the extra rent), zero-fills the new fields and stamps the
+/// current version, so reputation and history survive program upgrades.
+#[derive(Accounts)]
+pub struct UpgradeAgentAccount<'info> {
+    /// Agent owner (pays for the additional rent)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Legacy layouts may not deserialize as the current AgentAccount,
+    /// so discriminator, owner and PDA seeds are validated in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub agent: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpgradeAgentAccount>) -> Result<()> {
+    let agent_info = ctx.accounts.agent.to_account_info();
+
+    // Read the fixed-offset header fields: discriminator and current owner
+    let owner = {
+        let data = agent_info.try_borrow_data()?;
+        require!(data.len() >= 48, ErrorCode::AccountDidNotDeserialize);
+        require!(
+            &data[..8] == AgentAccount::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        Pubkey::try_from(&data[16..48]).unwrap()
+    };
+
+    require_keys_eq!(owner, ctx.accounts.owner.key(), RegistryError::Unauthorized);
+
+    // Grow the account and top up rent if needed
+    let new_len = 8 + AgentAccount::INIT_SPACE;
+    let old_len = agent_info.data_len();
+    if old_len < new_len {
+        let required = Rent::get()?.minimum_balance(new_len);
+        let shortfall = required.saturating_sub(agent_info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: agent_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        agent_info.resize(new_len)?;
+    }
+
+    // New fields are zero-initialized; stamp the current version
+    let mut agent = {
+        let data = agent_info.try_borrow_data()?;
+        AgentAccount::try_deserialize(&mut &data[..])?
+    };
+
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref(),
+        ],
+        ctx.program_id,
+    );
+    require_keys_eq!(expected_pda, agent_info.key(), ErrorCode::ConstraintSeeds);
+
+    require!(
+        agent.version < AgentAccount::CURRENT_VERSION,
+        RegistryError::AgentAlreadyUpgraded
+    );
+    let old_version = agent.version;
+    agent.version = AgentAccount::CURRENT_VERSION;
+    if agent.original_owner == Pubkey::default() {
+        agent.original_owner = agent.owner;
+    }
+    agent.updated_at = Clock::get()?.unix_timestamp;
+
+    let mut data = agent_info.try_borrow_mut_data()?;
+    agent.try_serialize(&mut &mut data[..])?;
+
+    msg!(
+        "Agent account upgraded: id={}, version {} -> {}, size {} -> {}",
+        agent.agent_id,
+        old_version,
+        agent.version,
+        old_len,
+        new_len
+    );
+
+    Ok(())
+}
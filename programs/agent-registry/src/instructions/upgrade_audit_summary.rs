@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::AgentAuditSummary;
+use crate::errors::RegistryError;
+
+/// Grow a legacy audit summary to the current layout (anyone may pay)
+///
+/// Summaries created before per-action counters existed are too small to
+/// deserialize as the current `AgentAuditSummary`. This reallocates the
+/// account and zero-fills the counters, which then count entries from here on.
+#[derive(Accounts)]
+pub struct UpgradeAuditSummary<'info> {
+    /// Pays for the additional rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy layout does not deserialize as the current AgentAuditSummary,
+    /// so discriminator and PDA seeds are validated in the handler.
+    #[account(mut, owner = crate::ID)]
+    pub audit_summary: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpgradeAuditSummary>) -> Result<()> {
+    let summary_info = ctx.accounts.audit_summary.to_account_info();
+
+    // Discriminator and agent key sit at fixed offsets in every layout
+    let agent = {
+        let data = summary_info.try_borrow_data()?;
+        require!(
+            data.len() >= AgentAuditSummary::LEGACY_SPACE,
+            ErrorCode::AccountDidNotDeserialize
+        );
+        require!(
+            &data[..8] == AgentAuditSummary::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        Pubkey::try_from(&data[8..40]).unwrap()
+    };
+
+    let (expected_pda, _) = Pubkey::find_program_address(
+        &[AgentAuditSummary::SEED_PREFIX, agent.as_ref()],
+        ctx.program_id,
+    );
+    require_keys_eq!(expected_pda, summary_info.key(), ErrorCode::ConstraintSeeds);
+
+    let new_len = 8 + AgentAuditSummary::INIT_SPACE;
+    let old_len = summary_info.data_len();
+    require!(old_len < new_len, RegistryError::AuditSummaryAlreadyUpgraded);
+
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(summary_info.lamports());
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: summary_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    summary_info.resize(new_len)?;
+
+    msg!(
+        "Audit summary upgraded: agent={}, size {} -> {}",
+        agent,
+        old_len,
+        new_len
+    );
+
+    Ok(())
+}
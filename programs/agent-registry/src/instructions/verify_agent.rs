@@ -1,16 +1,26 @@
 use anchor_lang::prelude::*;
-use crate::state::{AgentAccount, RegistryState};
+use crate::state::{
+    AgentAccount, AgentAuditSummary, AuditEntry, RegistryAction, RegistryState, RiskWeights,
+    VerificationRequest, VerifiedVerifier,
+};
 use crate::errors::RegistryError;
+use crate::events::VerificationRequestClosed;
+use crate::utils::{log_registry_action, transfer_from_pda};
 
+/// Verify an agent (registry admin)
+///
+/// When the agent has an open verification request, passing it lets the admin
+/// or a verified verifier service it: the escrowed fee goes to the signer and
+/// the request rent back to the requester.
 #[derive(Accounts)]
 pub struct VerifyAgent<'info> {
+    /// Registry admin, or a verified verifier servicing a verification request
     #[account(mut)]
     pub admin: Signer<'info>,
 
     #[account(
         seeds = [RegistryState::SEED_PREFIX],
-        bump = registry.bump,
-        constraint = registry.admin == admin.key() @ RegistryError::Unauthorized
+        bump = registry.bump
     )]
     pub registry: Account<'info, RegistryState>,
 
@@ -18,24 +28,128 @@ pub struct VerifyAgent<'info> {
         mut,
         seeds = [
             AgentAccount::SEED_PREFIX,
-            agent.owner.as_ref(),
+            agent.pda_owner().as_ref(),
             agent.agent_id.to_le_bytes().as_ref()
         ],
         bump = agent.bump
     )]
     pub agent: Account<'info, AgentAccount>,
+
+    /// CHECK: Audit base-risk table (may not exist yet = built-in defaults)
+    #[account(
+        seeds = [RiskWeights::SEED_PREFIX],
+        bump
+    )]
+    pub risk_weights: UncheckedAccount<'info>,
+
+    /// Registry-scoped audit summary (created on the first logged admin action)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + AgentAuditSummary::INIT_SPACE,
+        seeds = [AgentAuditSummary::SEED_PREFIX, registry.key().as_ref()],
+        bump
+    )]
+    pub registry_audit_summary: Account<'info, AgentAuditSummary>,
+
+    /// Registry audit entry recording the verification
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AuditEntry::INIT_SPACE,
+        seeds = [
+            AuditEntry::SEED_PREFIX,
+            registry.key().as_ref(),
+            registry_audit_summary.total_entries.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub registry_audit_entry: Account<'info, AuditEntry>,
+
+    /// CHECK: Signer's verified verifier record (may not exist = not verified)
+    #[account(
+        seeds = [VerifiedVerifier::SEED_PREFIX, admin.key().as_ref()],
+        bump
+    )]
+    pub verifier_record: Option<UncheckedAccount<'info>>,
+
+    /// Paid verification request being serviced (closed, fee claimed by the signer)
+    #[account(
+        mut,
+        seeds = [VerificationRequest::SEED_PREFIX, agent.key().as_ref()],
+        bump = verification_request.bump
+    )]
+    pub verification_request: Option<Account<'info, VerificationRequest>>,
+
+    /// CHECK: Requester wallet receiving the request rent (validated in the handler)
+    #[account(mut)]
+    pub requester: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<VerifyAgent>) -> Result<()> {
+    let signer = ctx.accounts.admin.key();
+    let is_admin = signer == ctx.accounts.registry.admin;
     let agent = &mut ctx.accounts.agent;
 
     require!(!agent.verified, RegistryError::AlreadyVerified);
 
+    if let Some(request) = ctx.accounts.verification_request.as_ref() {
+        let is_verifier = match ctx.accounts.verifier_record.as_ref() {
+            Some(record) => VerifiedVerifier::is_active(record)?,
+            None => false,
+        };
+        require!(
+            (is_admin || is_verifier) && !agent.is_responder(&signer),
+            RegistryError::Unauthorized
+        );
+        let requester = ctx
+            .accounts
+            .requester
+            .as_ref()
+            .ok_or(RegistryError::Unauthorized)?;
+        require_keys_eq!(requester.key(), request.requester, RegistryError::Unauthorized);
+
+        transfer_from_pda(
+            &request.to_account_info(),
+            &ctx.accounts.admin.to_account_info(),
+            request.fee,
+        )?;
+        request.close(requester.to_account_info())?;
+
+        emit!(VerificationRequestClosed {
+            agent: agent.key(),
+            requester: request.requester,
+            handled_by: signer,
+            verified: true,
+            fee: request.fee,
+            reason_hash: [0u8; 32],
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    } else {
+        require!(is_admin, RegistryError::Unauthorized);
+    }
+
     agent.verified = true;
 
     let clock = Clock::get()?;
     agent.updated_at = clock.unix_timestamp;
 
+    let summary = &mut ctx.accounts.registry_audit_summary;
+    summary.agent = ctx.accounts.registry.key();
+    summary.bump = ctx.bumps.registry_audit_summary;
+    ctx.accounts.registry_audit_entry.bump = ctx.bumps.registry_audit_entry;
+    log_registry_action(
+        ctx.accounts.admin.key(),
+        summary,
+        &mut ctx.accounts.registry_audit_entry,
+        &ctx.accounts.risk_weights,
+        RegistryAction::AgentVerified,
+        agent.key(),
+        &[],
+    )?;
+
     msg!("Agent verified: id={}, name={}", agent.agent_id, agent.name);
 
     Ok(())
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::{AuditInclusionAttestation, MerkleAuditRoot, MerkleProofNode};
+use crate::errors::RegistryError;
+use crate::events::AuditInclusionVerified;
+
+/// Accounts for proving an audit entry against a stored Merkle audit root
+#[derive(Accounts)]
+#[instruction(leaf_hash: [u8; 32])]
+pub struct VerifyAuditInclusion<'info> {
+    /// Anyone can submit a proof (pays for the attestation)
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    /// The stored batch root
+    #[account(
+        seeds = [
+            MerkleAuditRoot::SEED_PREFIX,
+            audit_root.agent.as_ref(),
+            audit_root.epoch.to_le_bytes().as_ref(),
+            audit_root.sequence.to_le_bytes().as_ref()
+        ],
+        bump = audit_root.bump
+    )]
+    pub audit_root: Account<'info, MerkleAuditRoot>,
+
+    /// Attestation that the leaf is part of the batch (one per root and leaf)
+    #[account(
+        init,
+        payer = verifier,
+        space = 8 + AuditInclusionAttestation::INIT_SPACE,
+        seeds = [
+            AuditInclusionAttestation::SEED_PREFIX,
+            audit_root.key().as_ref(),
+            leaf_hash.as_ref()
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, AuditInclusionAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<VerifyAuditInclusion>,
+    leaf_hash: [u8; 32],
+    proof: Vec<MerkleProofNode>,
+) -> Result<()> {
+    require!(
+        proof.len() <= MerkleAuditRoot::MAX_PROOF_DEPTH,
+        RegistryError::MerkleProofTooLong
+    );
+
+    let audit_root = &ctx.accounts.audit_root;
+    require!(
+        audit_root.verify(&leaf_hash, &proof),
+        RegistryError::InvalidMerkleProof
+    );
+
+    let clock = Clock::get()?;
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.audit_root = audit_root.key();
+    attestation.agent = audit_root.agent;
+    attestation.leaf_hash = leaf_hash;
+    attestation.batch_index = audit_root.batch_index;
+    attestation.verified_by = ctx.accounts.verifier.key();
+    attestation.verified_at = clock.unix_timestamp;
+    attestation.bump = ctx.bumps.attestation;
+
+    emit!(AuditInclusionVerified {
+        audit_root: attestation.audit_root,
+        agent: attestation.agent,
+        leaf_hash,
+        batch_index: attestation.batch_index,
+        verified_by: attestation.verified_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Audit inclusion verified: agent={}, batch={}, leaf={:?}, depth={}",
+        attestation.agent,
+        attestation.batch_index,
+        &leaf_hash[..8],
+        proof.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AgentAccount, StakePosition, StakeVault};
+use crate::errors::RegistryError;
+use crate::utils::{pay_out_stake, transfer_from_pda, TokenMint};
+
+/// Withdraw an unbonded stake position and close it (staker only)
+/// Pays out the position's share of the vault after any slashing
+/// (SPL vaults pay into the staker's token account), plus unclaimed staking rewards
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    /// The staked agent (staked_amount is synced)
+    #[account(
+        mut,
+        seeds = [
+            AgentAccount::SEED_PREFIX,
+            agent.pda_owner().as_ref(),
+            agent.agent_id.to_le_bytes().as_ref()
+        ],
+        bump = agent.bump
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [StakeVault::SEED_PREFIX, agent.key().as_ref()],
+        bump = stake_vault.bump
+    )]
+    pub stake_vault: Account<'info, StakeVault>,
+
+    #[account(
+        mut,
+        close = staker,
+        seeds = [
+            StakePosition::SEED_PREFIX,
+            stake_vault.key().as_ref(),
+            staker.key().as_ref(),
+        ],
+        bump = stake_position.bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    /// Token account owned by the vault PDA (SPL vaults only)
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Staker's token account receiving the withdrawal (SPL vaults only)
+    #[account(mut, token::authority = staker)]
+    pub staker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Stake mint (SPL vaults only, needed for transfer_checked)
+    pub stake_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawStake<'info>>) -> Result<()> {
+    let position = &mut ctx.accounts.stake_position;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        position.is_unbonding() && now >= position.unbonding_at,
+        RegistryError::StakeLocked
+    );
+
+    let vault = &mut ctx.accounts.stake_vault;
+    position.settle_rewards(vault.reward_per_share);
+    let rewards = position.unclaimed_rewards;
+    position.unclaimed_rewards = 0;
+    let amount = vault.amount_for(position.shares);
+    vault.total_shares = vault.total_shares.saturating_sub(position.shares);
+    vault.total_staked = vault.total_staked.saturating_sub(amount);
+    ctx.accounts.agent.staked_amount = vault.total_staked;
+    pay_out_stake(
+        vault,
+        &ctx.accounts.staker.to_account_info(),
+        ctx.accounts.vault_token_account.as_ref(),
+        ctx.accounts.staker_token_account.as_ref(),
+        TokenMint::from_accounts(
+            ctx.accounts.stake_mint.as_ref(),
+            ctx.accounts.token_program.as_ref(),
+            ctx.remaining_accounts,
+        ),
+        amount,
+    )?;
+    transfer_from_pda(
+        &vault.to_account_info(),
+        &ctx.accounts.staker.to_account_info(),
+        rewards,
+    )?;
+
+    msg!(
+        "Stake withdrawn by {}: {} (mint {}) and {} reward lamports. Total staked: {}",
+        position.staker,
+        amount,
+        vault.mint,
+        rewards,
+        vault.total_staked
+    );
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, PaymentStream};
+use crate::errors::RegistryError;
+use crate::events::StreamWithdrawn;
+use crate::utils::transfer_from_pda;
+
+/// Withdraw the vested part of a payment stream (agent owner only)
+/// Once the stream has ended and is fully withdrawn it is closed, rent to the client
+#[derive(Accounts)]
+pub struct WithdrawStream<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        address = stream.agent @ RegistryError::Unauthorized,
+        constraint = agent.owner == owner.key() @ RegistryError::Unauthorized
+    )]
+    pub agent: Account<'info, AgentAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            PaymentStream::SEED_PREFIX,
+            stream.client.as_ref(),
+            stream.agent.as_ref(),
+            stream.nonce.to_le_bytes().as_ref()
+        ],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, PaymentStream>,
+
+    /// CHECK: Stream client receiving the rent on close (validated against stream.client)
+    #[account(mut, address = stream.client @ RegistryError::Unauthorized)]
+    pub client: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawStream>) -> Result<()> {
+    let clock = Clock::get()?;
+    let stream = &mut ctx.accounts.stream;
+    let amount = stream.withdrawable(clock.unix_timestamp);
+    require!(amount > 0, RegistryError::StreamNothingVested);
+
+    transfer_from_pda(&stream.to_account_info(), &ctx.accounts.owner.to_account_info(), amount)?;
+    stream.withdrawn += amount;
+
+    emit!(StreamWithdrawn {
+        stream: stream.key(),
+        agent: stream.agent,
+        amount,
+        withdrawn: stream.withdrawn,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Payment stream withdrawal: {} lamports ({} of {})",
+        amount,
+        stream.withdrawn,
+        stream.total()
+    );
+
+    if stream.withdrawn >= stream.total() {
+        stream.close(ctx.accounts.client.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -3,8 +3,15 @@ use anchor_lang::prelude::*;
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod events;
+pub mod utils;
+pub mod zk;
 
 use instructions::*;
+use state::{
+    AclMode, ChallengeKind, CommitmentScheme, Groth16Proof, IncidentClass, IncidentSeverity,
+    MerkleProofNode, PriceUnit, RedactionReason, ReportCategory, SlashTrigger,
+};
 
 declare_id!("EQ2Zv3cTDBzY1PafPz2WDoup6niUv6X8t9id4PBACL38");
 
@@ -17,6 +24,88 @@ pub mod agent_registry {
         instructions::initialize::handler(ctx)
     }
 
+    /// Create the registry config with default parameters (admin only, one-time)
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::handler(ctx)
+    }
+
+    /// Update registry config parameters (admin only)
+    /// Logged to the registry audit stream with the serialized params as details
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
+    }
+
+    /// Create the protocol treasury that collects challenge fees (admin only, one-time)
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::initialize_treasury::handler(ctx)
+    }
+
+    /// Set or replace the multisig council governing treasury spends (admin only)
+    /// Replacing a council also needs its current threshold of member co-signers
+    pub fn set_treasury_council(
+        ctx: Context<SetTreasuryCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        timelock_secs: u32,
+    ) -> Result<()> {
+        instructions::set_treasury_council::handler(ctx, members, threshold, timelock_secs)
+    }
+
+    /// Propose paying lamports or tokens out of the treasury (council member only)
+    pub fn propose_spend(
+        ctx: Context<ProposeSpend>,
+        recipient: Pubkey,
+        amount: u64,
+        memo_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::propose_spend::handler(ctx, recipient, amount, memo_hash)
+    }
+
+    /// Approve a proposed treasury spend (council member only)
+    pub fn approve_spend(ctx: Context<ApproveSpend>) -> Result<()> {
+        instructions::approve_spend::handler(ctx)
+    }
+
+    /// Pay out a treasury spend once approved and past its timelock (anyone can call)
+    /// A spend that lost its approvals to council changes has its timelock reset instead
+    pub fn execute_spend<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSpend<'info>>,
+    ) -> Result<()> {
+        instructions::execute_spend::handler(ctx)
+    }
+
+    /// Withdraw a treasury spend proposal before execution (proposer only)
+    pub fn cancel_spend(ctx: Context<CancelSpend>) -> Result<()> {
+        instructions::cancel_spend::handler(ctx)
+    }
+
+    /// Mark a verifier as verified (fee exempt) or revoke it (admin only)
+    pub fn set_verified_verifier(
+        ctx: Context<SetVerifiedVerifier>,
+        verifier: Pubkey,
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_verified_verifier::handler(ctx, verifier, active)
+    }
+
+    /// Allowlist or remove an external verifier program for CPI resolution (admin only)
+    pub fn set_verifier_program(
+        ctx: Context<SetVerifierProgram>,
+        program_id: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::set_verifier_program::handler(ctx, program_id, allowed)
+    }
+
+    /// Register a Groth16 verifying key for proof-of-inference challenges (admin only)
+    pub fn register_zk_verifying_key(
+        ctx: Context<RegisterZkVerifyingKey>,
+        circuit_id: u64,
+        params: VerifyingKeyParams,
+    ) -> Result<()> {
+        instructions::register_zk_verifying_key::handler(ctx, circuit_id, params)
+    }
+
     /// Set the NFT collection address for agent identities (admin only, one-time)
     /// The collection itself is created off-chain using Metaplex SDK
     pub fn create_collection(ctx: Context<CreateCollection>) -> Result<()> {
@@ -43,12 +132,101 @@ pub mod agent_registry {
         instructions::update_agent::handler(ctx, name, capabilities)
     }
 
-    /// Verify an agent (admin only)
+    /// Create or update the optional profile extension for an agent
+    /// Holds rarely-changing metadata (description hash, links, avatar, jurisdiction)
+    pub fn update_agent_profile(
+        ctx: Context<UpdateAgentProfile>,
+        description_hash: Option<String>,
+        links: Option<String>,
+        avatar_uri: Option<String>,
+        jurisdiction: Option<String>,
+    ) -> Result<()> {
+        instructions::update_agent_profile::handler(
+            ctx,
+            description_hash,
+            links,
+            avatar_uri,
+            jurisdiction,
+        )
+    }
+
+    /// Upgrade a legacy agent account to the current layout (realloc)
+    /// Preserves reputation and history across program upgrades
+    pub fn upgrade_agent_account(ctx: Context<UpgradeAgentAccount>) -> Result<()> {
+        instructions::upgrade_agent_account::handler(ctx)
+    }
+
+    /// Carry a discounted share of an old agent's reputation to a new agent (owner only)
+    /// Retires the old agent and logs the lineage in the new agent's audit trail
+    pub fn migrate_reputation(ctx: Context<MigrateReputation>) -> Result<()> {
+        instructions::migrate_reputation::handler(ctx)
+    }
+
+    /// Set or clear the agent's operator key (owner only)
+    /// The operator may submit challenge responses; default pubkey revokes
+    pub fn set_agent_operator(ctx: Context<SetAgentOperator>, operator: Pubkey) -> Result<()> {
+        instructions::set_agent_operator::handler(ctx, operator)
+    }
+
+    /// Restrict who may challenge an agent (allowlist or blocklist, owner only)
+    pub fn set_challenger_acl(
+        ctx: Context<SetChallengerAcl>,
+        mode: AclMode,
+        entries: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_challenger_acl::handler(ctx, mode, entries)
+    }
+
+    /// Request owner recovery as the current holder of the identity NFT
+    /// The new owner can be installed after a delay; the owner may cancel meanwhile
+    pub fn recover_agent(ctx: Context<RecoverAgent>, new_owner: Pubkey) -> Result<()> {
+        instructions::recover_agent::handler(ctx, new_owner)
+    }
+
+    /// Finalize a pending recovery after the delay (NFT holder only)
+    pub fn finalize_recovery(ctx: Context<FinalizeRecovery>) -> Result<()> {
+        instructions::finalize_recovery::handler(ctx)
+    }
+
+    /// Cancel a pending recovery (current owner only)
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::cancel_recovery::handler(ctx)
+    }
+
+    /// Escrow a fee to queue an agent for verification (owner only)
+    pub fn request_verification(ctx: Context<RequestVerification>, fee: u64) -> Result<()> {
+        instructions::request_verification::handler(ctx, fee)
+    }
+
+    /// Verify an agent (logged to the registry audit stream)
+    /// Admin only, or admin/verified verifier when servicing a paid request (claims its fee)
     pub fn verify_agent(ctx: Context<VerifyAgent>) -> Result<()> {
         instructions::verify_agent::handler(ctx)
     }
 
-    /// Update agent reputation (called by challenge program)
+    /// Reject a verification request and refund its fee (admin, verified verifier or requester)
+    pub fn reject_verification(
+        ctx: Context<RejectVerification>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::reject_verification::handler(ctx, reason_hash)
+    }
+
+    /// Lift an audit circuit breaker suspension (admin only, logged to the registry audit stream)
+    pub fn reinstate_agent(ctx: Context<ReinstateAgent>) -> Result<()> {
+        instructions::reinstate_agent::handler(ctx)
+    }
+
+    /// Assign an agent's sentinel guardian (admin only)
+    /// Only the sentinel may log SecurityAlert or high-risk audit entries for the agent
+    pub fn set_sentinel_authority(
+        ctx: Context<SetSentinelAuthority>,
+        sentinel_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::set_sentinel_authority::handler(ctx, sentinel_authority)
+    }
+
+    /// Update agent reputation (admin, or an AuthorizedUpdaters signer via CPI)
     pub fn update_reputation(
         ctx: Context<UpdateReputation>,
         delta: i32,
@@ -56,32 +234,485 @@ pub mod agent_registry {
         instructions::update_reputation::handler(ctx, delta)
     }
 
+    /// Add or remove a signer allowed to call update_reputation (admin only)
+    /// Typically the PDA an integrating program signs with via CPI
+    pub fn set_authorized_updater(
+        ctx: Context<SetAuthorizedUpdater>,
+        updater: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::set_authorized_updater::handler(ctx, updater, allowed)
+    }
+
+    /// Record the agent's reputation for the current epoch (anyone can call, once per epoch)
+    /// Kept in a per-agent ring buffer of the latest ReputationSnapshots::MAX_SNAPSHOTS epochs
+    pub fn snapshot_reputation(ctx: Context<SnapshotReputation>) -> Result<()> {
+        instructions::snapshot_reputation::handler(ctx)
+    }
+
+    /// Get an agent's reputation estimate (view function)
+    /// Pass-rate point estimate and 95% lower confidence bound next to the additive score
+    pub fn get_reputation(
+        ctx: Context<GetReputation>,
+    ) -> Result<instructions::get_reputation::ReputationEstimate> {
+        instructions::get_reputation::handler(ctx)
+    }
+
+    /// Submit an agent to the top-N reputation leaderboard (anyone can call)
+    /// Refreshes a listed agent, or inserts one that beats the current minimum
+    pub fn update_leaderboard(ctx: Context<UpdateLeaderboard>) -> Result<()> {
+        instructions::update_leaderboard::handler(ctx)
+    }
+
+    /// Record a Merkle root over (agent, score) pairs for the current epoch (anyone can call)
+    /// Agents are passed as remaining_accounts in ascending key order; the root is computed on-chain
+    pub fn checkpoint_reputations<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CheckpointReputations<'info>>,
+        epoch: u64,
+        batch_index: u32,
+    ) -> Result<()> {
+        instructions::checkpoint_reputations::handler(ctx, epoch, batch_index)
+    }
+
+    /// Publish a reputation recalibration curve (admin only), bumping the schema version
+    /// Curve point i is the new score for an old score of i * 1000 (linear in between)
+    pub fn set_reputation_schema(ctx: Context<SetReputationSchema>, curve: [u16; 11]) -> Result<()> {
+        instructions::set_reputation_schema::handler(ctx, curve)
+    }
+
+    /// Apply the reputation curves an agent missed (permissionless, once per schema version)
+    pub fn recalibrate_reputation(ctx: Context<RecalibrateReputation>) -> Result<()> {
+        instructions::recalibrate_reputation::handler(ctx)
+    }
+
+    /// Lock SOL, or SPL tokens of config.stake_mint, behind an agent (owner or any backer)
+    /// Boosts reputation gains (see RegistryConfig); slashable on failed or expired challenges
+    pub fn stake_agent<'info>(
+        ctx: Context<'_, '_, 'info, 'info, StakeAgent<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::stake_agent::handler(ctx, amount)
+    }
+
+    /// Start unbonding a stake position (withdrawable after StakeVault::UNBONDING_PERIOD)
+    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+        instructions::request_unstake::handler(ctx)
+    }
+
+    /// Withdraw an unbonded stake position (its share of the vault after slashing)
+    pub fn withdraw_stake<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawStake<'info>>,
+    ) -> Result<()> {
+        instructions::withdraw_stake::handler(ctx)
+    }
+
+    /// Add reward lamports to an agent's stake vault, shared pro-rata by its stakers (anyone)
+    pub fn fund_stake_rewards(ctx: Context<FundStakeRewards>, amount: u64) -> Result<()> {
+        instructions::fund_stake_rewards::handler(ctx, amount)
+    }
+
+    /// Claim a stake position's accrued staking rewards
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        instructions::claim_rewards::handler(ctx)
+    }
+
+    /// Vouch for another agent with a verified agent, locking a small stake
+    /// Grants a bounded reputation bonus (Endorsement::BONUS, capped per agent)
+    pub fn endorse_agent(ctx: Context<EndorseAgent>, amount: u64) -> Result<()> {
+        instructions::endorse_agent::handler(ctx, amount)
+    }
+
+    /// Revoke an endorsement after its lock period, removing the bonus and returning the stake
+    pub fn revoke_endorsement(ctx: Context<RevokeEndorsement>) -> Result<()> {
+        instructions::revoke_endorsement::handler(ctx)
+    }
+
+    /// Claw back an endorsement after the endorsed agent logged a critical-risk audit entry
+    /// Permissionless: removes the bonus, penalizes the endorser, stake goes to the treasury
+    pub fn clawback_endorsement(ctx: Context<ClawbackEndorsement>) -> Result<()> {
+        instructions::clawback_endorsement::handler(ctx)
+    }
+
     /// Create a new challenge for an agent (nonce enables multiple challenges per pair)
+    /// expected_hash is a commitment: SHA256(answer_hash || salt)
+    /// Locks the configured challenger bond and optional reward in the challenge PDA
+    /// Verified agents require a tier-scaled minimum stake as the bond
+    /// Charges config.challenge_fee_bps of the escrow to the treasury (verified verifiers exempt)
+    /// options.difficulty scales the pass/fail reputation deltas
+    /// options.verifier delegates scoring to a third party via resolve_challenge
+    /// options.verifiers + quorum delegate scoring to an M-of-N panel via attest_result
+    /// options.oracle_feed delegates scoring to a Switchboard feed
+    /// options.verifier_program delegates scoring to an allowlisted program via CPI
+    /// Passing a zk_verifying_key account switches to proof-of-inference mode
+    /// options.rounds adds follow-up commit-reveal rounds, scored per round
+    /// options.metadata (URI + content hash) and options.tags describe the challenge for explorers
+    /// Rate limited per agent (daily) and per challenger (pending) via RegistryConfig
+    /// Records the challenger's latest nonce in the agent's ChallengeIndex
+    /// Gains are boosted by the agent's stake vault balance at creation
+    /// Passing a challenger_agent account attributes the challenge to that agent (peer challenge)
+    /// Rejected if the agent's challenger ACL does not allow the challenger
     pub fn create_challenge(
         ctx: Context<CreateChallenge>,
         question: String,
         expected_hash: String,
         nonce: u64,
+        options: ChallengeOptions,
+    ) -> Result<()> {
+        instructions::create_challenge::handler(ctx, question, expected_hash, nonce, options)
+    }
+
+    /// Publish a reusable challenge template (question hash, scheme, kind, difficulty)
+    pub fn publish_challenge_template(
+        ctx: Context<PublishChallengeTemplate>,
+        template_id: u64,
+        question_hash: String,
+        scheme: CommitmentScheme,
+        kind: ChallengeKind,
+        difficulty: u8,
     ) -> Result<()> {
-        instructions::create_challenge::handler(ctx, question, expected_hash, nonce)
+        instructions::publish_challenge_template::handler(
+            ctx,
+            template_id,
+            question_hash,
+            scheme,
+            kind,
+            difficulty,
+        )
     }
 
-    /// Submit a response to a challenge (verifies and updates reputation)
+    /// Create a challenge from a template (question must match the template hash)
+    pub fn create_challenge_from_template(
+        ctx: Context<CreateChallengeFromTemplate>,
+        question: String,
+        expected_hash: String,
+        nonce: u64,
+        reward_lamports: u64,
+    ) -> Result<()> {
+        instructions::create_challenge_from_template::handler(
+            ctx,
+            question,
+            expected_hash,
+            nonce,
+            reward_lamports,
+        )
+    }
+
+    /// Submit a response to a challenge (verified when the challenger reveals)
+    /// evidence_uri optionally points to the full output (Arweave / IPFS) for auditors
+    /// Signed by the agent owner or its operator key
     pub fn submit_response(
         ctx: Context<SubmitResponse>,
         response_hash: String,
         nonce: u64,
+        evidence_uri: Option<String>,
+    ) -> Result<()> {
+        instructions::submit_response::handler(ctx, response_hash, nonce, evidence_uri)
+    }
+
+    /// Answer a proof-of-inference challenge with a Groth16 proof
+    /// Verified on-chain via the alt_bn128 syscalls; a valid proof passes immediately
+    pub fn submit_zk_response(
+        ctx: Context<SubmitZkResponse>,
+        response_hash: String,
+        nonce: u64,
+        proof: Groth16Proof,
+    ) -> Result<()> {
+        instructions::submit_zk_response::handler(ctx, response_hash, nonce, proof)
+    }
+
+    /// Reveal the committed answer hash and salt and resolve a responded challenge
+    /// The opening must match the commitment; the revealed answer is then
+    /// compared with the agent's response, updates reputation
+    /// and pays the escrowed reward to the agent owner on pass
+    /// Multi-round challenges advance to the next round until the last is revealed
+    pub fn reveal_challenge(
+        ctx: Context<RevealChallenge>,
+        nonce: u64,
+        answer_hash: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_challenge::handler(ctx, nonce, answer_hash, salt)
+    }
+
+    /// Resolve a responded challenge as its designated verifier
+    /// Enables human or oracle graders for open-ended questions
+    /// score (0-100) maps to a proportional reputation delta; 50+ passes
+    /// Gated by config.min_reputation_verifier on the verifier's agent
+    pub fn resolve_challenge(
+        ctx: Context<ResolveChallenge>,
+        nonce: u64,
+        score: u8,
+    ) -> Result<()> {
+        instructions::resolve_challenge::handler(ctx, nonce, score)
+    }
+
+    /// Attest a verdict as one member of the challenge's verifier panel
+    /// Finalizes the challenge once the M-of-N quorum decides it
+    /// Gated by config.min_reputation_verifier on the verifier's agent
+    pub fn attest_result(ctx: Context<AttestResult>, nonce: u64, passed: bool) -> Result<()> {
+        instructions::attest_result::handler(ctx, nonce, passed)
+    }
+
+    /// Resolve a responded challenge from its Switchboard feed result
+    /// Permissionless: the feed authority must match config.oracle_authority
+    pub fn resolve_challenge_with_oracle(
+        ctx: Context<ResolveChallengeWithOracle>,
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::resolve_challenge_with_oracle::handler(ctx, nonce)
+    }
+
+    /// Resolve a responded challenge by CPI into its allowlisted verifier program
+    /// Permissionless: payload must hash to the response; the program returns a 0-100 score
+    /// Accounts the verifier needs are passed as remaining_accounts
+    pub fn resolve_with_verifier_program<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveWithVerifierProgram<'info>>,
+        nonce: u64,
+        payload: Vec<u8>,
+    ) -> Result<()> {
+        instructions::resolve_with_verifier_program::handler(ctx, nonce, payload)
+    }
+
+    /// Dispute a pass/fail verdict within the dispute window (agent owner or challenger)
+    /// Locks the configured dispute bond until the dispute is arbitrated
+    /// The verdict's reputation delta is withheld from the agent until arbitration
+    /// (arbitration corrects reputation only, not the released challenge escrow)
+    pub fn dispute_challenge(
+        ctx: Context<DisputeChallenge>,
+        nonce: u64,
+        reason_hash: String,
+    ) -> Result<()> {
+        instructions::dispute_challenge::handler(ctx, nonce, reason_hash)
+    }
+
+    /// Settle a disputed challenge (registry admin as arbitrator)
+    /// Rejecting restores the withheld reputation delta; overturning applies the opposite verdict
+    /// Only reputation is corrected: the already-released challenge escrow is not moved
+    pub fn arbitrate_dispute(
+        ctx: Context<ArbitrateDispute>,
+        nonce: u64,
+        overturn: bool,
+    ) -> Result<()> {
+        instructions::arbitrate_dispute::handler(ctx, nonce, overturn)
+    }
+
+    /// Report agent misbehavior with a SHA256 evidence commitment, locking the report bond
+    pub fn report_agent(
+        ctx: Context<ReportAgent>,
+        nonce: u64,
+        category: ReportCategory,
+        evidence_hash: String,
+    ) -> Result<()> {
+        instructions::report_agent::handler(ctx, nonce, category, evidence_hash)
+    }
+
+    /// Settle a misbehavior report (admin or verified verifier)
+    /// Upheld: reputation penalty, optional stake slash, bond refunded; dismissed: bond to treasury
+    pub fn resolve_report<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveReport<'info>>,
+        nonce: u64,
+        upheld: bool,
+        penalty: u32,
+        slash_bps: u16,
+    ) -> Result<()> {
+        instructions::resolve_report::handler(ctx, nonce, upheld, penalty, slash_bps)
+    }
+
+    /// Create the shared insurance pool PDA (admin only, one-time)
+    pub fn initialize_insurance_pool(ctx: Context<InitializeInsurancePool>) -> Result<()> {
+        instructions::initialize_insurance_pool::handler(ctx)
+    }
+
+    /// Pay an insurance premium for an agent into the pool (owner only, opens the policy)
+    pub fn pay_insurance_premium(ctx: Context<PayInsurancePremium>, amount: u64) -> Result<()> {
+        instructions::pay_insurance_premium::handler(ctx, amount)
+    }
+
+    /// File a bonded claim against an insured agent, backed by one of its slash records
+    /// and by the claimant's task, payment stream or challenge with that agent
+    pub fn file_insurance_claim(
+        ctx: Context<FileInsuranceClaim>,
+        amount: u64,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::file_insurance_claim::handler(ctx, amount, evidence_hash)
+    }
+
+    /// Settle an insurance claim (admin or verified verifier)
+    /// Approved: payout from the pool and bond refunded; denied: bond to treasury
+    pub fn arbitrate_insurance_claim(
+        ctx: Context<ArbitrateInsuranceClaim>,
+        approved: bool,
+        payout: u64,
+    ) -> Result<()> {
+        instructions::arbitrate_insurance_claim::handler(ctx, approved, payout)
+    }
+
+    /// Post a task and escrow its payment (client)
+    /// SPL and Token-2022 payments escrow in a token account owned by the task
+    pub fn post_task<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PostTask<'info>>,
+        nonce: u64,
+        payment: u64,
+        spec_hash: [u8; 32],
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::post_task::handler(ctx, nonce, payment, spec_hash, deadline)
+    }
+
+    /// Assign an open task to an active agent off probation (client only)
+    pub fn assign_task(ctx: Context<AssignTask>) -> Result<()> {
+        instructions::assign_task::handler(ctx)
+    }
+
+    /// Submit the result hash for an assigned task before its deadline (agent owner or operator)
+    pub fn submit_task_result(ctx: Context<SubmitTaskResult>, result_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_task_result::handler(ctx, result_hash)
+    }
+
+    /// Accept a task result: payment to the agent owner plus reputation credit
+    /// Client only until the review window ends, then anyone
+    pub fn accept_task_result<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AcceptTaskResult<'info>>,
+    ) -> Result<()> {
+        instructions::accept_task_result::handler(ctx)
+    }
+
+    /// Dispute a submitted task result within the review window (client only)
+    pub fn dispute_task(ctx: Context<DisputeTask>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::dispute_task::handler(ctx, reason_hash)
+    }
+
+    /// Settle a disputed task: pay the agent or refund the client (admin only)
+    pub fn arbitrate_task<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ArbitrateTask<'info>>,
+        agent_wins: bool,
+    ) -> Result<()> {
+        instructions::arbitrate_task::handler(ctx, agent_wins)
+    }
+
+    /// Cancel an open task, or an assigned one past its deadline, refunding the client
+    pub fn cancel_task<'info>(ctx: Context<'_, '_, 'info, 'info, CancelTask<'info>>) -> Result<()> {
+        instructions::cancel_task::handler(ctx)
+    }
+
+    /// Open a payment stream to an agent, escrowing rate * duration (client)
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        nonce: u64,
+        rate_per_sec: u64,
+        start_ts: i64,
+        end_ts: i64,
     ) -> Result<()> {
-        instructions::submit_response::handler(ctx, response_hash, nonce)
+        instructions::create_stream::handler(ctx, nonce, rate_per_sec, start_ts, end_ts)
+    }
+
+    /// Withdraw the vested part of a payment stream (agent owner only)
+    pub fn withdraw_stream(ctx: Context<WithdrawStream>) -> Result<()> {
+        instructions::withdraw_stream::handler(ctx)
+    }
+
+    /// Stop a payment stream: vested part to the agent owner, the rest refunded (either party)
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        instructions::cancel_stream::handler(ctx)
     }
 
     /// Expire a challenge that was not responded to in time
     /// Can be called by anyone - permissionless cleanup
     /// Agent receives penalty for not responding
+    /// The caller earns a share of the challenger bond as a keeper bounty
+    /// The escrowed reward and remaining bond are refunded to the challenger
     pub fn expire_challenge(ctx: Context<ExpireChallenge>, nonce: u64) -> Result<()> {
         instructions::expire_challenge::handler(ctx, nonce)
     }
 
+    /// Expire many stale challenges at once
+    /// remaining_accounts: (agent, challenge, agent owner, challenger, challenger stats) groups, all writable
+    pub fn expire_challenges_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExpireChallengesBatch<'info>>,
+    ) -> Result<()> {
+        instructions::expire_challenges_batch::handler(ctx)
+    }
+
+    /// Record an agent-to-agent challenge outcome on the challenger agent (anyone can call)
+    /// Once per challenge, after the verdict is final and before the challenge is closed
+    pub fn record_peer_result(ctx: Context<RecordPeerResult>, nonce: u64) -> Result<()> {
+        instructions::record_peer_result::handler(ctx, nonce)
+    }
+
+    /// Publish or update a priced service listing for an agent (owner only)
+    /// Listings carry a reputation snapshot so discovery needs no agent lookups
+    pub fn set_service_listing(
+        ctx: Context<SetServiceListing>,
+        listing_id: u16,
+        capability: ChallengeKind,
+        price_lamports: u64,
+        unit: PriceUnit,
+        sla_hash: [u8; 32],
+        active: bool,
+    ) -> Result<()> {
+        instructions::set_service_listing::handler(
+            ctx,
+            listing_id,
+            capability,
+            price_lamports,
+            unit,
+            sla_hash,
+            active,
+        )
+    }
+
+    /// Re-sync a listing's reputation snapshot with its agent (anyone can call)
+    pub fn refresh_service_listing(ctx: Context<RefreshServiceListing>) -> Result<()> {
+        instructions::refresh_service_listing::handler(ctx)
+    }
+
+    /// Remove a service listing and reclaim its rent (owner only)
+    pub fn close_service_listing(ctx: Context<CloseServiceListing>) -> Result<()> {
+        instructions::close_service_listing::handler(ctx)
+    }
+
+    /// Apply a final verdict to the agent's per-domain reputation (anyone can call)
+    /// Once per challenge, after the verdict is final and before the challenge is closed
+    pub fn record_domain_result(ctx: Context<RecordDomainResult>, nonce: u64) -> Result<()> {
+        instructions::record_domain_result::handler(ctx, nonce)
+    }
+
+    /// Slash the agent's stake to the treasury for a failed or expired boosted challenge (anyone can call)
+    /// Once per challenge, after the verdict is final and before the challenge is closed
+    pub fn slash_stake<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SlashStake<'info>>,
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::slash_stake::handler(ctx, nonce)
+    }
+
+    /// Slash the agent's stake for a verifier-confirmed failure or a Critical audit entry
+    /// Anyone can call, once per trigger; the affected challenger can receive a share
+    /// when an independent, verified verifier confirmed the failure
+    pub fn slash_agent<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SlashAgent<'info>>,
+        trigger: SlashTrigger,
+    ) -> Result<()> {
+        instructions::slash_agent::handler(ctx, trigger)
+    }
+
+    /// Withdraw a still-pending challenge before the agent responds (challenger only)
+    /// Refunds rent, bond and reward and closes the PDA; no reputation effect
+    pub fn cancel_challenge(ctx: Context<CancelChallenge>, nonce: u64) -> Result<()> {
+        instructions::cancel_challenge::handler(ctx, nonce)
+    }
+
+    /// Extend the response deadline of a pending challenge by up to 24 hours
+    /// Challenger alone: capped at 24h after creation; agent owner co-signing: 7 days
+    pub fn extend_challenge_deadline(
+        ctx: Context<ExtendChallengeDeadline>,
+        nonce: u64,
+        extension: i64,
+    ) -> Result<()> {
+        instructions::extend_challenge_deadline::handler(ctx, nonce, extension)
+    }
+
     /// Close a resolved challenge and reclaim rent (~0.012 SOL per challenge)
     /// Only the original challenger can close, only after challenge is resolved
     /// Critical mainnet optimization: reduces per-challenge cost from 0.012 SOL to ~0 SOL
@@ -89,19 +720,317 @@ pub mod agent_registry {
         instructions::close_challenge::handler(ctx, nonce)
     }
 
+    /// Close many resolved challenges at once (passed as remaining_accounts)
+    /// Same rules as close_challenge; rent from every account goes to the challenger
+    pub fn close_challenges_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseChallengesBatch<'info>>,
+    ) -> Result<()> {
+        instructions::close_challenges_batch::handler(ctx)
+    }
+
+    /// Expire and close a challenge its challenger never cleaned up (anyone can call)
+    /// Only after the challenger's reclaim window lapses; the caller keeps a share
+    /// of the rent, the remaining lamports go back to the challenger
+    pub fn expire_and_close(ctx: Context<ExpireAndClose>, nonce: u64) -> Result<()> {
+        instructions::expire_and_close::handler(ctx, nonce)
+    }
+
+    /// Create an open challenge answerable by any registered agent
+    /// Same commit-reveal scheme as create_challenge; the earliest correct answer wins
+    /// Gated by config.min_reputation_open_challenge on the challenger's agent
+    pub fn create_open_challenge(
+        ctx: Context<CreateOpenChallenge>,
+        question: String,
+        expected_hash: String,
+        nonce: u64,
+        reward_lamports: u64,
+        kind: ChallengeKind,
+        difficulty: u8,
+    ) -> Result<()> {
+        instructions::create_open_challenge::handler(
+            ctx,
+            question,
+            expected_hash,
+            nonce,
+            reward_lamports,
+            kind,
+            difficulty,
+        )
+    }
+
+    /// Submit an answer to an open challenge before its deadline (agents on probation cannot)
+    /// The response hash is SHA256(answer_hash || agent pubkey), binding it to the agent
+    pub fn submit_open_response(
+        ctx: Context<SubmitOpenResponse>,
+        nonce: u64,
+        response_hash: String,
+    ) -> Result<()> {
+        instructions::submit_open_response::handler(ctx, nonce, response_hash)
+    }
+
+    /// Reveal the answer hash and salt of an open challenge after its deadline
+    /// A matching opening opens the claim window; any other slashes the bond
+    pub fn reveal_open_challenge(
+        ctx: Context<RevealOpenChallenge>,
+        nonce: u64,
+        answer_hash: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_open_challenge::handler(ctx, nonce, answer_hash, salt)
+    }
+
+    /// Claim an open challenge with a correct response (earliest slot wins)
+    pub fn claim_open_challenge(ctx: Context<ClaimOpenChallenge>, nonce: u64) -> Result<()> {
+        instructions::claim_open_challenge::handler(ctx, nonce)
+    }
+
+    /// Pay the winner (or slash an unrevealed bond) and close the open challenge
+    pub fn finalize_open_challenge(ctx: Context<FinalizeOpenChallenge>, nonce: u64) -> Result<()> {
+        instructions::finalize_open_challenge::handler(ctx, nonce)
+    }
+
+    /// Close an open challenge response after the challenge is finalized
+    pub fn close_open_response(ctx: Context<CloseOpenResponse>) -> Result<()> {
+        instructions::close_open_response::handler(ctx)
+    }
+
+    /// Attach an SPL or Token-2022 reward to a pending challenge (held in a PDA-owned vault)
+    pub fn fund_token_reward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundTokenReward<'info>>,
+        nonce: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_token_reward::handler(ctx, nonce, amount)
+    }
+
+    /// Release the token reward of a resolved challenge (permissionless)
+    /// Paid to the agent owner on pass, refunded to the challenger otherwise
+    pub fn settle_token_reward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleTokenReward<'info>>,
+    ) -> Result<()> {
+        instructions::settle_token_reward::handler(ctx)
+    }
+
     // ============================================
     // SentinelAgent Security Layer Instructions
     // ============================================
 
     /// Log an audit entry for an agent (SentinelAgent)
     /// Creates immutable on-chain audit trail for compliance
+    /// A registered auditor may co-sign; the entry then records the auditor
+    /// Sensitive context can be referenced by an URI to details encrypted for the agent's readers
     pub fn log_audit(
         ctx: Context<LogAudit>,
         action_type: state::ActionType,
         context_risk: u8,
         details_hash: String,
+        encrypted_details_uri: Option<String>,
+    ) -> Result<()> {
+        instructions::log_audit::handler(
+            ctx,
+            action_type,
+            context_risk,
+            details_hash,
+            encrypted_details_uri,
+        )
+    }
+
+    /// Log an audit entry from another program over CPI
+    /// The actor is the caller's `[b"audit_actor"]` PDA, which must be an allowed audit actor;
+    /// instruction builders are available with the `cpi` feature
+    pub fn log_audit_cpi(
+        ctx: Context<LogAuditCpi>,
+        action_type: state::ActionType,
+        context_risk: u8,
+        details_hash: String,
+    ) -> Result<()> {
+        instructions::log_audit_cpi::handler(ctx, action_type, context_risk, details_hash)
+    }
+
+    /// Create a compressed audit tree for an agent (owner only)
+    /// The tree account is allocated by the client for the SPL account-compression program
+    pub fn init_audit_tree(
+        ctx: Context<InitAuditTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        instructions::init_audit_tree::handler(ctx, max_depth, max_buffer_size)
+    }
+
+    /// Log an audit entry as a leaf in the agent's compressed audit tree
+    /// Only the tree header lives on-chain; leaves are rebuilt from AuditTreeAppended events
+    pub fn log_audit_compressed(
+        ctx: Context<LogAuditCompressed>,
+        action_type: state::ActionType,
+        context_risk: u8,
+        details_hash: String,
     ) -> Result<()> {
-        instructions::log_audit::handler(ctx, action_type, context_risk, details_hash)
+        instructions::log_audit_compressed::handler(ctx, action_type, context_risk, details_hash)
+    }
+
+    /// Create a fixed-size audit ring for an agent (owner only)
+    /// Rent is paid once instead of per audit entry
+    pub fn init_audit_ring(ctx: Context<InitAuditRing>) -> Result<()> {
+        instructions::init_audit_ring::handler(ctx)
+    }
+
+    /// Log an audit entry into the agent's audit ring (owner or operator)
+    /// Overwrites the oldest entry once full; updates the audit summary like log_audit
+    pub fn log_audit_ring(
+        ctx: Context<LogAuditRing>,
+        action_type: state::ActionType,
+        context_risk: u8,
+        details_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::log_audit_ring::handler(ctx, action_type, context_risk, details_hash)
+    }
+
+    /// Log up to AuditRing::MAX_BATCH audit entries into the agent's audit ring in one transaction
+    /// The audit summary is updated once for the whole batch
+    pub fn log_audit_batch(ctx: Context<LogAuditBatch>, entries: Vec<AuditBatchItem>) -> Result<()> {
+        instructions::log_audit_batch::handler(ctx, entries)
+    }
+
+    /// Initialize a client-allocated AuditBatch account for an agent (owner only)
+    /// Holds AuditBatch::CAPACITY fixed-size entries without per-entry PDAs
+    pub fn init_audit_batch(ctx: Context<InitAuditBatch>) -> Result<()> {
+        instructions::init_audit_batch::handler(ctx)
+    }
+
+    /// Append up to AuditBatch::MAX_APPEND entries to an agent's AuditBatch (owner or operator)
+    /// Fails once the batch is full; updates the audit summary once
+    pub fn append_audit_batch(
+        ctx: Context<AppendAuditBatch>,
+        entries: Vec<AuditBatchItem>,
+    ) -> Result<()> {
+        instructions::append_audit_batch::handler(ctx, entries)
+    }
+
+    /// Grow a legacy audit summary to the current layout (per-action counters)
+    pub fn upgrade_audit_summary(ctx: Context<UpgradeAuditSummary>) -> Result<()> {
+        instructions::upgrade_audit_summary::handler(ctx)
+    }
+
+    /// Create or replace an agent's audit policy (owner or compliance officer)
+    /// Sets the alert threshold, allowed actors and per-action risk caps used by audit logging
+    pub fn set_audit_policy(ctx: Context<SetAuditPolicy>, params: AuditPolicyParams) -> Result<()> {
+        instructions::set_audit_policy::handler(ctx, params)
+    }
+
+    /// Register or update a custom audit action code (admin only)
+    /// Codes map ActionType::Custom(code) to a name and base risk score
+    pub fn set_action_code(
+        ctx: Context<SetActionCode>,
+        code: u16,
+        name: String,
+        base_risk: u8,
+    ) -> Result<()> {
+        instructions::set_action_code::handler(ctx, code, name, base_risk)
+    }
+
+    /// Publish a new base-risk table for audit scoring (admin only)
+    /// Audit entries record the weights version they were scored with
+    pub fn set_risk_weights(
+        ctx: Context<SetRiskWeights>,
+        base_risk: [u8; state::ActionType::COUNT],
+    ) -> Result<()> {
+        instructions::set_risk_weights::handler(ctx, base_risk)
+    }
+
+    /// Redact an audit entry's details, keeping a tombstone and its chain link
+    /// Requires the agent owner and the registry admin to co-sign
+    pub fn redact_audit_entry(ctx: Context<RedactAuditEntry>, reason: RedactionReason) -> Result<()> {
+        instructions::redact_audit_entry::handler(ctx, reason)
+    }
+
+    /// Roll a finished audit day up into a compact DailyDigest
+    /// Counts and risk figures come from the audit summary; the caller supplies
+    /// the Merkle root over that day's entry link hashes
+    pub fn finalize_audit_day(
+        ctx: Context<FinalizeAuditDay>,
+        day: u32,
+        merkle_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::finalize_audit_day::handler(ctx, day, merkle_root)
+    }
+
+    /// Declare the SHA256 of the agent's safety/audit policy document (owner only)
+    /// Each change is also written to the audit trail
+    pub fn set_policy_hash(ctx: Context<SetPolicyHash>, policy_hash: String) -> Result<()> {
+        instructions::set_policy_hash::handler(ctx, policy_hash)
+    }
+
+    /// Register or remove an external auditor allowed to co-sign log_audit (admin only)
+    pub fn set_auditor(ctx: Context<SetAuditor>, auditor: Pubkey, registered: bool) -> Result<()> {
+        instructions::set_auditor::handler(ctx, auditor, registered)
+    }
+
+    /// Recount an agent's audit summary from its AuditEntry accounts (anyone can crank)
+    /// Entries go in remaining_accounts in audit_index order, across as many calls as needed;
+    /// drifted statistics are corrected once the last entry is counted
+    pub fn recompute_audit_summary<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecomputeAuditSummary<'info>>,
+    ) -> Result<()> {
+        instructions::recompute_audit_summary::handler(ctx)
+    }
+
+    /// Add, re-key or remove a reader of an agent's encrypted audit details
+    /// (owner or compliance officer)
+    pub fn set_audit_reader(
+        ctx: Context<SetAuditReader>,
+        reader: Pubkey,
+        encryption_key: [u8; 32],
+        registered: bool,
+    ) -> Result<()> {
+        instructions::set_audit_reader::handler(ctx, reader, encryption_key, registered)
+    }
+
+    /// Pin a commitment to an agent's whole audit history (count, chain head and an
+    /// optional Merkle root) into a new AuditCheckpoint PDA
+    pub fn checkpoint_full_audit(
+        ctx: Context<CheckpointFullAudit>,
+        merkle_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::checkpoint_full_audit::handler(ctx, merkle_root)
+    }
+
+    /// Set how often an agent's sentinel must send a heartbeat (owner or compliance officer)
+    pub fn set_heartbeat_interval(
+        ctx: Context<SetHeartbeatInterval>,
+        interval_secs: u32,
+    ) -> Result<()> {
+        instructions::set_heartbeat_interval::handler(ctx, interval_secs)
+    }
+
+    /// Check in as an agent's monitoring sentinel
+    pub fn sentinel_heartbeat(ctx: Context<SentinelHeartbeat>) -> Result<()> {
+        instructions::sentinel_heartbeat::handler(ctx)
+    }
+
+    /// Log a SecurityAlert when an agent's sentinel missed its heartbeat (anyone can crank)
+    /// Detects the watchdog itself going dark
+    pub fn flag_missed_heartbeat(ctx: Context<FlagMissedHeartbeat>) -> Result<()> {
+        instructions::flag_missed_heartbeat::handler(ctx)
+    }
+
+    /// File a serious-incident report for an agent (EU AI Act Article 73)
+    /// Owner, operator or a designated sentinel; counted in the audit summary
+    pub fn report_incident(
+        ctx: Context<ReportIncident>,
+        class: IncidentClass,
+        severity: IncidentSeverity,
+        affected_parties: u32,
+        report_hash: String,
+        occurred_at: i64,
+    ) -> Result<()> {
+        instructions::report_incident::handler(
+            ctx,
+            class,
+            severity,
+            affected_parties,
+            report_hash,
+            occurred_at,
+        )
     }
 
     /// Get audit status for an agent (view function)
@@ -118,11 +1047,32 @@ pub mod agent_registry {
     /// Store a Merkle root of batched audit entries
     /// More gas-efficient: 1 tx for N entries instead of N txs
     /// Off-chain logs can be verified against the on-chain root
+    /// Roots are keyed by (agent, epoch, sequence); epochs may only move forward
     pub fn store_merkle_audit(
         ctx: Context<StoreMerkleAudit>,
         merkle_root: [u8; 32],
         entries_count: u32,
+        epoch: u64,
+    ) -> Result<()> {
+        instructions::store_merkle_audit::handler(ctx, merkle_root, entries_count, epoch)
+    }
+
+    /// Prove an audit entry hash is included in a stored Merkle audit root (anyone can call)
+    /// Records an attestation PDA per (root, leaf) and emits AuditInclusionVerified
+    pub fn verify_audit_inclusion(
+        ctx: Context<VerifyAuditInclusion>,
+        leaf_hash: [u8; 32],
+        proof: Vec<MerkleProofNode>,
+    ) -> Result<()> {
+        instructions::verify_audit_inclusion::handler(ctx, leaf_hash, proof)
+    }
+
+    /// Close an audit entry committed into a Merkle audit root (agent owner or original actor)
+    /// Requires an inclusion proof of the entry's details hash; rent returns to the payer
+    pub fn close_audit_entry(
+        ctx: Context<CloseAuditEntry>,
+        proof: Vec<MerkleProofNode>,
     ) -> Result<()> {
-        instructions::store_merkle_audit::handler(ctx, merkle_root, entries_count)
+        instructions::close_audit_entry::handler(ctx, proof)
     }
 }
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Which challengers may target an agent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum AclMode {
+    /// Anyone may challenge (same as having no ACL)
+    Open,
+    /// Only listed challengers may challenge
+    Allowlist,
+    /// Anyone except listed challengers may challenge
+    Blocklist,
+}
+
+/// Per-agent challenger access list (owner managed, optional)
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengerAcl {
+    /// The agent this ACL protects
+    pub agent: Pubkey,
+
+    /// Allowlist or blocklist mode
+    pub mode: AclMode,
+
+    /// Challenger wallets the mode applies to
+    #[max_len(16)]
+    pub entries: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ChallengerAcl {
+    pub const SEED_PREFIX: &'static [u8] = b"challenger_acl";
+
+    /// Maximum number of ACL entries
+    pub const MAX_ENTRIES: usize = 16;
+
+    /// Check a challenger against the agent's ACL account
+    /// An uninitialized ACL PDA means the agent accepts anyone
+    pub fn check(acl_info: &AccountInfo, challenger: &Pubkey) -> Result<()> {
+        if acl_info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*acl_info.owner, crate::ID, RegistryError::Unauthorized);
+        let acl = ChallengerAcl::try_deserialize(&mut &acl_info.try_borrow_data()?[..])?;
+        let listed = acl.entries.contains(challenger);
+        let allowed = match acl.mode {
+            AclMode::Open => true,
+            AclMode::Allowlist => listed,
+            AclMode::Blocklist => !listed,
+        };
+        require!(allowed, RegistryError::ChallengerNotAllowed);
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// A registered custom audit action code
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ActionCode {
+    /// Code used in ActionType::Custom
+    pub code: u16,
+
+    /// Human-readable action name (max 32 chars)
+    #[max_len(32)]
+    pub name: String,
+
+    /// Base risk score added to the caller's context risk (0-100)
+    pub base_risk: u8,
+}
+
+/// Admin-managed vocabulary of custom audit action codes
+///
+/// Audit logging rejects `ActionType::Custom(code)` unless the code is
+/// registered here, so integrators cannot collide on the same code.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct ActionCodeRegistry {
+    #[max_len(32)]
+    pub codes: Vec<ActionCode>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ActionCodeRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"action_codes";
+
+    /// Maximum registered codes
+    pub const MAX_CODES: usize = 32;
+
+    /// Maximum action name length
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Registry stored in the registry account, or an empty one if uninitialized
+    pub fn load_or_empty(registry_info: &AccountInfo) -> Result<Self> {
+        if registry_info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*registry_info.owner, crate::ID, RegistryError::Unauthorized);
+        ActionCodeRegistry::try_deserialize(&mut &registry_info.try_borrow_data()?[..])
+    }
+
+    /// Look up a registered code
+    pub fn get(&self, code: u16) -> Option<&ActionCode> {
+        self.codes.iter().find(|entry| entry.code == code)
+    }
+
+    /// Base risk of a registered code
+    pub fn base_risk(&self, code: u16) -> Result<u8> {
+        self.get(code)
+            .map(|entry| entry.base_risk)
+            .ok_or(error!(RegistryError::UnknownActionCode))
+    }
+}
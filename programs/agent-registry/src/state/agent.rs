@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
 
 /// Agent account - represents a registered AI agent
 #[account]
@@ -45,11 +46,86 @@ pub struct AgentAccount {
 
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// Account layout version (0 = legacy layout before versioning)
+    /// New fields must be appended after this one and bump CURRENT_VERSION
+    pub version: u8,
+
+    /// Owner wallet used for PDA derivation at registration
+    /// Stays fixed when ownership is reassigned via `recover_agent`
+    pub original_owner: Pubkey,
+
+    /// Delegated hot key allowed to submit challenge responses (default = none)
+    pub operator: Pubkey,
+
+    /// Challenges this agent issued against other agents
+    pub challenges_issued: u32,
+
+    /// Peer challenges issued by this agent that the challenged agent failed
+    pub peer_wins: u32,
+
+    /// Peer challenges issued by this agent that the challenged agent passed
+    /// (or that this agent abandoned)
+    pub peer_losses: u32,
+
+    /// Unix timestamp when the current reputation update window started
+    pub reputation_window_start: i64,
+
+    /// Absolute reputation change in the current window (direct updates plus
+    /// registry gains and losses)
+    pub reputation_window_change: u32,
+
+    /// Unix timestamp of the last reputation change
+    pub reputation_updated_at: i64,
+
+    /// Agent this one's reputation migrated to (default = active)
+    pub successor: Pubkey,
+
+    /// Combined reputation bonus from active endorsements
+    pub endorsement_bonus: u32,
+
+    /// Whether the agent fell below the probation floor and has not recovered yet
+    pub on_probation: bool,
+
+    /// Consecutive verifier-resolved challenges passed while on probation
+    pub probation_streak: u8,
+
+    /// ReputationSchema version the reputation score is expressed in
+    pub reputation_schema_version: u16,
+
+    /// Whether the audit circuit breaker suspended the agent (cleared by reinstate_agent)
+    pub suspended: bool,
+
+    /// Admin-assigned guardian key, the only one allowed to log high-risk audit entries
+    /// (default = none, any audit actor may)
+    pub sentinel_authority: Pubkey,
+
+    /// SHA256 of the agent's declared safety/audit policy document (zero = none declared)
+    pub policy_hash: [u8; 32],
+
+    /// Number of policy declarations so far (0 = none)
+    pub policy_version: u32,
+
+    /// Unix timestamp when the current policy took effect
+    pub policy_updated_at: i64,
+
+    /// Amount in the agent's stake vault, in lamports or base units of the vault's
+    /// mint (mirrors StakeVault::total_staked, synced on every stake, withdrawal and slash)
+    pub staked_amount: u64,
+
+    /// Unix timestamp when the current task credit window started
+    pub task_credit_window_start: i64,
+
+    /// Reputation credited for paid tasks in the current task credit window
+    pub task_credit_window_change: u32,
 }
 
 impl AgentAccount {
     pub const SEED_PREFIX: &'static [u8] = b"agent";
 
+    /// Current account layout version (see `upgrade_agent_account`)
+    pub const CURRENT_VERSION: u8 = 15;
+
     /// Initial reputation score (50%)
     pub const INITIAL_REPUTATION: u32 = 5000;
 
@@ -59,16 +135,218 @@ impl AgentAccount {
     /// Minimum reputation score (0%)
     pub const MIN_REPUTATION: u32 = 0;
 
+    /// Reputation points per tier (tiers 0..=4)
+    pub const TIER_SIZE: u32 = 2500;
+
+    /// Delay before a requested owner recovery can be finalized (48 hours)
+    pub const RECOVERY_DELAY: i64 = 48 * 3600;
+
+    /// Wilson score z value (95% confidence), scaled by WILSON_SCALE
+    pub const WILSON_Z: u128 = 19_600;
+
+    /// z squared, scaled by WILSON_SCALE
+    pub const WILSON_Z_SQUARED: u128 = 38_416;
+
+    /// Fixed-point scale of the Wilson constants
+    pub const WILSON_SCALE: u128 = 10_000;
+
+    /// Length of the reputation update rate-limit window
+    pub const REPUTATION_WINDOW: i64 = 24 * 3600;
+
+    /// Owner key used in this agent's PDA seeds
+    /// Legacy accounts have no original_owner recorded and were never recovered
+    pub fn pda_owner(&self) -> Pubkey {
+        if self.original_owner == Pubkey::default() {
+            self.owner
+        } else {
+            self.original_owner
+        }
+    }
+
+    /// Check if the agent was retired by migrate_reputation
+    pub fn is_retired(&self) -> bool {
+        self.successor != Pubkey::default()
+    }
+
+    /// Check if a key may answer challenges for this agent (owner or operator)
+    pub fn is_responder(&self, key: &Pubkey) -> bool {
+        *key == self.owner || (self.operator != Pubkey::default() && *key == self.operator)
+    }
+
+    /// Check if the owner or operator of `other` also controls this agent
+    pub fn shares_controller(&self, other: &AgentAccount) -> bool {
+        self.is_responder(&other.owner)
+            || (other.operator != Pubkey::default() && self.is_responder(&other.operator))
+    }
+
+    /// Check if an actor may log high-risk audit entries for this agent
+    pub fn may_log_high_risk(&self, actor: &Pubkey) -> bool {
+        self.sentinel_authority == Pubkey::default() || self.sentinel_authority == *actor
+    }
+
+    /// Put the agent on probation if a reputation decrease from `old_reputation`
+    /// left it below `floor` (0 = disabled). Returns true if probation started
+    pub fn enter_probation(&mut self, old_reputation: u32, floor: u32) -> bool {
+        if self.on_probation || self.reputation_score >= old_reputation || self.reputation_score >= floor {
+            return false;
+        }
+        self.on_probation = true;
+        self.probation_streak = 0;
+        true
+    }
+
+    /// Count a verifier-resolved verdict towards leaving probation
+    /// A failure resets the streak; `exit_streak` consecutive passes end probation.
+    /// Returns true if probation ended
+    pub fn record_probation_result(&mut self, passed: bool, exit_streak: u8) -> bool {
+        if !self.on_probation {
+            return false;
+        }
+        if !passed {
+            self.probation_streak = 0;
+            return false;
+        }
+        self.probation_streak = self.probation_streak.saturating_add(1);
+        if self.probation_streak < exit_streak {
+            return false;
+        }
+        self.on_probation = false;
+        self.probation_streak = 0;
+        true
+    }
+
+    /// Start a new reputation change window if the current one elapsed
+    fn roll_reputation_window(&mut self, now: i64) {
+        if now >= self.reputation_window_start + Self::REPUTATION_WINDOW {
+            self.reputation_window_start = now;
+            self.reputation_window_change = 0;
+        }
+    }
+
+    /// Count a direct reputation update against the fixed-window cap,
+    /// starting a new window if the current one elapsed
+    pub fn record_reputation_change(&mut self, delta: i32, now: i64, max: u32) -> Result<()> {
+        self.roll_reputation_window(now);
+        let change = self.reputation_window_change.saturating_add(delta.unsigned_abs());
+        require!(change <= max, RegistryError::ReputationRateLimited);
+        self.reputation_window_change = change;
+        Ok(())
+    }
+
     /// Calculate reputation percentage (0.00 - 100.00)
     pub fn reputation_percentage(&self) -> f64 {
         (self.reputation_score as f64) / 100.0
     }
 
+    /// Reputation tier: 0 (< 25%) up to 4 (100%)
+    pub fn tier(&self) -> u8 {
+        (self.reputation_score / Self::TIER_SIZE) as u8
+    }
+
+    /// Apply a reputation change earned through registry activity (challenge
+    /// verdicts, open challenge wins, endorsements, tasks, migration)
+    /// Gains count against the same window cap as direct updates and whatever
+    /// exceeds the window's remaining budget is dropped. Losses go through
+    /// debit_reputation. Returns the delta actually applied (see adjust_reputation).
+    pub fn credit_reputation(&mut self, delta: i32, now: i64, max: u32) -> i32 {
+        if delta <= 0 {
+            return self.debit_reputation(delta.unsigned_abs(), now);
+        }
+        self.roll_reputation_window(now);
+        let budget = max.saturating_sub(self.reputation_window_change);
+        let credited = (delta as u32).min(budget);
+        self.reputation_window_change += credited;
+        self.adjust_reputation(credited as i32, now)
+    }
+
+    /// Apply a paid-task reputation credit: at most `task_max` per window on top
+    /// of the shared window cap `max`, since clients are not independent of the
+    /// agent the way verifiers are. Returns the delta actually applied
+    pub fn credit_task_reputation(
+        &mut self,
+        delta: u32,
+        now: i64,
+        task_max: u32,
+        max: u32,
+    ) -> i32 {
+        if now >= self.task_credit_window_start + Self::REPUTATION_WINDOW {
+            self.task_credit_window_start = now;
+            self.task_credit_window_change = 0;
+        }
+        let budget = task_max.saturating_sub(self.task_credit_window_change);
+        if budget == 0 {
+            return 0;
+        }
+        let applied = self.credit_reputation(delta.min(budget) as i32, now, max);
+        self.task_credit_window_change += applied.max(0) as u32;
+        applied
+    }
+
+    /// Apply a reputation loss (failed or expired challenges, penalties, clawbacks)
+    /// Losses are never capped, so a penalty cannot be dodged, but they count
+    /// towards the window's absolute change and so use up the budget for gains.
+    /// Returns the delta actually applied (see adjust_reputation).
+    pub fn debit_reputation(&mut self, loss: u32, now: i64) -> i32 {
+        self.roll_reputation_window(now);
+        let applied = self.adjust_reputation(-(loss.min(Self::MAX_REPUTATION) as i32), now);
+        self.reputation_window_change =
+            self.reputation_window_change.saturating_add(applied.unsigned_abs());
+        applied
+    }
+
     /// Update reputation with bounds checking
-    pub fn adjust_reputation(&mut self, delta: i32) {
+    /// Returns the delta actually applied (new score - old score), which is
+    /// smaller than `delta` when the score hits MIN/MAX_REPUTATION
+    pub fn adjust_reputation(&mut self, delta: i32, now: i64) -> i32 {
+        let old_score = self.reputation_score;
         let new_score = (self.reputation_score as i64) + (delta as i64);
         self.reputation_score = new_score
             .max(Self::MIN_REPUTATION as i64)
             .min(Self::MAX_REPUTATION as i64) as u32;
+        self.reputation_updated_at = now;
+        self.reputation_score as i32 - old_score as i32
+    }
+
+    /// Posterior mean pass rate (basis points) under a uniform Beta(1, 1) prior
+    /// An agent without challenges sits at 50%
+    pub fn reputation_mean_bps(&self) -> u16 {
+        let successes = self.challenges_passed as u64;
+        let total = successes + self.challenges_failed as u64;
+        ((successes + 1) * 10_000 / (total + 2)) as u16
+    }
+
+    /// Lower bound (basis points) of the 95% Wilson score interval of the pass rate
+    /// Grows with evidence: 2/2 passes scores far below 950/1000
+    pub fn reputation_lower_bound_bps(&self) -> u16 {
+        let successes = self.challenges_passed as u128;
+        let failures = self.challenges_failed as u128;
+        let total = successes + failures;
+        if total == 0 {
+            return 0;
+        }
+
+        // Wilson lower bound multiplied through by n (all terms scaled by SCALE^2):
+        // (s + z^2/2 - z * sqrt(s*f/n + z^2/4)) / (n + z^2)
+        let scale = Self::WILSON_SCALE;
+        let spread = successes * failures * scale * scale / total
+            + Self::WILSON_Z_SQUARED * scale / 4;
+        let numerator = (successes * scale * scale + Self::WILSON_Z_SQUARED * scale / 2)
+            .saturating_sub(Self::WILSON_Z * isqrt(spread));
+        let denominator = total * scale * scale + Self::WILSON_Z_SQUARED * scale;
+        (numerator * 10_000 / denominator) as u16
+    }
+}
+
+/// Integer square root (floor)
+fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
     }
+    x
 }
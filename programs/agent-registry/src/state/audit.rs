@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::errors::RegistryError;
+use crate::state::{DayRollup, RegistryConfig};
 
 /// Action types for the audit trail
 /// Follows A2A protocol patterns for agent activity classification
@@ -22,8 +25,87 @@ pub enum ActionType {
     ReputationDecreased,
     /// Security alert triggered
     SecurityAlert,
-    /// Custom action for extensibility
-    Custom,
+    /// Integrator-defined action, identified by a code from the ActionCodeRegistry
+    Custom(u16),
+    /// Reputation carried over from a retired predecessor agent
+    ReputationMigrated,
+}
+
+impl ActionType {
+    /// Number of action types (length of AgentAuditSummary::action_counts)
+    pub const COUNT: usize = 11;
+
+    /// Position of the variant (all custom codes share the Custom slot)
+    pub fn index(&self) -> usize {
+        match self {
+            ActionType::AgentRegistered => 0,
+            ActionType::AgentUpdated => 1,
+            ActionType::AgentVerified => 2,
+            ActionType::ChallengeCreated => 3,
+            ActionType::ChallengePassed => 4,
+            ActionType::ChallengeFailed => 5,
+            ActionType::ReputationIncreased => 6,
+            ActionType::ReputationDecreased => 7,
+            ActionType::SecurityAlert => 8,
+            ActionType::Custom(_) => 9,
+            ActionType::ReputationMigrated => 10,
+        }
+    }
+
+    /// Custom action code (0 for built-in action types)
+    pub fn custom_code(&self) -> u16 {
+        match self {
+            ActionType::Custom(code) => *code,
+            _ => 0,
+        }
+    }
+}
+
+/// Governance action recorded in the registry-scoped audit stream
+///
+/// Registry actions reuse the agent audit machinery: the stream's summary and
+/// entries are keyed by the RegistryState PDA in place of an agent account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RegistryAction {
+    /// Admin verified an agent
+    AgentVerified,
+    /// Admin updated the registry config
+    ConfigUpdated,
+    /// Admin set the identity NFT collection
+    CollectionSet,
+    /// Admin lifted a circuit breaker suspension
+    AgentReinstated,
+}
+
+impl RegistryAction {
+    /// Built-in action type the entry is counted under in the registry summary
+    pub fn action_type(&self) -> ActionType {
+        match self {
+            RegistryAction::AgentVerified => ActionType::AgentVerified,
+            RegistryAction::ConfigUpdated | RegistryAction::CollectionSet => {
+                ActionType::AgentUpdated
+            }
+            RegistryAction::AgentReinstated => ActionType::SecurityAlert,
+        }
+    }
+
+    /// Details digest: sha256(action || subject || payload)
+    pub fn details(&self, subject: &Pubkey, payload: &[u8]) -> [u8; 32] {
+        hashv(&[&[*self as u8], subject.as_ref(), payload]).to_bytes()
+    }
+}
+
+/// Reason recorded on a redacted audit entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RedactionReason {
+    /// Erasure request from a data subject (GDPR Article 17)
+    DataSubjectRequest,
+    /// Court or regulator order
+    LegalOrder,
+    /// Details were logged in error (e.g. secrets or personal data)
+    ErroneousEntry,
+    /// Anything else (documented off-chain)
+    Other,
 }
 
 /// Risk level classification following security best practices
@@ -52,6 +134,11 @@ impl RiskLevel {
         }
     }
 
+    /// Check if a risk score falls in the Critical band (circuit breaker input)
+    pub fn is_critical(score: u8) -> bool {
+        matches!(Self::from_score(score), RiskLevel::Critical)
+    }
+
     pub fn to_score(&self) -> u8 {
         match self {
             RiskLevel::None => 0,
@@ -95,25 +182,92 @@ pub struct AuditEntry {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Audit chain head before this entry (see AgentAuditSummary::advance_chain)
+    pub prev_entry_hash: [u8; 32],
+
+    /// RiskWeights version the risk score was calculated with (0 = built-in defaults)
+    pub risk_weights_version: u32,
+
+    /// Why the details were redacted (None = not redacted)
+    pub redaction: Option<RedactionReason>,
+
+    /// Unix timestamp of the redaction (0 = not redacted)
+    pub redacted_at: i64,
+
+    /// Chain link of this entry captured before redaction, so the next entry's
+    /// prev_entry_hash still verifies without the original details (zero = not redacted)
+    pub redacted_link_hash: [u8; 32],
+
+    /// Whether the entry counted as a security alert (set from the risk score
+    /// even when the action type is not SecurityAlert)
+    pub is_alert: bool,
+
+    /// Registered auditor that co-signed the entry (default = self-reported)
+    pub auditor: Pubkey,
+
+    /// Where the details, encrypted to the agent's AuditReaders, can be fetched (None = public only)
+    #[max_len(128)]
+    pub encrypted_details_uri: Option<String>,
 }
 
 impl AuditEntry {
     pub const SEED_PREFIX: &'static [u8] = b"audit";
 
-    /// Calculate risk score based on action type and context
-    pub fn calculate_risk_score(action_type: &ActionType, context_risk: u8) -> u8 {
-        let base_risk = match action_type {
-            ActionType::AgentRegistered => 0,
-            ActionType::AgentUpdated => 5,
-            ActionType::AgentVerified => 0,
-            ActionType::ChallengeCreated => 10,
-            ActionType::ChallengePassed => 0,
-            ActionType::ChallengeFailed => 25,
-            ActionType::ReputationIncreased => 0,
-            ActionType::ReputationDecreased => 20,
-            ActionType::SecurityAlert => 75,
-            ActionType::Custom => context_risk,
-        };
+    /// Maximum encrypted details URI length
+    pub const MAX_URI_LEN: usize = 128;
+
+    /// Seed a calling program signs with to act as an audit actor over CPI
+    pub const CPI_ACTOR_SEED: &'static [u8] = b"audit_actor";
+
+    /// Program-derived actor identity of `program` for log_audit_cpi
+    /// (derived under `program`, so only that program can sign as it)
+    pub fn cpi_actor(program: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::CPI_ACTOR_SEED], program)
+    }
+
+    /// Built-in base risk per ActionType index, used until the admin publishes RiskWeights
+    /// Custom codes take their base risk from the ActionCodeRegistry instead
+    pub const DEFAULT_BASE_RISK: [u8; ActionType::COUNT] = [
+        0,  // AgentRegistered
+        5,  // AgentUpdated
+        0,  // AgentVerified
+        10, // ChallengeCreated
+        0,  // ChallengePassed
+        25, // ChallengeFailed
+        0,  // ReputationIncreased
+        20, // ReputationDecreased
+        75, // SecurityAlert
+        0,  // Custom (see ActionCodeRegistry)
+        0,  // ReputationMigrated
+    ];
+
+    /// Audit chain link for an entry:
+    /// sha256(prev_head || agent || audit_index || action index || risk_score
+    ///        || custom code || timestamp || details_hash)
+    pub fn link_hash(
+        prev: &[u8; 32],
+        agent: &Pubkey,
+        audit_index: u64,
+        action_type: ActionType,
+        risk_score: u8,
+        timestamp: i64,
+        details_hash: &[u8; 32],
+    ) -> [u8; 32] {
+        hashv(&[
+            prev,
+            agent.as_ref(),
+            &audit_index.to_le_bytes(),
+            &[action_type.index() as u8, risk_score],
+            &action_type.custom_code().to_le_bytes(),
+            &timestamp.to_le_bytes(),
+            details_hash,
+        ])
+        .to_bytes()
+    }
+
+    /// Calculate risk score from an action's base risk and the caller's context risk
+    pub fn calculate_risk_score(base_risk: u8, context_risk: u8) -> u8 {
         base_risk.saturating_add(context_risk).min(100)
     }
 }
@@ -146,14 +300,92 @@ pub struct AgentAuditSummary {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Entries logged per ActionType (indexed by discriminant)
+    /// Summaries created before this field count only entries logged after upgrade_audit_summary
+    pub action_counts: [u32; ActionType::COUNT],
+
+    /// Unix timestamp when the current circuit breaker window started
+    pub critical_window_start: i64,
+
+    /// Critical-risk entries logged in the current circuit breaker window
+    pub critical_window_alerts: u32,
+
+    /// Hash of the latest entry in the tamper-evident audit chain (zero = no entries linked yet)
+    pub chain_head: [u8; 32],
+
+    /// Serious-incident reports filed for this agent (next IncidentReport index)
+    pub incidents_reported: u32,
+
+    /// Unix timestamp of the latest incident report (0 = none)
+    pub last_incident_at: i64,
+
+    /// Entries logged on the current UTC day, not yet closed
+    pub open_day: DayRollup,
+
+    /// Entries from earlier days waiting for finalize_audit_day
+    pub closed_days: DayRollup,
+
+    /// Lowest risk score recorded (valid once risk_histogram is non-empty)
+    pub min_risk_score: u8,
+
+    /// Entries per risk bucket of width 10 (90-100 share the last bucket)
+    /// Summaries created before this field count only entries logged after upgrade_audit_summary
+    pub risk_histogram: [u32; AgentAuditSummary::RISK_BUCKETS],
+
+    /// Security alerts per UTC day, indexed by day % ALERT_WINDOW_DAYS
+    pub daily_alerts: [u32; AgentAuditSummary::ALERT_WINDOW_DAYS],
+
+    /// Latest UTC day written to daily_alerts
+    pub last_alert_day: u32,
+
+    /// Unix timestamp of the latest security alert (0 = none since this field was added)
+    pub last_alert_at: i64,
+
+    /// Start of the alert-free period not yet applied to avg_risk_score decay
+    pub risk_decay_at: i64,
+
+    /// Unix timestamp of the latest max_risk_score reset
+    pub max_reset_at: i64,
+
+    /// Whether decay or a reset has adjusted avg/max (they are no longer plain lifetime stats)
+    pub risk_decayed: bool,
+
+    /// Whole-history checkpoints taken (next AuditCheckpoint index)
+    pub checkpoints: u32,
+
+    /// Critical-risk entries logged (RiskLevel::is_critical, never reset)
+    /// Summaries created before this field count only entries logged after upgrade_audit_summary
+    pub critical_alerts: u32,
 }
 
 impl AgentAuditSummary {
     pub const SEED_PREFIX: &'static [u8] = b"audit_summary";
 
+    /// Size of the layout before per-action counters were added (including discriminator)
+    pub const LEGACY_SPACE: usize = 8 + 32 + 8 + 4 + 1 + 1 + 8 + 4 + 1;
+
+    pub const RISK_BUCKETS: usize = 10;
+
+    pub const ALERT_WINDOW_DAYS: usize = 30;
+
+    /// Entries logged with this action type
+    pub fn action_count(&self, action_type: ActionType) -> u32 {
+        self.action_counts[action_type.index()]
+    }
+
     /// Update summary with new audit entry
-    pub fn record_entry(&mut self, risk_score: u8, is_alert: bool, timestamp: i64) {
+    pub fn record_entry(
+        &mut self,
+        action_type: ActionType,
+        risk_score: u8,
+        is_alert: bool,
+        timestamp: i64,
+    ) {
+        self.track_entry(self.total_entries, risk_score, is_alert, timestamp);
         self.total_entries = self.total_entries.saturating_add(1);
+        let count = &mut self.action_counts[action_type.index()];
+        *count = count.saturating_add(1);
 
         if is_alert {
             self.security_alerts = self.security_alerts.saturating_add(1);
@@ -176,8 +408,249 @@ impl AgentAuditSummary {
         self.last_audit_at = timestamp;
     }
 
+    /// Update summary with a batch of audit entries (action type, risk score, is_alert) in log order
+    /// Equivalent to record_entry per item, but the rolling average is taken over the whole batch
+    pub fn record_batch(&mut self, entries: &[(ActionType, u8, bool)], timestamp: i64) {
+        if entries.is_empty() {
+            return;
+        }
+        let old_total = self.total_entries;
+        let mut risk_sum = 0u64;
+
+        for (i, &(action_type, risk_score, is_alert)) in entries.iter().enumerate() {
+            self.track_entry(old_total + i as u64, risk_score, is_alert, timestamp);
+            let count = &mut self.action_counts[action_type.index()];
+            *count = count.saturating_add(1);
+            if is_alert {
+                self.security_alerts = self.security_alerts.saturating_add(1);
+                self.safe_streak = 0;
+            } else if risk_score <= 10 {
+                self.safe_streak = self.safe_streak.saturating_add(1);
+            }
+            if risk_score > self.max_risk_score {
+                self.max_risk_score = risk_score;
+            }
+            risk_sum += risk_score as u64;
+        }
+
+        self.total_entries = old_total.saturating_add(entries.len() as u64);
+        let weighted = (self.avg_risk_score as u128) * (old_total as u128) + risk_sum as u128;
+        self.avg_risk_score = (weighted / self.total_entries as u128) as u8;
+
+        self.last_audit_at = timestamp;
+    }
+
+    /// Count an entry towards the day rollups, risk distribution and alert window
+    /// The open day is closed first if the entry falls on a later one
+    fn track_entry(&mut self, audit_index: u64, risk_score: u8, is_alert: bool, timestamp: i64) {
+        let day = DayRollup::day_of(timestamp);
+        if self.open_day.entries > 0 && day > self.open_day.start_day {
+            let open = std::mem::take(&mut self.open_day);
+            self.closed_days.absorb(&open);
+        }
+        self.open_day.record(day, audit_index, risk_score);
+
+        if self.risk_histogram.iter().all(|&n| n == 0) || risk_score < self.min_risk_score {
+            self.min_risk_score = risk_score;
+        }
+        let bucket = &mut self.risk_histogram[Self::risk_bucket(risk_score)];
+        *bucket = bucket.saturating_add(1);
+        if RiskLevel::is_critical(risk_score) {
+            self.critical_alerts = self.critical_alerts.saturating_add(1);
+        }
+
+        if is_alert {
+            self.last_alert_at = timestamp;
+            if day > self.last_alert_day {
+                let stale = (day - self.last_alert_day).min(Self::ALERT_WINDOW_DAYS as u32);
+                for k in 0..stale {
+                    self.daily_alerts[((day - k) as usize) % Self::ALERT_WINDOW_DAYS] = 0;
+                }
+                self.last_alert_day = day;
+            }
+            let slot = &mut self.daily_alerts[(day as usize) % Self::ALERT_WINDOW_DAYS];
+            *slot = slot.saturating_add(1);
+        }
+    }
+
+    fn risk_bucket(risk_score: u8) -> usize {
+        (risk_score as usize / 10).min(Self::RISK_BUCKETS - 1)
+    }
+
+    /// Approximate risk percentile from the histogram: the upper bound of the
+    /// bucket holding the pct-th percentile entry (0 if no entries are counted)
+    pub fn risk_percentile(&self, pct: u8) -> u8 {
+        let total: u64 = self.risk_histogram.iter().map(|&n| n as u64).sum();
+        if total == 0 {
+            return 0;
+        }
+        let rank = (total * pct.min(100) as u64).div_ceil(100).max(1);
+        let mut seen = 0u64;
+        for (i, &n) in self.risk_histogram.iter().enumerate() {
+            seen += n as u64;
+            if seen >= rank {
+                return if i == Self::RISK_BUCKETS - 1 { 100 } else { (i * 10 + 9) as u8 };
+            }
+        }
+        100
+    }
+
+    /// Security alerts logged in the ALERT_WINDOW_DAYS UTC days ending `today`
+    pub fn recent_alerts(&self, today: u32) -> u32 {
+        let window = Self::ALERT_WINDOW_DAYS as u32;
+        if self.last_alert_day + window <= today {
+            return 0;
+        }
+        let oldest = today.saturating_sub(window - 1);
+        (oldest..=self.last_alert_day.min(today))
+            .map(|day| self.daily_alerts[(day as usize) % Self::ALERT_WINDOW_DAYS])
+            .fold(0u32, |sum, n| sum.saturating_add(n))
+    }
+
+    /// Decay avg_risk_score toward zero, halving it per full half-life since the
+    /// later of the last alert and the last decay step, and reset max_risk_score
+    /// after config.max_risk_reset_secs without alerts. Call before recording entries
+    pub fn decay_risk(&mut self, now: i64, config: &RegistryConfig) {
+        if self.risk_decay_at == 0 && self.last_alert_at == 0 {
+            // Start the clock on the first entry after this field was added
+            self.risk_decay_at = now;
+            self.max_reset_at = now;
+            return;
+        }
+
+        let half_life = config.risk_decay_half_life_secs as i64;
+        let decay_from = self.risk_decay_at.max(self.last_alert_at);
+        if half_life > 0 && now > decay_from {
+            let halvings = (now - decay_from) / half_life;
+            if halvings > 0 {
+                if self.avg_risk_score > 0 {
+                    let shift = halvings.min(8) as u32;
+                    self.avg_risk_score = self.avg_risk_score.checked_shr(shift).unwrap_or(0);
+                    self.risk_decayed = true;
+                }
+                self.risk_decay_at = decay_from + halvings * half_life;
+            }
+        }
+
+        let reset_after = config.max_risk_reset_secs as i64;
+        let clean_since = self.max_reset_at.max(self.last_alert_at);
+        if reset_after > 0 && now - clean_since >= reset_after && self.max_risk_score > 0 {
+            self.max_risk_score = 0;
+            self.max_reset_at = now;
+            self.risk_decayed = true;
+        }
+    }
+
+    /// Remove and return the next rollup to digest: days already closed by a
+    /// later entry, otherwise the open day once `today` has moved past it
+    pub fn take_finished_days(&mut self, today: u32) -> Option<DayRollup> {
+        if self.closed_days.entries > 0 {
+            return Some(std::mem::take(&mut self.closed_days));
+        }
+        if self.open_day.entries > 0 && self.open_day.start_day < today {
+            return Some(std::mem::take(&mut self.open_day));
+        }
+        None
+    }
+
+    /// Count a serious-incident report
+    pub fn record_incident(&mut self, timestamp: i64) {
+        self.incidents_reported = self.incidents_reported.saturating_add(1);
+        self.last_incident_at = timestamp;
+    }
+
+    /// Link an entry into the audit hash chain, returning the previous head
+    /// Every logging path (entry PDA, ring, packed batch) advances the same chain in audit_index order
+    pub fn advance_chain(
+        &mut self,
+        audit_index: u64,
+        action_type: ActionType,
+        risk_score: u8,
+        timestamp: i64,
+        details_hash: &[u8; 32],
+    ) -> [u8; 32] {
+        let prev = self.chain_head;
+        self.chain_head = AuditEntry::link_hash(
+            &prev,
+            &self.agent,
+            audit_index,
+            action_type,
+            risk_score,
+            timestamp,
+            details_hash,
+        );
+        prev
+    }
+
+    /// Count critical-risk entries against the fixed circuit breaker window,
+    /// starting a new window if the current one elapsed. Returns the window total
+    pub fn record_critical_alerts(&mut self, count: u32, now: i64, window_secs: u32) -> u32 {
+        if now >= self.critical_window_start + window_secs as i64 {
+            self.critical_window_start = now;
+            self.critical_window_alerts = 0;
+        }
+        self.critical_window_alerts = self.critical_window_alerts.saturating_add(count);
+        self.critical_window_alerts
+    }
+
+    /// Critical-risk entries recorded in an agent's audit summary account
+    /// An uninitialized summary means no audits and no alerts
+    pub fn critical_alerts_of(summary_info: &AccountInfo) -> Result<u32> {
+        if summary_info.data_is_empty() {
+            return Ok(0);
+        }
+        require_keys_eq!(*summary_info.owner, crate::ID, RegistryError::Unauthorized);
+        let summary = AgentAuditSummary::try_deserialize(&mut &summary_info.try_borrow_data()?[..])?;
+        Ok(summary.critical_alerts)
+    }
+
     /// Check if agent has good security standing
     pub fn is_trusted(&self) -> bool {
         self.avg_risk_score <= 25 && self.safe_streak >= 10 && self.security_alerts == 0
     }
 }
+
+/// In-progress recomputation of an agent's audit summary from its AuditEntry PDAs
+/// Created by the first recompute_audit_summary call and closed when the last entry is counted
+#[account]
+#[derive(InitSpace)]
+pub struct AuditRecompute {
+    /// The agent being recomputed
+    pub agent: Pubkey,
+
+    /// audit_index of the next entry to count
+    pub next_index: u64,
+
+    /// Sum of risk scores counted so far
+    pub risk_sum: u64,
+
+    /// Highest risk score counted so far
+    pub max_risk: u8,
+
+    /// Entries counted per ActionType index
+    pub action_counts: [u32; ActionType::COUNT],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditRecompute {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_recompute";
+
+    /// Count one entry
+    pub fn record(&mut self, entry: &AuditEntry) {
+        self.next_index += 1;
+        self.risk_sum = self.risk_sum.saturating_add(entry.risk_score as u64);
+        self.max_risk = self.max_risk.max(entry.risk_score);
+        let count = &mut self.action_counts[entry.action_type.index()];
+        *count = count.saturating_add(1);
+    }
+
+    /// Average risk over the counted entries
+    pub fn avg_risk(&self) -> u8 {
+        if self.next_index == 0 {
+            return 0;
+        }
+        (self.risk_sum / self.next_index) as u8
+    }
+}
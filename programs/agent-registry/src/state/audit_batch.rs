@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::ActionType;
+
+/// One fixed-size (80 byte) audit record in an AuditBatch
+#[zero_copy]
+pub struct AuditBatchEntry {
+    /// Unix timestamp when the action occurred
+    pub timestamp: i64,
+
+    /// Slot the entry was appended in
+    pub slot: u64,
+
+    /// Sequential audit index for this agent (shared with the summary)
+    pub audit_index: u64,
+
+    /// SHA256 hash of detailed action data (stored off-chain)
+    pub details_hash: [u8; 32],
+
+    /// ActionType index (see ActionType::index)
+    pub action_type: u8,
+
+    /// Risk level assessment (0-100)
+    pub risk_score: u8,
+
+    /// Custom action code (0 for built-in action types)
+    pub custom_code: u16,
+
+    /// RiskWeights version the risk score was calculated with
+    pub risk_weights_version: u32,
+
+    pub _padding: [u8; 16],
+}
+
+/// Large append-only audit log for high-volume agents (zero-copy)
+///
+/// Too big to be created inside an instruction, so the client allocates it
+/// with a system create_account (owner = this program, AuditBatch::SPACE bytes)
+/// in the same transaction as init_audit_batch. An agent may own several.
+/// Entries are never overwritten: appends fail once the cursor reaches CAPACITY.
+#[account(zero_copy)]
+pub struct AuditBatch {
+    /// The agent this batch belongs to
+    pub agent: Pubkey,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// Index the next entry is appended at (= entries written)
+    pub cursor: u32,
+
+    pub _padding: [u8; 4],
+
+    /// Entry storage, filled in order
+    pub entries: [AuditBatchEntry; 4096],
+}
+
+impl AuditBatch {
+    /// Number of entries a batch holds
+    pub const CAPACITY: usize = 4096;
+
+    /// Account size including the discriminator
+    pub const SPACE: usize = 8 + std::mem::size_of::<AuditBatch>();
+
+    /// Maximum entries per append_audit_batch call
+    pub const MAX_APPEND: usize = 16;
+
+    /// Entries left before the batch is full
+    pub fn remaining(&self) -> usize {
+        Self::CAPACITY - self.cursor as usize
+    }
+
+    /// Append an audit record at the cursor, returning its position
+    /// Callers check remaining() first
+    pub fn append(
+        &mut self,
+        action_type: ActionType,
+        risk_score: u8,
+        details_hash: [u8; 32],
+        audit_index: u64,
+        risk_weights_version: u32,
+        clock: &Clock,
+    ) -> usize {
+        let position = self.cursor as usize;
+        self.entries[position] = AuditBatchEntry {
+            timestamp: clock.unix_timestamp,
+            slot: clock.slot,
+            audit_index,
+            details_hash,
+            action_type: action_type.index() as u8,
+            risk_score,
+            custom_code: action_type.custom_code(),
+            risk_weights_version,
+            _padding: [0; 16],
+        };
+        self.cursor += 1;
+        position
+    }
+}
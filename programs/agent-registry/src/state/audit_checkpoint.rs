@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Whole-history commitment to an agent's audit log at a point in time
+///
+/// `chain_head` is the audit hash chain head after `entry_count` entries, so it
+/// commits to every linked entry to date in order. `merkle_root` optionally adds
+/// a caller-computed Merkle root over the same entries' link hashes for
+/// logarithmic inclusion proofs. External archives can pin checkpoints for
+/// long-term retention and litigation holds.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditCheckpoint {
+    /// The agent this checkpoint belongs to
+    pub agent: Pubkey,
+
+    /// Sequential checkpoint index for this agent
+    pub index: u32,
+
+    /// Audit entries covered (audit indexes 0..entry_count)
+    pub entry_count: u64,
+
+    /// Audit chain head after the last covered entry
+    pub chain_head: [u8; 32],
+
+    /// Merkle root over the covered entries' link hashes (zero = not provided)
+    pub merkle_root: [u8; 32],
+
+    /// Who signed the checkpoint
+    pub created_by: Pubkey,
+
+    /// Unix timestamp of the checkpoint
+    pub timestamp: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditCheckpoint {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_checkpoint";
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::{ActionType, AgentAccount, RegistryConfig};
+use crate::errors::RegistryError;
+
+/// Per-agent audit risk policy (owner or compliance officer managed)
+///
+/// Consulted by every audit logging instruction. Agents without a policy use
+/// the defaults: only the owner and operator may log, alerts at
+/// DEFAULT_ALERT_THRESHOLD and no per-action risk caps.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditPolicy {
+    /// The agent this policy applies to
+    pub agent: Pubkey,
+
+    /// Delegate allowed to manage the policy besides the owner (default = none)
+    pub compliance_officer: Pubkey,
+
+    /// Risk score at or above which an entry counts as a security alert (1-100)
+    pub alert_threshold: u8,
+
+    /// Designated sentinel keys allowed to log audit entries besides the owner and operator
+    #[max_len(8)]
+    pub sentinels: Vec<Pubkey>,
+
+    /// Highest expected risk score per ActionType (indexed by discriminant)
+    /// Entries above their cap count as security alerts
+    pub max_risk: [u8; ActionType::COUNT],
+
+    /// Last update timestamp
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AuditPolicy {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_policy";
+
+    /// Maximum designated sentinel keys
+    pub const MAX_SENTINELS: usize = 8;
+
+    /// Alert threshold used when an agent has no policy
+    pub const DEFAULT_ALERT_THRESHOLD: u8 = 75;
+
+    /// Policy applied when an agent has none
+    pub fn default_for(agent: Pubkey) -> Self {
+        Self {
+            agent,
+            compliance_officer: Pubkey::default(),
+            alert_threshold: Self::DEFAULT_ALERT_THRESHOLD,
+            sentinels: Vec::new(),
+            max_risk: [100; ActionType::COUNT],
+            updated_at: 0,
+            bump: 0,
+        }
+    }
+
+    /// Policy stored in an agent's policy account, or the default if uninitialized
+    pub fn load_or_default(policy_info: &AccountInfo, agent: Pubkey) -> Result<Self> {
+        if policy_info.data_is_empty() {
+            return Ok(Self::default_for(agent));
+        }
+        require_keys_eq!(*policy_info.owner, crate::ID, RegistryError::Unauthorized);
+        AuditPolicy::try_deserialize(&mut &policy_info.try_borrow_data()?[..])
+    }
+
+    /// Check if a signer may manage this agent's policy
+    pub fn is_manager(&self, agent: &AgentAccount, signer: &Pubkey) -> bool {
+        agent.owner == *signer
+            || (self.compliance_officer != Pubkey::default() && self.compliance_officer == *signer)
+    }
+
+    /// Check if a signer may log audit entries for an agent: its owner, operator,
+    /// sentinel authority or a sentinel designated in its policy account
+    pub fn is_audit_actor(policy_info: &AccountInfo, agent: &AgentAccount, actor: &Pubkey) -> bool {
+        agent.is_responder(actor) || Self::is_sentinel(policy_info, agent, actor)
+    }
+
+    /// Check if a signer is one of the agent's sentinels: its sentinel authority
+    /// or a sentinel designated in its policy account (not the owner or operator)
+    pub fn is_sentinel(policy_info: &AccountInfo, agent: &AgentAccount, actor: &Pubkey) -> bool {
+        if agent.sentinel_authority != Pubkey::default() && agent.sentinel_authority == *actor {
+            return true;
+        }
+        if policy_info.data_is_empty() || *policy_info.owner != crate::ID {
+            return false;
+        }
+        let Ok(data) = policy_info.try_borrow_data() else {
+            return false;
+        };
+        AuditPolicy::try_deserialize(&mut &data[..])
+            .map(|policy| policy.sentinels.contains(actor))
+            .unwrap_or(false)
+    }
+
+    /// Whether an entry counts as a security alert under this policy
+    /// Anything the registry config treats as high risk is an alert regardless of
+    /// the stated action type or a lenient policy threshold
+    pub fn is_alert(
+        &self,
+        action_type: ActionType,
+        risk_score: u8,
+        config: &RegistryConfig,
+    ) -> bool {
+        matches!(action_type, ActionType::SecurityAlert)
+            || risk_score >= self.alert_threshold
+            || risk_score > self.max_risk[action_type.index()]
+            || config.requires_sentinel(&action_type, risk_score)
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// A key allowed to read an agent's encrypted audit details
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct AuditReader {
+    /// Reader identity (regulator, auditor)
+    pub reader: Pubkey,
+
+    /// X25519 public key the agent encrypts audit details to for this reader
+    pub encryption_key: [u8; 32],
+
+    /// Unix timestamp when the reader was added
+    pub added_at: i64,
+}
+
+/// Per-agent registry of readers for encrypted audit details
+///
+/// Sensitive audit context is encrypted off-chain to every listed reader's
+/// encryption key and referenced by an entry's `encrypted_details_uri`; the
+/// plaintext hash stays in `details_hash` so anyone can still check integrity
+/// once a reader discloses the details.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditReaders {
+    /// The agent these readers belong to
+    pub agent: Pubkey,
+
+    /// Registered readers
+    #[max_len(8)]
+    pub readers: Vec<AuditReader>,
+
+    /// Unix timestamp of the last change
+    pub updated_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditReaders {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_readers";
+
+    /// Maximum registered readers
+    pub const MAX_READERS: usize = 8;
+
+    /// Check if a key is a registered reader
+    pub fn is_reader(&self, reader: &Pubkey) -> bool {
+        self.readers.iter().any(|r| r.reader == *reader)
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::ActionType;
+
+/// One compact audit record in an AuditRing
+#[zero_copy]
+pub struct AuditRingEntry {
+    /// Unix timestamp when the action occurred
+    pub timestamp: i64,
+
+    /// Sequential audit index for this agent (shared with the summary)
+    pub audit_index: u64,
+
+    /// SHA256 hash of detailed action data (stored off-chain)
+    pub details_hash: [u8; 32],
+
+    /// ActionType index (see ActionType::index)
+    pub action_type: u8,
+
+    /// Risk level assessment (0-100)
+    pub risk_score: u8,
+
+    /// Custom action code (0 for built-in action types)
+    pub custom_code: u16,
+
+    /// RiskWeights version the risk score was calculated with
+    pub risk_weights_version: u32,
+}
+
+/// Fixed-size audit log per agent (zero-copy ring buffer)
+///
+/// Alternative to one AuditEntry PDA per action: rent is paid once and the
+/// newest entry overwrites the oldest once CAPACITY is reached. Entries still
+/// feed the agent's AgentAuditSummary. CAPACITY keeps the account under the
+/// 10 KiB limit for accounts created inside an instruction.
+#[account(zero_copy)]
+pub struct AuditRing {
+    /// The agent this ring belongs to
+    pub agent: Pubkey,
+
+    /// Entries written since creation
+    pub total_written: u64,
+
+    /// Slot the next entry is written to
+    pub head: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    pub _padding: [u8; 3],
+
+    /// Ring storage (oldest entries are overwritten first)
+    pub entries: [AuditRingEntry; 128],
+}
+
+impl AuditRing {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_ring";
+
+    /// Number of entries kept
+    pub const CAPACITY: usize = 128;
+
+    /// Account size including the discriminator
+    pub const SPACE: usize = 8 + std::mem::size_of::<AuditRing>();
+
+    /// Maximum entries per log_audit_batch call
+    pub const MAX_BATCH: usize = 16;
+
+    /// Append an audit record, returning the slot it was written to
+    pub fn record(
+        &mut self,
+        action_type: ActionType,
+        risk_score: u8,
+        details_hash: [u8; 32],
+        audit_index: u64,
+        timestamp: i64,
+        risk_weights_version: u32,
+    ) -> usize {
+        let slot = self.head as usize;
+        self.push(AuditRingEntry {
+            timestamp,
+            audit_index,
+            details_hash,
+            action_type: action_type.index() as u8,
+            risk_score,
+            custom_code: action_type.custom_code(),
+            risk_weights_version,
+        });
+        slot
+    }
+
+    /// Write an entry at the head, overwriting the oldest once full
+    pub fn push(&mut self, entry: AuditRingEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = ((self.head as usize + 1) % Self::CAPACITY) as u32;
+        self.total_written = self.total_written.saturating_add(1);
+    }
+
+    /// Number of entries currently held
+    pub fn len(&self) -> usize {
+        (self.total_written as usize).min(Self::CAPACITY)
+    }
+
+    /// Check if no entry was written yet
+    pub fn is_empty(&self) -> bool {
+        self.total_written == 0
+    }
+}
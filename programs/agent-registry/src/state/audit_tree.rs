@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+/// Per-agent audit trail kept in an SPL account-compression concurrent Merkle tree
+///
+/// Only the tree account (header + canopy-free buffer) lives on-chain; each
+/// audit entry is appended as one 32-byte leaf, its audit chain link hash, so
+/// storage cost no longer grows with one PDA per entry. This PDA is the tree
+/// authority and signs every append. Leaves are reconstructed off-chain from
+/// the AuditTreeAppended events.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditTree {
+    /// The agent this tree belongs to
+    pub agent: Pubkey,
+
+    /// The concurrent Merkle tree account (owned by the compression program)
+    pub merkle_tree: Pubkey,
+
+    /// Tree depth (capacity is 2^max_depth leaves)
+    pub max_depth: u32,
+
+    /// Concurrent changelog buffer size
+    pub max_buffer_size: u32,
+
+    /// Leaves appended so far (next leaf index)
+    pub leaves_appended: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditTree {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_tree";
+
+    /// SPL account-compression program
+    pub const COMPRESSION_PROGRAM_ID: Pubkey =
+        pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+    /// SPL noop program (changelog sink required by the compression program)
+    pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+    /// Anchor discriminators of the compression program instructions used here
+    const INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+    const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+
+    /// Deepest tree accepted (the compression program's own limit)
+    pub const MAX_DEPTH: u32 = 30;
+
+    /// Whether the next leaf still fits in the tree
+    pub fn has_capacity(&self) -> bool {
+        self.leaves_appended < 1u64 << self.max_depth
+    }
+
+    /// `init_empty_merkle_tree(max_depth, max_buffer_size)` with `authority` as tree authority
+    pub fn init_ix(
+        merkle_tree: Pubkey,
+        authority: Pubkey,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Instruction {
+        let mut data = Self::INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&max_depth.to_le_bytes());
+        data.extend_from_slice(&max_buffer_size.to_le_bytes());
+        Self::modify_ix(merkle_tree, authority, data)
+    }
+
+    /// `append(leaf)` signed by the tree authority
+    pub fn append_ix(merkle_tree: Pubkey, authority: Pubkey, leaf: [u8; 32]) -> Instruction {
+        let mut data = Self::APPEND_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&leaf);
+        Self::modify_ix(merkle_tree, authority, data)
+    }
+
+    /// Both instructions take (merkle_tree mut, authority signer, noop)
+    fn modify_ix(merkle_tree: Pubkey, authority: Pubkey, data: Vec<u8>) -> Instruction {
+        Instruction {
+            program_id: Self::COMPRESSION_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(merkle_tree, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(Self::NOOP_PROGRAM_ID, false),
+            ],
+            data,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Admin-managed registry of external auditor keys
+///
+/// A registered auditor may co-sign `log_audit`; the entry then records the
+/// auditor, which is stronger evidence than a self-reported log.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct AuditorRegistry {
+    /// Registered auditor keys
+    #[max_len(16)]
+    pub auditors: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AuditorRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"auditors";
+
+    /// Maximum registered auditors
+    pub const MAX_AUDITORS: usize = 16;
+
+    /// Registry stored in the registry account, or an empty one if uninitialized
+    pub fn load_or_empty(registry_info: &AccountInfo) -> Result<Self> {
+        if registry_info.data_is_empty() {
+            return Ok(Self::default());
+        }
+        require_keys_eq!(*registry_info.owner, crate::ID, RegistryError::Unauthorized);
+        AuditorRegistry::try_deserialize(&mut &registry_info.try_borrow_data()?[..])
+    }
+
+    /// Check if a key is a registered auditor
+    pub fn is_registered(&self, auditor: &Pubkey) -> bool {
+        self.auditors.contains(auditor)
+    }
+}
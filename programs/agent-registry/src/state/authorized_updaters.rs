@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Admin-managed list of signers allowed to call `update_reputation`
+///
+/// Intended for program-derived signers: an integrating program (e.g. a
+/// marketplace) CPIs into `update_reputation` with `invoke_signed`, and its
+/// PDA must be listed here. The registry admin is always allowed.
+#[account]
+#[derive(InitSpace)]
+pub struct AuthorizedUpdaters {
+    /// Authorized updater keys (usually PDAs of integrating programs)
+    #[max_len(16)]
+    pub updaters: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl AuthorizedUpdaters {
+    pub const SEED_PREFIX: &'static [u8] = b"authorized_updaters";
+
+    /// Maximum authorized updaters
+    pub const MAX_UPDATERS: usize = 16;
+
+    /// Check if a signer may update reputation
+    pub fn is_authorized(&self, updater: &Pubkey) -> bool {
+        self.updaters.contains(updater)
+    }
+}
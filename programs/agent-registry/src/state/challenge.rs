@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+use crate::state::{ActionType, AgentAccount, RegistryConfig};
 
 /// Challenge status enum
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
@@ -11,6 +13,12 @@ pub enum ChallengeStatus {
     Failed,
     /// Challenge expired without response
     Expired,
+    /// Agent responded, waiting for the challenger to reveal the salt
+    Responded,
+    /// Challenger did not reveal within the reveal window (no reputation effect)
+    Abandoned,
+    /// Verdict contested within the dispute window, awaiting arbitration
+    Disputed,
 }
 
 impl Default for ChallengeStatus {
@@ -19,6 +27,102 @@ impl Default for ChallengeStatus {
     }
 }
 
+/// Challenge category - drives reputation weights and audit classification
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum ChallengeKind {
+    /// Domain knowledge question
+    Knowledge,
+    /// Liveness check (is the agent online and responsive)
+    Liveness,
+    /// Code execution / tool-use task
+    CodeExec,
+    /// Safety / policy compliance probe
+    Safety,
+    /// Custom challenge type for extensibility
+    Custom,
+}
+
+impl ChallengeKind {
+    /// Number of kinds (capability domains in DomainReputation)
+    pub const COUNT: usize = 5;
+
+    /// All kinds, in domain_index order
+    pub const ALL: [ChallengeKind; Self::COUNT] = [
+        ChallengeKind::Knowledge,
+        ChallengeKind::Liveness,
+        ChallengeKind::CodeExec,
+        ChallengeKind::Safety,
+        ChallengeKind::Custom,
+    ];
+
+    /// Slot of this kind's capability domain in DomainReputation
+    pub fn domain_index(&self) -> usize {
+        match self {
+            ChallengeKind::Knowledge => 0,
+            ChallengeKind::Liveness => 1,
+            ChallengeKind::CodeExec => 2,
+            ChallengeKind::Safety => 3,
+            ChallengeKind::Custom => 4,
+        }
+    }
+
+    /// Default reputation gain for passing a challenge of this kind
+    /// (initial value of RegistryConfig::pass_reputation_deltas)
+    pub fn default_pass_delta(&self) -> i32 {
+        match self {
+            ChallengeKind::Knowledge => 100,
+            ChallengeKind::Liveness => 50,
+            ChallengeKind::CodeExec => 150,
+            ChallengeKind::Safety => 150,
+            ChallengeKind::Custom => 100,
+        }
+    }
+
+    /// Default reputation loss for failing (or not answering) a challenge of this kind
+    /// (initial value of RegistryConfig::fail_reputation_deltas)
+    pub fn default_fail_delta(&self) -> i32 {
+        match self {
+            ChallengeKind::Knowledge => -50,
+            ChallengeKind::Liveness => -100,
+            ChallengeKind::CodeExec => -50,
+            ChallengeKind::Safety => -150,
+            ChallengeKind::Custom => -50,
+        }
+    }
+
+    /// Audit action type recorded for a resolved challenge of this kind
+    /// Failed safety probes are classified as security alerts
+    pub fn audit_action(&self, passed: bool) -> ActionType {
+        match (self, passed) {
+            (_, true) => ActionType::ChallengePassed,
+            (ChallengeKind::Safety, false) => ActionType::SecurityAlert,
+            (_, false) => ActionType::ChallengeFailed,
+        }
+    }
+}
+
+/// One round of a multi-round challenge
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ChallengeRound {
+    /// Round question/prompt
+    #[max_len(128)]
+    pub question: String,
+
+    /// Commitment to the round's expected answer: SHA256(answer_hash || salt) as hex
+    #[max_len(64)]
+    pub expected_hash: String,
+
+    /// Agent's answer hash for this round (set when the round is revealed)
+    #[max_len(64)]
+    pub response_hash: String,
+
+    /// Whether the agent passed this round
+    pub passed: bool,
+
+    /// Unix timestamp when the round was revealed (0 = not yet)
+    pub resolved_at: i64,
+}
+
 /// Challenge account - represents a verification challenge for an agent
 #[account]
 #[derive(InitSpace)]
@@ -33,7 +137,9 @@ pub struct Challenge {
     #[max_len(256)]
     pub question: String,
 
-    /// SHA256 hash of the expected answer (for verification)
+    /// Commitment to the expected answer: SHA256(answer_hash || salt) as hex
+    /// where answer_hash is the hex SHA256 of the answer (what the agent submits).
+    /// Keeping the salt secret until `reveal_challenge` stops agents copying it.
     #[max_len(64)]
     pub expected_hash: String,
 
@@ -54,6 +160,142 @@ pub struct Challenge {
 
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// SHA256 hash of the agent's answer (set by `submit_response`)
+    #[max_len(64)]
+    pub response_hash: String,
+
+    /// Challenger bond (lamports) escrowed in this PDA on top of rent
+    /// Refunded on reveal, slashed to the agent owner if the challenger abandons
+    pub bond: u64,
+
+    /// Reward (lamports) escrowed in this PDA, paid to the agent owner on pass
+    /// Refunded to the challenger on fail; otherwise returned when the PDA is closed
+    pub reward: u64,
+
+    /// SPL mint of the token reward (default = no token reward)
+    pub reward_mint: Pubkey,
+
+    /// Token reward amount held in the challenge vault ATA
+    /// Settled via `settle_token_reward` once the challenge is resolved
+    pub token_reward: u64,
+
+    /// Challenge category (selects reputation weights)
+    pub kind: ChallengeKind,
+
+    /// Difficulty level (1..=config.max_difficulty) scaling reputation deltas
+    pub difficulty: u8,
+
+    /// Third-party verifier who scores the response via `resolve_challenge`
+    /// Default pubkey = no verifier (challenger resolves via commit-reveal)
+    pub verifier: Pubkey,
+
+    /// Unix timestamp of the pass/fail verdict (opens the dispute window)
+    pub resolved_at: i64,
+
+    /// Reputation delta applied by the verdict after clamping, summed over every
+    /// scored round of a multi-round challenge (reversed if a dispute is upheld)
+    pub reputation_delta: i32,
+
+    /// Whether the verdict has already been disputed (one dispute per challenge)
+    pub disputed: bool,
+
+    /// Verifier panel for M-of-N consensus resolution via `attest_result`
+    /// Empty = no panel (single verifier or commit-reveal)
+    #[max_len(5)]
+    pub verifiers: Vec<Pubkey>,
+
+    /// Pass attestations required from the panel to finalize a pass
+    pub quorum: u8,
+
+    /// Bitmask of panel members (by index) that have already attested
+    pub attested_mask: u8,
+
+    /// Pass attestations received so far
+    pub pass_votes: u8,
+
+    /// Fail attestations received so far
+    pub fail_votes: u8,
+
+    /// Switchboard feed whose result decides the verdict (default = no oracle)
+    pub oracle_feed: Pubkey,
+
+    /// Groth16 verifying key for proof-of-inference mode (default = hash mode)
+    /// The agent resolves the challenge itself via `submit_zk_response`
+    pub zk_verifying_key: Pubkey,
+
+    /// Template this challenge was created from (default = free-form)
+    pub template: Pubkey,
+
+    /// Off-chain pointer to the full model output backing `response_hash`
+    /// (e.g. "ar://<tx id>" or "ipfs://<cid>"), empty if not provided
+    #[max_len(128)]
+    pub evidence_uri: String,
+
+    /// Rounds of a multi-round challenge (empty = single-shot)
+    /// The active round is mirrored into question / expected_hash / expires_at
+    #[max_len(4)]
+    pub rounds: Vec<ChallengeRound>,
+
+    /// Index of the active round
+    pub current_round: u8,
+
+    /// Rounds passed so far
+    pub rounds_passed: u8,
+
+    /// Resolver's score (0-100); binary verdicts record 0 or MAX_SCORE
+    pub score: u8,
+
+    /// External verifier program resolving via CPI (default = none)
+    pub verifier_program: Pubkey,
+
+    /// Challenger weight (basis points) applied to reputation deltas, fixed at creation
+    /// 0 = created before weighting existed (1x)
+    pub challenger_weight_bps: u16,
+
+    /// Reputation bonus for a fast response, granted on pass (0 = none)
+    /// Weighted by challenger_weight_bps like the pass delta
+    pub response_bonus: u16,
+
+    /// Registered agent issuing this challenge via its owner or operator key
+    /// (default = plain wallet challenger)
+    pub challenger_agent: Pubkey,
+
+    /// Whether the peer result was recorded on challenger_agent (see record_peer_result)
+    pub peer_recorded: bool,
+
+    /// Off-chain document with rich challenge context (empty if not provided)
+    #[max_len(128)]
+    pub metadata_uri: String,
+
+    /// SHA256 (hex) of the metadata document, so explorers can verify what the URI serves
+    #[max_len(64)]
+    pub metadata_hash: String,
+
+    /// Classification codes for explorers (registry-defined, up to MAX_TAGS)
+    #[max_len(4)]
+    pub tags: Vec<u16>,
+
+    /// Whether the verdict was applied to the agent's DomainReputation (see record_domain_result)
+    pub domain_recorded: bool,
+
+    /// Boost on reputation gains from the agent's stake at creation (basis points, 0 = unstaked)
+    pub stake_boost_bps: u16,
+
+    /// Whether the agent's stake was slashed for this challenge (see slash_stake)
+    pub stake_slashed: bool,
+
+    /// Reputation delta withheld from the agent while the verdict is disputed
+    pub pending_delta: i32,
+
+    /// EMA smoothing factor at creation (basis points, 0 = additive deltas)
+    pub ema_alpha_bps: u16,
+
+    /// Configured pass delta for the challenge kind at creation (before scaling)
+    pub base_pass_delta: i32,
+
+    /// Configured fail delta for the challenge kind at creation (before scaling)
+    pub base_fail_delta: i32,
 }
 
 impl Challenge {
@@ -62,14 +304,372 @@ impl Challenge {
     /// Default challenge duration (1 hour in seconds)
     pub const DEFAULT_DURATION: i64 = 3600;
 
-    /// Reputation gain for passing a challenge
-    pub const PASS_REPUTATION_DELTA: i32 = 100;
+    /// Time the challenger has to reveal the salt after the agent responds (1 hour)
+    pub const REVEAL_WINDOW: i64 = 3600;
+
+    /// Maximum length of a response evidence URI
+    pub const MAX_EVIDENCE_URI_LEN: usize = 128;
+
+    /// Maximum length of a challenge metadata URI
+    pub const MAX_METADATA_URI_LEN: usize = 128;
+
+    /// Maximum tag codes per challenge
+    pub const MAX_TAGS: usize = 4;
+
+    /// Maximum rounds in a multi-round challenge
+    pub const MAX_ROUNDS: usize = 4;
+
+    /// Maximum question length for a multi-round challenge round
+    pub const MAX_ROUND_QUESTION_LEN: usize = 128;
+
+    /// Longest single deadline extension (24 hours)
+    pub const MAX_DEADLINE_EXTENSION: i64 = 24 * 3600;
+
+    /// Latest deadline (after creation) the challenger can set alone (24 hours)
+    pub const MAX_SOLO_LIFETIME: i64 = 24 * 3600;
+
+    /// Latest deadline (after creation) with the agent owner co-signing (7 days)
+    pub const MAX_AGREED_LIFETIME: i64 = 7 * 24 * 3600;
+
+    /// Time the challenger has to close a finished challenge before anyone can (7 days)
+    pub const RECLAIM_WINDOW: i64 = 7 * 24 * 3600;
+
+    /// Share of the rent (basis points) kept by whoever closes an unclaimed challenge
+    pub const CLEANUP_RENT_BPS: u64 = 2_000;
+
+    /// Reputation bonus for responding under config.fast_response_secs
+    pub const FAST_RESPONSE_BONUS: u16 = 20;
 
-    /// Reputation loss for failing a challenge
-    pub const FAIL_REPUTATION_DELTA: i32 = -50;
+    /// Reputation bonus for responding under config.quick_response_secs
+    pub const QUICK_RESPONSE_BONUS: u16 = 10;
+
+    /// Highest (full credit) score
+    pub const MAX_SCORE: u8 = 100;
+
+    /// Lowest score that counts as a pass
+    pub const PASS_SCORE: u8 = 50;
+
+    /// Maximum size of a verifier panel
+    pub const MAX_VERIFIERS: usize = 5;
+
+    /// Time either party has to dispute a pass/fail verdict (24 hours)
+    pub const DISPUTE_WINDOW: i64 = 24 * 3600;
+
+    /// Difficulty at which deltas equal the kind's base deltas
+    pub const BASELINE_DIFFICULTY: u8 = 5;
+
+    /// Reputation gain for passing this challenge (kind weight scaled by difficulty,
+    /// challenger weight and the agent's stake boost)
+    pub fn pass_delta(&self) -> i32 {
+        let delta = self.scale_for_challenge(self.base_pass_delta) as i64;
+        let denominator = RegistryConfig::BPS_DENOMINATOR as i64;
+        (delta * (denominator + self.stake_boost_bps as i64) / denominator) as i32
+    }
+
+    /// Reputation loss for failing this challenge (kind weight scaled by difficulty and challenger weight)
+    pub fn fail_delta(&self) -> i32 {
+        self.scale_for_challenge(self.base_fail_delta)
+    }
+
+    fn scale_for_challenge(&self, delta: i32) -> i32 {
+        self.apply_challenger_weight(Self::scale_delta(delta, self.difficulty))
+    }
+
+    /// Scale a delta by the challenger weight (1x for challenges created before weighting)
+    fn apply_challenger_weight(&self, delta: i32) -> i32 {
+        if self.challenger_weight_bps == 0 {
+            return delta;
+        }
+        (delta as i64 * self.challenger_weight_bps as i64 / RegistryConfig::BPS_DENOMINATOR as i64) as i32
+    }
+
+    /// Scale a delta by difficulty / BASELINE_DIFFICULTY
+    /// Challenges created before difficulty existed (0) use the baseline
+    pub fn scale_delta(delta: i32, difficulty: u8) -> i32 {
+        let difficulty = if difficulty == 0 {
+            Self::BASELINE_DIFFICULTY
+        } else {
+            difficulty
+        };
+        delta * difficulty as i32 / Self::BASELINE_DIFFICULTY as i32
+    }
+
+    /// Apply a final pass/fail verdict: status, agent counters and reputation
+    /// Gains are capped by `max_change` per window (AgentAccount::credit_reputation)
+    /// Returns the reputation delta applied
+    pub fn apply_verdict(
+        &mut self,
+        agent: &mut AgentAccount,
+        passed: bool,
+        now: i64,
+        max_change: u32,
+    ) -> i32 {
+        let score = if passed { Self::MAX_SCORE } else { 0 };
+        self.apply_score(agent, score, now, max_change)
+    }
+
+    /// Reputation delta for a score: linear from fail_delta (0) to pass_delta (MAX_SCORE)
+    pub fn score_delta(&self, score: u8) -> i32 {
+        let fail = self.fail_delta();
+        fail + (self.pass_delta() - fail) * score as i32 / Self::MAX_SCORE as i32
+    }
+
+    /// Reputation delta for a score given the agent's current reputation
+    /// Additive mode uses score_delta. EMA mode moves the reputation towards the
+    /// score (as a share of MAX_REPUTATION) by alpha, scaled by difficulty and
+    /// challenger weight, so bursts of challenges cannot swing it violently.
+    pub fn verdict_delta(&self, current: u32, score: u8) -> i32 {
+        if self.ema_alpha_bps == 0 {
+            return self.score_delta(score);
+        }
+        let denominator = RegistryConfig::BPS_DENOMINATOR as i64;
+        let alpha = (self.scale_for_challenge(self.ema_alpha_bps as i32) as i64).clamp(0, denominator);
+        let target = AgentAccount::MAX_REPUTATION as i64 * score as i64 / Self::MAX_SCORE as i64;
+        ((target - current as i64) * alpha / denominator) as i32
+    }
+
+    /// Apply a partial-credit score: status (Passed at PASS_SCORE or above),
+    /// agent counters and reputation (verdict_delta, plus the response bonus on pass)
+    /// Returns the reputation delta applied
+    pub fn apply_score(
+        &mut self,
+        agent: &mut AgentAccount,
+        score: u8,
+        now: i64,
+        max_change: u32,
+    ) -> i32 {
+        let mut delta = self.verdict_delta(agent.reputation_score, score);
+        if score >= Self::PASS_SCORE {
+            delta += self.apply_challenger_weight(self.response_bonus as i32);
+            self.status = ChallengeStatus::Passed;
+            agent.challenges_passed = agent.challenges_passed.saturating_add(1);
+        } else {
+            self.status = ChallengeStatus::Failed;
+            agent.challenges_failed = agent.challenges_failed.saturating_add(1);
+        }
+        let delta = agent.credit_reputation(delta, now, max_change);
+        agent.updated_at = now;
+        self.resolved_at = now;
+        self.reputation_delta = delta;
+        self.score = score;
+        delta
+    }
+
+    /// Check if this is a multi-round challenge
+    pub fn is_multi_round(&self) -> bool {
+        self.rounds.len() > 1
+    }
+
+    /// Score the active round of a multi-round challenge
+    /// Each round is worth 1/N of the challenge's pass/fail verdict delta; the applied
+    /// deltas add up in `reputation_delta` so a dispute reverts all rounds. Advances to the
+    /// next round (status back to Pending with a fresh deadline) and returns false,
+    /// or returns true once the last round has been scored.
+    pub fn record_round(
+        &mut self,
+        agent: &mut AgentAccount,
+        passed: bool,
+        now: i64,
+        max_change: u32,
+    ) -> bool {
+        let total = self.rounds.len() as i32;
+        let score = if passed { Self::MAX_SCORE } else { 0 };
+        let delta = self.verdict_delta(agent.reputation_score, score) / total;
+        let delta = agent.credit_reputation(delta, now, max_change);
+        agent.updated_at = now;
+        self.reputation_delta += delta;
+
+        let index = self.current_round as usize;
+        let round = &mut self.rounds[index];
+        round.response_hash = self.response_hash.clone();
+        round.passed = passed;
+        round.resolved_at = now;
+        if passed {
+            self.rounds_passed += 1;
+        }
+
+        if index + 1 == self.rounds.len() {
+            return true;
+        }
+
+        self.current_round += 1;
+        let next = &self.rounds[index + 1];
+        self.question = next.question.clone();
+        self.expected_hash = next.expected_hash.clone();
+        self.response_hash.clear();
+        self.status = ChallengeStatus::Pending;
+        self.expires_at = now + Self::DEFAULT_DURATION;
+        false
+    }
+
+    /// Finalize a multi-round challenge once its last round is scored
+    /// Reputation was already applied per round; passes only if every round passed
+    pub fn finish_rounds(&mut self, agent: &mut AgentAccount, now: i64) -> bool {
+        let passed = self.rounds_passed as usize == self.rounds.len();
+        self.score = (self.rounds_passed as usize * Self::MAX_SCORE as usize / self.rounds.len()) as u8;
+        if passed {
+            self.status = ChallengeStatus::Passed;
+            agent.challenges_passed = agent.challenges_passed.saturating_add(1);
+        } else {
+            self.status = ChallengeStatus::Failed;
+            agent.challenges_failed = agent.challenges_failed.saturating_add(1);
+        }
+        self.resolved_at = now;
+        passed
+    }
+
+    /// Withhold the verdict's reputation delta from the agent until arbitration
+    /// Records what was actually withheld, so unfreeze_delta restores exactly that
+    pub fn freeze_delta(&mut self, agent: &mut AgentAccount, now: i64) {
+        self.pending_delta = -agent.adjust_reputation(-self.reputation_delta, now);
+    }
+
+    /// Re-apply a delta withheld by freeze_delta
+    pub fn unfreeze_delta(&mut self, agent: &mut AgentAccount, now: i64) {
+        agent.adjust_reputation(self.pending_delta, now);
+        self.pending_delta = 0;
+    }
+
+    /// Undo the counters and reputation applied by the current pass/fail verdict
+    /// (the cumulative delta of all rounds on a multi-round challenge)
+    pub fn revert_verdict(&mut self, agent: &mut AgentAccount, was_passed: bool, now: i64) {
+        if was_passed {
+            agent.challenges_passed = agent.challenges_passed.saturating_sub(1);
+        } else {
+            agent.challenges_failed = agent.challenges_failed.saturating_sub(1);
+        }
+        agent.adjust_reputation(-self.reputation_delta, now);
+        self.reputation_delta = 0;
+    }
+
+    /// Check if the challenger is a registered agent
+    pub fn is_peer_challenge(&self) -> bool {
+        self.challenger_agent != Pubkey::default()
+    }
+
+    /// Check if a peer result still has to be recorded before the account can close
+    pub fn is_peer_result_pending(&self) -> bool {
+        self.is_peer_challenge() && !self.peer_recorded
+    }
+
+    /// Check if a domain result can still be recorded (record_domain_result)
+    /// Only verdicts with a reputation effect (pass, fail, expiry) count
+    pub fn is_domain_result_pending(&self) -> bool {
+        matches!(
+            self.status,
+            ChallengeStatus::Passed | ChallengeStatus::Failed | ChallengeStatus::Expired
+        ) && !self.domain_recorded
+    }
+
+    /// Check if the agent's stake still has to be slashed before the account can close
+    pub fn is_stake_slash_pending(&self) -> bool {
+        self.stake_boost_bps > 0
+            && matches!(self.status, ChallengeStatus::Failed | ChallengeStatus::Expired)
+            && !self.stake_slashed
+    }
+
+    /// Check if a third party (verifier, verifier panel or oracle) resolves this challenge
+    pub fn has_verifier(&self) -> bool {
+        self.verifier != Pubkey::default()
+            || self.has_verifier_panel()
+            || self.has_oracle()
+            || self.has_verifier_program()
+    }
+
+    /// Check that no named verifier (single or panel) is the challenger or
+    /// controls the agent, i.e. the challenger did not appoint themselves or the owner
+    pub fn has_independent_verifiers(&self, agent: &AgentAccount) -> bool {
+        let independent = |key: &Pubkey| *key != self.challenger && !agent.is_responder(key);
+        (self.verifier == Pubkey::default() || independent(&self.verifier))
+            && self.verifiers.iter().all(independent)
+    }
+
+    /// Check if an external verifier program resolves this challenge via CPI
+    pub fn has_verifier_program(&self) -> bool {
+        self.verifier_program != Pubkey::default()
+    }
+
+    /// Check if this challenge is resolved from a Switchboard feed
+    pub fn has_oracle(&self) -> bool {
+        self.oracle_feed != Pubkey::default()
+    }
+
+    /// Check if this challenge is resolved by M-of-N panel consensus
+    pub fn has_verifier_panel(&self) -> bool {
+        !self.verifiers.is_empty()
+    }
+
+    /// Check if the agent must answer with a zero-knowledge proof
+    pub fn requires_zk_proof(&self) -> bool {
+        self.zk_verifying_key != Pubkey::default()
+    }
+
+    /// Panel verdict once reachable: Some(true) when the pass quorum is met,
+    /// Some(false) once enough members failed it that the quorum is unreachable
+    pub fn panel_verdict(&self) -> Option<bool> {
+        if self.pass_votes >= self.quorum {
+            Some(true)
+        } else if self.fail_votes as usize > self.verifiers.len() - self.quorum as usize {
+            Some(false)
+        } else {
+            None
+        }
+    }
 
     /// Check if challenge has expired
     pub fn is_expired(&self, current_time: i64) -> bool {
         current_time > self.expires_at
     }
+
+    /// Check if the challenger missed the reveal window after a response
+    pub fn is_reveal_expired(&self, current_time: i64) -> bool {
+        current_time > self.responded_at + Self::REVEAL_WINDOW
+    }
+
+    /// Check if the challenge has reached a final status
+    pub fn is_resolved(&self) -> bool {
+        !matches!(
+            self.status,
+            ChallengeStatus::Pending | ChallengeStatus::Responded | ChallengeStatus::Disputed
+        )
+    }
+
+    /// Check if the challenge is still waiting for a response or a verdict
+    pub fn is_awaiting_verdict(&self) -> bool {
+        matches!(self.status, ChallengeStatus::Pending | ChallengeStatus::Responded)
+    }
+
+    /// Check if a pass/fail verdict can still be disputed
+    pub fn is_dispute_window_open(&self, current_time: i64) -> bool {
+        matches!(self.status, ChallengeStatus::Passed | ChallengeStatus::Failed)
+            && !self.disputed
+            && current_time <= self.resolved_at + Self::DISPUTE_WINDOW
+    }
+
+    /// Check if the challenger's exclusive window to close this challenge is still open
+    /// Runs RECLAIM_WINDOW past the latest of deadline, response and verdict
+    pub fn is_reclaim_window_open(&self, current_time: i64) -> bool {
+        let last_activity = self
+            .expires_at
+            .max(self.responded_at)
+            .max(self.resolved_at);
+        current_time <= last_activity + Self::RECLAIM_WINDOW
+    }
+
+    /// PDA signer seeds for this challenge (escrow/vault authority)
+    pub fn signer_seeds<'a>(&'a self, nonce_bytes: &'a [u8; 8], bump: &'a [u8; 1]) -> [&'a [u8]; 5] {
+        [
+            Self::SEED_PREFIX,
+            self.agent.as_ref(),
+            self.challenger.as_ref(),
+            nonce_bytes.as_ref(),
+            bump.as_ref(),
+        ]
+    }
+
+    /// Compute the commitment for a response hash and salt: hex(SHA256(response_hash || salt))
+    pub fn compute_commitment(response_hash: &str, salt: &[u8; 32]) -> String {
+        let digest = hashv(&[response_hash.as_bytes(), salt.as_ref()]).to_bytes();
+        digest.iter().map(|b| format!("{:02x}", b)).collect()
+    }
 }
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+/// Latest challenge from one challenger against the indexed agent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ChallengerEntry {
+    pub challenger: Pubkey,
+
+    /// Nonce of the most recent challenge (PDA: [challenge, agent, challenger, nonce])
+    pub latest_nonce: u64,
+
+    /// Challenges this challenger has created against the agent
+    pub count: u32,
+
+    /// Unix timestamp of the most recent challenge
+    pub last_challenged_at: i64,
+}
+
+/// Per-agent challenge index
+///
+/// Lets clients enumerate an agent's challenge history and derive challenge
+/// PDAs without scanning program accounts. Tracks the most recently active
+/// challengers; when full, the least recently active entry is replaced.
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeIndex {
+    /// The indexed agent
+    pub agent: Pubkey,
+
+    /// Challenges ever created against the agent
+    pub total_challenges: u64,
+
+    /// Distinct challengers ever recorded (including evicted entries)
+    pub total_challengers: u32,
+
+    /// Most recently active challengers
+    #[max_len(16)]
+    pub entries: Vec<ChallengerEntry>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ChallengeIndex {
+    pub const SEED_PREFIX: &'static [u8] = b"challenge_index";
+
+    /// Maximum challengers tracked per agent
+    pub const MAX_ENTRIES: usize = 16;
+
+    /// Record a new challenge, updating or inserting the challenger's entry
+    pub fn record(&mut self, challenger: Pubkey, nonce: u64, now: i64) {
+        self.total_challenges = self.total_challenges.saturating_add(1);
+
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.challenger == challenger) {
+            entry.latest_nonce = nonce;
+            entry.count = entry.count.saturating_add(1);
+            entry.last_challenged_at = now;
+            return;
+        }
+
+        self.total_challengers = self.total_challengers.saturating_add(1);
+        let entry = ChallengerEntry {
+            challenger,
+            latest_nonce: nonce,
+            count: 1,
+            last_challenged_at: now,
+        };
+
+        if self.entries.len() < Self::MAX_ENTRIES {
+            self.entries.push(entry);
+        } else if let Some(oldest) = self
+            .entries
+            .iter_mut()
+            .min_by_key(|e| e.last_challenged_at)
+        {
+            *oldest = entry;
+        }
+    }
+}
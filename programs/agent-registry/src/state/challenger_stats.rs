@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Aggregate track record of one challenger across all agents
+///
+/// Lets agents and clients judge whether a challenger is a reliable evaluator
+/// or a griefing bot (e.g. never reveals, keeps losing disputes).
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengerStats {
+    /// The challenger wallet
+    pub challenger: Pubkey,
+
+    /// Challenges created
+    pub challenges_issued: u32,
+
+    /// Verdicts where the agent passed
+    pub agent_passes: u32,
+
+    /// Verdicts where the agent failed (including expired, unanswered challenges)
+    pub agent_fails: u32,
+
+    /// Challenges abandoned by the challenger (never revealed)
+    pub abandoned: u32,
+
+    /// Disputes decided against the challenger
+    pub disputes_lost: u32,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Challenges created and still awaiting a verdict (pending, responded or mid-rounds)
+    pub pending: u32,
+}
+
+impl ChallengerStats {
+    pub const SEED_PREFIX: &'static [u8] = b"challenger_stats";
+
+    /// Verdicts needed before a challenger counts as established
+    pub const ESTABLISHED_MIN_VERDICTS: u32 = 10;
+
+    /// Check if the challenger has a clean enough track record for full weight:
+    /// enough verdicts, and abandoned challenges plus lost disputes under 20% of them
+    pub fn is_established(&self) -> bool {
+        let verdicts = self.agent_passes.saturating_add(self.agent_fails);
+        verdicts >= Self::ESTABLISHED_MIN_VERDICTS
+            && self.abandoned.saturating_add(self.disputes_lost) < verdicts / 5
+    }
+
+    /// Count a newly created challenge, rejecting it if the challenger already
+    /// has `max_pending` challenges awaiting a verdict
+    pub fn record_issued(&mut self, max_pending: u32) -> Result<()> {
+        require!(self.pending < max_pending, RegistryError::ChallengeRateLimited);
+        self.pending += 1;
+        self.challenges_issued = self.challenges_issued.saturating_add(1);
+        Ok(())
+    }
+
+    /// Release a pending slot once a challenge is abandoned or cancelled (no verdict)
+    pub fn record_released(&mut self) {
+        self.pending = self.pending.saturating_sub(1);
+    }
+
+    /// Record an abandoned challenge (never revealed, or revealed with a bad opening)
+    pub fn record_abandoned(&mut self) {
+        self.abandoned = self.abandoned.saturating_add(1);
+        self.record_released();
+    }
+
+    /// Record a final verdict on one of this challenger's challenges
+    pub fn record_verdict(&mut self, agent_passed: bool) {
+        self.record_released();
+        self.count_verdict(agent_passed);
+    }
+
+    fn count_verdict(&mut self, agent_passed: bool) {
+        if agent_passed {
+            self.agent_passes = self.agent_passes.saturating_add(1);
+        } else {
+            self.agent_fails = self.agent_fails.saturating_add(1);
+        }
+    }
+
+    /// Swap a recorded verdict after it was overturned in a dispute
+    pub fn record_overturn(&mut self, was_passed: bool) {
+        if was_passed {
+            self.agent_passes = self.agent_passes.saturating_sub(1);
+        } else {
+            self.agent_fails = self.agent_fails.saturating_sub(1);
+        }
+        self.count_verdict(!was_passed);
+    }
+
+    /// Agent pass rate against this challenger in basis points (0 if no verdicts)
+    pub fn pass_rate_bps(&self) -> u16 {
+        let total = self.agent_passes as u64 + self.agent_fails as u64;
+        if total == 0 {
+            return 0;
+        }
+        (self.agent_passes as u64 * 10_000 / total) as u16
+    }
+}
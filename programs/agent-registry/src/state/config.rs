@@ -0,0 +1,309 @@
+use anchor_lang::prelude::*;
+use crate::state::{ActionType, AgentAccount, Challenge, ChallengeKind, ChallengerStats};
+
+/// Registry-wide tunable parameters (admin managed)
+/// Kept separate from RegistryState so new knobs don't change the core registry layout
+#[account]
+#[derive(InitSpace)]
+pub struct RegistryConfig {
+    /// Lamports a challenger must lock in the challenge PDA as a bond
+    pub challenge_bond_lamports: u64,
+
+    /// Highest difficulty a challenge may declare (scales reputation deltas)
+    pub max_difficulty: u8,
+
+    /// Lamports a disputant must lock when contesting a challenge verdict
+    pub dispute_bond_lamports: u64,
+
+    /// Switchboard feed authority trusted for oracle resolution (default = disabled)
+    pub oracle_authority: Pubkey,
+
+    /// Share of the challenger bond (basis points) paid to whoever expires a stale challenge
+    pub expire_bounty_bps: u16,
+
+    /// Maximum challenges an agent can receive per day (all challengers)
+    pub max_challenges_per_day: u32,
+
+    /// Maximum challenges one challenger can have awaiting a verdict at once
+    pub max_pending_per_challenger: u32,
+
+    /// Minimum stake (lamports) per tier to challenge a verified agent
+    /// Required stake = verified_stake_per_tier * (tier + 1); locked as the bond
+    pub verified_stake_per_tier: u64,
+
+    /// Protocol fee on challenge creation (basis points of bond + reward), paid to the treasury
+    pub challenge_fee_bps: u16,
+
+    /// Reputation weight (basis points) of challenges from admin-verified verifiers
+    pub verified_challenger_weight_bps: u16,
+
+    /// Reputation weight (basis points) of challenges from challengers without a track record
+    pub anonymous_challenger_weight_bps: u16,
+
+    /// Response latency (seconds after creation) that earns the fast-response bonus
+    pub fast_response_secs: u32,
+
+    /// Response latency (seconds after creation) that earns the quick-response bonus
+    pub quick_response_secs: u32,
+
+    /// Maximum absolute reputation change update_reputation may apply to one agent per day;
+    /// reputation gains from verdicts, endorsements, tasks and migration share the budget,
+    /// and losses (never capped themselves) use it up too
+    pub max_reputation_change_per_day: u32,
+
+    /// Stake (lamports, or stake_mint base units) per step of the stake reputation boost
+    pub stake_boost_unit_lamports: u64,
+
+    /// Boost on reputation gains (basis points) per staked unit
+    pub stake_boost_bps_per_unit: u16,
+
+    /// Upper bound for the stake boost (basis points)
+    pub max_stake_boost_bps: u16,
+
+    /// Share of the agent's stake (basis points) slashed per failed or expired boosted challenge
+    pub stake_slash_bps: u16,
+
+    /// Minimum reputation of the creator's agent to create open challenges (0 = ungated)
+    pub min_reputation_open_challenge: u32,
+
+    /// Minimum reputation of the verifier's agent to resolve or attest challenges (0 = ungated)
+    pub min_reputation_verifier: u32,
+
+    /// Share (basis points) of an old agent's reputation distance carried over by migrate_reputation
+    pub reputation_migration_bps: u16,
+
+    /// EMA smoothing factor for challenge verdicts (basis points, 0 = additive deltas)
+    pub ema_alpha_bps: u16,
+
+    /// Lamports a reporter must lock when filing a misbehavior report
+    pub report_bond_lamports: u64,
+
+    /// Reputation below which a decrease puts the agent on probation (0 = disabled)
+    pub probation_floor: u32,
+
+    /// Consecutive verifier-resolved passes needed to leave probation
+    pub probation_exit_streak: u8,
+
+    /// Base reputation gain per challenge kind (indexed by ChallengeKind::domain_index)
+    pub pass_reputation_deltas: [i32; ChallengeKind::COUNT],
+
+    /// Base reputation loss per challenge kind (indexed by ChallengeKind::domain_index)
+    pub fail_reputation_deltas: [i32; ChallengeKind::COUNT],
+
+    /// Critical-risk audit entries within suspend_window_secs that suspend an agent (0 = disabled)
+    pub suspend_critical_alerts: u32,
+
+    /// Length of the audit circuit breaker window (seconds)
+    pub suspend_window_secs: u32,
+
+    /// Risk score at or above which only an agent's sentinel authority may log an entry
+    pub sentinel_risk_threshold: u8,
+
+    /// Alert-free time over which an agent's average audit risk halves (0 = no decay)
+    pub risk_decay_half_life_secs: u32,
+
+    /// Alert-free time after which an agent's max audit risk is reset (0 = never)
+    pub max_risk_reset_secs: u32,
+
+    /// SPL mint that new stake vaults are denominated in (default = native SOL)
+    /// Existing vaults keep their denomination; the stake boost only counts
+    /// vaults in the current denomination, measured in its base units
+    pub stake_mint: Pubkey,
+
+    /// Share of the agent's stake (basis points) slashed per Critical audit entry via slash_agent
+    pub critical_alert_slash_bps: u16,
+
+    /// Share of a slash_agent slash (basis points) paid to the affected challenger
+    /// instead of the treasury
+    pub slash_affected_share_bps: u16,
+
+    /// Share of each challenge fee (basis points) paid to the challenged agent's stakers
+    /// instead of the treasury (only when the agent has stake)
+    pub staker_fee_share_bps: u16,
+
+    /// Minimum fee (lamports) an owner must escrow with request_verification
+    pub min_verification_fee: u64,
+
+    /// Lamports a client must lock when filing an insurance claim
+    pub insurance_claim_bond_lamports: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RegistryConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    /// Default challenger bond (0.01 SOL)
+    pub const DEFAULT_CHALLENGE_BOND: u64 = 10_000_000;
+
+    /// Default dispute bond (0.01 SOL)
+    pub const DEFAULT_DISPUTE_BOND: u64 = 10_000_000;
+
+    /// Default misbehavior report bond (0.01 SOL)
+    pub const DEFAULT_REPORT_BOND: u64 = 10_000_000;
+
+    /// Default consecutive verifier-resolved passes to leave probation
+    pub const DEFAULT_PROBATION_EXIT_STREAK: u8 = 3;
+
+    /// Default critical audit alerts that trip the circuit breaker
+    pub const DEFAULT_SUSPEND_CRITICAL_ALERTS: u32 = 3;
+
+    /// Default circuit breaker window (24 hours)
+    pub const DEFAULT_SUSPEND_WINDOW_SECS: u32 = 24 * 3600;
+
+    /// Default sentinel-only risk threshold (the default alert threshold)
+    pub const DEFAULT_SENTINEL_RISK_THRESHOLD: u8 = 75;
+
+    /// Default average risk half-life (30 days)
+    pub const DEFAULT_RISK_DECAY_HALF_LIFE_SECS: u32 = 30 * 24 * 3600;
+
+    /// Default max risk reset period (180 days)
+    pub const DEFAULT_MAX_RISK_RESET_SECS: u32 = 180 * 24 * 3600;
+
+    /// Default expiration bounty (10% of the bond)
+    pub const DEFAULT_EXPIRE_BOUNTY_BPS: u16 = 1_000;
+
+    /// Default daily challenge cap per agent
+    pub const DEFAULT_MAX_CHALLENGES_PER_DAY: u32 = 50;
+
+    /// Default cap on challenges one challenger can have awaiting a verdict
+    pub const DEFAULT_MAX_PENDING_PER_CHALLENGER: u32 = 10;
+
+    /// Default stake per tier for challenging verified agents (0.01 SOL)
+    pub const DEFAULT_VERIFIED_STAKE_PER_TIER: u64 = 10_000_000;
+
+    /// Default challenge creation fee (1%)
+    pub const DEFAULT_CHALLENGE_FEE_BPS: u16 = 100;
+
+    /// Default weight of verified verifier challenges (2x)
+    pub const DEFAULT_VERIFIED_CHALLENGER_WEIGHT_BPS: u16 = 20_000;
+
+    /// Default weight of anonymous challenger challenges (0.25x)
+    pub const DEFAULT_ANONYMOUS_CHALLENGER_WEIGHT_BPS: u16 = 2_500;
+
+    /// Default fast-response threshold (1 minute)
+    pub const DEFAULT_FAST_RESPONSE_SECS: u32 = 60;
+
+    /// Default quick-response threshold (10 minutes)
+    pub const DEFAULT_QUICK_RESPONSE_SECS: u32 = 600;
+
+    /// Default daily cap on direct reputation updates per agent
+    pub const DEFAULT_MAX_REPUTATION_CHANGE_PER_DAY: u32 = 2_000;
+
+    /// Default stake boost unit (1 SOL)
+    pub const DEFAULT_STAKE_BOOST_UNIT_LAMPORTS: u64 = 1_000_000_000;
+
+    /// Default stake boost per unit (+10%)
+    pub const DEFAULT_STAKE_BOOST_BPS_PER_UNIT: u16 = 1_000;
+
+    /// Default stake boost cap (+100%, i.e. 2x gains)
+    pub const DEFAULT_MAX_STAKE_BOOST_BPS: u16 = 10_000;
+
+    /// Default stake slash per failure (10%)
+    pub const DEFAULT_STAKE_SLASH_BPS: u16 = 1_000;
+
+    /// Default stake slash per Critical audit entry (5%)
+    pub const DEFAULT_CRITICAL_ALERT_SLASH_BPS: u16 = 500;
+
+    /// Upper bound for a single stake slash (50%), so no slash can empty a
+    /// vault while its stakers still hold shares
+    pub const MAX_SLASH_BPS: u16 = 5_000;
+
+    /// Default affected challenger share of a slash (50%)
+    pub const DEFAULT_SLASH_AFFECTED_SHARE_BPS: u16 = 5_000;
+
+    /// Default staker share of challenge fees (20%)
+    pub const DEFAULT_STAKER_FEE_SHARE_BPS: u16 = 2_000;
+
+    /// Default minimum verification fee (0.01 SOL)
+    pub const DEFAULT_MIN_VERIFICATION_FEE: u64 = 10_000_000;
+
+    /// Default insurance claim bond (0.01 SOL)
+    pub const DEFAULT_INSURANCE_CLAIM_BOND: u64 = 10_000_000;
+
+    /// Upper bound for max_stake_boost_bps (+300%, i.e. 4x gains)
+    pub const MAX_STAKE_BOOST_CAP_BPS: u16 = 30_000;
+
+    /// Default reputation migration share (50%)
+    pub const DEFAULT_REPUTATION_MIGRATION_BPS: u16 = 5_000;
+
+    /// Upper bound for the magnitude of per-kind base deltas
+    pub const MAX_KIND_DELTA: i32 = 1_000;
+
+    /// Upper bound for challenger weights (4x)
+    pub const MAX_CHALLENGER_WEIGHT_BPS: u16 = 40_000;
+
+    /// Basis point denominator
+    pub const BPS_DENOMINATOR: u16 = 10_000;
+
+    /// Default maximum difficulty (2x the baseline delta)
+    pub const DEFAULT_MAX_DIFFICULTY: u8 = 10;
+
+    /// Upper bound for max_difficulty (4x the baseline delta)
+    pub const MAX_DIFFICULTY_CAP: u8 = 20;
+
+    /// Bond a challenger must lock against this agent
+    /// Verified agents require at least the tier-scaled minimum stake
+    pub fn challenge_bond_for(&self, agent: &AgentAccount) -> u64 {
+        if !agent.verified {
+            return self.challenge_bond_lamports;
+        }
+        let stake = self
+            .verified_stake_per_tier
+            .saturating_mul(agent.tier() as u64 + 1);
+        self.challenge_bond_lamports.max(stake)
+    }
+
+    /// Boost on reputation gains for an agent with `staked` lamports:
+    /// stake_boost_bps_per_unit per full stake_boost_unit_lamports, capped at max_stake_boost_bps
+    pub fn stake_boost_bps(&self, staked: u64) -> u16 {
+        if self.stake_boost_unit_lamports == 0 {
+            return 0;
+        }
+        let units = staked / self.stake_boost_unit_lamports;
+        units
+            .saturating_mul(self.stake_boost_bps_per_unit as u64)
+            .min(self.max_stake_boost_bps as u64) as u16
+    }
+
+    /// Base reputation gain for passing a challenge of this kind
+    pub fn pass_delta(&self, kind: ChallengeKind) -> i32 {
+        self.pass_reputation_deltas[kind.domain_index()]
+    }
+
+    /// Base reputation loss for failing (or not answering) a challenge of this kind
+    pub fn fail_delta(&self, kind: ChallengeKind) -> i32 {
+        self.fail_reputation_deltas[kind.domain_index()]
+    }
+
+    /// Whether an audit entry is high-risk (SecurityAlert or at/above the sentinel threshold)
+    /// and must come from the agent's sentinel authority when one is assigned
+    pub fn requires_sentinel(&self, action_type: &ActionType, risk_score: u8) -> bool {
+        matches!(action_type, ActionType::SecurityAlert) || risk_score >= self.sentinel_risk_threshold
+    }
+
+    /// Reputation bonus earned by a response `latency` seconds after creation
+    pub fn response_bonus(&self, latency: i64) -> u16 {
+        if latency < self.fast_response_secs as i64 {
+            Challenge::FAST_RESPONSE_BONUS
+        } else if latency < self.quick_response_secs as i64 {
+            Challenge::QUICK_RESPONSE_BONUS
+        } else {
+            0
+        }
+    }
+
+    /// Reputation weight for a challenger's challenges
+    /// Verified verifiers get the verified weight, established challengers 1x,
+    /// everyone else the anonymous weight
+    pub fn challenger_weight_bps(&self, verified: bool, stats: &ChallengerStats) -> u16 {
+        if verified {
+            self.verified_challenger_weight_bps
+        } else if stats.is_established() {
+            Self::BPS_DENOMINATOR
+        } else {
+            self.anonymous_challenger_weight_bps
+        }
+    }
+}
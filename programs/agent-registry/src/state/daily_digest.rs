@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+/// Running totals for a contiguous range of audit entries grouped by UTC day
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct DayRollup {
+    /// First UTC day (unix timestamp / 86400) covered
+    pub start_day: u32,
+
+    /// Last UTC day covered (equal to start_day unless finalization fell behind)
+    pub end_day: u32,
+
+    /// Entries counted (0 = empty rollup)
+    pub entries: u32,
+
+    /// Sum of risk scores, for the average
+    pub risk_sum: u64,
+
+    /// Highest risk score counted
+    pub max_risk: u8,
+
+    /// audit_index of the first entry counted
+    pub first_index: u64,
+}
+
+impl DayRollup {
+    pub const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// UTC day number of a unix timestamp
+    pub fn day_of(timestamp: i64) -> u32 {
+        timestamp.div_euclid(Self::SECONDS_PER_DAY).max(0) as u32
+    }
+
+    /// Count one entry, starting the rollup at `day` if it is empty
+    pub fn record(&mut self, day: u32, audit_index: u64, risk_score: u8) {
+        if self.entries == 0 {
+            *self = DayRollup {
+                start_day: day,
+                end_day: day,
+                first_index: audit_index,
+                ..Default::default()
+            };
+        }
+        self.end_day = self.end_day.max(day);
+        self.entries = self.entries.saturating_add(1);
+        self.risk_sum = self.risk_sum.saturating_add(risk_score as u64);
+        self.max_risk = self.max_risk.max(risk_score);
+    }
+
+    /// Append the rollup that immediately follows this one in audit_index order
+    pub fn absorb(&mut self, next: &DayRollup) {
+        if self.entries == 0 {
+            *self = *next;
+            return;
+        }
+        self.end_day = self.end_day.max(next.end_day);
+        self.entries = self.entries.saturating_add(next.entries);
+        self.risk_sum = self.risk_sum.saturating_add(next.risk_sum);
+        self.max_risk = self.max_risk.max(next.max_risk);
+    }
+
+    pub fn avg_risk(&self) -> u8 {
+        if self.entries == 0 {
+            return 0;
+        }
+        (self.risk_sum / self.entries as u64) as u8
+    }
+}
+
+/// Compact per-day rollup of an agent's audit log
+///
+/// Produced by `finalize_audit_day` once the day is over. Counts and risk
+/// figures come from the on-chain summary; the Merkle root is supplied by the
+/// finalizer over the chain link hashes of entries
+/// `first_index..first_index + entry_count`, using the same tree as
+/// MerkleAuditRoot, so raw entries can later be pruned and proven against it.
+#[account]
+#[derive(InitSpace)]
+pub struct DailyDigest {
+    /// The agent this digest belongs to
+    pub agent: Pubkey,
+
+    /// UTC day (unix timestamp / 86400) the digest starts on
+    pub day: u32,
+
+    /// Last UTC day covered (equal to day unless finalization fell behind)
+    pub end_day: u32,
+
+    /// Number of entries rolled up
+    pub entry_count: u32,
+
+    /// audit_index of the first entry rolled up
+    pub first_index: u64,
+
+    /// Highest risk score of the day
+    pub max_risk: u8,
+
+    /// Average risk score of the day
+    pub avg_risk: u8,
+
+    /// Merkle root over the day's entry link hashes
+    pub merkle_root: [u8; 32],
+
+    /// Who finalized the digest
+    pub finalized_by: Pubkey,
+
+    /// Unix timestamp of finalization
+    pub finalized_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DailyDigest {
+    pub const SEED_PREFIX: &'static [u8] = b"daily_digest";
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::ChallengeStatus;
+
+/// An open dispute against a challenge verdict, awaiting arbitration
+/// Holds the disputant bond on top of rent; closed by `arbitrate_dispute`
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeDispute {
+    /// The disputed challenge
+    pub challenge: Pubkey,
+
+    /// Agent owner or challenger who opened the dispute
+    pub disputant: Pubkey,
+
+    /// Verdict being contested (Passed or Failed)
+    pub original_status: ChallengeStatus,
+
+    /// SHA256 hash of the off-chain dispute statement / evidence
+    #[max_len(64)]
+    pub reason_hash: String,
+
+    /// Dispute bond (lamports) escrowed in this PDA on top of rent
+    /// Refunded if the verdict is overturned, paid to the counterparty otherwise
+    pub bond: u64,
+
+    /// Unix timestamp when the dispute was opened
+    pub opened_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ChallengeDispute {
+    pub const SEED_PREFIX: &'static [u8] = b"dispute";
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{AgentAccount, ChallengeKind};
+
+/// Reputation within one capability domain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct DomainScore {
+    /// Domain reputation (same scale and bounds as AgentAccount::reputation_score)
+    pub score: u32,
+    pub challenges_passed: u32,
+    pub challenges_failed: u32,
+    pub updated_at: i64,
+}
+
+/// Per-agent reputation split by capability domain (one per ChallengeKind)
+///
+/// The global score on AgentAccount blends every challenge kind; this keeps a
+/// separate score per domain so a strong coding agent is not dragged down (or
+/// propped up) by unrelated answers. Updated via record_domain_result.
+#[account]
+#[derive(InitSpace)]
+pub struct DomainReputation {
+    /// The agent these scores belong to
+    pub agent: Pubkey,
+
+    /// Scores indexed by ChallengeKind::domain_index
+    pub domains: [DomainScore; ChallengeKind::COUNT],
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl DomainReputation {
+    pub const SEED_PREFIX: &'static [u8] = b"domain_reputation";
+
+    /// Start every domain at the initial reputation
+    pub fn init(&mut self, agent: Pubkey, bump: u8) {
+        self.agent = agent;
+        self.bump = bump;
+        for domain in self.domains.iter_mut() {
+            domain.score = AgentAccount::INITIAL_REPUTATION;
+        }
+    }
+
+    /// Score for a challenge kind
+    pub fn domain(&self, kind: ChallengeKind) -> &DomainScore {
+        &self.domains[kind.domain_index()]
+    }
+
+    /// Apply a challenge outcome to its domain with the global reputation bounds
+    pub fn record(&mut self, kind: ChallengeKind, passed: bool, delta: i32, now: i64) {
+        let domain = &mut self.domains[kind.domain_index()];
+        if passed {
+            domain.challenges_passed = domain.challenges_passed.saturating_add(1);
+        } else {
+            domain.challenges_failed = domain.challenges_failed.saturating_add(1);
+        }
+        let new_score = (domain.score as i64) + (delta as i64);
+        domain.score = new_score
+            .max(AgentAccount::MIN_REPUTATION as i64)
+            .min(AgentAccount::MAX_REPUTATION as i64) as u32;
+        domain.updated_at = now;
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// A verified agent vouching for another agent
+///
+/// The endorser locks ENDORSEMENT_STAKE or more in this PDA and the endorsed
+/// agent gets a bounded reputation bonus. If the endorsed agent logs a
+/// critical-risk audit entry afterwards, anyone can claw the bonus back,
+/// penalize the endorser and send the stake to the treasury.
+#[account]
+#[derive(InitSpace)]
+pub struct Endorsement {
+    /// The vouching agent
+    pub endorser_agent: Pubkey,
+
+    /// The endorsed agent
+    pub endorsed_agent: Pubkey,
+
+    /// Wallet that locked the stake (receives it back on revocation)
+    pub endorser: Pubkey,
+
+    /// Lamports locked (excluding rent)
+    pub stake: u64,
+
+    /// Reputation bonus granted to the endorsed agent
+    pub bonus: u32,
+
+    /// Endorsed agent's critical-risk entry count when the endorsement was made
+    pub critical_alerts_at_endorsement: u32,
+
+    /// Unix timestamp of the endorsement
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Endorsement {
+    pub const SEED_PREFIX: &'static [u8] = b"endorsement";
+
+    /// Minimum stake per endorsement (0.01 SOL)
+    pub const MIN_STAKE: u64 = 10_000_000;
+
+    /// Reputation bonus per endorsement (+1%)
+    pub const BONUS: u32 = 100;
+
+    /// Upper bound for an agent's combined endorsement bonus (+5%)
+    pub const MAX_AGENT_BONUS: u32 = 500;
+
+    /// Reputation penalty for an endorser whose endorsement is clawed back (-2%)
+    pub const ENDORSER_PENALTY: u32 = 200;
+
+    /// Minimum time before an endorsement can be revoked (30 days)
+    pub const LOCK_PERIOD: i64 = 30 * 24 * 3600;
+
+    /// Check if the endorsed agent logged critical-risk entries since the endorsement
+    pub fn has_new_critical_alerts(&self, critical_alerts: u32) -> bool {
+        critical_alerts > self.critical_alerts_at_endorsement
+    }
+}
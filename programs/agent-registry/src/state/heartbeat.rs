@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Liveness tracking for an agent's monitoring sentinel
+///
+/// The owner or compliance officer sets the expected interval; a sentinel calls
+/// `sentinel_heartbeat` at least that often. If it goes quiet for longer,
+/// anyone can call `flag_missed_heartbeat` to log a SecurityAlert, at most once
+/// per missed interval.
+#[account]
+#[derive(InitSpace)]
+pub struct HeartbeatMonitor {
+    /// The agent being watched
+    pub agent: Pubkey,
+
+    /// Maximum allowed gap between heartbeats (seconds, 0 = disabled)
+    pub interval_secs: u32,
+
+    /// Sentinel that sent the latest heartbeat
+    pub last_sentinel: Pubkey,
+
+    /// Unix timestamp of the latest heartbeat (or when monitoring was enabled)
+    pub last_beat_at: i64,
+
+    /// Unix timestamp of the latest missed-heartbeat alert (0 = none)
+    pub last_flagged_at: i64,
+
+    /// Missed-heartbeat alerts raised so far
+    pub missed_beats: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HeartbeatMonitor {
+    pub const SEED_PREFIX: &'static [u8] = b"heartbeat";
+
+    /// Whether the sentinel is overdue and no alert was raised for this gap yet
+    pub fn is_missed(&self, now: i64) -> bool {
+        self.interval_secs > 0
+            && now - self.last_beat_at.max(self.last_flagged_at) > self.interval_secs as i64
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Serious-incident class (EU AI Act Article 3(49))
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum IncidentClass {
+    /// Death of a person or serious harm to a person's health
+    HealthOrSafety,
+    /// Serious and irreversible disruption of critical infrastructure
+    CriticalInfrastructure,
+    /// Infringement of obligations protecting fundamental rights
+    FundamentalRights,
+    /// Serious harm to property or the environment
+    PropertyOrEnvironment,
+    /// Anything else (described in the report)
+    Other,
+}
+
+/// Incident severity as assessed by the reporter
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum IncidentSeverity {
+    Low,
+    Medium,
+    High,
+    /// Counts towards the audit circuit breaker like a critical-risk entry
+    Critical,
+}
+
+/// Structured serious-incident report for an agent (EU AI Act Article 73)
+/// One PDA per report, indexed by the agent's AgentAuditSummary::incidents_reported
+#[account]
+#[derive(InitSpace)]
+pub struct IncidentReport {
+    /// The agent involved in the incident
+    pub agent: Pubkey,
+
+    /// Wallet that filed the report (owner, operator or designated sentinel)
+    pub reporter: Pubkey,
+
+    /// Sequential incident index for this agent
+    pub incident_index: u32,
+
+    /// Incident class
+    pub class: IncidentClass,
+
+    /// Assessed severity
+    pub severity: IncidentSeverity,
+
+    /// Number of persons or parties affected (best estimate)
+    pub affected_parties: u32,
+
+    /// SHA256 hash of the full off-chain incident report
+    #[max_len(64)]
+    pub report_hash: String,
+
+    /// Unix timestamp when the incident occurred (or was detected)
+    pub occurred_at: i64,
+
+    /// Unix timestamp when the report was filed
+    pub reported_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl IncidentReport {
+    pub const SEED_PREFIX: &'static [u8] = b"incident";
+}
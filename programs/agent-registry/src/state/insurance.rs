@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+use crate::state::{Challenge, PaymentStream, Task};
+
+/// Shared insurance pool - holds agent premiums (lamports on top of rent)
+///
+/// Clients harmed by a slashed agent file bonded claims against it; approved
+/// claims are paid from here after arbitration.
+#[account]
+#[derive(InitSpace)]
+pub struct InsurancePool {
+    /// Total premiums paid in since initialization
+    pub total_premiums: u64,
+
+    /// Total paid out on approved claims
+    pub total_paid_out: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl InsurancePool {
+    pub const SEED_PREFIX: &'static [u8] = b"insurance_pool";
+}
+
+/// An agent's opt-in insurance cover
+#[account]
+#[derive(InitSpace)]
+pub struct InsurancePolicy {
+    /// The insured agent
+    pub agent: Pubkey,
+
+    /// Premiums paid for this agent (lamports)
+    pub total_premiums: u64,
+
+    /// Claims paid out against this agent (lamports)
+    pub claims_paid: u64,
+
+    /// Unix timestamp of the first premium (slashes before this are not covered)
+    pub created_at: i64,
+
+    /// Unix timestamp of the latest premium
+    pub last_paid_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl InsurancePolicy {
+    pub const SEED_PREFIX: &'static [u8] = b"insurance_policy";
+
+    /// Cover per premium lamport paid
+    pub const COVERAGE_MULTIPLIER: u64 = 10;
+
+    /// Cover still available for claims against this agent
+    pub fn remaining_coverage(&self) -> u64 {
+        self.total_premiums
+            .saturating_mul(Self::COVERAGE_MULTIPLIER)
+            .saturating_sub(self.claims_paid)
+    }
+}
+
+/// Bonded claim by a client harmed by a slashed agent (one per claimant per slash)
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceClaim {
+    /// The slash record the claim is based on
+    pub slash_record: Pubkey,
+
+    /// The insured agent
+    pub agent: Pubkey,
+
+    /// The harmed interaction: a task, payment stream or challenge between the
+    /// claimant and the agent
+    pub interaction: Pubkey,
+
+    /// Client filing the claim (receives the payout, bond refund and rent)
+    pub claimant: Pubkey,
+
+    /// Amount claimed (lamports)
+    pub amount: u64,
+
+    /// SHA256 of the off-chain evidence of harm
+    pub evidence_hash: [u8; 32],
+
+    /// Bond locked in this account (lamports on top of rent)
+    pub bond: u64,
+
+    /// Unix timestamp when the claim was filed
+    pub filed_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl InsuranceClaim {
+    pub const SEED_PREFIX: &'static [u8] = b"insurance_claim";
+
+    /// Client and agent of a harmed interaction: a Task (client), PaymentStream
+    /// (client) or Challenge (challenger) account of this program
+    pub fn interaction_parties(info: &AccountInfo) -> Result<(Pubkey, Pubkey)> {
+        require_keys_eq!(*info.owner, crate::ID, RegistryError::InsuranceInteractionMismatch);
+        let data = info.try_borrow_data()?;
+        require!(data.len() >= 8, RegistryError::InsuranceInteractionMismatch);
+        let discriminator = &data[..8];
+        if discriminator == Task::DISCRIMINATOR {
+            let task = Task::try_deserialize(&mut &data[..])?;
+            Ok((task.client, task.agent))
+        } else if discriminator == PaymentStream::DISCRIMINATOR {
+            let stream = PaymentStream::try_deserialize(&mut &data[..])?;
+            Ok((stream.client, stream.agent))
+        } else if discriminator == Challenge::DISCRIMINATOR {
+            let challenge = Challenge::try_deserialize(&mut &data[..])?;
+            Ok((challenge.challenger, challenge.agent))
+        } else {
+            err!(RegistryError::InsuranceInteractionMismatch)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// One ranked agent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LeaderboardEntry {
+    pub agent: Pubkey,
+    pub agent_id: u64,
+    pub reputation_score: u32,
+}
+
+/// Registry-wide top agents by reputation
+///
+/// Maintained opportunistically via the permissionless `update_leaderboard`:
+/// the submitted agent account is its own proof of score. Entries are
+/// refreshed only when resubmitted, so a listed score may lag the agent.
+#[account]
+#[derive(InitSpace)]
+pub struct Leaderboard {
+    /// Ranked entries, highest reputation first
+    #[max_len(32)]
+    pub entries: Vec<LeaderboardEntry>,
+
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const SEED_PREFIX: &'static [u8] = b"leaderboard";
+
+    /// Number of ranked agents
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Insert or refresh an agent's entry and keep the board sorted
+    /// A new agent must beat the lowest score once the board is full
+    pub fn submit(&mut self, entry: LeaderboardEntry) -> Result<()> {
+        if let Some(existing) = self.entries.iter_mut().find(|e| e.agent == entry.agent) {
+            existing.reputation_score = entry.reputation_score;
+        } else if self.entries.len() < Self::MAX_ENTRIES {
+            self.entries.push(entry);
+        } else {
+            // Sorted descending, so the minimum is last
+            let last = self.entries.len() - 1;
+            require!(
+                entry.reputation_score > self.entries[last].reputation_score,
+                RegistryError::BelowLeaderboardMinimum
+            );
+            self.entries[last] = entry;
+        }
+
+        self.entries
+            .sort_by_key(|e| std::cmp::Reverse(e.reputation_score));
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
 
 /// Merkle audit root - stores a batch of audit entries as a single root hash
 /// This is more gas-efficient than storing each entry individually
@@ -20,15 +21,79 @@ pub struct MerkleAuditRoot {
     /// Unix timestamp when batch was committed
     pub timestamp: i64,
 
-    /// Sequential batch index for this agent
+    /// Sequential batch index for this agent (across all epochs)
     pub batch_index: u64,
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Audit epoch the batch was posted in (chosen by the poster, never decreasing)
+    pub epoch: u64,
+
+    /// Position of the batch within its epoch
+    pub sequence: u32,
 }
 
 impl MerkleAuditRoot {
     pub const SEED_PREFIX: &'static [u8] = b"merkle_audit";
+
+    /// Longest accepted inclusion proof (trees of up to 2^32 entries)
+    pub const MAX_PROOF_DEPTH: usize = 32;
+
+    /// Check that `leaf_hash` is included under this root
+    /// Same construction as the off-chain batcher: parent = SHA256(left || right),
+    /// an odd node is paired with itself, and a single-entry tree's root is the leaf
+    pub fn verify(&self, leaf_hash: &[u8; 32], proof: &[MerkleProofNode]) -> bool {
+        let computed = proof.iter().fold(*leaf_hash, |current, node| {
+            if node.is_left {
+                hashv(&[&node.hash, &current]).to_bytes()
+            } else {
+                hashv(&[&current, &node.hash]).to_bytes()
+            }
+        });
+        computed == self.merkle_root
+    }
+}
+
+/// One step of a Merkle inclusion proof
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct MerkleProofNode {
+    /// Sibling hash at this level
+    pub hash: [u8; 32],
+
+    /// Whether the sibling sits on the left
+    pub is_left: bool,
+}
+
+/// On-chain record that an audit entry was proven to be part of a Merkle batch
+/// Created once per (batch root, leaf) by `verify_audit_inclusion`
+#[account]
+#[derive(InitSpace)]
+pub struct AuditInclusionAttestation {
+    /// The Merkle audit root the leaf was proven against
+    pub audit_root: Pubkey,
+
+    /// The agent the audit batch belongs to
+    pub agent: Pubkey,
+
+    /// The proven audit entry hash
+    pub leaf_hash: [u8; 32],
+
+    /// Batch index of the audit root
+    pub batch_index: u64,
+
+    /// Who submitted the proof
+    pub verified_by: Pubkey,
+
+    /// Unix timestamp of the verification
+    pub verified_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AuditInclusionAttestation {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_inclusion";
 }
 
 /// Lightweight summary tracking total batches per agent
@@ -50,8 +115,83 @@ pub struct MerkleAuditSummary {
 
     /// PDA bump seed
     pub bump: u8,
+
+    /// Latest audit epoch a batch was posted in
+    pub current_epoch: u64,
+
+    /// Batches posted in current_epoch (next sequence number)
+    pub epoch_sequence: u32,
 }
 
 impl MerkleAuditSummary {
     pub const SEED_PREFIX: &'static [u8] = b"merkle_summary";
+
+    /// Sequence the next batch posted in `epoch` gets (a newer epoch starts at 0)
+    pub fn sequence_for(&self, epoch: u64) -> u32 {
+        if epoch == self.current_epoch {
+            self.epoch_sequence
+        } else {
+            0
+        }
+    }
+}
+
+/// One stored Merkle audit root as listed in a MerkleRootIndex
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct MerkleRootRecord {
+    /// Audit epoch of the batch
+    pub epoch: u64,
+
+    /// Position of the batch within its epoch
+    pub sequence: u32,
+
+    /// Merkle root of the batch
+    pub merkle_root: [u8; 32],
+
+    /// Number of entries in the batch
+    pub entries_count: u32,
+
+    /// Entries stored in earlier batches (the batch covers first_entry..first_entry + entries_count)
+    pub first_entry: u64,
+
+    /// Unix timestamp when the batch was committed
+    pub timestamp: i64,
+}
+
+/// Recent Merkle audit roots per agent, oldest first
+/// Lets verifiers find the root covering an off-chain entry without scanning every root PDA
+#[account]
+#[derive(InitSpace)]
+pub struct MerkleRootIndex {
+    /// The agent these roots belong to
+    pub agent: Pubkey,
+
+    /// The last CAPACITY roots stored
+    #[max_len(32)]
+    pub roots: Vec<MerkleRootRecord>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MerkleRootIndex {
+    pub const SEED_PREFIX: &'static [u8] = b"merkle_root_index";
+
+    /// Number of roots kept
+    pub const CAPACITY: usize = 32;
+
+    /// Append a root, dropping the oldest once CAPACITY is reached
+    pub fn push(&mut self, record: MerkleRootRecord) {
+        if self.roots.len() >= Self::CAPACITY {
+            self.roots.remove(0);
+        }
+        self.roots.push(record);
+    }
+
+    /// The listed root covering the `entry`-th batched entry, if still indexed
+    pub fn find(&self, entry: u64) -> Option<&MerkleRootRecord> {
+        self.roots
+            .iter()
+            .find(|r| entry >= r.first_entry && entry < r.first_entry + r.entries_count as u64)
+    }
 }
@@ -2,10 +2,92 @@ pub mod agent;
 pub mod audit;
 pub mod challenge;
 pub mod merkle_audit;
+pub mod audit_ring;
+pub mod audit_batch;
+pub mod audit_tree;
+pub mod auditor;
+pub mod audit_readers;
+pub mod audit_checkpoint;
+pub mod heartbeat;
+pub mod audit_policy;
+pub mod incident;
+pub mod action_codes;
+pub mod risk_weights;
+pub mod daily_digest;
+pub mod profile;
 pub mod registry;
+pub mod recovery;
+pub mod config;
+pub mod dispute;
+pub mod oracle;
+pub mod verifying_key;
+pub mod open_challenge;
+pub mod template;
+pub mod rate_limit;
+pub mod acl;
+pub mod treasury;
+pub mod verifier;
+pub mod challenge_index;
+pub mod verifier_program;
+pub mod challenger_stats;
+pub mod reputation_snapshot;
+pub mod domain_reputation;
+pub mod authorized_updaters;
+pub mod stake;
+pub mod leaderboard;
+pub mod reputation_checkpoint;
+pub mod endorsement;
+pub mod report;
+pub mod reputation_schema;
+pub mod verification_request;
+pub mod insurance;
+pub mod task;
+pub mod service_listing;
+pub mod payment_stream;
 
 pub use agent::*;
 pub use audit::*;
 pub use challenge::*;
 pub use merkle_audit::*;
+pub use audit_ring::*;
+pub use audit_batch::*;
+pub use audit_tree::*;
+pub use auditor::*;
+pub use audit_readers::*;
+pub use audit_checkpoint::*;
+pub use heartbeat::*;
+pub use audit_policy::*;
+pub use incident::*;
+pub use action_codes::*;
+pub use risk_weights::*;
+pub use daily_digest::*;
+pub use profile::*;
 pub use registry::*;
+pub use recovery::*;
+pub use config::*;
+pub use dispute::*;
+pub use oracle::*;
+pub use verifying_key::*;
+pub use open_challenge::*;
+pub use template::*;
+pub use rate_limit::*;
+pub use acl::*;
+pub use treasury::*;
+pub use verifier::*;
+pub use challenge_index::*;
+pub use verifier_program::*;
+pub use challenger_stats::*;
+pub use reputation_snapshot::*;
+pub use domain_reputation::*;
+pub use authorized_updaters::*;
+pub use stake::*;
+pub use leaderboard::*;
+pub use reputation_checkpoint::*;
+pub use endorsement::*;
+pub use report::*;
+pub use reputation_schema::*;
+pub use verification_request::*;
+pub use insurance::*;
+pub use task::*;
+pub use service_listing::*;
+pub use payment_stream::*;
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use crate::state::{Challenge, ChallengeKind};
+
+/// Open challenge lifecycle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum OpenChallengeStatus {
+    /// Accepting responses from any registered agent until `expires_at`
+    Open,
+    /// Salt revealed; correct responders can claim until the claim window ends
+    Revealed,
+    /// Reveal did not open the commitment; bond slashed to the first responder
+    Abandoned,
+}
+
+/// Open challenge - a public benchmark question any registered agent can answer
+///
+/// Uses the same commit-reveal scheme as `Challenge`. After the deadline the
+/// challenger reveals the answer hash and salt and the earliest correct response
+/// wins the reward and the pass reputation delta. Responses are bound to the
+/// responding agent (see `OpenResponse::response_hash`), so another agent's
+/// visible response cannot be copied.
+#[account]
+#[derive(InitSpace)]
+pub struct OpenChallenge {
+    /// Who created the challenge
+    pub challenger: Pubkey,
+
+    /// The challenge question/prompt
+    #[max_len(256)]
+    pub question: String,
+
+    /// Commitment to the expected answer: SHA256(answer_hash || salt) as hex
+    #[max_len(64)]
+    pub expected_hash: String,
+
+    /// Current status
+    pub status: OpenChallengeStatus,
+
+    /// Challenge category (selects reputation weights)
+    pub kind: ChallengeKind,
+
+    /// Difficulty level scaling the pass delta
+    pub difficulty: u8,
+
+    /// Unix timestamp when the challenge was created
+    pub created_at: i64,
+
+    /// Responses are accepted until this timestamp
+    pub expires_at: i64,
+
+    /// Unix timestamp of the salt reveal (0 = not revealed)
+    pub revealed_at: i64,
+
+    /// Salt revealed by the challenger (checked against each claim)
+    pub revealed_salt: [u8; 32],
+
+    /// Challenger bond escrowed in this PDA (slashed to the first responder on abandonment)
+    pub bond: u64,
+
+    /// Reward escrowed in this PDA for the winner
+    pub reward: u64,
+
+    /// Number of responses submitted
+    pub response_count: u32,
+
+    /// Owner wallet of the first responding agent
+    pub first_responder: Pubkey,
+
+    /// Current winning agent (default = none yet)
+    pub winner: Pubkey,
+
+    /// Slot of the current winner's response (earlier slot wins)
+    pub winner_slot: u64,
+
+    /// Nonce for unique PDA derivation
+    pub nonce: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+
+    /// Configured pass delta for the challenge kind at creation (before scaling)
+    pub base_pass_delta: i32,
+
+    /// Answer hash revealed by the challenger (responses are checked against it)
+    #[max_len(64)]
+    pub revealed_answer_hash: String,
+}
+
+impl OpenChallenge {
+    pub const SEED_PREFIX: &'static [u8] = b"open_challenge";
+
+    /// Time correct responders have to claim after the reveal (1 hour)
+    pub const CLAIM_WINDOW: i64 = 3600;
+
+    /// Reputation gain for the winner (kind weight scaled by difficulty)
+    pub fn pass_delta(&self) -> i32 {
+        Challenge::scale_delta(self.base_pass_delta, self.difficulty)
+    }
+
+    /// Check if the challenger missed the reveal window after the deadline
+    pub fn is_reveal_expired(&self, current_time: i64) -> bool {
+        current_time > self.expires_at + Challenge::REVEAL_WINDOW
+    }
+
+    /// Check if the claim window after the reveal is still open
+    pub fn is_claim_open(&self, current_time: i64) -> bool {
+        self.status == OpenChallengeStatus::Revealed
+            && current_time <= self.revealed_at + Self::CLAIM_WINDOW
+    }
+
+    /// Check if a winner has been recorded
+    pub fn has_winner(&self) -> bool {
+        self.winner != Pubkey::default()
+    }
+}
+
+/// One agent's answer to an open challenge
+#[account]
+#[derive(InitSpace)]
+pub struct OpenResponse {
+    /// The open challenge answered
+    pub open_challenge: Pubkey,
+
+    /// Responding agent
+    pub agent: Pubkey,
+
+    /// Agent owner who submitted (receives rent back)
+    pub owner: Pubkey,
+
+    /// The agent's answer bound to the responding agent:
+    /// SHA256(answer_hash || agent pubkey) as hex (see `OpenResponse::bind`)
+    #[max_len(64)]
+    pub response_hash: String,
+
+    /// Slot of submission (orders responses; earlier wins)
+    pub submitted_slot: u64,
+
+    /// Unix timestamp of submission
+    pub submitted_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl OpenResponse {
+    pub const SEED_PREFIX: &'static [u8] = b"open_response";
+
+    /// Bind an answer hash to the responding agent, so a response only ever
+    /// counts for the agent that submitted it
+    pub fn bind(answer_hash: &str, agent: &Pubkey) -> String {
+        Challenge::compute_commitment(answer_hash, &agent.to_bytes())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+/// Verdict read from a Switchboard On-Demand pull feed
+///
+/// Off-chain evaluation pipelines publish 1.0 (pass) or 0.0 (fail) to a feed
+/// whose authority is whitelisted in `RegistryConfig::oracle_authority`.
+pub struct SwitchboardResult {
+    /// Feed authority (who controls the feed's job definition)
+    pub authority: Pubkey,
+
+    /// Latest aggregated value, scaled by 10^18
+    pub value: i128,
+
+    /// Unix timestamp of the latest aggregated update
+    pub last_update_timestamp: i64,
+}
+
+impl SwitchboardResult {
+    /// Switchboard On-Demand program (owner of pull feed accounts)
+    pub const PROGRAM_ID: Pubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+    /// Anchor discriminator of `PullFeedAccountData`
+    const DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+
+    /// Offsets into the account data (8-byte discriminator + PullFeedAccountData)
+    /// submissions: [OracleSubmission; 32] (64 bytes each) come first
+    const AUTHORITY_OFFSET: usize = 8 + 2048;
+    const LAST_UPDATE_OFFSET: usize = 8 + 2208;
+    const RESULT_VALUE_OFFSET: usize = 8 + 2256;
+
+    /// Values at or above 0.5 (scaled by 10^18) count as a pass
+    pub const PASS_THRESHOLD: i128 = 500_000_000_000_000_000;
+
+    /// Parse a pull feed account owned by the Switchboard program
+    pub fn read(feed: &AccountInfo) -> Option<Self> {
+        if feed.owner != &Self::PROGRAM_ID {
+            return None;
+        }
+        let data = feed.try_borrow_data().ok()?;
+        if data.len() < Self::RESULT_VALUE_OFFSET + 16 || data[..8] != Self::DISCRIMINATOR {
+            return None;
+        }
+
+        let authority =
+            Pubkey::try_from(&data[Self::AUTHORITY_OFFSET..Self::AUTHORITY_OFFSET + 32]).ok()?;
+        let last_update_timestamp = i64::from_le_bytes(
+            data[Self::LAST_UPDATE_OFFSET..Self::LAST_UPDATE_OFFSET + 8].try_into().ok()?,
+        );
+        let value = i128::from_le_bytes(
+            data[Self::RESULT_VALUE_OFFSET..Self::RESULT_VALUE_OFFSET + 16].try_into().ok()?,
+        );
+
+        Some(Self {
+            authority,
+            value,
+            last_update_timestamp,
+        })
+    }
+
+    /// Interpret the feed value as a pass/fail verdict
+    pub fn passed(&self) -> bool {
+        self.value >= Self::PASS_THRESHOLD
+    }
+}
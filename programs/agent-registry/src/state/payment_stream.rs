@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+/// Time-based payment from a client to an agent
+///
+/// The client escrows `rate_per_sec * (end_ts - start_ts)` lamports in this
+/// PDA on top of rent. The agent owner withdraws whatever has vested; either
+/// side can cancel, splitting the balance by what has vested so far.
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentStream {
+    /// Wallet funding the stream (receives the unvested remainder and the rent)
+    pub client: Pubkey,
+
+    /// The agent being paid (its owner withdraws)
+    pub agent: Pubkey,
+
+    /// Client-chosen nonce (enables multiple streams per client and agent)
+    pub nonce: u64,
+
+    /// Lamports vesting per second
+    pub rate_per_sec: u64,
+
+    /// Unix timestamp when vesting starts
+    pub start_ts: i64,
+
+    /// Unix timestamp when vesting ends
+    pub end_ts: i64,
+
+    /// Lamports already withdrawn by the agent owner
+    pub withdrawn: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PaymentStream {
+    pub const SEED_PREFIX: &'static [u8] = b"payment_stream";
+
+    /// Total lamports escrowed for the whole stream
+    pub fn total(&self) -> u64 {
+        self.rate_per_sec.saturating_mul((self.end_ts - self.start_ts) as u64)
+    }
+
+    /// Lamports vested at `now`
+    pub fn vested(&self, now: i64) -> u64 {
+        let elapsed = now.min(self.end_ts) - self.start_ts;
+        if elapsed <= 0 {
+            return 0;
+        }
+        self.rate_per_sec.saturating_mul(elapsed as u64)
+    }
+
+    /// Vested lamports not yet withdrawn
+    pub fn withdrawable(&self, now: i64) -> u64 {
+        self.vested(now).saturating_sub(self.withdrawn)
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+/// Agent profile - optional extension of AgentAccount for rarely-changing metadata
+///
+/// Kept in a separate PDA so minimal registrations only pay rent for the core
+/// AgentAccount. Created on the first `update_agent_profile` call.
+#[account]
+#[derive(InitSpace)]
+pub struct AgentProfile {
+    /// The agent this profile extends
+    pub agent: Pubkey,
+
+    /// SHA256 hash of the full agent description (stored off-chain)
+    #[max_len(64)]
+    pub description_hash: String,
+
+    /// Comma-separated list of links (website, repo, docs)
+    #[max_len(256)]
+    pub links: String,
+
+    /// Avatar image URI (e.g., "https://arweave.net/...")
+    #[max_len(128)]
+    pub avatar_uri: String,
+
+    /// Jurisdiction code (e.g., "EU", "US-CA")
+    #[max_len(8)]
+    pub jurisdiction: String,
+
+    /// Unix timestamp when profile was last updated
+    pub updated_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl AgentProfile {
+    pub const SEED_PREFIX: &'static [u8] = b"agent_profile";
+
+    /// Maximum length of the links field
+    pub const MAX_LINKS_LEN: usize = 256;
+
+    /// Maximum length of the avatar URI
+    pub const MAX_AVATAR_URI_LEN: usize = 128;
+
+    /// Maximum length of the jurisdiction code
+    pub const MAX_JURISDICTION_LEN: usize = 8;
+}
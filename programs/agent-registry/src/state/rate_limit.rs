@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Fixed-window challenge counter used for rate limiting
+///
+/// One instance per agent, `[SEED_PREFIX, agent]`, caps the challenges it
+/// receives per day. The per-challenger pending cap lives on `ChallengerStats`.
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeCounter {
+    /// Unix timestamp when the current window started
+    pub window_start: i64,
+
+    /// Challenges counted in the current window
+    pub count: u32,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ChallengeCounter {
+    pub const SEED_PREFIX: &'static [u8] = b"challenge_counter";
+
+    /// Length of the per-agent daily window
+    pub const DAY: i64 = 24 * 3600;
+
+    /// Count one more challenge, starting a new window if the current one elapsed
+    pub fn record(&mut self, now: i64, window: i64, max: u32) -> Result<()> {
+        if now >= self.window_start + window {
+            self.window_start = now;
+            self.count = 0;
+        }
+        require!(self.count < max, RegistryError::ChallengeRateLimited);
+        self.count += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+/// Pending owner recovery request for an agent
+///
+/// Created by the current holder of the agent's identity NFT when the owner
+/// key is lost. The new owner can only be installed after `AgentAccount::RECOVERY_DELAY`,
+/// giving the current owner a window to cancel a malicious request.
+#[account]
+#[derive(InitSpace)]
+pub struct AgentRecovery {
+    /// The agent being recovered
+    pub agent: Pubkey,
+
+    /// NFT holder who requested the recovery (receives rent back)
+    pub requester: Pubkey,
+
+    /// Wallet that will become the agent owner
+    pub new_owner: Pubkey,
+
+    /// Unix timestamp when recovery was requested
+    pub requested_at: i64,
+
+    /// Unix timestamp after which recovery can be finalized
+    pub executable_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AgentRecovery {
+    pub const SEED_PREFIX: &'static [u8] = b"recovery";
+
+    /// Metaplex Core program (owner of identity NFT assets)
+    pub const MPL_CORE_PROGRAM_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+    /// Metaplex Core `Key::AssetV1` discriminator byte
+    const ASSET_V1_KEY: u8 = 1;
+
+    /// Read the current holder of a Metaplex Core asset
+    /// Layout: [key: u8][owner: Pubkey][...]
+    pub fn nft_holder(asset: &AccountInfo) -> Option<Pubkey> {
+        if asset.owner != &Self::MPL_CORE_PROGRAM_ID {
+            return None;
+        }
+        let data = asset.try_borrow_data().ok()?;
+        if data.len() < 33 || data[0] != Self::ASSET_V1_KEY {
+            return None;
+        }
+        Pubkey::try_from(&data[1..33]).ok()
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+/// Kind of misbehavior a report alleges
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum ReportCategory {
+    /// Harmful or unsafe output
+    Safety,
+    /// Fraudulent claims or manipulated results
+    Fraud,
+    /// Posing as another agent or model
+    Impersonation,
+    /// Spam or abuse of the registry
+    Spam,
+    /// Anything else (described in the evidence)
+    Other,
+}
+
+/// A bonded misbehavior report against an agent, awaiting resolution
+/// Holds the reporter bond on top of rent; closed by `resolve_report`
+#[account]
+#[derive(InitSpace)]
+pub struct Report {
+    /// The reported agent
+    pub agent: Pubkey,
+
+    /// Wallet that filed the report
+    pub reporter: Pubkey,
+
+    /// Reporter-chosen nonce (enables multiple reports per reporter and agent)
+    pub nonce: u64,
+
+    /// Alleged misbehavior
+    pub category: ReportCategory,
+
+    /// SHA256 hash of the off-chain evidence bundle
+    #[max_len(64)]
+    pub evidence_hash: String,
+
+    /// Report bond (lamports) escrowed in this PDA on top of rent
+    /// Refunded if the report is upheld, sent to the treasury if dismissed
+    pub bond: u64,
+
+    /// Unix timestamp when the report was filed
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Report {
+    pub const SEED_PREFIX: &'static [u8] = b"report";
+
+    /// Largest reputation penalty a single upheld report may apply (-10%)
+    pub const MAX_PENALTY: u32 = 1_000;
+}
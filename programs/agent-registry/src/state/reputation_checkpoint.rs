@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Merkle root over (agent, reputation score) pairs at an epoch
+///
+/// Written permissionlessly by `checkpoint_reputations`, which computes the
+/// root on-chain from the agent accounts themselves. Other chains or programs
+/// can then verify an agent's score with a Merkle proof against this root
+/// instead of reading the agent account.
+///
+/// Leaf: sha256(0x00 || agent || score_le). Node: sha256(0x01 || lo || hi)
+/// with children in ascending byte order, so proofs need no left/right flags.
+/// An unpaired node is promoted to the next level unchanged.
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationCheckpoint {
+    /// Epoch the scores were read in
+    pub epoch: u64,
+
+    /// Batch number within the epoch (several checkpoints can cover one epoch)
+    pub batch_index: u32,
+
+    /// Merkle root over the batch's (agent, score) leaves
+    pub merkle_root: [u8; 32],
+
+    /// Number of agents in the batch
+    pub agent_count: u32,
+
+    /// Unix timestamp when the checkpoint was written
+    pub timestamp: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ReputationCheckpoint {
+    pub const SEED_PREFIX: &'static [u8] = b"reputation_checkpoint";
+
+    /// Maximum agents per checkpoint batch
+    pub const MAX_AGENTS: usize = 32;
+
+    /// Leaf hash for an agent's score
+    pub fn leaf(agent: &Pubkey, reputation_score: u32) -> [u8; 32] {
+        hashv(&[&[0u8], agent.as_ref(), &reputation_score.to_le_bytes()]).to_bytes()
+    }
+
+    /// Parent hash of two nodes (order-independent)
+    pub fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1u8], lo, hi]).to_bytes()
+    }
+
+    /// Merkle root over leaves (zero hash for an empty set)
+    pub fn root(mut level: Vec<[u8; 32]>) -> [u8; 32] {
+        if level.is_empty() {
+            return [0u8; 32];
+        }
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => Self::node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        level[0]
+    }
+
+    /// Check a Merkle proof of an agent's score against this checkpoint
+    pub fn verify(&self, agent: &Pubkey, reputation_score: u32, proof: &[[u8; 32]]) -> bool {
+        let computed = proof
+            .iter()
+            .fold(Self::leaf(agent, reputation_score), |acc, sibling| {
+                Self::node(&acc, sibling)
+            });
+        computed == self.merkle_root
+    }
+}
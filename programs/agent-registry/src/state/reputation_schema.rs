@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+use crate::state::AgentAccount;
+
+/// History of reputation recalibration curves (admin managed)
+///
+/// Each curve maps scores on the previous scale to the new scale: point `i` is
+/// the new score for an old score of `i * POINT_SPACING`, with linear
+/// interpolation in between. The schema version is the number of curves
+/// published; agents record the version their score is expressed in and are
+/// brought forward lazily by `recalibrate_reputation`, applying every curve
+/// they missed in order.
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationSchema {
+    /// Published curves, oldest first (curve `v` upgrades version `v` to `v + 1`)
+    #[max_len(16)]
+    pub curves: Vec<[u16; 11]>,
+
+    /// Unix timestamp of the last published curve
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ReputationSchema {
+    pub const SEED_PREFIX: &'static [u8] = b"reputation_schema";
+
+    /// Maximum number of published curves
+    pub const MAX_CURVES: usize = 16;
+
+    /// Number of breakpoints per curve
+    pub const CURVE_POINTS: usize = 11;
+
+    /// Old-score distance between breakpoints
+    pub const POINT_SPACING: u32 = AgentAccount::MAX_REPUTATION / (Self::CURVE_POINTS as u32 - 1);
+
+    /// Current schema version (number of published curves)
+    pub fn version(&self) -> u16 {
+        self.curves.len() as u16
+    }
+
+    /// Schema version recorded in a schema account
+    /// An uninitialized schema means version 0 (no recalibrations)
+    pub fn current_version(schema_info: &AccountInfo) -> Result<u16> {
+        if schema_info.data_is_empty() {
+            return Ok(0);
+        }
+        require_keys_eq!(*schema_info.owner, crate::ID, RegistryError::Unauthorized);
+        let schema = ReputationSchema::try_deserialize(&mut &schema_info.try_borrow_data()?[..])?;
+        Ok(schema.version())
+    }
+
+    /// Check that a curve is non-decreasing and stays within the reputation range
+    pub fn is_valid_curve(curve: &[u16; 11]) -> bool {
+        curve.windows(2).all(|pair| pair[0] <= pair[1])
+            && curve.iter().all(|point| *point as u32 <= AgentAccount::MAX_REPUTATION)
+    }
+
+    /// Map a score through one curve (piecewise-linear interpolation)
+    pub fn apply_curve(curve: &[u16; 11], score: u32) -> u32 {
+        let score = score.min(AgentAccount::MAX_REPUTATION);
+        let index = ((score / Self::POINT_SPACING) as usize).min(Self::CURVE_POINTS - 2);
+        let low = curve[index] as u32;
+        let high = curve[index + 1] as u32;
+        let offset = score - index as u32 * Self::POINT_SPACING;
+        low + (high - low) * offset / Self::POINT_SPACING
+    }
+
+    /// Map a score expressed in `from_version` to the current version
+    pub fn recalibrate(&self, score: u32, from_version: u16) -> u32 {
+        self.curves
+            .iter()
+            .skip(from_version as usize)
+            .fold(score, |score, curve| Self::apply_curve(curve, score))
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+/// Agent reputation as of one epoch
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ReputationSnapshot {
+    pub epoch: u64,
+    pub reputation_score: u32,
+    pub challenges_passed: u32,
+    pub challenges_failed: u32,
+    pub timestamp: i64,
+}
+
+/// Per-agent ring buffer of epoch reputation snapshots
+///
+/// Written permissionlessly (at most once per epoch) so downstream protocols
+/// can look up an agent's reputation at a past point in time. Once full, the
+/// oldest snapshot is overwritten.
+#[account]
+#[derive(InitSpace)]
+pub struct ReputationSnapshots {
+    /// The snapshotted agent
+    pub agent: Pubkey,
+
+    /// Slot in `snapshots` the next snapshot overwrites once the buffer is full
+    pub head: u8,
+
+    /// Snapshots ever taken (including overwritten ones)
+    pub total_snapshots: u64,
+
+    /// Most recent snapshots (oldest at `head` once full)
+    #[max_len(32)]
+    pub snapshots: Vec<ReputationSnapshot>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ReputationSnapshots {
+    pub const SEED_PREFIX: &'static [u8] = b"reputation_snapshots";
+
+    /// Maximum snapshots retained per agent
+    pub const MAX_SNAPSHOTS: usize = 32;
+
+    /// Most recently written snapshot
+    pub fn latest(&self) -> Option<&ReputationSnapshot> {
+        if self.snapshots.len() < Self::MAX_SNAPSHOTS {
+            self.snapshots.last()
+        } else {
+            let index = (self.head as usize + Self::MAX_SNAPSHOTS - 1) % Self::MAX_SNAPSHOTS;
+            self.snapshots.get(index)
+        }
+    }
+
+    /// Append a snapshot, overwriting the oldest one when full
+    pub fn push(&mut self, snapshot: ReputationSnapshot) {
+        self.total_snapshots = self.total_snapshots.saturating_add(1);
+
+        if self.snapshots.len() < Self::MAX_SNAPSHOTS {
+            self.snapshots.push(snapshot);
+            return;
+        }
+
+        self.snapshots[self.head as usize] = snapshot;
+        self.head = ((self.head as usize + 1) % Self::MAX_SNAPSHOTS) as u8;
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{ActionCodeRegistry, ActionType, AuditEntry};
+use crate::errors::RegistryError;
+
+/// Admin-managed base-risk table for audit scoring
+///
+/// Replaces AuditEntry::DEFAULT_BASE_RISK once published. Every update bumps
+/// `version`, which audit records store so scores stay comparable across
+/// recalibrations.
+#[account]
+#[derive(InitSpace)]
+pub struct RiskWeights {
+    /// Table version (0 = built-in defaults, never stored)
+    pub version: u32,
+
+    /// Base risk per ActionType index (the Custom slot is unused)
+    pub base_risk: [u8; ActionType::COUNT],
+
+    /// Last update timestamp
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl RiskWeights {
+    pub const SEED_PREFIX: &'static [u8] = b"risk_weights";
+
+    /// Weights stored in the weights account, or the built-in defaults if uninitialized
+    pub fn load_or_default(weights_info: &AccountInfo) -> Result<Self> {
+        if weights_info.data_is_empty() {
+            return Ok(Self {
+                version: 0,
+                base_risk: AuditEntry::DEFAULT_BASE_RISK,
+                updated_at: 0,
+                bump: 0,
+            });
+        }
+        require_keys_eq!(*weights_info.owner, crate::ID, RegistryError::Unauthorized);
+        RiskWeights::try_deserialize(&mut &weights_info.try_borrow_data()?[..])
+    }
+
+    /// Risk score of an action, resolving custom codes through the code registry
+    pub fn risk_score(
+        &self,
+        action_type: &ActionType,
+        context_risk: u8,
+        codes: &ActionCodeRegistry,
+    ) -> Result<u8> {
+        let base_risk = match action_type {
+            ActionType::Custom(code) => codes.base_risk(*code)?,
+            _ => self.base_risk[action_type.index()],
+        };
+        Ok(AuditEntry::calculate_risk_score(base_risk, context_risk))
+    }
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::ChallengeKind;
+
+/// Billing unit of a listing price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PriceUnit {
+    /// Per task (see the task marketplace)
+    PerTask,
+    /// Per request / call
+    PerRequest,
+    /// Per hour of work
+    PerHour,
+    /// Per 1,000 tokens processed
+    PerThousandTokens,
+}
+
+/// A priced service an agent offers, for on-chain discovery
+///
+/// All fields are fixed-size, so clients can `getProgramAccounts` with memcmp
+/// filters on capability and active, then rank by price and reputation
+/// without fetching the agents. `agent_reputation` is a snapshot taken on every
+/// update and by the permissionless `refresh_service_listing`.
+#[account]
+#[derive(InitSpace)]
+pub struct ServiceListing {
+    /// The agent offering the service
+    pub agent: Pubkey,
+
+    /// Owner-chosen listing number (several listings per agent)
+    pub listing_id: u16,
+
+    /// Capability domain of the service
+    pub capability: ChallengeKind,
+
+    /// Whether the listing is currently offered
+    pub active: bool,
+
+    /// Price in lamports per `unit`
+    pub price_lamports: u64,
+
+    /// Billing unit of the price
+    pub unit: PriceUnit,
+
+    /// SHA256 of the off-chain service-level agreement
+    pub sla_hash: [u8; 32],
+
+    /// Agent reputation score at the last update or refresh
+    pub agent_reputation: u32,
+
+    /// Whether the agent was verified at the last update or refresh
+    pub agent_verified: bool,
+
+    /// Unix timestamp when the listing was created
+    pub created_at: i64,
+
+    /// Unix timestamp of the last update or refresh
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ServiceListing {
+    pub const SEED_PREFIX: &'static [u8] = b"service_listing";
+
+    /// Copy the agent's current reputation and verification into the listing
+    pub fn sync_agent(&mut self, reputation_score: u32, verified: bool, now: i64) {
+        self.agent_reputation = reputation_score;
+        self.agent_verified = verified;
+        self.updated_at = now;
+    }
+}
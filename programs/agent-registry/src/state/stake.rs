@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+use crate::state::RegistryConfig;
+
+/// Per-agent stake vault
+///
+/// The agent owner or any backer can lock SOL here. Staked SOL boosts the
+/// reputation gained from passed challenges (formula in RegistryConfig) and is
+/// slashed to the treasury when a boosted challenge is failed or expires.
+/// Stakers hold shares, so slashing reduces every position proportionally.
+/// A SOL vault holds the staked lamports on top of its rent; a vault created
+/// while config.stake_mint is set holds SPL tokens in token accounts owned by
+/// the vault PDA instead.
+/// Staking rewards are always lamports held in the vault on top of the stake,
+/// accrued per share (`reward_per_share`) and claimed per position.
+#[account]
+#[derive(InitSpace)]
+pub struct StakeVault {
+    /// The staked agent
+    pub agent: Pubkey,
+
+    /// Lamports currently staked (excluding rent)
+    pub total_staked: u64,
+
+    /// Outstanding stake shares across all positions
+    pub total_shares: u64,
+
+    /// Lamports slashed since creation
+    pub total_slashed: u64,
+
+    /// SPL mint the vault is denominated in (default = native SOL, amounts in lamports)
+    pub mint: Pubkey,
+
+    /// Reward lamports accrued per share since creation, scaled by REWARD_PRECISION
+    pub reward_per_share: u128,
+
+    /// Reward lamports distributed to stakers since creation
+    pub total_rewards: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl StakeVault {
+    pub const SEED_PREFIX: &'static [u8] = b"stake_vault";
+
+    /// Delay between requesting an unstake and withdrawing it (covers the
+    /// longest challenge lifetime plus the dispute window, so stake cannot
+    /// leave ahead of a pending slash)
+    pub const UNBONDING_PERIOD: i64 = 14 * 24 * 3600;
+
+    /// Fixed-point scale of reward_per_share
+    pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+    /// Reputation boost for the agent's stake vault account
+    /// An uninitialized vault, or one in a different denomination than
+    /// config.stake_mint, means no boost
+    pub fn boost_bps(vault_info: &AccountInfo, config: &RegistryConfig) -> Result<u16> {
+        if vault_info.data_is_empty() {
+            return Ok(0);
+        }
+        require_keys_eq!(*vault_info.owner, crate::ID, RegistryError::Unauthorized);
+        let vault = StakeVault::try_deserialize(&mut &vault_info.try_borrow_data()?[..])?;
+        if vault.mint != config.stake_mint {
+            return Ok(0);
+        }
+        Ok(config.stake_boost_bps(vault.total_staked))
+    }
+
+    /// Whether the vault holds SPL tokens rather than lamports
+    pub fn is_token(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+
+    /// Record a deposit at the current share price, returning the shares minted
+    /// A vault whose stake is gone while shares remain has no share price, so
+    /// deposits are rejected rather than diluted into the old positions
+    pub fn deposit(&mut self, amount: u64) -> Result<u64> {
+        require!(
+            self.total_staked > 0 || self.total_shares == 0,
+            RegistryError::StakeVaultDepleted
+        );
+        let shares = self.shares_for(amount);
+        self.total_shares = self
+            .total_shares
+            .checked_add(shares)
+            .ok_or(RegistryError::MathOverflow)?;
+        self.total_staked = self
+            .total_staked
+            .checked_add(amount)
+            .ok_or(RegistryError::MathOverflow)?;
+        Ok(shares)
+    }
+
+    /// Distribute `amount` reward lamports (already in the vault) pro-rata to all shares
+    pub fn accrue_rewards(&mut self, amount: u64) -> Result<()> {
+        require!(self.total_shares > 0, RegistryError::NoStakers);
+        let per_share = amount as u128 * Self::REWARD_PRECISION / self.total_shares as u128;
+        self.reward_per_share = self
+            .reward_per_share
+            .checked_add(per_share)
+            .ok_or(RegistryError::MathOverflow)?;
+        self.total_rewards = self.total_rewards.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Shares minted for a deposit at the current share price
+    /// (callers must reject deposits into a depleted vault, see `deposit`)
+    pub fn shares_for(&self, amount: u64) -> u64 {
+        if self.total_shares == 0 || self.total_staked == 0 {
+            return amount;
+        }
+        (amount as u128 * self.total_shares as u128 / self.total_staked as u128) as u64
+    }
+
+    /// Lamports redeemable for shares at the current share price
+    pub fn amount_for(&self, shares: u64) -> u64 {
+        if self.total_shares == 0 {
+            return 0;
+        }
+        (shares as u128 * self.total_staked as u128 / self.total_shares as u128) as u64
+    }
+}
+
+/// What a `slash_agent` call was triggered by
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum SlashTrigger {
+    /// A challenge resolved as failed by a verifier, verifier panel, oracle or verifier program
+    ChallengeFailed,
+    /// A Critical-risk audit entry
+    CriticalAlert,
+}
+
+/// Receipt of a `slash_agent` call
+/// Keyed by the triggering challenge or audit entry, so each is slashed at most once
+#[account]
+#[derive(InitSpace)]
+pub struct SlashRecord {
+    /// The slashed agent
+    pub agent: Pubkey,
+
+    /// What triggered the slash
+    pub trigger: SlashTrigger,
+
+    /// The failed challenge or Critical audit entry
+    pub subject: Pubkey,
+
+    /// Total stake slashed (lamports or stake mint base units)
+    pub amount: u64,
+
+    /// Party compensated from the slash (default = none, all to the treasury)
+    pub affected_party: Pubkey,
+
+    /// Part of `amount` paid to the affected party
+    pub affected_amount: u64,
+
+    /// Who triggered the slash
+    pub slashed_by: Pubkey,
+
+    /// Unix timestamp of the slash
+    pub timestamp: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SlashRecord {
+    pub const SEED_PREFIX: &'static [u8] = b"slash_record";
+}
+
+/// One staker's share of an agent's stake vault
+#[account]
+#[derive(InitSpace)]
+pub struct StakePosition {
+    /// The vault this position belongs to
+    pub vault: Pubkey,
+
+    /// Wallet that staked (receives the withdrawal)
+    pub staker: Pubkey,
+
+    /// Vault shares held
+    pub shares: u64,
+
+    /// Unix timestamp when the unstake can be withdrawn (0 = not unbonding)
+    pub unbonding_at: i64,
+
+    /// shares * reward_per_share / REWARD_PRECISION at the last settlement
+    pub reward_debt: u128,
+
+    /// Settled reward lamports not yet claimed
+    pub unclaimed_rewards: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl StakePosition {
+    pub const SEED_PREFIX: &'static [u8] = b"stake_position";
+
+    /// Check if the staker has requested to unstake
+    pub fn is_unbonding(&self) -> bool {
+        self.unbonding_at != 0
+    }
+
+    /// Move rewards accrued since the last settlement into unclaimed_rewards
+    /// (call before the position's shares change)
+    pub fn settle_rewards(&mut self, reward_per_share: u128) {
+        let accumulated = self.accumulated_rewards(reward_per_share);
+        let pending = accumulated.saturating_sub(self.reward_debt);
+        self.unclaimed_rewards = self.unclaimed_rewards.saturating_add(pending as u64);
+        self.reward_debt = accumulated;
+    }
+
+    /// Restart reward accrual from the current shares (call after they change)
+    pub fn reset_reward_debt(&mut self, reward_per_share: u128) {
+        self.reward_debt = self.accumulated_rewards(reward_per_share);
+    }
+
+    fn accumulated_rewards(&self, reward_per_share: u128) -> u128 {
+        (self.shares as u128).saturating_mul(reward_per_share) / StakeVault::REWARD_PRECISION
+    }
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+/// Lifecycle of a marketplace task
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum TaskStatus {
+    /// Posted and funded, no agent assigned yet
+    Open,
+    /// Assigned to an agent, awaiting its result
+    Assigned,
+    /// Result submitted, awaiting the client's review
+    Submitted,
+    /// Client disputed the result, awaiting arbitration
+    Disputed,
+}
+
+/// How a task was settled (the task account is closed on settlement)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TaskOutcome {
+    /// Result accepted, payment released to the agent
+    Accepted,
+    /// Dispute decided for the agent, payment released to the agent
+    AgentWon,
+    /// Dispute decided for the client, payment refunded
+    ClientWon,
+    /// Withdrawn by the client before a result was submitted, payment refunded
+    Cancelled,
+}
+
+/// A paid task posted by a client for a registered agent
+///
+/// A SOL payment is escrowed in this PDA on top of rent; an SPL or Token-2022
+/// payment in a token account owned by this PDA. Accepting the result releases
+/// it to the agent owner and credits the agent's reputation (SOL tasks only).
+#[account]
+#[derive(InitSpace)]
+pub struct Task {
+    /// Wallet that posted and funded the task
+    pub client: Pubkey,
+
+    /// Client-chosen nonce (enables multiple tasks per client)
+    pub nonce: u64,
+
+    /// Assigned agent (default = not assigned yet)
+    pub agent: Pubkey,
+
+    /// Escrowed payment (lamports, or token base units for an SPL task)
+    pub payment: u64,
+
+    /// SPL mint the payment is denominated in (default = native SOL)
+    pub mint: Pubkey,
+
+    /// SHA256 of the off-chain task specification
+    pub spec_hash: [u8; 32],
+
+    /// SHA256 of the submitted result (zero = none yet)
+    pub result_hash: [u8; 32],
+
+    /// Current lifecycle state
+    pub status: TaskStatus,
+
+    /// Unix timestamp by which the result is due (client may cancel afterwards)
+    pub deadline: i64,
+
+    /// Unix timestamp of the result submission (0 = none)
+    pub submitted_at: i64,
+
+    /// Unix timestamp when the task was posted
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Task {
+    pub const SEED_PREFIX: &'static [u8] = b"task";
+
+    /// Time the client has to accept or dispute a result before the agent
+    /// may accept it on their behalf (3 days)
+    pub const REVIEW_WINDOW_SECS: i64 = 3 * 24 * 3600;
+
+    /// Reputation credited to the agent when it is paid for a SOL task
+    /// (token payments have no price the registry can check, so earn none)
+    pub const REPUTATION_CREDIT: u32 = 10;
+
+    /// Most reputation an agent can earn from paid tasks per reputation window
+    /// (AgentAccount::REPUTATION_WINDOW). Clients can be sybils of the agent, so
+    /// task credit gets its own cap far below config.max_reputation_change_per_day
+    pub const MAX_REPUTATION_CREDIT_PER_WINDOW: u32 = 20;
+
+    /// Smallest payment a SOL task can escrow (0.01 SOL), so reputation credit
+    /// cannot be farmed with dust tasks
+    pub const MIN_PAYMENT: u64 = 10_000_000;
+
+    /// Whether the payment is held in SPL tokens rather than lamports
+    pub fn is_token(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+}
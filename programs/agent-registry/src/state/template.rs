@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::ChallengeKind;
+
+/// How challenges created from a template are resolved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum CommitmentScheme {
+    /// Challenger commits SHA256(answer_hash || salt) and reveals the salt
+    CommitReveal,
+    /// The template author grades responses via `resolve_challenge`
+    AuthorVerified,
+}
+
+/// Reusable challenge definition published by a verifier
+///
+/// Standardized test suites reference the same template so results are
+/// comparable across agents. Only the question hash is stored; challengers
+/// supply the question text, which must hash to `question_hash`.
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeTemplate {
+    /// Verifier who published the template
+    pub author: Pubkey,
+
+    /// Author-chosen template ID (PDA seed)
+    pub template_id: u64,
+
+    /// SHA256 hash of the question text (hex)
+    #[max_len(64)]
+    pub question_hash: String,
+
+    /// How challenges from this template are resolved
+    pub scheme: CommitmentScheme,
+
+    /// Challenge category (selects reputation weights)
+    pub kind: ChallengeKind,
+
+    /// Difficulty level scaling reputation deltas
+    pub difficulty: u8,
+
+    /// Number of challenges created from this template
+    pub uses: u64,
+
+    /// Unix timestamp when the template was published
+    pub created_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ChallengeTemplate {
+    pub const SEED_PREFIX: &'static [u8] = b"challenge_template";
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+/// Protocol treasury - collects challenge fees (lamports held on top of rent)
+/// Slashed SPL stake is held in token accounts owned by this PDA
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    /// Total fees collected since initialization
+    pub total_collected: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const SEED_PREFIX: &'static [u8] = b"treasury";
+}
+
+/// Multisig council that governs treasury spending
+///
+/// Spends are proposed by a member, need `threshold` member approvals and can
+/// only be executed `timelock_secs` after the threshold was reached.
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryCouncil {
+    /// Council member keys
+    #[max_len(7)]
+    pub members: Vec<Pubkey>,
+
+    /// Member approvals required to execute a spend
+    pub threshold: u8,
+
+    /// Delay between reaching the threshold and executing a spend (seconds)
+    pub timelock_secs: u32,
+
+    /// Spend proposals created so far (index of the next one)
+    pub proposal_count: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl TreasuryCouncil {
+    pub const SEED_PREFIX: &'static [u8] = b"treasury_council";
+
+    /// Maximum council members
+    pub const MAX_MEMBERS: usize = 7;
+
+    /// Minimum spend timelock (24 hours)
+    pub const MIN_TIMELOCK_SECS: u32 = 24 * 3600;
+
+    /// Check if a key is a council member
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    /// Approvals from keys that are still members
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|key| self.is_member(key)).count()
+    }
+}
+
+/// A proposed treasury spend awaiting council approval and the timelock
+#[account]
+#[derive(InitSpace)]
+pub struct SpendProposal {
+    /// Sequential proposal index (TreasuryCouncil::proposal_count at creation)
+    pub index: u64,
+
+    /// Council member who proposed the spend (receives the rent back)
+    pub proposer: Pubkey,
+
+    /// Wallet receiving the lamports or tokens
+    pub recipient: Pubkey,
+
+    /// Lamports (or base units of `mint`) to pay out of the treasury
+    pub amount: u64,
+
+    /// Mint of a token spend, paid from a treasury-owned token account
+    /// (default = lamports)
+    pub mint: Pubkey,
+
+    /// SHA256 of the off-chain justification
+    pub memo_hash: [u8; 32],
+
+    /// Members that approved (the proposer approves on creation)
+    #[max_len(7)]
+    pub approvals: Vec<Pubkey>,
+
+    /// Unix timestamp when the threshold was reached (0 = not yet)
+    pub approved_at: i64,
+
+    /// Unix timestamp of the proposal
+    pub created_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SpendProposal {
+    pub const SEED_PREFIX: &'static [u8] = b"spend_proposal";
+
+    /// Whether the spend pays out SPL tokens rather than lamports
+    pub fn is_token(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Paid request for admin/verifier verification of an agent
+///
+/// The owner escrows `fee` lamports in this PDA (on top of rent). Whoever
+/// services it via `verify_agent` claims the fee; `reject_verification` refunds
+/// it. Off-chain queues order open requests by fee, so paying more buys priority.
+#[account]
+#[derive(InitSpace)]
+pub struct VerificationRequest {
+    /// The agent asking to be verified
+    pub agent: Pubkey,
+
+    /// Owner who paid the fee (receives refunds and the rent back)
+    pub requester: Pubkey,
+
+    /// Escrowed fee in lamports
+    pub fee: u64,
+
+    /// Unix timestamp of the request
+    pub created_at: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VerificationRequest {
+    pub const SEED_PREFIX: &'static [u8] = b"verification_request";
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::errors::RegistryError;
+
+/// Verifier vetted by the registry admin (exempt from challenge fees)
+#[account]
+#[derive(InitSpace)]
+pub struct VerifiedVerifier {
+    /// The verifier wallet
+    pub verifier: Pubkey,
+
+    /// Whether the verifier is currently verified
+    pub active: bool,
+
+    /// Unix timestamp of the last status change
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl VerifiedVerifier {
+    pub const SEED_PREFIX: &'static [u8] = b"verified_verifier";
+
+    /// Check whether a verifier record PDA marks its wallet as verified
+    /// An uninitialized record means not verified
+    pub fn is_active(record_info: &AccountInfo) -> Result<bool> {
+        if record_info.data_is_empty() {
+            return Ok(false);
+        }
+        require_keys_eq!(*record_info.owner, crate::ID, RegistryError::Unauthorized);
+        let record = VerifiedVerifier::try_deserialize(&mut &record_info.try_borrow_data()?[..])?;
+        Ok(record.active)
+    }
+
+    /// Check whether `record_info` is `verifier`'s record PDA and marks it verified
+    pub fn is_active_for(record_info: &AccountInfo, verifier: &Pubkey) -> Result<bool> {
+        let (expected, _) =
+            Pubkey::find_program_address(&[Self::SEED_PREFIX, verifier.as_ref()], &crate::ID);
+        require_keys_eq!(record_info.key(), expected, RegistryError::Unauthorized);
+        Self::is_active(record_info)
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+/// Admin-managed allowlist of external verifier programs
+///
+/// Challenges created with `verifier_program` are resolved by CPI into that
+/// program (see `resolve_with_verifier_program`), which must be listed here at
+/// resolution time.
+///
+/// Verifier program interface: instruction data is the Anchor discriminator of
+/// `global:verify_response` followed by borsh-encoded `VerifyResponseArgs`;
+/// accounts are the challenge (read-only) plus any caller-supplied accounts.
+/// The program reports a 0-100 score as the first byte of its return data.
+#[account]
+#[derive(InitSpace)]
+pub struct VerifierProgramRegistry {
+    /// Allowlisted verifier program ids
+    #[max_len(16)]
+    pub programs: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl VerifierProgramRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"verifier_programs";
+
+    /// Maximum allowlisted programs
+    pub const MAX_PROGRAMS: usize = 16;
+
+    /// Anchor discriminator of the verifier's `verify_response` instruction
+    /// (first 8 bytes of sha256("global:verify_response"))
+    pub const VERIFY_DISCRIMINATOR: [u8; 8] = [0xcd, 0x3f, 0xaf, 0x8a, 0x80, 0x3d, 0xe1, 0x4b];
+
+    /// Check if a program id is allowlisted
+    pub fn is_allowed(&self, program_id: &Pubkey) -> bool {
+        self.programs.contains(program_id)
+    }
+}
+
+/// Arguments passed to a verifier program's `verify_response`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerifyResponseArgs {
+    pub challenge: Pubkey,
+    pub question: String,
+    pub response_hash: String,
+    /// Full response whose SHA256 is `response_hash`
+    pub payload: Vec<u8>,
+}
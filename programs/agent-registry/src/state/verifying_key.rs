@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Groth16 verifying key for a proof-of-inference circuit (admin curated)
+///
+/// Points use the EIP-197 big-endian encoding expected by the alt_bn128 syscalls.
+/// The circuit has `NUM_PUBLIC_INPUTS` public inputs, in order:
+/// model commitment, question commitment, output commitment (see `crate::zk`).
+#[account]
+#[derive(InitSpace)]
+pub struct ZkVerifyingKey {
+    /// Circuit identifier (PDA seed)
+    pub circuit_id: u64,
+
+    /// Admin who registered the key
+    pub authority: Pubkey,
+
+    /// alpha in G1
+    pub alpha_g1: [u8; 64],
+
+    /// beta in G2
+    pub beta_g2: [u8; 128],
+
+    /// gamma in G2
+    pub gamma_g2: [u8; 128],
+
+    /// delta in G2
+    pub delta_g2: [u8; 128],
+
+    /// Public input bases in G1 (IC[0] + one per public input)
+    pub ic: [[u8; 64]; 4],
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ZkVerifyingKey {
+    pub const SEED_PREFIX: &'static [u8] = b"zk_vk";
+
+    /// Public inputs every proof-of-inference circuit exposes
+    pub const NUM_PUBLIC_INPUTS: usize = 3;
+}
+
+/// Groth16 proof (EIP-197 big-endian encoding)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Groth16Proof {
+    pub a: [u8; 64],
+    pub b: [u8; 128],
+    pub c: [u8; 64],
+}
@@ -0,0 +1,598 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_interface::spl_token_2022;
+use anchor_spl::token_interface::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_interface::{
+    self, get_mint_extension_data, CloseAccount, HarvestWithheldTokensToMint, Mint, TokenAccount,
+    TokenInterface,
+};
+use crate::errors::RegistryError;
+use crate::events::{
+    AgentSuspended, AuditLogged, ChallengeResolved, ProbationChanged, RegistryActionLogged,
+    ReputationChangeReason, ReputationChanged, StakeRewardsFunded,
+};
+use crate::state::{
+    ActionCodeRegistry, AgentAccount, AgentAuditSummary, AuditEntry, Challenge, ChallengeStatus,
+    RegistryAction, RegistryConfig, RiskLevel, RiskWeights, StakeVault, Task, Treasury,
+    VerifiedVerifier,
+};
+
+/// Move lamports from a signer wallet into an escrow PDA via the system program
+pub fn deposit_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// Move lamports out of a program-owned account (escrow PDA) into any account
+/// The caller is responsible for leaving the source rent-exempt
+pub fn transfer_from_pda(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let from_balance = from.lamports();
+    require!(from_balance >= amount, RegistryError::InsufficientEscrow);
+
+    **from.try_borrow_mut_lamports()? = from_balance - amount;
+    **to.try_borrow_mut_lamports()? = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(RegistryError::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Mint-level accounts for moving SPL or Token-2022 tokens with `transfer_checked`
+/// `hook_accounts` are the extra accounts a transfer-hook mint needs (the
+/// instruction's remaining accounts), forwarded to the token program as-is
+pub struct TokenMint<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub hook_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> TokenMint<'a, 'info> {
+    /// Collect the optional mint and token program of an SPL payment path
+    pub fn from_accounts(
+        mint: Option<&'a InterfaceAccount<'info, Mint>>,
+        token_program: Option<&'a Interface<'info, TokenInterface>>,
+        hook_accounts: &'a [AccountInfo<'info>],
+    ) -> Option<Self> {
+        Some(Self {
+            mint: mint?,
+            token_program: token_program?,
+            hook_accounts,
+        })
+    }
+
+    /// `transfer_checked` from `from` to `to`, signed by `authority` (PDA seeds optional)
+    pub fn transfer(
+        &self,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        amount: u64,
+    ) -> Result<()> {
+        let mut ix = spl_token_2022::instruction::transfer_checked(
+            self.token_program.key,
+            from.key,
+            &self.mint.key(),
+            to.key,
+            authority.key,
+            &[],
+            amount,
+            self.mint.decimals,
+        )?;
+        let mut infos = vec![
+            from.clone(),
+            self.mint.to_account_info(),
+            to.clone(),
+            authority.clone(),
+        ];
+        for account in self.hook_accounts {
+            ix.accounts.push(AccountMeta {
+                pubkey: *account.key,
+                is_signer: false,
+                is_writable: account.is_writable,
+            });
+            infos.push(account.clone());
+        }
+        invoke_signed(&ix, &infos, signer_seeds).map_err(Into::into)
+    }
+
+    /// Close an emptied token account owned by a PDA, its rent to `destination`
+    /// Fees a Token-2022 transfer-fee mint withheld in the account are harvested
+    /// to the (writable) mint first, as the account cannot close holding them
+    pub fn close(
+        &self,
+        account: &AccountInfo<'info>,
+        destination: &AccountInfo<'info>,
+        authority: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let mint_info = self.mint.to_account_info();
+        if get_mint_extension_data::<TransferFeeConfig>(&mint_info).is_ok() {
+            token_interface::harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    HarvestWithheldTokensToMint {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: mint_info,
+                    },
+                ),
+                vec![account.clone()],
+            )?;
+        }
+
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: account.clone(),
+                destination: destination.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ))
+    }
+}
+
+/// Tokens that arrived in `account` since its balance was `before`
+/// (net of any Token-2022 transfer fee)
+pub fn tokens_received(account: &mut InterfaceAccount<TokenAccount>, before: u64) -> Result<u64> {
+    account.reload()?;
+    Ok(account.amount.saturating_sub(before))
+}
+
+/// Pay `amount` out of a stake vault: lamports to `to` for a SOL vault, or
+/// tokens from the vault's token account to `to_tokens` for an SPL vault
+/// The caller validates who owns the recipient accounts
+pub fn pay_out_stake<'info>(
+    vault: &Account<'info, StakeVault>,
+    to: &AccountInfo<'info>,
+    vault_tokens: Option<&InterfaceAccount<'info, TokenAccount>>,
+    to_tokens: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token: Option<TokenMint<'_, 'info>>,
+    amount: u64,
+) -> Result<()> {
+    if !vault.is_token() {
+        return transfer_from_pda(&vault.to_account_info(), to, amount);
+    }
+
+    let (Some(from), Some(to_tokens), Some(token)) = (vault_tokens, to_tokens, token) else {
+        return err!(RegistryError::StakeMintMismatch);
+    };
+    require!(
+        token.mint.key() == vault.mint
+            && from.owner == vault.key()
+            && from.mint == vault.mint
+            && to_tokens.mint == vault.mint,
+        RegistryError::StakeMintMismatch
+    );
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let agent = vault.agent;
+    let seeds: &[&[u8]] = &[StakeVault::SEED_PREFIX, agent.as_ref(), &[vault.bump]];
+    token.transfer(
+        &from.to_account_info(),
+        &to_tokens.to_account_info(),
+        &vault.to_account_info(),
+        &[seeds],
+        amount,
+    )
+}
+
+/// Decode a 64-character hex string (e.g. a SHA256 details hash) into 32 bytes
+pub fn decode_hex32(hex: &str) -> Option<[u8; 32]> {
+    let bytes = hex.as_bytes();
+    if bytes.len() != 64 {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for (byte, pair) in out.iter_mut().zip(bytes.chunks_exact(2)) {
+        let high = (pair[0] as char).to_digit(16)?;
+        let low = (pair[1] as char).to_digit(16)?;
+        *byte = (high * 16 + low) as u8;
+    }
+    Some(out)
+}
+
+/// Release the SOL escrow of a challenge that reached a verdict
+/// Bond goes back to the challenger; reward goes to the agent owner on pass,
+/// back to the challenger otherwise. Returns the reward paid to the agent owner.
+pub fn release_challenge_escrow<'info>(
+    challenge: &mut Account<'info, Challenge>,
+    challenger: &AccountInfo<'info>,
+    agent_owner: &AccountInfo<'info>,
+    passed: bool,
+) -> Result<u64> {
+    let challenge_info = challenge.to_account_info();
+
+    let bond = challenge.bond;
+    let reward = challenge.reward;
+    challenge.bond = 0;
+    challenge.reward = 0;
+
+    transfer_from_pda(&challenge_info, challenger, bond)?;
+    if passed {
+        transfer_from_pda(&challenge_info, agent_owner, reward)?;
+        Ok(reward)
+    } else {
+        transfer_from_pda(&challenge_info, challenger, reward)?;
+        Ok(0)
+    }
+}
+
+/// Release the SOL escrow of a challenge the challenger failed to open honestly
+/// (reveal window missed or an opening that does not match the commitment)
+/// The remaining bond is slashed to the agent owner and the unpaid reward is
+/// refunded to the challenger. Returns (slashed bond, refunded reward).
+pub fn slash_challenge_bond<'info>(
+    challenge: &mut Account<'info, Challenge>,
+    challenger: &AccountInfo<'info>,
+    agent_owner: &AccountInfo<'info>,
+) -> Result<(u64, u64)> {
+    let challenge_info = challenge.to_account_info();
+
+    let bond = challenge.bond;
+    let reward = challenge.reward;
+    challenge.bond = 0;
+    challenge.reward = 0;
+
+    transfer_from_pda(&challenge_info, agent_owner, bond)?;
+    transfer_from_pda(&challenge_info, challenger, reward)?;
+
+    Ok((bond, reward))
+}
+
+/// Pay the expiration bounty (a share of the challenger bond) to the crank caller
+/// Deducts it from `challenge.bond` and returns the amount paid
+pub fn pay_expire_bounty<'info>(
+    challenge: &mut Account<'info, Challenge>,
+    caller: &AccountInfo<'info>,
+    bounty_bps: u16,
+) -> Result<u64> {
+    let bounty = (challenge.bond as u128)
+        .checked_mul(bounty_bps as u128)
+        .ok_or(RegistryError::MathOverflow)?
+        / RegistryConfig::BPS_DENOMINATOR as u128;
+    let bounty = bounty as u64;
+
+    challenge.bond -= bounty;
+    transfer_from_pda(&challenge.to_account_info(), caller, bounty)?;
+
+    Ok(bounty)
+}
+
+/// Charge the protocol fee on a new challenge's escrow (bond + reward)
+/// Paid by the challenger into the treasury; verified verifiers are exempt.
+/// config.staker_fee_share_bps of it goes to the challenged agent's stake vault
+/// as staking rewards when the vault has stakers. Returns the fee charged.
+pub fn collect_challenge_fee<'info>(
+    config: &RegistryConfig,
+    escrow: u64,
+    challenger: &AccountInfo<'info>,
+    verifier_record: &AccountInfo<'info>,
+    treasury: &mut Account<'info, Treasury>,
+    stake_vault: Option<&AccountInfo<'info>>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    if VerifiedVerifier::is_active(verifier_record)? {
+        return Ok(0);
+    }
+
+    let fee = ((escrow as u128)
+        .checked_mul(config.challenge_fee_bps as u128)
+        .ok_or(RegistryError::MathOverflow)?
+        / RegistryConfig::BPS_DENOMINATOR as u128) as u64;
+
+    let mut treasury_fee = fee;
+    if let Some(vault_info) = stake_vault.filter(|info| !info.data_is_empty()) {
+        require_keys_eq!(*vault_info.owner, crate::ID, RegistryError::Unauthorized);
+        let mut vault = StakeVault::try_deserialize(&mut &vault_info.try_borrow_data()?[..])?;
+        let staker_fee = (fee as u128 * config.staker_fee_share_bps as u128
+            / RegistryConfig::BPS_DENOMINATOR as u128) as u64;
+        if staker_fee > 0 && vault.total_shares > 0 {
+            deposit_lamports(challenger, vault_info, system_program, staker_fee)?;
+            vault.accrue_rewards(staker_fee)?;
+            vault.try_serialize(&mut &mut vault_info.try_borrow_mut_data()?[..])?;
+            treasury_fee -= staker_fee;
+            emit!(StakeRewardsFunded {
+                agent: vault.agent,
+                funder: challenger.key(),
+                amount: staker_fee,
+                reward_per_share: vault.reward_per_share,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+    }
+
+    deposit_lamports(challenger, &treasury.to_account_info(), system_program, treasury_fee)?;
+    treasury.total_collected = treasury.total_collected.saturating_add(treasury_fee);
+
+    Ok(fee)
+}
+
+/// Emit `ReputationChanged` and `ChallengeResolved` for a challenge whose final verdict was just applied
+/// `old_reputation` is the agent score before the verdict
+pub fn emit_challenge_resolved(
+    challenge: &Account<Challenge>,
+    agent: &AgentAccount,
+    old_reputation: u32,
+) {
+    emit_reputation_changed(
+        challenge.agent,
+        old_reputation,
+        agent.reputation_score,
+        ReputationChangeReason::ChallengeResolved,
+        challenge.key(),
+        challenge.resolved_at,
+    );
+    emit!(ChallengeResolved {
+        challenge: challenge.key(),
+        agent: challenge.agent,
+        challenger: challenge.challenger,
+        nonce: challenge.nonce,
+        passed: challenge.status == ChallengeStatus::Passed,
+        score: challenge.score,
+        reputation_delta: challenge.reputation_delta,
+        reputation_score: agent.reputation_score,
+        timestamp: challenge.resolved_at,
+    });
+}
+
+/// Put an agent on probation if its reputation just dropped below config.probation_floor
+/// `old_reputation` is the agent score before the change
+pub fn enforce_probation(
+    agent: &mut Account<AgentAccount>,
+    old_reputation: u32,
+    config: &RegistryConfig,
+    now: i64,
+) {
+    if agent.enter_probation(old_reputation, config.probation_floor) {
+        emit_probation_changed(agent, now);
+    }
+}
+
+/// Count a verifier-resolved verdict towards the agent leaving probation
+/// Only verdicts from named verifiers independent of the challenger and of the
+/// agent's owner and operator count; oracle and verifier-program verdicts do not
+pub fn record_probation_result(
+    agent: &mut Account<AgentAccount>,
+    challenge: &Challenge,
+    passed: bool,
+    config: &RegistryConfig,
+    now: i64,
+) {
+    let named = challenge.verifier != Pubkey::default() || challenge.has_verifier_panel();
+    if !named || !challenge.has_independent_verifiers(agent) {
+        return;
+    }
+    if agent.record_probation_result(passed, config.probation_exit_streak) {
+        emit_probation_changed(agent, now);
+    }
+}
+
+/// Count `critical` critical-risk audit entries towards the circuit breaker and
+/// suspend the agent once config.suspend_critical_alerts is reached within the window
+pub fn enforce_circuit_breaker(
+    agent: &mut Account<AgentAccount>,
+    summary: &mut AgentAuditSummary,
+    critical: u32,
+    config: &RegistryConfig,
+    now: i64,
+) {
+    if critical == 0 {
+        return;
+    }
+    let alerts = summary.record_critical_alerts(critical, now, config.suspend_window_secs);
+    if agent.suspended || config.suspend_critical_alerts == 0 || alerts < config.suspend_critical_alerts {
+        return;
+    }
+    agent.suspended = true;
+    agent.updated_at = now;
+    emit!(AgentSuspended {
+        agent: agent.key(),
+        critical_alerts: alerts,
+        window_start: summary.critical_window_start,
+        timestamp: now,
+    });
+    msg!(
+        "Agent {} suspended: {} critical audit alerts since {}",
+        agent.agent_id,
+        alerts,
+        summary.critical_window_start
+    );
+}
+
+/// Append an admin action to the registry audit stream
+///
+/// The caller sets the summary's agent (the RegistryState PDA) and both bumps;
+/// `payload` is folded into the details digest (e.g. the serialized config update).
+pub fn log_registry_action(
+    admin: Pubkey,
+    summary: &mut AgentAuditSummary,
+    entry: &mut AuditEntry,
+    risk_weights: &AccountInfo,
+    action: RegistryAction,
+    subject: Pubkey,
+    payload: &[u8],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let registry = summary.agent;
+    let action_type = action.action_type();
+    let weights = RiskWeights::load_or_default(risk_weights)?;
+    let risk_score = weights.risk_score(&action_type, 0, &ActionCodeRegistry::default())?;
+    let details = action.details(&subject, payload);
+    entry.agent = registry;
+    entry.actor = admin;
+    entry.action_type = action_type;
+    entry.risk_score = risk_score;
+    entry.risk_level = RiskLevel::from_score(risk_score);
+    entry.timestamp = now;
+    entry.details_hash = details.iter().map(|b| format!("{:02x}", b)).collect();
+    entry.audit_index = summary.total_entries;
+    entry.risk_weights_version = weights.version;
+    entry.auditor = Pubkey::default();
+    entry.encrypted_details_uri = None;
+    entry.is_alert = false;
+    entry.prev_entry_hash =
+        summary.advance_chain(entry.audit_index, action_type, risk_score, now, &details);
+    summary.record_entry(action_type, risk_score, false, now);
+
+    emit!(RegistryActionLogged {
+        registry,
+        admin,
+        action,
+        subject,
+        index: entry.audit_index,
+        timestamp: now,
+    });
+    emit!(AuditLogged {
+        agent: registry,
+        actor: admin,
+        action_type,
+        risk_score,
+        index: entry.audit_index,
+        is_alert: false,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+fn emit_probation_changed(agent: &Account<AgentAccount>, now: i64) {
+    emit!(ProbationChanged {
+        agent: agent.key(),
+        on_probation: agent.on_probation,
+        reputation_score: agent.reputation_score,
+        timestamp: now,
+    });
+    msg!(
+        "Agent {} {} probation (reputation {})",
+        agent.agent_id,
+        if agent.on_probation { "entered" } else { "left" },
+        agent.reputation_score
+    );
+}
+
+/// Emit the reputation history event for an agent score change
+pub fn emit_reputation_changed(
+    agent: Pubkey,
+    old: u32,
+    new: u32,
+    reason: ReputationChangeReason,
+    source: Pubkey,
+    timestamp: i64,
+) {
+    emit!(ReputationChanged {
+        agent,
+        old,
+        new,
+        reason,
+        source,
+        timestamp,
+    });
+}
+
+/// Release a task's escrowed payment: lamports to `to` for a SOL task, or the
+/// task token account's balance to `to_tokens` for an SPL task, closing the
+/// emptied token account to the client
+/// The caller validates who owns the recipient accounts
+pub fn release_task_payment<'info>(
+    task: &Account<'info, Task>,
+    to: &AccountInfo<'info>,
+    task_tokens: Option<&InterfaceAccount<'info, TokenAccount>>,
+    to_tokens: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token: Option<TokenMint<'_, 'info>>,
+    client: &AccountInfo<'info>,
+) -> Result<()> {
+    if !task.is_token() {
+        return transfer_from_pda(&task.to_account_info(), to, task.payment);
+    }
+
+    let (Some(from), Some(to_tokens), Some(token)) = (task_tokens, to_tokens, token) else {
+        return err!(RegistryError::TaskMintMismatch);
+    };
+    require!(
+        token.mint.key() == task.mint
+            && from.owner == task.key()
+            && from.mint == task.mint
+            && to_tokens.mint == task.mint,
+        RegistryError::TaskMintMismatch
+    );
+
+    let nonce = task.nonce.to_le_bytes();
+    let seeds: &[&[u8]] = &[Task::SEED_PREFIX, task.client.as_ref(), &nonce, &[task.bump]];
+    let task_info = task.to_account_info();
+    let from_info = from.to_account_info();
+    token.transfer(&from_info, &to_tokens.to_account_info(), &task_info, &[seeds], from.amount)?;
+    token.close(&from_info, client, &task_info, &[seeds])
+}
+
+/// Credit the agent's reputation for a paid SOL task (token tasks earn none)
+/// The credit counts against Task::MAX_REPUTATION_CREDIT_PER_WINDOW and
+/// config.max_reputation_change_per_day; once either is exhausted the agent is
+/// still paid but earns no more reputation
+pub fn credit_task_to_agent(
+    task: &Account<Task>,
+    agent: &mut Account<AgentAccount>,
+    config: &RegistryConfig,
+    now: i64,
+) {
+    if task.is_token() {
+        return;
+    }
+
+    let old_reputation = agent.reputation_score;
+    agent.credit_task_reputation(
+        Task::REPUTATION_CREDIT,
+        now,
+        Task::MAX_REPUTATION_CREDIT_PER_WINDOW,
+        config.max_reputation_change_per_day,
+    );
+    emit_reputation_changed(
+        agent.key(),
+        old_reputation,
+        agent.reputation_score,
+        ReputationChangeReason::TaskPaid,
+        task.key(),
+        now,
+    );
+}
+
+/// Require the signer to control an agent with at least `min` reputation
+/// A minimum of 0 leaves the action ungated (no agent needed)
+pub fn require_min_reputation(
+    agent: Option<&Account<AgentAccount>>,
+    signer: &Pubkey,
+    min: u32,
+) -> Result<()> {
+    if min == 0 {
+        return Ok(());
+    }
+    let agent = agent.ok_or(RegistryError::ReputationTooLow)?;
+    require!(agent.is_responder(signer), RegistryError::Unauthorized);
+    require!(agent.reputation_score >= min, RegistryError::ReputationTooLow);
+    Ok(())
+}
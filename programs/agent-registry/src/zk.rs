@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+use crate::errors::RegistryError;
+use crate::state::{Groth16Proof, ZkVerifyingKey};
+
+/// alt_bn128 syscall operation codes
+const ALT_BN128_ADD: u64 = 0;
+const ALT_BN128_MUL: u64 = 2;
+const ALT_BN128_PAIRING: u64 = 3;
+
+/// Base field modulus of BN254 (big-endian), used to negate G1 points
+const FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// Map arbitrary bytes to a BN254 scalar: SHA256 with the top byte cleared
+/// (always below the scalar field modulus)
+pub fn public_input(data: &[u8]) -> [u8; 32] {
+    let mut scalar = hash(data).to_bytes();
+    scalar[0] = 0;
+    scalar
+}
+
+/// Verify a Groth16 proof against a verifying key and public inputs
+/// e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+pub fn verify_groth16(
+    vk: &ZkVerifyingKey,
+    proof: &Groth16Proof,
+    inputs: &[[u8; 32]; ZkVerifyingKey::NUM_PUBLIC_INPUTS],
+) -> Result<bool> {
+    // vk_x = IC[0] + sum(input_i * IC[i + 1])
+    let mut vk_x = vk.ic[0];
+    for (input, base) in inputs.iter().zip(vk.ic[1..].iter()) {
+        let mut mul_input = [0u8; 96];
+        mul_input[..64].copy_from_slice(base);
+        mul_input[64..].copy_from_slice(input);
+        let term = g1_op::<96>(ALT_BN128_MUL, &mul_input)?;
+
+        let mut add_input = [0u8; 128];
+        add_input[..64].copy_from_slice(&vk_x);
+        add_input[64..].copy_from_slice(&term);
+        vk_x = g1_op::<128>(ALT_BN128_ADD, &add_input)?;
+    }
+
+    let mut pairing_input = [0u8; 4 * 192];
+    let pairs: [(&[u8; 64], &[u8; 128]); 4] = [
+        (&negate_g1(&proof.a), &proof.b),
+        (&vk.alpha_g1, &vk.beta_g2),
+        (&vk_x, &vk.gamma_g2),
+        (&proof.c, &vk.delta_g2),
+    ];
+    for (i, (g1, g2)) in pairs.iter().enumerate() {
+        let offset = i * 192;
+        pairing_input[offset..offset + 64].copy_from_slice(*g1);
+        pairing_input[offset + 64..offset + 192].copy_from_slice(*g2);
+    }
+
+    let mut result = [0u8; 32];
+    syscall(ALT_BN128_PAIRING, &pairing_input, &mut result)?;
+    Ok(result[..31].iter().all(|b| *b == 0) && result[31] == 1)
+}
+
+/// Negate a G1 point: (x, y) -> (x, p - y); the point at infinity is unchanged
+fn negate_g1(point: &[u8; 64]) -> [u8; 64] {
+    let mut negated = *point;
+    if point[32..].iter().all(|b| *b == 0) {
+        return negated;
+    }
+
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let mut diff = FIELD_MODULUS[i] as i16 - point[32 + i] as i16 - borrow;
+        borrow = if diff < 0 {
+            diff += 256;
+            1
+        } else {
+            0
+        };
+        negated[32 + i] = diff as u8;
+    }
+    negated
+}
+
+/// Run a G1 add/mul syscall returning the resulting point
+fn g1_op<const N: usize>(op: u64, input: &[u8; N]) -> Result<[u8; 64]> {
+    let mut result = [0u8; 64];
+    syscall(op, input, &mut result)?;
+    Ok(result)
+}
+
+#[cfg(target_os = "solana")]
+fn syscall(op: u64, input: &[u8], result: &mut [u8]) -> Result<()> {
+    let code = unsafe {
+        solana_define_syscall::definitions::sol_alt_bn128_group_op(
+            op,
+            input.as_ptr(),
+            input.len() as u64,
+            result.as_mut_ptr(),
+        )
+    };
+    require!(code == 0, RegistryError::InvalidZkProof);
+    Ok(())
+}
+
+/// alt_bn128 syscalls are only available on-chain
+#[cfg(not(target_os = "solana"))]
+fn syscall(_op: u64, _input: &[u8], _result: &mut [u8]) -> Result<()> {
+    err!(RegistryError::InvalidZkProof)
+}
@@ -36,6 +36,26 @@ async function main() {
   const collectionKeypair = Keypair.generate();
   console.log("Collection:", collectionKeypair.publicKey.toBase58());
 
+  // Registry audit stream PDAs (the entry index is the summary's total_entries)
+  const [riskWeightsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("risk_weights")],
+    PROGRAM_ID
+  );
+  const [registryAuditSummary] = PublicKey.findProgramAddressSync(
+    [Buffer.from("audit_summary"), registryPda.toBuffer()],
+    PROGRAM_ID
+  );
+  const summaryInfo = await connection.getAccountInfo(registryAuditSummary);
+  const auditIndex = Buffer.alloc(8);
+  if (summaryInfo) {
+    // discriminator (8) + agent (32), then total_entries u64
+    summaryInfo.data.copy(auditIndex, 0, 40, 48);
+  }
+  const [registryAuditEntry] = PublicKey.findProgramAddressSync(
+    [Buffer.from("audit"), registryPda.toBuffer(), auditIndex],
+    PROGRAM_ID
+  );
+
   // Build the create_collection instruction
   // Discriminator for "create_collection"
   const discriminator = Buffer.from([156, 251, 92, 54, 233, 2, 16, 82]);
@@ -44,6 +64,10 @@ async function main() {
     { pubkey: keypair.publicKey, isSigner: true, isWritable: true },
     { pubkey: registryPda, isSigner: false, isWritable: true },
     { pubkey: collectionKeypair.publicKey, isSigner: false, isWritable: false },
+    { pubkey: riskWeightsPda, isSigner: false, isWritable: false },
+    { pubkey: registryAuditSummary, isSigner: false, isWritable: true },
+    { pubkey: registryAuditEntry, isSigner: false, isWritable: true },
+    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
 
   const instruction = new TransactionInstruction({
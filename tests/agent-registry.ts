@@ -27,6 +27,46 @@ describe("agent-registry", () => {
     programId
   );
 
+  // Registry audit stream: summary and next entry PDAs keyed by the registry PDA
+  const registryAuditAccounts = async () => {
+    const [registryAuditSummary] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_summary"), registryPda.toBuffer()],
+      programId
+    );
+    const summary = await program.account.agentAuditSummary.fetchNullable(registryAuditSummary);
+    const index: BN = summary ? summary.totalEntries : new BN(0);
+    const [registryAuditEntry] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit"), registryPda.toBuffer(), index.toArrayLike(Buffer, "le", 8)],
+      programId
+    );
+    return { registryAuditSummary, registryAuditEntry };
+  };
+
+  // Agent 0 (owned by the provider wallet), registered below and the target of
+  // most challenge, staking and payment flows
+  const [ownerAgentPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("agent"),
+      provider.wallet.publicKey.toBuffer(),
+      new BN(0).toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+
+  const [configPda] = PublicKey.findProgramAddressSync([Buffer.from("config")], programId);
+
+  // Fresh wallet with an airdrop, e.g. a challenger, client or backer
+  const fundedKeypair = async (lamports = 2_000_000_000) => {
+    const keypair = Keypair.generate();
+    const airdropSig = await provider.connection.requestAirdrop(keypair.publicKey, lamports);
+    await provider.connection.confirmTransaction(airdropSig);
+    return keypair;
+  };
+
+  // Validator clock, for deadlines and stream schedules
+  const chainTime = async () =>
+    (await provider.connection.getBlockTime(await provider.connection.getSlot()))!;
+
   // Test data
   const testModelHash = "sha256:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
   const testAgentName = "TestAgent";
@@ -36,6 +76,69 @@ describe("agent-registry", () => {
   const mockCollection = Keypair.generate();
   const mockNft = Keypair.generate();
 
+  // Register a fresh agent owned by `owner`, e.g. one a test may suspend or report
+  const registerAgentFor = async (owner: Keypair, name: string) => {
+    const registryState = await program.account.registryState.fetch(registryPda);
+    const [agentPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("agent"),
+        owner.publicKey.toBuffer(),
+        registryState.totalAgents.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+    await program.methods
+      .registerAgent(name, testModelHash, testCapabilities)
+      .accounts({
+        owner: owner.publicKey,
+        registry: registryPda,
+        agent: agentPda,
+        nftMint: Keypair.generate().publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+    return agentPda;
+  };
+
+  // Agent audit summary and the PDA of its next audit entry
+  const agentAuditAccounts = async (agent: PublicKey) => {
+    const [auditSummary] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_summary"), agent.toBuffer()],
+      programId
+    );
+    const summary = await program.account.agentAuditSummary.fetchNullable(auditSummary);
+    const index: BN = summary ? summary.totalEntries : new BN(0);
+    const [auditEntry] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit"), agent.toBuffer(), index.toArrayLike(Buffer, "le", 8)],
+      programId
+    );
+    return { auditSummary, auditEntry };
+  };
+
+  // Log an agentUpdated audit entry as `actor`; a context risk of 100 is critical
+  const logAuditAs = async (actor: Keypair, agent: PublicKey, contextRisk: number) => {
+    const { auditSummary, auditEntry } = await agentAuditAccounts(agent);
+    await program.methods
+      .logAudit(
+        { agentUpdated: {} },
+        contextRisk,
+        createHash("sha256").update(`audit ${contextRisk}`).digest("hex"),
+        null
+      )
+      .accounts({
+        actor: actor.publicKey,
+        agent,
+        auditor: null,
+        auditSummary,
+        auditEntry,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([actor])
+      .rpc();
+    return auditEntry;
+  };
+
   it("Initialize registry", async () => {
     try {
       const tx = await program.methods
@@ -74,6 +177,7 @@ describe("agent-registry", () => {
           admin: provider.wallet.publicKey,
           registry: registryPda,
           collection: mockCollection.publicKey,
+          ...(await registryAuditAccounts()),
         })
         .rpc();
 
@@ -189,12 +293,14 @@ describe("agent-registry", () => {
       programId
     );
 
+    const registryAudit = await registryAuditAccounts();
     const tx = await program.methods
       .verifyAgent()
       .accounts({
         admin: provider.wallet.publicKey,
         registry: registryPda,
         agent: agentPda,
+        ...registryAudit,
       })
       .rpc();
 
@@ -204,6 +310,11 @@ describe("agent-registry", () => {
     const agentAccount = await program.account.agentAccount.fetch(agentPda);
     expect(agentAccount.verified).to.be.true;
 
+    // The verification is recorded in the registry audit stream
+    const auditEntry = await program.account.auditEntry.fetch(registryAudit.registryAuditEntry);
+    expect(auditEntry.agent.toString()).to.equal(registryPda.toString());
+    expect(auditEntry.actor.toString()).to.equal(provider.wallet.publicKey.toString());
+
     console.log("Agent verified:", agentAccount.verified);
   });
 
@@ -230,6 +341,7 @@ describe("agent-registry", () => {
         authority: provider.wallet.publicKey,
         registry: registryPda,
         agent: agentPda,
+        authorizedUpdaters: null,
       })
       .rpc();
 
@@ -252,7 +364,55 @@ describe("agent-registry", () => {
   // ============================================
 
   const challengeAnswer = "The answer to life, the universe, and everything is 42";
-  const expectedHash = createHash("sha256").update(challengeAnswer).digest("hex");
+  const answerHash = createHash("sha256").update(challengeAnswer).digest("hex");
+
+  // Commit-reveal: challenger commits SHA256(answer_hash || salt), reveals salt later
+  const commit = (hash: string, salt: Buffer) =>
+    createHash("sha256").update(Buffer.concat([Buffer.from(hash), salt])).digest("hex");
+  const challengeSalt = Keypair.generate().publicKey.toBuffer();
+  const expectedHash = commit(answerHash, challengeSalt);
+
+  // Challenge PDAs are derived per (agent, challenger, nonce)
+  const findChallengePda = (agent: PublicKey, challenger: PublicKey, nonce: BN) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("challenge"),
+        agent.toBuffer(),
+        challenger.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+
+  const defaultOptions = {
+    rewardLamports: new BN(0),
+    kind: { knowledge: {} },
+    difficulty: 5,
+    verifier: null,
+    verifiers: [],
+    quorum: 0,
+    oracleFeed: null,
+    verifierProgram: null,
+    rounds: [],
+    metadata: null,
+    tags: [],
+  };
+
+  it("Initialize config and treasury", async () => {
+    for (const method of ["initializeConfig", "initializeTreasury"]) {
+      try {
+        await program.methods[method]()
+          .accounts({ admin: provider.wallet.publicKey })
+          .rpc();
+      } catch (err: any) {
+        if (err.message && err.message.includes("already in use")) {
+          console.log(`${method}: already initialized`);
+        } else {
+          throw err;
+        }
+      }
+    }
+  });
 
   it("Create a challenge for an agent", async () => {
     const agentId = new BN(0);
@@ -266,23 +426,18 @@ describe("agent-registry", () => {
       programId
     );
 
-    const [challengePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("challenge"),
-        agentPda.toBuffer(),
-        provider.wallet.publicKey.toBuffer(),
-      ],
-      programId
-    );
+    const [challengePda] = findChallengePda(agentPda, provider.wallet.publicKey, new BN(0));
 
     const question = "What is the meaning of life?";
 
     const tx = await program.methods
-      .createChallenge(question, expectedHash)
+      .createChallenge(question, expectedHash, new BN(0), defaultOptions)
       .accounts({
         challenger: provider.wallet.publicKey,
         agent: agentPda,
         challenge: challengePda,
+        zkVerifyingKey: null,
+        challengerAgent: null,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -316,14 +471,7 @@ describe("agent-registry", () => {
       programId
     );
 
-    const [challengePda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("challenge"),
-        agentPda.toBuffer(),
-        provider.wallet.publicKey.toBuffer(),
-      ],
-      programId
-    );
+    const [challengePda] = findChallengePda(agentPda, provider.wallet.publicKey, new BN(0));
 
     // Get agent state before
     const agentBefore = await program.account.agentAccount.fetch(agentPda);
@@ -333,7 +481,7 @@ describe("agent-registry", () => {
     const responseHash = createHash("sha256").update(challengeAnswer).digest("hex");
 
     const tx = await program.methods
-      .submitResponse(responseHash)
+      .submitResponse(responseHash, new BN(0), null)
       .accounts({
         owner: provider.wallet.publicKey,
         registry: registryPda,
@@ -344,13 +492,32 @@ describe("agent-registry", () => {
 
     console.log("Submit response tx:", tx);
 
+    // Response is recorded but not scored until the challenger reveals
+    const responded = await program.account.challenge.fetch(challengePda);
+    expect(responded.status).to.deep.equal({ responded: {} });
+
+    const revealTx = await program.methods
+      .revealChallenge(new BN(0), answerHash, Array.from(challengeSalt))
+      .accounts({
+        challenger: provider.wallet.publicKey,
+        agent: agentPda,
+        agentOwner: provider.wallet.publicKey,
+        challenge: challengePda,
+      })
+      .rpc();
+
+    console.log("Reveal challenge tx:", revealTx);
+
     // Verify challenge status
     const challengeAccount = await program.account.challenge.fetch(challengePda);
     expect(challengeAccount.status).to.deep.equal({ passed: {} });
 
     // Verify agent reputation increased
     const agentAfter = await program.account.agentAccount.fetch(agentPda);
-    expect(agentAfter.reputationScore).to.equal(reputationBefore + 100); // +100 for passing
+    // +100 for passing and +20 fast-response bonus (answered within a minute),
+    // both weighted 0.25x: the test wallet is an anonymous challenger
+    expect(agentAfter.reputationScore).to.equal(reputationBefore + 30);
+    expect(challengeAccount.responseBonus).to.equal(20);
     expect(agentAfter.challengesPassed).to.equal(agentBefore.challengesPassed + 1);
 
     console.log("Challenge PASSED:", {
@@ -382,26 +549,25 @@ describe("agent-registry", () => {
     );
     await provider.connection.confirmTransaction(airdropSig);
 
-    const [challengePda2] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("challenge"),
-        agentPda.toBuffer(),
-        challenger2.publicKey.toBuffer(),
-      ],
-      programId
-    );
+    const [challengePda2] = findChallengePda(agentPda, challenger2.publicKey, new BN(0));
 
     const question2 = "What is 2 + 2?";
     const correctAnswer = "4";
-    const expectedHash2 = createHash("sha256").update(correctAnswer).digest("hex");
+    const salt2 = Keypair.generate().publicKey.toBuffer();
+    const expectedHash2 = commit(
+      createHash("sha256").update(correctAnswer).digest("hex"),
+      salt2
+    );
 
     // Create challenge with challenger2
     const createTx = await program.methods
-      .createChallenge(question2, expectedHash2)
+      .createChallenge(question2, expectedHash2, new BN(0), defaultOptions)
       .accounts({
         challenger: challenger2.publicKey,
         agent: agentPda,
         challenge: challengePda2,
+        zkVerifyingKey: null,
+        challengerAgent: null,
         systemProgram: SystemProgram.programId,
       })
       .signers([challenger2])
@@ -418,7 +584,7 @@ describe("agent-registry", () => {
     const wrongHash = createHash("sha256").update(wrongAnswer).digest("hex");
 
     const submitTx = await program.methods
-      .submitResponse(wrongHash)
+      .submitResponse(wrongHash, new BN(0), null)
       .accounts({
         owner: provider.wallet.publicKey,
         registry: registryPda,
@@ -429,14 +595,36 @@ describe("agent-registry", () => {
 
     console.log("Submit wrong response tx:", submitTx);
 
+    // The challenger opens the commitment with the correct answer; it differs
+    // from the agent's response, so the challenge fails
+    const revealTx = await program.methods
+      .revealChallenge(
+        new BN(0),
+        createHash("sha256").update(correctAnswer).digest("hex"),
+        Array.from(salt2)
+      )
+      .accounts({
+        challenger: challenger2.publicKey,
+        agent: agentPda,
+        agentOwner: provider.wallet.publicKey,
+        challenge: challengePda2,
+      })
+      .signers([challenger2])
+      .rpc();
+
+    console.log("Reveal challenge 2 tx:", revealTx);
+
     // Verify challenge status
     const challengeAccount = await program.account.challenge.fetch(challengePda2);
     expect(challengeAccount.status).to.deep.equal({ failed: {} });
 
     // Verify agent reputation decreased
     const agentAfter = await program.account.agentAccount.fetch(agentPda);
-    expect(agentAfter.reputationScore).to.equal(reputationBefore - 50); // -50 for failing
+    // -50 for failing, weighted 0.25x (rounded toward zero)
+    expect(agentAfter.reputationScore).to.equal(reputationBefore - 12);
     expect(agentAfter.challengesFailed).to.equal(agentBefore.challengesFailed + 1);
+    // The loss counts towards the reputation window like a gain would
+    expect(agentAfter.reputationWindowChange).to.equal(agentBefore.reputationWindowChange + 12);
 
     console.log("Challenge FAILED:", {
       reputationBefore: reputationBefore / 100 + "%",
@@ -445,6 +633,1779 @@ describe("agent-registry", () => {
     });
   });
 
+  it("Respond to multiple challenges from the same challenger (nonce > 0)", async () => {
+    const agentId = new BN(0);
+
+    const [agentPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("agent"),
+        provider.wallet.publicKey.toBuffer(),
+        agentId.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+
+    const nonces = [new BN(1), new BN(2)];
+    const salts = nonces.map(() => Keypair.generate().publicKey.toBuffer());
+
+    // Two concurrent challenges from the same challenger, distinguished by nonce
+    for (const [i, nonce] of nonces.entries()) {
+      const [challengePda] = findChallengePda(agentPda, provider.wallet.publicKey, nonce);
+      await program.methods
+        .createChallenge(`Question #${nonce}`, commit(answerHash, salts[i]), nonce, defaultOptions)
+        .accounts({
+          challenger: provider.wallet.publicKey,
+          agent: agentPda,
+          challenge: challengePda,
+          zkVerifyingKey: null,
+          challengerAgent: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+
+    // Answer the newest one first: the nonce selects the challenge PDA
+    for (const [i, nonce] of [...nonces.entries()].reverse()) {
+      const [challengePda] = findChallengePda(agentPda, provider.wallet.publicKey, nonce);
+
+      await program.methods
+        .submitResponse(answerHash, nonce, null)
+        .accounts({
+          owner: provider.wallet.publicKey,
+          registry: registryPda,
+          agent: agentPda,
+          challenge: challengePda,
+        })
+        .rpc();
+
+      await program.methods
+        .revealChallenge(nonce, answerHash, Array.from(salts[i]))
+        .accounts({
+          challenger: provider.wallet.publicKey,
+          agent: agentPda,
+          agentOwner: provider.wallet.publicKey,
+          challenge: challengePda,
+        })
+        .rpc();
+
+      const challengeAccount = await program.account.challenge.fetch(challengePda);
+      expect(challengeAccount.nonce.toNumber()).to.equal(nonce.toNumber());
+      expect(challengeAccount.status).to.deep.equal({ passed: {} });
+    }
+
+    // Responding with a nonce that was never created must fail
+    const [missingPda] = findChallengePda(agentPda, provider.wallet.publicKey, new BN(99));
+    try {
+      await program.methods
+        .submitResponse(answerHash, new BN(99), null)
+        .accounts({
+          owner: provider.wallet.publicKey,
+          registry: registryPda,
+          agent: agentPda,
+          challenge: missingPda,
+        })
+        .rpc();
+      throw new Error("Should have failed with AccountNotInitialized");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("AccountNotInitialized");
+    }
+  });
+
+  // ============================================
+  // Challenger Bond Tests
+  // ============================================
+
+  it("Slash the challenger bond to the agent owner on a bad opening", async () => {
+    const challenger = await fundedKeypair();
+    const [challengePda] = findChallengePda(ownerAgentPda, challenger.publicKey, new BN(0));
+    const salt = Keypair.generate().publicKey.toBuffer();
+
+    await program.methods
+      .createChallenge("What is 3 + 3?", commit(answerHash, salt), new BN(0), defaultOptions)
+      .accounts({
+        challenger: challenger.publicKey,
+        agent: ownerAgentPda,
+        challenge: challengePda,
+        zkVerifyingKey: null,
+        challengerAgent: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([challenger])
+      .rpc();
+
+    // Revealing before the agent answered is rejected
+    try {
+      await program.methods
+        .revealChallenge(new BN(0), answerHash, Array.from(salt))
+        .accounts({
+          challenger: challenger.publicKey,
+          agent: ownerAgentPda,
+          agentOwner: provider.wallet.publicKey,
+          challenge: challengePda,
+        })
+        .signers([challenger])
+        .rpc();
+      throw new Error("Should have failed with ChallengeNotResponded");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("ChallengeNotResponded");
+    }
+
+    await program.methods
+      .submitResponse(answerHash, new BN(0), null)
+      .accounts({
+        owner: provider.wallet.publicKey,
+        registry: registryPda,
+        agent: ownerAgentPda,
+        challenge: challengePda,
+      })
+      .rpc();
+
+    const agentBefore = await program.account.agentAccount.fetch(ownerAgentPda);
+    const challengeBefore = await program.account.challenge.fetch(challengePda);
+    const escrowBefore = await provider.connection.getBalance(challengePda);
+
+    // Open the commitment with a different salt: the challenge is abandoned
+    // and the bond goes to the agent owner instead of back to the challenger
+    await program.methods
+      .revealChallenge(new BN(0), answerHash, Array.from(Keypair.generate().publicKey.toBuffer()))
+      .accounts({
+        challenger: challenger.publicKey,
+        agent: ownerAgentPda,
+        agentOwner: provider.wallet.publicKey,
+        challenge: challengePda,
+      })
+      .signers([challenger])
+      .rpc();
+
+    const challengeAccount = await program.account.challenge.fetch(challengePda);
+    expect(challengeAccount.status).to.deep.equal({ abandoned: {} });
+    expect(challengeAccount.bond.toNumber()).to.equal(0);
+    const escrowAfter = await provider.connection.getBalance(challengePda);
+    expect(escrowBefore - escrowAfter).to.equal(challengeBefore.bond.toNumber());
+
+    // No reputation effect on the agent, and the abandonment counts against the challenger
+    const agentAfter = await program.account.agentAccount.fetch(ownerAgentPda);
+    expect(agentAfter.reputationScore).to.equal(agentBefore.reputationScore);
+    const [statsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("challenger_stats"), challenger.publicKey.toBuffer()],
+      programId
+    );
+    const stats = await program.account.challengerStats.fetch(statsPda);
+    expect(stats.abandoned).to.equal(1);
+    expect(stats.pending).to.equal(0);
+
+    console.log("Bad opening slashed bond:", challengeBefore.bond.toNumber());
+  });
+
+  // ============================================
+  // Verifier Panel Tests
+  // ============================================
+
+  // Challenge `agent` from `challenger` (nonce 0) and have its owner answer
+  const createAnsweredChallenge = async (
+    challenger: Keypair,
+    agent: PublicKey,
+    owner: Keypair,
+    options: typeof defaultOptions
+  ) => {
+    const [challengePda] = findChallengePda(agent, challenger.publicKey, new BN(0));
+    await program.methods
+      .createChallenge("What is 6 x 7?", expectedHash, new BN(0), options)
+      .accounts({
+        challenger: challenger.publicKey,
+        agent,
+        challenge: challengePda,
+        zkVerifyingKey: null,
+        challengerAgent: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([challenger])
+      .rpc();
+    await program.methods
+      .submitResponse(answerHash, new BN(0), null)
+      .accounts({ owner: owner.publicKey, registry: registryPda, agent, challenge: challengePda })
+      .signers([owner])
+      .rpc();
+    return challengePda;
+  };
+
+  it("Resolve a challenge once a verifier panel reaches quorum", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "PanelAgent");
+    const challenger = await fundedKeypair();
+    const panel = [await fundedKeypair(), await fundedKeypair(), await fundedKeypair()];
+    const challengePda = await createAnsweredChallenge(challenger, agent, owner, {
+      ...defaultOptions,
+      verifiers: panel.map((verifier) => verifier.publicKey),
+      quorum: 2,
+    });
+    const attest = (verifier: Keypair) =>
+      program.methods
+        .attestResult(new BN(0), true)
+        .accounts({
+          verifier: verifier.publicKey,
+          agent,
+          agentOwner: owner.publicKey,
+          challenger: challenger.publicKey,
+          challenge: challengePda,
+          verifierAgent: null,
+        })
+        .signers([verifier])
+        .rpc();
+
+    // Only panel members attest, and each only once
+    const outsider = await fundedKeypair();
+    try {
+      await attest(outsider);
+      throw new Error("Should have failed with NotChallengeVerifier");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("NotChallengeVerifier");
+    }
+    await attest(panel[0]);
+    try {
+      await attest(panel[0]);
+      throw new Error("Should have failed with AlreadyAttested");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("AlreadyAttested");
+    }
+    let challenge = await program.account.challenge.fetch(challengePda);
+    expect(challenge.status).to.deep.equal({ responded: {} });
+    expect(challenge.passVotes).to.equal(1);
+
+    // The second pass vote reaches the 2-of-3 quorum
+    const agentBefore = await program.account.agentAccount.fetch(agent);
+    await attest(panel[1]);
+    challenge = await program.account.challenge.fetch(challengePda);
+    expect(challenge.status).to.deep.equal({ passed: {} });
+    expect(challenge.passVotes).to.equal(2);
+    const agentAfter = await program.account.agentAccount.fetch(agent);
+    expect(agentAfter.reputationScore).to.be.greaterThan(agentBefore.reputationScore);
+  });
+
+  // ============================================
+  // ZK Proof-of-Inference Tests
+  // ============================================
+
+  // 32-byte big-endian field element (EIP-197 encoding)
+  const fieldBytes = (value: bigint) =>
+    Array.from(Buffer.from(value.toString(16).padStart(64, "0"), "hex"));
+
+  // BN254 generators: G1 = (1, 2); G2 coordinates are encoded imaginary part first
+  const g1Generator = [...fieldBytes(1n), ...fieldBytes(2n)];
+  const g2Generator = [
+    ...fieldBytes(11559732032986387107991004021392285783925812861821192530917403151452391805634n),
+    ...fieldBytes(10857046999023057135944570762232829481370756359578518086990519993285655852781n),
+    ...fieldBytes(4082367875863433681332203403145435568316851327593401208105741076214120093531n),
+    ...fieldBytes(8495653923123431417604973247489272438418190587263600148770280649306958101930n),
+  ];
+
+  // Degenerate verifying key (every point at infinity): the pairing check holds
+  // for the all-infinity proof, which lets the test drive the flow without a circuit
+  const zkCircuitId = new BN(1);
+  const [zkVerifyingKeyPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("zk_vk"), zkCircuitId.toArrayLike(Buffer, "le", 8)],
+    programId
+  );
+  const infinityProof = {
+    a: new Array(64).fill(0),
+    b: new Array(128).fill(0),
+    c: new Array(64).fill(0),
+  };
+
+  it("Pass a ZK challenge with a valid proof and reject an invalid one", async () => {
+    await program.methods
+      .registerZkVerifyingKey(zkCircuitId, {
+        alphaG1: new Array(64).fill(0),
+        betaG2: new Array(128).fill(0),
+        gammaG2: new Array(128).fill(0),
+        deltaG2: new Array(128).fill(0),
+        ic: [0, 1, 2, 3].map(() => new Array(64).fill(0)),
+      })
+      .accounts({
+        admin: provider.wallet.publicKey,
+        registry: registryPda,
+        verifyingKey: zkVerifyingKeyPda,
+      })
+      .rpc();
+
+    const challenger = await fundedKeypair();
+    const [challengePda] = findChallengePda(ownerAgentPda, challenger.publicKey, new BN(0));
+    const salt = Keypair.generate().publicKey.toBuffer();
+
+    await program.methods
+      .createChallenge("Summarize prompt #1", commit(answerHash, salt), new BN(0), defaultOptions)
+      .accounts({
+        challenger: challenger.publicKey,
+        agent: ownerAgentPda,
+        challenge: challengePda,
+        zkVerifyingKey: zkVerifyingKeyPda,
+        challengerAgent: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([challenger])
+      .rpc();
+
+    const zkAccounts = {
+      owner: provider.wallet.publicKey,
+      agent: ownerAgentPda,
+      challenger: challenger.publicKey,
+      challenge: challengePda,
+      verifyingKey: zkVerifyingKeyPda,
+    };
+
+    // e(-A, B) != 1 for the generators, so this proof does not verify
+    try {
+      await program.methods
+        .submitZkResponse(answerHash, new BN(0), {
+          ...infinityProof,
+          a: g1Generator,
+          b: g2Generator,
+        })
+        .accounts(zkAccounts)
+        .rpc();
+      throw new Error("Should have failed with InvalidZkProof");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidZkProof");
+    }
+
+    // The challenge stays open after a rejected proof; a valid one passes it
+    const agentBefore = await program.account.agentAccount.fetch(ownerAgentPda);
+    await program.methods
+      .submitZkResponse(answerHash, new BN(0), infinityProof)
+      .accounts(zkAccounts)
+      .rpc();
+
+    const challengeAccount = await program.account.challenge.fetch(challengePda);
+    expect(challengeAccount.status).to.deep.equal({ passed: {} });
+    expect(challengeAccount.responseHash).to.equal(answerHash);
+    const agentAfter = await program.account.agentAccount.fetch(ownerAgentPda);
+    expect(agentAfter.challengesPassed).to.equal(agentBefore.challengesPassed + 1);
+    expect(agentAfter.reputationScore).to.be.greaterThan(agentBefore.reputationScore);
+
+    console.log("ZK challenge PASSED:", {
+      reputationBefore: agentBefore.reputationScore / 100 + "%",
+      reputationAfter: agentAfter.reputationScore / 100 + "%",
+    });
+  });
+
+  // ============================================
+  // Open Challenge Tests
+  // ============================================
+
+  it("Answer an open challenge and reject a reveal while responses are open", async () => {
+    const challenger = await fundedKeypair();
+    const nonce = new BN(0);
+    const salt = Keypair.generate().publicKey.toBuffer();
+    const [openChallengePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("open_challenge"),
+        challenger.publicKey.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+
+    await program.methods
+      .createOpenChallenge(
+        "Who wrote SICP?",
+        commit(answerHash, salt),
+        nonce,
+        new BN(0),
+        { knowledge: {} },
+        5
+      )
+      .accounts({
+        challenger: challenger.publicKey,
+        openChallenge: openChallengePda,
+        challengerAgent: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([challenger])
+      .rpc();
+
+    const created = await program.account.openChallenge.fetch(openChallengePda);
+    expect(created.status).to.deep.equal({ open: {} });
+    expect(created.bond.toNumber()).to.be.greaterThan(0);
+
+    // Any registered agent can answer with the answer hash bound to its own key;
+    // the first responder is recorded for a bond slash
+    const boundResponse = commit(answerHash, ownerAgentPda.toBuffer());
+    await program.methods
+      .submitOpenResponse(nonce, boundResponse)
+      .accounts({
+        owner: provider.wallet.publicKey,
+        agent: ownerAgentPda,
+        openChallenge: openChallengePda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const answered = await program.account.openChallenge.fetch(openChallengePda);
+    expect(answered.responseCount).to.equal(1);
+    expect(answered.firstResponder.toString()).to.equal(provider.wallet.publicKey.toString());
+    const [openResponsePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("open_response"), openChallengePda.toBuffer(), ownerAgentPda.toBuffer()],
+      programId
+    );
+    const response = await program.account.openResponse.fetch(openResponsePda);
+    expect(response.responseHash).to.equal(boundResponse);
+
+    // The answer stays sealed until the response period ends
+    try {
+      await program.methods
+        .revealOpenChallenge(nonce, answerHash, Array.from(salt))
+        .accounts({
+          challenger: challenger.publicKey,
+          openChallenge: openChallengePda,
+          firstResponder: provider.wallet.publicKey,
+        })
+        .signers([challenger])
+        .rpc();
+      throw new Error("Should have failed with ResponsePeriodActive");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("ResponsePeriodActive");
+    }
+
+    // One response per agent
+    try {
+      await program.methods
+        .submitOpenResponse(nonce, boundResponse)
+        .accounts({
+          owner: provider.wallet.publicKey,
+          agent: ownerAgentPda,
+          openChallenge: openChallengePda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      throw new Error("Should have failed with already in use");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("already in use");
+    }
+  });
+
+  // ============================================
+  // Staking Tests
+  // ============================================
+
+  const [stakeVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stake_vault"), ownerAgentPda.toBuffer()],
+    programId
+  );
+
+  // UpdateConfigParams with every field unset (None = keep current value)
+  const unchangedConfig = () => {
+    const params = program.idl.types!.find((t) => t.name.toLowerCase() === "updateconfigparams")!;
+    return Object.fromEntries((params.type as any).fields.map((f: any) => [f.name, null]));
+  };
+
+  it("Stake behind an agent and claim staking rewards", async () => {
+    const backer = await fundedKeypair();
+    const stakeAmount = new BN(1_000_000_000);
+    const rewardAmount = new BN(100_000_000);
+    const [positionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_position"), stakeVaultPda.toBuffer(), backer.publicKey.toBuffer()],
+      programId
+    );
+
+    await program.methods
+      .stakeAgent(stakeAmount)
+      .accounts({
+        staker: backer.publicKey,
+        agent: ownerAgentPda,
+        stakeVault: stakeVaultPda,
+        stakePosition: positionPda,
+        stakerTokenAccount: null,
+        vaultTokenAccount: null,
+        stakeMint: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([backer])
+      .rpc();
+
+    const vault = await program.account.stakeVault.fetch(stakeVaultPda);
+    expect(vault.totalStaked.toString()).to.equal(stakeAmount.toString());
+    const agentAccount = await program.account.agentAccount.fetch(ownerAgentPda);
+    expect(agentAccount.stakedAmount.toString()).to.equal(stakeAmount.toString());
+    const position = await program.account.stakePosition.fetch(positionPda);
+    expect(position.shares.toString()).to.equal(vault.totalShares.toString());
+
+    // Rewards are shared pro-rata; the only staker gets all of them
+    await program.methods
+      .fundStakeRewards(rewardAmount)
+      .accounts({
+        funder: provider.wallet.publicKey,
+        agent: ownerAgentPda,
+        stakeVault: stakeVaultPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const vaultBalanceBefore = await provider.connection.getBalance(stakeVaultPda);
+    await program.methods
+      .claimRewards()
+      .accounts({
+        staker: backer.publicKey,
+        stakeVault: stakeVaultPda,
+        stakePosition: positionPda,
+      })
+      .signers([backer])
+      .rpc();
+
+    const vaultBalanceAfter = await provider.connection.getBalance(stakeVaultPda);
+    expect(vaultBalanceBefore - vaultBalanceAfter).to.equal(rewardAmount.toNumber());
+    const claimed = await program.account.stakePosition.fetch(positionPda);
+    expect(claimed.unclaimedRewards.toNumber()).to.equal(0);
+
+    // Nothing left to claim
+    try {
+      await program.methods
+        .claimRewards()
+        .accounts({
+          staker: backer.publicKey,
+          stakeVault: stakeVaultPda,
+          stakePosition: positionPda,
+        })
+        .signers([backer])
+        .rpc();
+      throw new Error("Should have failed with NoRewardsToClaim");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("NoRewardsToClaim");
+    }
+  });
+
+  it("Reject slashing a passed challenge and slash rates above the cap", async () => {
+    // Only failed or expired boosted challenges are slashable
+    const [passedChallengePda] = findChallengePda(
+      ownerAgentPda,
+      provider.wallet.publicKey,
+      new BN(0)
+    );
+    try {
+      await program.methods
+        .slashStake(new BN(0))
+        .accounts({
+          caller: provider.wallet.publicKey,
+          agent: ownerAgentPda,
+          stakeVault: stakeVaultPda,
+          challenge: passedChallengePda,
+          vaultTokenAccount: null,
+          treasuryTokenAccount: null,
+          stakeMint: null,
+          tokenProgram: null,
+        })
+        .rpc();
+      throw new Error("Should have failed with StakeNotSlashable");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("StakeNotSlashable");
+    }
+
+    // A slash may never take more than half of the vault
+    try {
+      await program.methods
+        .updateConfig({ ...unchangedConfig(), stakeSlashBps: 6_000 })
+        .accounts({
+          admin: provider.wallet.publicKey,
+          registry: registryPda,
+          config: configPda,
+          ...(await registryAuditAccounts()),
+        })
+        .rpc();
+      throw new Error("Should have failed with InvalidConfigValue");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidConfigValue");
+    }
+  });
+
+  // ============================================
+  // Endorsement Tests
+  // ============================================
+
+  it("Reject endorsing an agent with the same owner", async () => {
+    const registryState = await program.account.registryState.fetch(registryPda);
+    const [siblingPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("agent"),
+        provider.wallet.publicKey.toBuffer(),
+        registryState.totalAgents.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+    await program.methods
+      .registerAgent("SiblingAgent", testModelHash, testCapabilities)
+      .accounts({
+        owner: provider.wallet.publicKey,
+        registry: registryPda,
+        agent: siblingPda,
+        nftMint: Keypair.generate().publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Agent 0 is verified, but vouching for its own owner's agent is self-dealing
+    try {
+      await program.methods
+        .endorseAgent(new BN(10_000_000))
+        .accounts({
+          endorser: provider.wallet.publicKey,
+          endorserAgent: ownerAgentPda,
+          endorsedAgent: siblingPda,
+          endorsement: PublicKey.findProgramAddressSync(
+            [Buffer.from("endorsement"), siblingPda.toBuffer(), ownerAgentPda.toBuffer()],
+            programId
+          )[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      throw new Error("Should have failed with InvalidEndorsement");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidEndorsement");
+    }
+  });
+
+  // ============================================
+  // Treasury Governance Tests
+  // ============================================
+
+  it("Approve a treasury spend by council threshold and enforce its timelock", async () => {
+    const member = await fundedKeypair();
+    const [councilPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_council")],
+      programId
+    );
+
+    await program.methods
+      .setTreasuryCouncil([provider.wallet.publicKey, member.publicKey], 2, 24 * 3600)
+      .accounts({
+        admin: provider.wallet.publicKey,
+        registry: registryPda,
+        council: councilPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const council = await program.account.treasuryCouncil.fetch(councilPda);
+    const [proposalPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("spend_proposal"), council.proposalCount.toArrayLike(Buffer, "le", 8)],
+      programId
+    );
+    const recipient = Keypair.generate().publicKey;
+    const memoHash = Array.from(createHash("sha256").update("grant #1").digest());
+
+    // Only council members can propose
+    const outsider = await fundedKeypair();
+    try {
+      await program.methods
+        .proposeSpend(recipient, new BN(1_000_000), memoHash)
+        .accounts({
+          proposer: outsider.publicKey,
+          council: councilPda,
+          proposal: proposalPda,
+          mint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([outsider])
+        .rpc();
+      throw new Error("Should have failed with NotCouncilMember");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("NotCouncilMember");
+    }
+
+    await program.methods
+      .proposeSpend(recipient, new BN(1_000_000), memoHash)
+      .accounts({
+        proposer: provider.wallet.publicKey,
+        council: councilPda,
+        proposal: proposalPda,
+        mint: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // One of two approvals: the timelock has not started
+    const proposed = await program.account.spendProposal.fetch(proposalPda);
+    expect(proposed.approvals.length).to.equal(1);
+    expect(proposed.approvedAt.toNumber()).to.equal(0);
+
+    await program.methods
+      .approveSpend()
+      .accounts({
+        member: member.publicKey,
+        council: councilPda,
+        proposal: proposalPda,
+      })
+      .signers([member])
+      .rpc();
+
+    const approved = await program.account.spendProposal.fetch(proposalPda);
+    expect(approved.approvals.length).to.equal(2);
+    expect(approved.approvedAt.toNumber()).to.be.greaterThan(0);
+
+    // Fully approved spends still wait out the timelock
+    const executeAccounts = {
+      caller: provider.wallet.publicKey,
+      council: councilPda,
+      proposal: proposalPda,
+      recipient,
+      proposer: provider.wallet.publicKey,
+      mint: null,
+      treasuryTokenAccount: null,
+      recipientTokenAccount: null,
+      tokenProgram: null,
+    };
+    try {
+      await program.methods.executeSpend().accounts(executeAccounts).rpc();
+      throw new Error("Should have failed with SpendTimelocked");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("SpendTimelocked");
+    }
+
+    // Replacing the approving member drops the spend below the threshold, so
+    // executing only resets its timelock
+    const replacement = Keypair.generate().publicKey;
+    await program.methods
+      .setTreasuryCouncil([provider.wallet.publicKey, replacement], 2, 24 * 3600)
+      .accounts({
+        admin: provider.wallet.publicKey,
+        registry: registryPda,
+        council: councilPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: provider.wallet.publicKey, isSigner: true, isWritable: false },
+        { pubkey: member.publicKey, isSigner: true, isWritable: false },
+      ])
+      .signers([member])
+      .rpc();
+    await program.methods.executeSpend().accounts(executeAccounts).rpc();
+    const reset = await program.account.spendProposal.fetch(proposalPda);
+    expect(reset.approvedAt.toNumber()).to.equal(0);
+
+    // The proposer can withdraw it instead
+    await program.methods
+      .cancelSpend()
+      .accounts({
+        proposer: provider.wallet.publicKey,
+        proposal: proposalPda,
+      })
+      .rpc();
+    expect(await program.account.spendProposal.fetchNullable(proposalPda)).to.be.null;
+  });
+
+  // ============================================
+  // Insurance Tests
+  // ============================================
+
+  it("File an insurance claim backed by a slash and the claimant's own interaction", async () => {
+    // Insured agent with its own owner and stake
+    const insuredOwner = await fundedKeypair(5_000_000_000);
+    const registryState = await program.account.registryState.fetch(registryPda);
+    const [insuredAgentPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("agent"),
+        insuredOwner.publicKey.toBuffer(),
+        registryState.totalAgents.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+    await program.methods
+      .registerAgent("InsuredAgent", testModelHash, testCapabilities)
+      .accounts({
+        owner: insuredOwner.publicKey,
+        registry: registryPda,
+        agent: insuredAgentPda,
+        nftMint: Keypair.generate().publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([insuredOwner])
+      .rpc();
+
+    const [insuredVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_vault"), insuredAgentPda.toBuffer()],
+      programId
+    );
+    await program.methods
+      .stakeAgent(new BN(1_000_000_000))
+      .accounts({
+        staker: insuredOwner.publicKey,
+        agent: insuredAgentPda,
+        stakeVault: insuredVaultPda,
+        stakerTokenAccount: null,
+        vaultTokenAccount: null,
+        stakeMint: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([insuredOwner])
+      .rpc();
+
+    const [policyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance_policy"), insuredAgentPda.toBuffer()],
+      programId
+    );
+    await program.methods
+      .initializeInsurancePool()
+      .accounts({
+        admin: provider.wallet.publicKey,
+        registry: registryPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .payInsurancePremium(new BN(100_000_000))
+      .accounts({
+        owner: insuredOwner.publicKey,
+        agent: insuredAgentPda,
+        policy: policyPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([insuredOwner])
+      .rpc();
+
+    // The claimant's interaction with the agent: a challenge it opened
+    const claimant = await fundedKeypair();
+    const [interactionPda] = findChallengePda(insuredAgentPda, claimant.publicKey, new BN(0));
+    await program.methods
+      .createChallenge("What is 5 + 5?", expectedHash, new BN(0), defaultOptions)
+      .accounts({
+        challenger: claimant.publicKey,
+        agent: insuredAgentPda,
+        challenge: interactionPda,
+        zkVerifyingKey: null,
+        challengerAgent: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([claimant])
+      .rpc();
+
+    // A Critical audit entry slashes the agent's stake
+    const [auditSummaryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_summary"), insuredAgentPda.toBuffer()],
+      programId
+    );
+    const summary = await program.account.agentAuditSummary.fetchNullable(auditSummaryPda);
+    const [auditEntryPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("audit"),
+        insuredAgentPda.toBuffer(),
+        (summary ? summary.totalEntries : new BN(0)).toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+    await program.methods
+      .logAudit(
+        { agentUpdated: {} },
+        100,
+        createHash("sha256").update("leaked credentials").digest("hex"),
+        null
+      )
+      .accounts({
+        actor: insuredOwner.publicKey,
+        agent: insuredAgentPda,
+        auditor: null,
+        auditSummary: auditSummaryPda,
+        auditEntry: auditEntryPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([insuredOwner])
+      .rpc();
+
+    const [slashRecordPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("slash_record"), auditEntryPda.toBuffer()],
+      programId
+    );
+    await program.methods
+      .slashAgent({ criticalAlert: {} })
+      .accounts({
+        caller: provider.wallet.publicKey,
+        agent: insuredAgentPda,
+        stakeVault: insuredVaultPda,
+        subject: auditEntryPda,
+        slashRecord: slashRecordPda,
+        affectedParty: null,
+        verifierRecord: null,
+        vaultTokenAccount: null,
+        treasuryTokenAccount: null,
+        affectedPartyTokenAccount: null,
+        stakeMint: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const evidenceHash = Array.from(createHash("sha256").update("incident report").digest());
+    const claimAccounts = (filer: PublicKey) => ({
+      claimant: filer,
+      slashRecord: slashRecordPda,
+      policy: policyPda,
+      interaction: interactionPda,
+      claim: PublicKey.findProgramAddressSync(
+        [Buffer.from("insurance_claim"), slashRecordPda.toBuffer(), filer.toBuffer()],
+        programId
+      )[0],
+      systemProgram: SystemProgram.programId,
+    });
+
+    // Someone else's interaction does not back a claim
+    const outsider = await fundedKeypair();
+    try {
+      await program.methods
+        .fileInsuranceClaim(new BN(50_000_000), evidenceHash)
+        .accounts(claimAccounts(outsider.publicKey))
+        .signers([outsider])
+        .rpc();
+      throw new Error("Should have failed with InsuranceInteractionMismatch");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InsuranceInteractionMismatch");
+    }
+
+    const accounts = claimAccounts(claimant.publicKey);
+    await program.methods
+      .fileInsuranceClaim(new BN(50_000_000), evidenceHash)
+      .accounts(accounts)
+      .signers([claimant])
+      .rpc();
+
+    const claim = await program.account.insuranceClaim.fetch(accounts.claim);
+    expect(claim.agent.toString()).to.equal(insuredAgentPda.toString());
+    expect(claim.interaction.toString()).to.equal(interactionPda.toString());
+    expect(claim.claimant.toString()).to.equal(claimant.publicKey.toString());
+    expect(claim.bond.toNumber()).to.be.greaterThan(0);
+  });
+
+  // ============================================
+  // Task Escrow Tests
+  // ============================================
+
+  it("Pay an agent through task escrow and reject dust payments", async () => {
+    const client = await fundedKeypair();
+    const nonce = new BN(0);
+    const [taskPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("task"), client.publicKey.toBuffer(), nonce.toArrayLike(Buffer, "le", 8)],
+      programId
+    );
+    const specHash = Array.from(createHash("sha256").update("translate the docs").digest());
+    const deadline = new BN((await chainTime()) + 3600);
+    const postAccounts = {
+      client: client.publicKey,
+      task: taskPda,
+      paymentMint: null,
+      clientTokenAccount: null,
+      taskTokenAccount: null,
+      tokenProgram: null,
+      systemProgram: SystemProgram.programId,
+    };
+
+    // SOL tasks below Task::MIN_PAYMENT are rejected
+    try {
+      await program.methods
+        .postTask(nonce, new BN(1_000_000), specHash, deadline)
+        .accounts(postAccounts)
+        .signers([client])
+        .rpc();
+      throw new Error("Should have failed with InvalidTaskParams");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidTaskParams");
+    }
+
+    const payment = new BN(50_000_000);
+    await program.methods
+      .postTask(nonce, payment, specHash, deadline)
+      .accounts(postAccounts)
+      .signers([client])
+      .rpc();
+
+    await program.methods
+      .assignTask()
+      .accounts({ client: client.publicKey, task: taskPda, agent: ownerAgentPda })
+      .signers([client])
+      .rpc();
+
+    await program.methods
+      .submitTaskResult(Array.from(createHash("sha256").update("translated docs").digest()))
+      .accounts({ responder: provider.wallet.publicKey, task: taskPda, agent: ownerAgentPda })
+      .rpc();
+
+    const submitted = await program.account.task.fetch(taskPda);
+    expect(submitted.status).to.deep.equal({ submitted: {} });
+
+    const ownerBefore = await provider.connection.getBalance(provider.wallet.publicKey);
+    await program.methods
+      .acceptTaskResult()
+      .accounts({
+        caller: client.publicKey,
+        task: taskPda,
+        client: client.publicKey,
+        agent: ownerAgentPda,
+        agentOwner: provider.wallet.publicKey,
+        paymentMint: null,
+        taskTokenAccount: null,
+        agentOwnerTokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([client])
+      .rpc();
+
+    // The payment reaches the agent owner (less the transaction fee it paid) and the task is closed
+    const ownerAfter = await provider.connection.getBalance(provider.wallet.publicKey);
+    expect(ownerAfter - ownerBefore).to.be.closeTo(payment.toNumber(), 10_000);
+    expect(await program.account.task.fetchNullable(taskPda)).to.be.null;
+
+    // Task credit has its own cap (Task::MAX_REPUTATION_CREDIT_PER_WINDOW)
+    const agent = await program.account.agentAccount.fetch(ownerAgentPda);
+    expect(agent.taskCreditWindowChange).to.be.at.most(20);
+  });
+
+  it("Reject task results submitted after the deadline", async () => {
+    const client = await fundedKeypair();
+    const nonce = new BN(0);
+    const [taskPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("task"), client.publicKey.toBuffer(), nonce.toArrayLike(Buffer, "le", 8)],
+      programId
+    );
+    await program.methods
+      .postTask(
+        nonce,
+        new BN(20_000_000),
+        Array.from(createHash("sha256").update("late task").digest()),
+        new BN((await chainTime()) + 2)
+      )
+      .accounts({
+        client: client.publicKey,
+        task: taskPda,
+        paymentMint: null,
+        clientTokenAccount: null,
+        taskTokenAccount: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([client])
+      .rpc();
+    await program.methods
+      .assignTask()
+      .accounts({ client: client.publicKey, task: taskPda, agent: ownerAgentPda })
+      .signers([client])
+      .rpc();
+
+    while ((await chainTime()) <= (await program.account.task.fetch(taskPda)).deadline.toNumber()) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    try {
+      await program.methods
+        .submitTaskResult(Array.from(createHash("sha256").update("late result").digest()))
+        .accounts({ responder: provider.wallet.publicKey, task: taskPda, agent: ownerAgentPda })
+        .rpc();
+      throw new Error("Should have failed with TaskDeadlinePassed");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("TaskDeadlinePassed");
+    }
+  });
+
+  // ============================================
+  // Payment Stream Tests
+  // ============================================
+
+  it("Stream payments to an agent, withdraw the vested part and cancel", async () => {
+    const client = await fundedKeypair();
+    const nonce = new BN(0);
+    const [streamPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payment_stream"),
+        client.publicKey.toBuffer(),
+        ownerAgentPda.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      programId
+    );
+    const startTs = (await chainTime()) + 5;
+    const endTs = startTs + 60;
+    const rate = new BN(100_000);
+
+    await program.methods
+      .createStream(nonce, rate, new BN(startTs), new BN(endTs))
+      .accounts({
+        client: client.publicKey,
+        agent: ownerAgentPda,
+        stream: streamPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([client])
+      .rpc();
+
+    const withdrawAccounts = {
+      owner: provider.wallet.publicKey,
+      agent: ownerAgentPda,
+      stream: streamPda,
+      client: client.publicKey,
+    };
+
+    // Nothing has vested before the stream starts
+    try {
+      await program.methods.withdrawStream().accounts(withdrawAccounts).rpc();
+      throw new Error("Should have failed with StreamNothingVested");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("StreamNothingVested");
+    }
+
+    while ((await chainTime()) <= startTs + 2) {
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+
+    await program.methods.withdrawStream().accounts(withdrawAccounts).rpc();
+    const stream = await program.account.paymentStream.fetch(streamPda);
+    expect(stream.withdrawn.toNumber()).to.be.greaterThan(0);
+    expect(stream.withdrawn.toNumber() % rate.toNumber()).to.equal(0);
+
+    // Cancelling returns the unvested remainder to the client and closes the stream
+    const clientBefore = await provider.connection.getBalance(client.publicKey);
+    await program.methods
+      .cancelStream()
+      .accounts({
+        caller: client.publicKey,
+        stream: streamPda,
+        agent: ownerAgentPda,
+        client: client.publicKey,
+        agentOwner: provider.wallet.publicKey,
+      })
+      .signers([client])
+      .rpc();
+
+    expect(await program.account.paymentStream.fetchNullable(streamPda)).to.be.null;
+    const clientAfter = await provider.connection.getBalance(client.publicKey);
+    expect(clientAfter).to.be.greaterThan(clientBefore);
+  });
+
+  // ============================================
+  // Oracle Resolution Tests
+  // ============================================
+
+  // A passing resolution needs a Switchboard feed, which localnet does not load
+  it("Only resolve an oracle challenge from its own Switchboard feed", async () => {
+    const setOracleAuthority = (oracleAuthority: PublicKey) =>
+      program.methods
+        .updateConfig({ ...unchangedConfig(), oracleAuthority })
+        .accounts({
+          admin: provider.wallet.publicKey,
+          registry: registryPda,
+          config: configPda,
+          ...(await registryAuditAccounts()),
+        })
+        .rpc();
+    await setOracleAuthority(Keypair.generate().publicKey);
+
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "OracleAgent");
+    const challenger = await fundedKeypair();
+    const feed = Keypair.generate().publicKey;
+    const challengePda = await createAnsweredChallenge(challenger, agent, owner, {
+      ...defaultOptions,
+      oracleFeed: feed,
+    });
+    const resolve = (oracleFeed: PublicKey) =>
+      program.methods
+        .resolveChallengeWithOracle(new BN(0))
+        .accounts({
+          caller: provider.wallet.publicKey,
+          agent,
+          agentOwner: owner.publicKey,
+          challenger: challenger.publicKey,
+          oracleFeed,
+          challenge: challengePda,
+        })
+        .rpc();
+
+    // The feed must be the one named at creation...
+    try {
+      await resolve(Keypair.generate().publicKey);
+      throw new Error("Should have failed with InvalidOracleFeed");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidOracleFeed");
+    }
+
+    // ...and hold a Switchboard pull-feed result
+    try {
+      await resolve(feed);
+      throw new Error("Should have failed with InvalidOracleFeed");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidOracleFeed");
+    }
+    const challenge = await program.account.challenge.fetch(challengePda);
+    expect(challenge.status).to.deep.equal({ responded: {} });
+
+    await setOracleAuthority(PublicKey.default);
+  });
+
+  // ============================================
+  // Misbehavior Report Tests
+  // ============================================
+
+  it("Report an agent and settle the report", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "ReportedAgent");
+    const reporter = await fundedKeypair();
+    const evidenceHash = createHash("sha256").update("harmful transcript").digest("hex");
+    const [treasuryPda] = PublicKey.findProgramAddressSync([Buffer.from("treasury")], programId);
+
+    const report = async (nonce: BN) => {
+      const [reportPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("report"),
+          agent.toBuffer(),
+          reporter.publicKey.toBuffer(),
+          nonce.toArrayLike(Buffer, "le", 8),
+        ],
+        programId
+      );
+      await program.methods
+        .reportAgent(nonce, { safety: {} }, evidenceHash)
+        .accounts({
+          reporter: reporter.publicKey,
+          agent,
+          report: reportPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reporter])
+        .rpc();
+      return reportPda;
+    };
+
+    // Resolve as the registry admin unless another resolver signs
+    const resolve = (reportPda: PublicKey, nonce: BN, upheld: boolean, resolver?: Keypair) =>
+      program.methods
+        .resolveReport(nonce, upheld, upheld ? 200 : 0, 0)
+        .accounts({
+          resolver: resolver ? resolver.publicKey : provider.wallet.publicKey,
+          agent,
+          report: reportPda,
+          reporter: reporter.publicKey,
+          stakeVault: null,
+          treasury: treasuryPda,
+          vaultTokenAccount: null,
+          treasuryTokenAccount: null,
+          stakeMint: null,
+          tokenProgram: null,
+        })
+        .signers(resolver ? [resolver] : [])
+        .rpc();
+
+    const upheldReport = await report(new BN(0));
+    const { bond } = await program.account.report.fetch(upheldReport);
+
+    // Only the admin or a verified verifier settles reports
+    try {
+      await resolve(upheldReport, new BN(0), true, await fundedKeypair());
+      throw new Error("Should have failed with NotReportResolver");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("NotReportResolver");
+    }
+
+    // Upheld: the penalty lands and the bond goes back to the reporter
+    const reporterBefore = await provider.connection.getBalance(reporter.publicKey);
+    const agentBefore = await program.account.agentAccount.fetch(agent);
+    await resolve(upheldReport, new BN(0), true);
+    const agentAfter = await program.account.agentAccount.fetch(agent);
+    expect(agentAfter.reputationScore).to.equal(agentBefore.reputationScore - 200);
+    expect(await program.account.report.fetchNullable(upheldReport)).to.be.null;
+    const reporterAfter = await provider.connection.getBalance(reporter.publicKey);
+    expect(reporterAfter - reporterBefore).to.be.greaterThan(bond.toNumber());
+
+    // Dismissed: the bond is forfeited to the treasury
+    const dismissedReport = await report(new BN(1));
+    const treasuryBefore = await program.account.treasury.fetch(treasuryPda);
+    await resolve(dismissedReport, new BN(1), false);
+    const treasuryAfter = await program.account.treasury.fetch(treasuryPda);
+    expect(treasuryAfter.totalCollected.sub(treasuryBefore.totalCollected).toString()).to.equal(
+      bond.toString()
+    );
+    const dismissed = await program.account.agentAccount.fetch(agent);
+    expect(dismissed.reputationScore).to.equal(agentAfter.reputationScore);
+  });
+
+  // ============================================
+  // Probation Tests
+  // ============================================
+
+  it("Put an agent on probation below the floor until it passes again", async () => {
+    const { probationFloor, probationExitStreak } =
+      await program.account.registryConfig.fetch(configPda);
+    const setProbation = async (floor: number, exitStreak: number) =>
+      program.methods
+        .updateConfig({
+          ...unchangedConfig(),
+          probationFloor: floor,
+          probationExitStreak: exitStreak,
+        })
+        .accounts({
+          admin: provider.wallet.publicKey,
+          registry: registryPda,
+          config: configPda,
+          ...(await registryAuditAccounts()),
+        })
+        .rpc();
+    // Agents register at 5000, so any loss drops them under the floor
+    await setProbation(5000, 1);
+
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "ProbationAgent");
+    const verifier = await fundedKeypair();
+    const resolveByVerifier = async (score: number) => {
+      const challenger = await fundedKeypair();
+      const challengePda = await createAnsweredChallenge(challenger, agent, owner, {
+        ...defaultOptions,
+        verifier: verifier.publicKey,
+      });
+      await program.methods
+        .resolveChallenge(new BN(0), score)
+        .accounts({
+          verifier: verifier.publicKey,
+          agent,
+          agentOwner: owner.publicKey,
+          challenger: challenger.publicKey,
+          challenge: challengePda,
+          verifierAgent: null,
+        })
+        .signers([verifier])
+        .rpc();
+    };
+
+    // A failed verdict below the floor starts probation
+    await resolveByVerifier(0);
+    let agentAccount = await program.account.agentAccount.fetch(agent);
+    expect(agentAccount.reputationScore).to.be.lessThan(5000);
+    expect(agentAccount.onProbation).to.be.true;
+
+    // An agent on probation cannot take tasks
+    const client = await fundedKeypair();
+    const nonce = new BN(0);
+    const [taskPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("task"), client.publicKey.toBuffer(), nonce.toArrayLike(Buffer, "le", 8)],
+      programId
+    );
+    await program.methods
+      .postTask(
+        nonce,
+        new BN(50_000_000),
+        Array.from(createHash("sha256").update("summarize the logs").digest()),
+        new BN((await chainTime()) + 3600)
+      )
+      .accounts({
+        client: client.publicKey,
+        task: taskPda,
+        paymentMint: null,
+        clientTokenAccount: null,
+        taskTokenAccount: null,
+        tokenProgram: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([client])
+      .rpc();
+    const assign = () =>
+      program.methods
+        .assignTask()
+        .accounts({ client: client.publicKey, task: taskPda, agent })
+        .signers([client])
+        .rpc();
+    try {
+      await assign();
+      throw new Error("Should have failed with AgentOnProbation");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("AgentOnProbation");
+    }
+
+    // A pass from an independent verifier completes the exit streak
+    await resolveByVerifier(100);
+    agentAccount = await program.account.agentAccount.fetch(agent);
+    expect(agentAccount.onProbation).to.be.false;
+    await assign();
+    const task = await program.account.task.fetch(taskPda);
+    expect(task.status).to.deep.equal({ assigned: {} });
+
+    await setProbation(probationFloor, probationExitStreak);
+  });
+
+  // ============================================
+  // Agent Recovery Tests
+  // ============================================
+
+  // The happy path needs a Metaplex Core asset, which localnet does not load
+  it("Reject recovery requests without custody of the identity NFT", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "RecoveryAgent");
+    const { nftMint } = await program.account.agentAccount.fetch(agent);
+    const holder = await fundedKeypair();
+    const [recovery] = PublicKey.findProgramAddressSync(
+      [Buffer.from("recovery"), agent.toBuffer()],
+      programId
+    );
+    const recover = (nftAsset: PublicKey) =>
+      program.methods
+        .recoverAgent(holder.publicKey)
+        .accounts({
+          holder: holder.publicKey,
+          agent,
+          nftAsset,
+          recovery,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([holder])
+        .rpc();
+
+    // The asset must be the agent's identity NFT
+    try {
+      await recover(Keypair.generate().publicKey);
+      throw new Error("Should have failed with NftMismatch");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("NftMismatch");
+    }
+
+    // ...and the signer must be its current holder
+    try {
+      await recover(nftMint);
+      throw new Error("Should have failed with NotNftHolder");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("NotNftHolder");
+    }
+    expect(await program.account.agentRecovery.fetchNullable(recovery)).to.be.null;
+  });
+
+  // ============================================
+  // Audit Trail Tests
+  // ============================================
+
+  it("Log audit entries into a fixed-size audit ring", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "RingAgent");
+    const [auditRingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_ring"), agent.toBuffer()],
+      programId
+    );
+    const { auditSummary } = await agentAuditAccounts(agent);
+
+    await program.methods
+      .initAuditRing()
+      .accounts({
+        owner: owner.publicKey,
+        agent,
+        auditRing: auditRingPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+
+    const details = [1, 2].map((n) =>
+      Array.from(createHash("sha256").update(`ring entry ${n}`).digest())
+    );
+    for (const detailsHash of details) {
+      await program.methods
+        .logAuditRing({ agentUpdated: {} }, 10, detailsHash)
+        .accounts({
+          actor: owner.publicKey,
+          agent,
+          auditSummary,
+          auditRing: auditRingPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+    }
+
+    // Entries land in consecutive slots and the summary counts them like log_audit
+    const ring = await program.account.auditRing.fetch(auditRingPda);
+    expect(ring.totalWritten.toNumber()).to.equal(2);
+    expect(ring.head).to.equal(2);
+    expect(ring.entries[0].detailsHash).to.deep.equal(details[0]);
+    expect(ring.entries[1].auditIndex.toNumber()).to.equal(1);
+    const summary = await program.account.agentAuditSummary.fetch(auditSummary);
+    expect(summary.totalEntries.toNumber()).to.equal(2);
+  });
+
+  it("Log a batch of audit entries in one transaction", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "BatchAgent");
+    const [auditRingPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_ring"), agent.toBuffer()],
+      programId
+    );
+    const { auditSummary } = await agentAuditAccounts(agent);
+    await program.methods
+      .initAuditRing()
+      .accounts({
+        owner: owner.publicKey,
+        agent,
+        auditRing: auditRingPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc();
+
+    const batchAccounts = {
+      actor: owner.publicKey,
+      agent,
+      auditSummary,
+      auditRing: auditRingPda,
+      systemProgram: SystemProgram.programId,
+    };
+    const items = [1, 2, 3].map((n) => ({
+      actionType: { agentUpdated: {} },
+      contextRisk: 10,
+      detailsHash: Array.from(createHash("sha256").update(`batch entry ${n}`).digest()),
+    }));
+    await program.methods.logAuditBatch(items).accounts(batchAccounts).signers([owner]).rpc();
+
+    const ring = await program.account.auditRing.fetch(auditRingPda);
+    expect(ring.totalWritten.toNumber()).to.equal(3);
+    expect(ring.entries[2].detailsHash).to.deep.equal(items[2].detailsHash);
+    const summary = await program.account.agentAuditSummary.fetch(auditSummary);
+    expect(summary.totalEntries.toNumber()).to.equal(3);
+
+    // Empty batches are rejected
+    try {
+      await program.methods.logAuditBatch([]).accounts(batchAccounts).signers([owner]).rpc();
+      throw new Error("Should have failed with InvalidAuditBatch");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidAuditBatch");
+    }
+  });
+
+  it("Suspend an agent when critical audit alerts trip the circuit breaker", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "BreakerAgent");
+    const { suspendCriticalAlerts } = await program.account.registryConfig.fetch(configPda);
+
+    for (let i = 1; i < suspendCriticalAlerts; i++) {
+      await logAuditAs(owner, agent, 100);
+    }
+    expect((await program.account.agentAccount.fetch(agent)).suspended).to.be.false;
+
+    // The alert that reaches config.suspend_critical_alerts trips the breaker
+    await logAuditAs(owner, agent, 100);
+    expect((await program.account.agentAccount.fetch(agent)).suspended).to.be.true;
+  });
+
+  it("Only let the owner, operator or a designated sentinel log audit entries", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "ActorAgent");
+    const outsider = await fundedKeypair();
+
+    try {
+      await logAuditAs(outsider, agent, 10);
+      throw new Error("Should have failed with ActorNotAllowed");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("ActorNotAllowed");
+    }
+
+    // Designating the outsider as a sentinel in the audit policy admits them
+    await program.methods
+      .setAuditPolicy({
+        alertThreshold: 75,
+        sentinels: [outsider.publicKey],
+        maxRisk: Array(11).fill(100),
+        complianceOfficer: null,
+      })
+      .accounts({ authority: owner.publicKey, agent, systemProgram: SystemProgram.programId })
+      .signers([owner])
+      .rpc();
+    const entry = await logAuditAs(outsider, agent, 10);
+    const logged = await program.account.auditEntry.fetch(entry);
+    expect(logged.actor.toString()).to.equal(outsider.publicKey.toString());
+  });
+
+  it("Redact an audit entry's details behind a tombstone", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "RedactedAgent");
+    const auditEntry = await logAuditAs(owner, agent, 10);
+
+    const redactAccounts = {
+      owner: owner.publicKey,
+      admin: provider.wallet.publicKey,
+      registry: registryPda,
+      agent,
+      auditEntry,
+    };
+    await program.methods
+      .redactAuditEntry({ dataSubjectRequest: {} })
+      .accounts(redactAccounts)
+      .signers([owner])
+      .rpc();
+
+    // The details are gone; the reason, time and chain link are kept
+    const entry = await program.account.auditEntry.fetch(auditEntry);
+    expect(entry.detailsHash).to.equal("0".repeat(64));
+    expect(entry.redaction).to.deep.equal({ dataSubjectRequest: {} });
+    expect(entry.redactedAt.toNumber()).to.be.greaterThan(0);
+    expect(entry.redactedLinkHash.some((byte: number) => byte !== 0)).to.be.true;
+
+    // An entry is redacted once
+    try {
+      await program.methods
+        .redactAuditEntry({ other: {} })
+        .accounts(redactAccounts)
+        .signers([owner])
+        .rpc();
+      throw new Error("Should have failed with AuditEntryRedacted");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("AuditEntryRedacted");
+    }
+  });
+
+  it("Only finalize an audit day once it has closed", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "DigestAgent");
+    await logAuditAs(owner, agent, 10);
+    const { auditSummary } = await agentAuditAccounts(agent);
+    const summary = await program.account.agentAuditSummary.fetch(auditSummary);
+    const today: number = summary.openDay.startDay;
+    expect(summary.openDay.entries).to.equal(1);
+
+    const [digest] = PublicKey.findProgramAddressSync(
+      [Buffer.from("daily_digest"), agent.toBuffer(), new BN(today).toArrayLike(Buffer, "le", 4)],
+      programId
+    );
+    const finalize = (merkleRoot: number[]) =>
+      program.methods
+        .finalizeAuditDay(today, merkleRoot)
+        .accounts({
+          actor: owner.publicKey,
+          agent,
+          auditSummary,
+          digest,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+
+    // A digest must commit to a Merkle root
+    try {
+      await finalize(Array(32).fill(0));
+      throw new Error("Should have failed with InvalidDigestRoot");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("InvalidDigestRoot");
+    }
+
+    // Today's entries stay in the open rollup until the UTC day ends
+    try {
+      await finalize(Array(32).fill(7));
+      throw new Error("Should have failed with AuditDayNotFinished");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("AuditDayNotFinished");
+    }
+    expect(await program.account.dailyDigest.fetchNullable(digest)).to.be.null;
+  });
+
+  it("Flag an agent whose sentinel misses its heartbeat", async () => {
+    const owner = await fundedKeypair();
+    const agent = await registerAgentFor(owner, "HeartbeatAgent");
+    const sentinel = await fundedKeypair();
+    const [heartbeat] = PublicKey.findProgramAddressSync(
+      [Buffer.from("heartbeat"), agent.toBuffer()],
+      programId
+    );
+    const setBeatInterval = (intervalSecs: number) =>
+      program.methods
+        .setHeartbeatInterval(intervalSecs)
+        .accounts({
+          authority: owner.publicKey,
+          agent,
+          heartbeat,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner])
+        .rpc();
+    const flagMissed = async () => {
+      const { auditSummary, auditEntry } = await agentAuditAccounts(agent);
+      await program.methods
+        .flagMissedHeartbeat()
+        .accounts({
+          caller: provider.wallet.publicKey,
+          agent,
+          heartbeat,
+          auditSummary,
+          auditEntry,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      return auditEntry;
+    };
+    const beat = () =>
+      program.methods
+        .sentinelHeartbeat()
+        .accounts({ sentinel: sentinel.publicKey, agent, heartbeat })
+        .signers([sentinel])
+        .rpc();
+
+    await setBeatInterval(3600);
+
+    // Only a designated sentinel checks in
+    try {
+      await beat();
+      throw new Error("Should have failed with ActorNotAllowed");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("ActorNotAllowed");
+    }
+    await program.methods
+      .setAuditPolicy({
+        alertThreshold: 75,
+        sentinels: [sentinel.publicKey],
+        maxRisk: Array(11).fill(100),
+        complianceOfficer: null,
+      })
+      .accounts({ authority: owner.publicKey, agent, systemProgram: SystemProgram.programId })
+      .signers([owner])
+      .rpc();
+    await beat();
+    let monitor = await program.account.heartbeatMonitor.fetch(heartbeat);
+    expect(monitor.lastSentinel.toString()).to.equal(sentinel.publicKey.toString());
+
+    // Nothing to flag while the sentinel is within its interval
+    try {
+      await flagMissed();
+      throw new Error("Should have failed with HeartbeatNotMissed");
+    } catch (err: unknown) {
+      const error = err as Error;
+      expect(error.message).to.include("HeartbeatNotMissed");
+    }
+
+    // Once the gap exceeds the interval anyone can raise a security alert
+    await setBeatInterval(1);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    const alert = await program.account.auditEntry.fetch(await flagMissed());
+    expect(alert.actionType).to.deep.equal({ securityAlert: {} });
+    expect(alert.isAlert).to.be.true;
+    monitor = await program.account.heartbeatMonitor.fetch(heartbeat);
+    expect(monitor.missedBeats).to.equal(1);
+    expect(monitor.lastFlaggedAt.toNumber()).to.be.greaterThan(0);
+  });
+
   // ============================================
   // Error Path Tests (Critical Security)
   // ============================================
@@ -511,6 +2472,7 @@ describe("agent-registry", () => {
             admin: nonAdmin.publicKey,
             registry: registryPda,
             agent: agentPda,
+            ...(await registryAuditAccounts()),
           })
           .signers([nonAdmin])
           .rpc();
@@ -581,6 +2543,7 @@ describe("agent-registry", () => {
             authority: provider.wallet.publicKey,
             registry: registryPda,
             agent: agentPda,
+            authorizedUpdaters: null,
           })
           .rpc();
         throw new Error("Should have failed with ReputationDeltaTooLarge");
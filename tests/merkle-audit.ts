@@ -5,6 +5,7 @@
  * 1. Register an agent
  * 2. Store Merkle audit root on-chain
  * 3. Verify the stored data
+ * 4. Prove an entry's inclusion on-chain
  */
 
 import * as anchor from "@coral-xyz/anchor";
@@ -110,13 +111,14 @@ describe("Merkle Audit", () => {
       program.programId
     );
 
-    // For first batch, batch_index = 0
-    const batchIndex = new anchor.BN(0);
+    // First batch of epoch 0 has sequence 0
+    const epoch = new anchor.BN(0);
     [merkleAuditRootPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("merkle_audit"),
         agentPda.toBuffer(),
-        batchIndex.toArrayLike(Buffer, "le", 8),
+        epoch.toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 4),
       ],
       program.programId
     );
@@ -132,7 +134,7 @@ describe("Merkle Audit", () => {
     console.log("  Root PDA:", merkleAuditRootPda.toBase58());
 
     const tx = await program.methods
-      .storeMerkleAudit(merkleRoot, entriesCount)
+      .storeMerkleAudit(merkleRoot, entriesCount, epoch)
       .accounts({
         owner,
         agent: agentPda,
@@ -153,6 +155,7 @@ describe("Merkle Audit", () => {
     const root = await program.account.merkleAuditRoot.fetch(merkleAuditRootPda);
     expect(root.entriesCount).to.equal(entriesCount);
     expect(Array.from(root.merkleRoot)).to.deep.equal(merkleRoot);
+    expect(root.sequence).to.equal(0);
     console.log("  ✓ Root verified");
   });
 
@@ -160,13 +163,15 @@ describe("Merkle Audit", () => {
     // Get current summary
     const summaryBefore = await program.account.merkleAuditSummary.fetch(merkleAuditSummaryPda);
     const batchIndex = summaryBefore.totalBatches;
+    const epoch = summaryBefore.currentEpoch;
 
-    // Derive PDA for second batch
+    // Derive PDA for the second batch of the same epoch
     [merkleAuditRootPda] = PublicKey.findProgramAddressSync(
       [
         Buffer.from("merkle_audit"),
         agentPda.toBuffer(),
-        batchIndex.toArrayLike(Buffer, "le", 8),
+        epoch.toArrayLike(Buffer, "le", 8),
+        new anchor.BN(summaryBefore.epochSequence).toArrayLike(Buffer, "le", 4),
       ],
       program.programId
     );
@@ -178,7 +183,7 @@ describe("Merkle Audit", () => {
     console.log("  Batch Index:", batchIndex.toNumber());
 
     await program.methods
-      .storeMerkleAudit(merkleRoot, entriesCount)
+      .storeMerkleAudit(merkleRoot, entriesCount, epoch)
       .accounts({
         owner,
         agent: agentPda,
@@ -192,8 +197,89 @@ describe("Merkle Audit", () => {
     const summaryAfter = await program.account.merkleAuditSummary.fetch(merkleAuditSummaryPda);
     expect(summaryAfter.totalBatches.toNumber()).to.equal(2);
     expect(summaryAfter.totalEntries.toNumber()).to.equal(10 + entriesCount);
+    expect(summaryAfter.epochSequence).to.equal(2);
+
+    const [rootIndexPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("merkle_root_index"), agentPda.toBuffer()],
+      program.programId
+    );
+    const rootIndex = await program.account.merkleRootIndex.fetch(rootIndexPda);
+    expect(rootIndex.roots.map((r) => r.sequence)).to.deep.equal([0, 1]);
+    expect(rootIndex.roots[1].firstEntry.toNumber()).to.equal(10);
+    expect(Array.from(rootIndex.roots[1].merkleRoot)).to.deep.equal(merkleRoot);
     console.log("  ✓ Second batch stored");
     console.log("  Total batches:", summaryAfter.totalBatches.toNumber());
     console.log("  Total entries:", summaryAfter.totalEntries.toNumber());
   });
+
+  it("Verifies an audit entry's inclusion against a stored root", async () => {
+    const sha256 = (data: Buffer) => crypto.createHash("sha256").update(data).digest();
+    const leaves = [0, 1, 2].map(() => crypto.randomBytes(32));
+
+    // Same construction as the off-chain batcher (odd node paired with itself)
+    const left = sha256(Buffer.concat([leaves[0], leaves[1]]));
+    const right = sha256(Buffer.concat([leaves[2], leaves[2]]));
+    const merkleRoot = Array.from(sha256(Buffer.concat([left, right])));
+
+    // Post into a new epoch, which restarts the sequence at 0
+    const summaryBefore = await program.account.merkleAuditSummary.fetch(merkleAuditSummaryPda);
+    const epoch = summaryBefore.currentEpoch.addn(1);
+    [merkleAuditRootPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("merkle_audit"),
+        agentPda.toBuffer(),
+        epoch.toArrayLike(Buffer, "le", 8),
+        new anchor.BN(0).toArrayLike(Buffer, "le", 4),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .storeMerkleAudit(merkleRoot, leaves.length, epoch)
+      .accounts({
+        owner,
+        agent: agentPda,
+        auditSummary: merkleAuditSummaryPda,
+        auditRoot: merkleAuditRootPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Leaf 2: sibling is itself (right), then the left subtree
+    const proof = [
+      { hash: Array.from(leaves[2]), isLeft: false },
+      { hash: Array.from(left), isLeft: true },
+    ];
+    const [attestationPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_inclusion"), merkleAuditRootPda.toBuffer(), leaves[2]],
+      program.programId
+    );
+
+    await program.methods
+      .verifyAuditInclusion(Array.from(leaves[2]), proof)
+      .accounts({
+        verifier: owner,
+        auditRoot: merkleAuditRootPda,
+      })
+      .rpc();
+
+    const attestation = await program.account.auditInclusionAttestation.fetch(attestationPda);
+    expect(attestation.agent.toBase58()).to.equal(agentPda.toBase58());
+    expect(Buffer.from(attestation.leafHash).equals(leaves[2])).to.be.true;
+    console.log("  ✓ Inclusion attested");
+
+    // A proof for a leaf that is not in the batch is rejected
+    try {
+      await program.methods
+        .verifyAuditInclusion(Array.from(crypto.randomBytes(32)), proof)
+        .accounts({
+          verifier: owner,
+          auditRoot: merkleAuditRootPda,
+        })
+        .rpc();
+      expect.fail("Expected InvalidMerkleProof");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidMerkleProof");
+    }
+  });
 });